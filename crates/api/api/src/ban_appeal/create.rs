@@ -0,0 +1,85 @@
+use super::current_ban_time;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_local_user_deleted, get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::source::{
+  ban_appeal::{BanAppeal, BanAppealInsertForm},
+  community::Community,
+};
+use lemmy_db_views_ban_appeal::{
+  BanAppealView,
+  api::{BanAppealResponse, CreateBanAppeal},
+};
+use lemmy_db_views_community_moderator::CommunityModeratorView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_email::admin::send_new_ban_appeal_email;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+
+pub async fn create_ban_appeal(
+  Json(data): Json<CreateBanAppeal>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BanAppealResponse>> {
+  // Banned users need to be able to do this, so only check for deleted accounts
+  check_local_user_deleted(&local_user_view)?;
+  let person = &local_user_view.person;
+
+  // Appeals are not federated, so they can only be resolved by the mods of local communities.
+  if let Some(community_id) = data.community_id {
+    let community = Community::read(&mut context.pool(), community_id).await?;
+    if !community.local {
+      Err(LemmyErrorType::BanAppealRemoteCommunity)?
+    }
+  }
+
+  let Some(banned_at) = current_ban_time(&mut context.pool(), person, data.community_id).await
+  else {
+    Err(LemmyErrorType::NotBanned)?
+  };
+
+  let existing =
+    BanAppeal::read_for_ban(&mut context.pool(), person.id, data.community_id, banned_at).await?;
+  if existing.is_some() {
+    Err(LemmyErrorType::BanAppealAlreadySubmitted)?
+  }
+
+  let slur_regex = slur_regex(&context).await?;
+  let url_blocklist = get_url_blocklist(&context).await?;
+  check_slurs(&data.content, &slur_regex)?;
+  is_valid_body_field(&data.content, false)?;
+  let content = process_markdown(&data.content, &slur_regex, &url_blocklist, &context).await?;
+
+  let form = BanAppealInsertForm::new(person.id, data.community_id, content, banned_at);
+  let appeal = BanAppeal::create(&mut context.pool(), &form).await?;
+  let ban_appeal_view = BanAppealView::read(&mut context.pool(), appeal.id).await?;
+
+  // Let the mods of the community, or the admins for site bans, know about the new appeal.
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  if !local_site.disable_email_notifications {
+    let recipients = match data.community_id {
+      Some(community_id) => {
+        let mut mods = vec![];
+        for m in CommunityModeratorView::for_community(&mut context.pool(), community_id).await? {
+          if let Ok(local_mod) =
+            LocalUserView::read_person(&mut context.pool(), m.moderator.id).await
+          {
+            mods.push(local_mod);
+          }
+        }
+        mods
+      }
+      None => LocalUserView::list_admins_with_emails(&mut context.pool()).await?,
+    };
+    send_new_ban_appeal_email(recipients, &person.name, context.settings());
+  }
+
+  Ok(Json(BanAppealResponse { ban_appeal_view }))
+}
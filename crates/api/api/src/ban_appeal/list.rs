@@ -0,0 +1,35 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_action, check_community_mod_of_any_or_admin_action, is_admin},
+};
+use lemmy_db_schema::source::community::Community;
+use lemmy_db_views_ban_appeal::{BanAppealView, api::ListBanAppeals, impls::BanAppealQuery};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::{pagination::PagedResponse, traits::Crud};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_ban_appeals(
+  Query(data): Query<ListBanAppeals>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<BanAppealView>>> {
+  if let Some(community_id) = data.community_id {
+    let community = Community::read(&mut context.pool(), community_id).await?;
+    check_community_mod_action(&local_user_view, &community, true, &mut context.pool()).await?;
+  } else {
+    check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+  }
+  let is_admin = is_admin(&local_user_view).is_ok();
+
+  let appeals = BanAppealQuery {
+    community_id: data.community_id,
+    unresolved_only: data.unresolved_only,
+    page_cursor: data.page_cursor,
+    limit: data.limit,
+  }
+  .list(&mut context.pool(), local_user_view.person.id, is_admin)
+  .await?;
+
+  Ok(Json(appeals))
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{community::CommunityActions, instance::InstanceActions, person::Person},
+};
+use lemmy_diesel_utils::connection::DbPool;
+
+pub mod create;
+pub mod list;
+pub mod resolve;
+
+/// Time at which the person received the ban which is currently in effect, either in the
+/// community or on the instance.
+async fn current_ban_time(
+  pool: &mut DbPool<'_>,
+  person: &Person,
+  community_id: Option<CommunityId>,
+) -> Option<DateTime<Utc>> {
+  match community_id {
+    Some(community_id) => CommunityActions::read(pool, community_id, person.id)
+      .await
+      .ok()
+      .and_then(|a| a.received_ban_at),
+    None => InstanceActions::read(pool, person.instance_id, person.id)
+      .await
+      .ok()
+      .and_then(|a| a.received_ban_at),
+  }
+}
@@ -0,0 +1,111 @@
+use super::current_ban_time;
+use crate::{community::ban::ban_from_community, local_user::ban_person::ban_from_site};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  utils::{check_community_mod_action, is_admin},
+};
+use lemmy_db_schema::source::{
+  ban_appeal::{BanAppeal, BanAppealUpdateForm},
+  community::Community,
+  modlog::{Modlog, ModlogInsertForm},
+  person::Person,
+};
+use lemmy_db_views_ban_appeal::{
+  BanAppealView,
+  api::{BanAppealResponse, ResolveBanAppeal},
+};
+use lemmy_db_views_community::api::BanFromCommunity;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::api::BanPerson;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+pub async fn resolve_ban_appeal(
+  Json(data): Json<ResolveBanAppeal>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BanAppealResponse>> {
+  let appeal = BanAppeal::read(&mut context.pool(), data.ban_appeal_id).await?;
+  if appeal.resolved_at.is_some() {
+    Err(LemmyErrorType::BanAppealAlreadyResolved)?
+  }
+  is_valid_body_field(&data.reason, false)?;
+
+  // The appeal only applies to the ban it was written for. If that ban has been lifted or
+  // replaced by a newer one in the meantime, resolving the appeal would act on the wrong ban.
+  let person = Person::read(&mut context.pool(), appeal.person_id).await?;
+  let current_ban = current_ban_time(&mut context.pool(), &person, appeal.community_id).await;
+  if current_ban != Some(appeal.banned_at) {
+    Err(LemmyErrorType::BanAppealOutdated)?
+  }
+
+  if data.accept {
+    // Lifting the ban checks permissions, writes the modlog entry, notifies the user and
+    // federates the unban.
+    match appeal.community_id {
+      Some(community_id) => {
+        let unban = BanFromCommunity {
+          community_id,
+          person_id: appeal.person_id,
+          ban: false,
+          remove_or_restore_data: None,
+          reason: data.reason.clone(),
+          expires_at: None,
+        };
+        ban_from_community(Json(unban), context.clone(), local_user_view.clone()).await?;
+      }
+      None => {
+        let unban = BanPerson {
+          person_id: appeal.person_id,
+          ban: false,
+          remove_or_restore_data: None,
+          reason: data.reason.clone(),
+          expires_at: None,
+        };
+        ban_from_site(Json(unban), context.clone(), local_user_view.clone()).await?;
+      }
+    }
+  } else {
+    let form = match appeal.community_id {
+      Some(community_id) => {
+        let community = Community::read(&mut context.pool(), community_id).await?;
+        check_community_mod_action(&local_user_view, &community, false, &mut context.pool())
+          .await?;
+        ModlogInsertForm::mod_deny_ban_appeal(
+          local_user_view.person.id,
+          community_id,
+          appeal.person_id,
+          &data.reason,
+        )
+      }
+      None => {
+        is_admin(&local_user_view)?;
+        ModlogInsertForm::admin_deny_ban_appeal(
+          &local_user_view.person,
+          appeal.person_id,
+          &data.reason,
+        )
+      }
+    };
+    let action = Modlog::create(&mut context.pool(), &[form]).await?;
+    notify_mod_action(action, &context);
+  }
+
+  let form = BanAppealUpdateForm {
+    resolver_id: Some(Some(local_user_view.person.id)),
+    accepted: Some(data.accept),
+    deny_reason: Some((!data.accept).then(|| data.reason.clone())),
+    resolved_at: Some(Some(Utc::now())),
+  };
+  BanAppeal::update(&mut context.pool(), appeal.id, &form).await?;
+  let ban_appeal_view = BanAppealView::read(&mut context.pool(), appeal.id).await?;
+
+  Ok(Json(BanAppealResponse { ban_appeal_view }))
+}
@@ -0,0 +1,35 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_action};
+use lemmy_apub_objects::objects::community::BACKFILL_COMMUNITY;
+use lemmy_db_schema::source::community::Community;
+use lemmy_db_views_community::api::BackfillCommunity;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+const DEFAULT_BACKFILL_PAGES: i32 = 100;
+const MAX_BACKFILL_PAGES: i32 = 1000;
+
+pub async fn backfill_community(
+  Json(data): Json<BackfillCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  if community.local {
+    Err(LemmyErrorType::CantBackfillLocalCommunity)?
+  }
+
+  let max_pages = data
+    .max_pages
+    .unwrap_or(DEFAULT_BACKFILL_PAGES)
+    .clamp(1, MAX_BACKFILL_PAGES);
+  if let Some(backfill_fn) = BACKFILL_COMMUNITY.get() {
+    backfill_fn(community.into(), max_pages, context.reset_request_count());
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
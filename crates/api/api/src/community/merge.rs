@@ -0,0 +1,74 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_deleted_removed, is_admin},
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  modlog::{Modlog, ModlogInsertForm},
+};
+use lemmy_db_views_community::{
+  CommunityView,
+  api::{GetCommunityResponse, MergeCommunity},
+};
+use lemmy_db_views_community_moderator::CommunityModeratorView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn merge_community(
+  Json(data): Json<MergeCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetCommunityResponse>> {
+  // Only admins can merge communities
+  is_admin(&local_user_view)?;
+
+  let source = Community::read(&mut context.pool(), data.community_id).await?;
+  let target = Community::read(&mut context.pool(), data.target_community_id).await?;
+
+  // Only local communities can be merged. For remote communities the origin instance is
+  // authoritative, so moved posts and subscribers would flip back whenever the community or its
+  // posts are refetched. Chains of redirects are not allowed either.
+  if source.id == target.id || !source.local || !target.local || source.moved_to_id.is_some() {
+    Err(LemmyErrorType::InvalidCommunityMerge)?
+  }
+  check_community_deleted_removed(&target)?;
+
+  let source = Community::merge(&mut context.pool(), source.id, target.id).await?;
+
+  let form =
+    ModlogInsertForm::admin_merge_community(local_user_view.person.id, source.id, &data.reason);
+  Modlog::create(&mut context.pool(), &[form]).await?;
+
+  // Let other instances know about the redirect, and about the tags which were moved to the
+  // target community
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), source),
+    &context,
+  )?;
+  let target = Community::read(&mut context.pool(), target.id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), target),
+    &context,
+  )?;
+
+  let community_view = CommunityView::read(
+    &mut context.pool(),
+    data.target_community_id,
+    Some(&local_user_view.local_user),
+    false,
+  )
+  .await?;
+  let moderators =
+    CommunityModeratorView::for_community(&mut context.pool(), data.target_community_id).await?;
+
+  Ok(Json(GetCommunityResponse {
+    community_view,
+    site: None,
+    moderators,
+    discussion_languages: vec![],
+  }))
+}
@@ -16,10 +16,13 @@ use lemmy_db_views_community_moderator::CommunityPersonBanView;
 use lemmy_utils::error::LemmyResult;
 
 pub mod add_mod;
+pub mod backfill;
 pub mod ban;
 pub mod block;
 pub mod follow;
+pub mod merge;
 pub mod multi_community_follow;
+pub mod mute;
 pub mod pending_follows;
 pub mod random;
 pub mod tag;
@@ -0,0 +1,80 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, check_expire_time},
+};
+use lemmy_db_schema::source::{
+  community::{Community, CommunityActions, CommunityPersonMuteForm},
+  local_user::LocalUser,
+  modlog::{Modlog, ModlogInsertForm},
+};
+use lemmy_db_views_community::api::MuteFromCommunity;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{PersonView, api::PersonResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
+
+pub async fn mute_from_community(
+  Json(data): Json<MuteFromCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonResponse>> {
+  let my_person_id = local_user_view.person.id;
+  let expires_at = check_expire_time(data.expires_at)?;
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+  LocalUser::is_higher_mod_or_admin_check(
+    &mut context.pool(),
+    data.community_id,
+    my_person_id,
+    vec![data.person_id],
+  )
+  .await?;
+  is_valid_body_field(&data.reason, false)?;
+
+  let form = CommunityPersonMuteForm {
+    mute_expires_at: Some(expires_at),
+    ..CommunityPersonMuteForm::new(data.community_id, data.person_id)
+  };
+  if data.mute {
+    CommunityActions::mute(&mut context.pool(), &form).await?;
+  } else {
+    CommunityActions::unmute(&mut context.pool(), &form).await?;
+  }
+
+  let form = ModlogInsertForm::mod_mute_from_community(
+    my_person_id,
+    data.community_id,
+    data.person_id,
+    data.mute,
+    expires_at,
+    &data.reason,
+  );
+  let action = Modlog::create(&mut context.pool(), &[form]).await?;
+  notify_mod_action(action, &context);
+
+  let person_view = PersonView::read(
+    &mut context.pool(),
+    data.person_id,
+    Some(my_person_id),
+    local_user_view.person.instance_id,
+    true,
+  )
+  .await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::MuteFromCommunity {
+      moderator: local_user_view.person,
+      community_id: data.community_id,
+      target: person_view.person.clone(),
+      data,
+    },
+    &context,
+  )?;
+
+  Ok(Json(PersonResponse { person_view }))
+}
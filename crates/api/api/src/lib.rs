@@ -1,8 +1,18 @@
+use activitypub_federation::config::Data;
 use base64::{Engine, engine::general_purpose::STANDARD_NO_PAD as base64};
 use captcha::Captcha;
-use lemmy_api_utils::{context::LemmyContext, utils::is_mod_or_admin_opt};
-use lemmy_db_schema::newtypes::CommunityId;
+use chrono::{DateTime, TimeDelta, Utc};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::is_mod_or_admin_opt,
+};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{local_site::LocalSite, person::Person, rule::Rule, site::Site},
+};
 use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::SiteView;
 use lemmy_utils::{
   error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
   utils::slurs::check_slurs,
@@ -10,7 +20,9 @@ use lemmy_utils::{
 use regex::Regex;
 use std::io::Cursor;
 use totp_rs::{Secret, TOTP};
+use url::Url;
 
+pub mod ban_appeal;
 pub mod comment;
 pub mod community;
 pub mod federation;
@@ -72,6 +84,47 @@ pub(crate) fn check_report_reason(reason: &str, slur_regex: &Regex) -> LemmyResu
   }
 }
 
+/// Reports which cite a site rule are meant for admins, not for community mods.
+pub(crate) fn violates_instance_rules(
+  violates_instance_rules: Option<bool>,
+  rule: &Option<Rule>,
+) -> bool {
+  violates_instance_rules.unwrap_or_default()
+    || rule.as_ref().is_some_and(|r| r.community_id.is_none())
+}
+
+/// Send a copy of the report to the admins of the instance where the reported user is registered.
+/// Nothing is sent for local users, or if the remote instance is unknown.
+///
+/// The report is sent by the system account which acts on behalf of this instance, so that the
+/// identity of the reporter is not revealed to the remote instance.
+pub(crate) async fn forward_report_to_origin(
+  reported: &Person,
+  object_id: Option<Url>,
+  reason: &str,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  if reported.local {
+    return Ok(());
+  }
+  let Ok(site) = Site::read_from_instance_id(&mut context.pool(), reported.instance_id).await
+  else {
+    return Ok(());
+  };
+  let actor = SiteView::read_system_account(&mut context.pool()).await?;
+  let mut object_ids = vec![reported.ap_id.inner().clone()];
+  object_ids.extend(object_id);
+  ActivityChannel::submit_activity(
+    SendActivityData::ForwardReport {
+      object_ids,
+      actor,
+      receiver: site,
+      reason: reason.to_string(),
+    },
+    context,
+  )
+}
+
 pub(crate) fn check_totp_2fa_valid(
   local_user_view: &LocalUserView,
   totp_token: &Option<String>,
@@ -139,6 +192,23 @@ async fn hide_modlog_names(
   }
 }
 
+/// Apply the modlog retention settings of the site for users who aren't admins. Returns the time
+/// of the oldest visible entry, and the time before which moderators are hidden.
+fn modlog_retention(
+  local_user_view: Option<&LocalUserView>,
+  local_site: &LocalSite,
+) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+  if local_user_view.is_some_and(|l| l.local_user.admin) {
+    return (None, None);
+  }
+  let days_ago =
+    |days: Option<i32>| days.and_then(|d| Utc::now().checked_sub_signed(TimeDelta::days(d.into())));
+  (
+    days_ago(local_site.modlog_retention_days),
+    days_ago(local_site.modlog_anonymize_days),
+  )
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -11,8 +11,9 @@ use lemmy_db_schema::{
     instance::{InstanceActions, InstanceBanForm},
     local_user::LocalUser,
     modlog::{Modlog, ModlogInsertForm},
+    person_report::PersonReport,
   },
-  traits::Bannable,
+  traits::{Bannable, Reportable},
 };
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_person::{
@@ -46,6 +47,7 @@ pub async fn ban_from_site(
   );
   if data.ban {
     InstanceActions::ban(&mut context.pool(), &form).await?;
+    PersonReport::resolve_all_for_object(&mut context.pool(), data.person_id, my_person_id).await?;
   } else {
     InstanceActions::unban(&mut context.pool(), &form).await?;
   }
@@ -0,0 +1,73 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_local_user_valid,
+};
+use lemmy_db_schema::{
+  source::{
+    instance::{Instance, InstanceActions},
+    person::{Person, PersonActions, PersonFollowerForm},
+  },
+  traits::{Blockable, Followable},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{
+  PersonView,
+  api::{FollowPerson, PersonResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn follow_person(
+  Json(data): Json<FollowPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  let target_id = data.person_id;
+  let person = &local_user_view.person;
+
+  if target_id == person.id {
+    Err(LemmyErrorType::CantFollowYourself)?
+  }
+
+  let target = Person::read(&mut context.pool(), target_id).await?;
+
+  if data.follow {
+    // Don't let people follow someone who blocked them, or who blocked their instance
+    PersonActions::read_block(&mut context.pool(), target.id, person.id).await?;
+    InstanceActions::read_persons_block(&mut context.pool(), target.id, person.instance_id).await?;
+
+    // Nothing would be federated to an instance which is blocked here
+    let blocked_instances = Instance::blocklist(&mut context.pool()).await?;
+    if blocked_instances.iter().any(|i| i.id == target.instance_id) {
+      Err(LemmyErrorType::InstanceIsBlocked)?
+    }
+
+    // Local follow is accepted immediately, remote follow needs to be federated first
+    let form = PersonFollowerForm::new(target.id, person.id, !target.local);
+    PersonActions::follow(&mut context.pool(), &form).await?;
+  } else {
+    PersonActions::unfollow(&mut context.pool(), person.id, target.id).await?;
+  }
+
+  // Send the federated follow
+  if !target.local {
+    ActivityChannel::submit_activity(
+      SendActivityData::FollowPerson(target, person.clone(), data.follow),
+      &context,
+    )?;
+  }
+
+  let person_view = PersonView::read(
+    &mut context.pool(),
+    target_id,
+    Some(person.id),
+    person.instance_id,
+    false,
+  )
+  .await?;
+  Ok(Json(PersonResponse { person_view }))
+}
@@ -0,0 +1,61 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::check_local_user_valid};
+use lemmy_db_schema_file::PersonId;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{PersonView, api::ListPersonFollows};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn list_person_followers(
+  Query(data): Query<ListPersonFollows>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<PersonView>>> {
+  check_can_list_follows(data.person_id, &local_user_view)?;
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+
+  let res = PersonView::list_followers(
+    &mut context.pool(),
+    data.person_id,
+    Some(local_user_view.person.id),
+    site_view.site.instance_id,
+    data.page_cursor,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(res))
+}
+
+pub async fn list_person_following(
+  Query(data): Query<ListPersonFollows>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<PersonView>>> {
+  check_can_list_follows(data.person_id, &local_user_view)?;
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+
+  let res = PersonView::list_following(
+    &mut context.pool(),
+    data.person_id,
+    Some(local_user_view.person.id),
+    site_view.site.instance_id,
+    data.page_cursor,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(res))
+}
+
+/// Follows would expose the social graph of a user, so they are only visible to the user
+/// themselves and to admins.
+fn check_can_list_follows(person_id: PersonId, local_user_view: &LocalUserView) -> LemmyResult<()> {
+  check_local_user_valid(local_user_view)?;
+  if local_user_view.person.id != person_id && !local_user_view.local_user.admin {
+    Err(LemmyErrorType::PersonFollowsArePrivate)?
+  }
+  Ok(())
+}
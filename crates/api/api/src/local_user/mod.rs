@@ -5,12 +5,14 @@ pub mod change_password;
 pub mod change_password_after_reset;
 pub mod donation_dialog_shown;
 pub mod export_data;
+pub mod follow_person;
 pub mod generate_totp_secret;
 pub mod get_captcha;
 pub mod list_hidden;
 pub mod list_liked;
 pub mod list_logins;
 pub mod list_media;
+pub mod list_person_follows;
 pub mod list_read;
 pub mod list_saved;
 pub mod login;
@@ -19,9 +21,11 @@ pub mod note_person;
 pub mod notifications;
 pub mod resend_verification_email;
 pub mod reset_password;
+pub mod restrict_person;
 pub mod save_settings;
 pub mod unread_counts;
 pub mod update_totp;
 pub mod user_block_instance;
 pub mod validate_auth;
 pub mod verify_email;
+pub mod warn_person;
@@ -0,0 +1,104 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::NotifyData,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::is_admin,
+};
+use lemmy_db_schema::source::{
+  comment::Comment,
+  community::Community,
+  local_user::{LocalUser, LocalUserUpdateForm},
+  modlog::{Modlog, ModlogInsertForm},
+  post::Post,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::api::RestrictPerson;
+use lemmy_db_views_site::{SiteView, api::SuccessResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn restrict_person(
+  Json(data): Json<RestrictPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  // Also make sure you're a higher admin than the target
+  LocalUser::is_higher_admin_check(
+    &mut context.pool(),
+    local_user_view.person.id,
+    vec![data.person_id],
+  )
+  .await?;
+
+  let target = LocalUserView::read_person(&mut context.pool(), data.person_id).await?;
+  if data.restrict == target.local_user.restricted_at.is_some() {
+    return Ok(Json(SuccessResponse::default()));
+  }
+
+  let form = LocalUserUpdateForm {
+    restricted_at: Some(data.restrict.then(Utc::now)),
+    ..Default::default()
+  };
+  LocalUser::update(&mut context.pool(), target.local_user.id, &form).await?;
+
+  let form =
+    ModlogInsertForm::admin_restrict_user(&local_user_view.person, data.person_id, data.restrict);
+  Modlog::create(&mut context.pool(), &[form]).await?;
+
+  if !data.restrict {
+    publish_held_content(&target, &context).await?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+/// Publish everything which the user wrote while restricted, as if it was created just now. Posts
+/// go out before comments, so that remote instances can resolve the post of each comment. Content
+/// which was removed or deleted in the meantime is released too, but not federated or notified.
+async fn publish_held_content(
+  target: &LocalUserView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  let do_send_email = !local_site.disable_email_notifications;
+
+  let posts = Post::release_held_for_creator(&mut context.pool(), target.person.id).await?;
+  for post in posts {
+    if post.removed || post.deleted {
+      continue;
+    }
+    let community = Community::read(&mut context.pool(), post.community_id).await?;
+    // Scheduled posts are federated by the scheduled task once they are due
+    if post.scheduled_publish_time_at.is_none() {
+      ActivityChannel::submit_activity(SendActivityData::CreatePost(post.clone()), context)?;
+    }
+    NotifyData {
+      do_send_email,
+      ..NotifyData::new(post, target.person.clone(), community)
+    }
+    .send(context);
+  }
+
+  let comments = Comment::release_held_for_creator(&mut context.pool(), target.person.id).await?;
+  for comment in comments {
+    if comment.removed || comment.deleted {
+      continue;
+    }
+    let post = Post::read(&mut context.pool(), comment.post_id).await?;
+    let community = Community::read(&mut context.pool(), post.community_id).await?;
+    ActivityChannel::submit_activity(SendActivityData::CreateComment(comment.clone()), context)?;
+    NotifyData {
+      comment: Some(comment),
+      do_send_email,
+      ..NotifyData::new(post, target.person.clone(), community)
+    }
+    .send(context);
+  }
+  Ok(())
+}
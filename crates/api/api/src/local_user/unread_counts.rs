@@ -4,8 +4,10 @@ use lemmy_api_utils::{
   context::LemmyContext,
   utils::{check_community_mod_of_any_or_admin_action, is_admin},
 };
+use lemmy_db_views_ban_appeal::BanAppealView;
 use lemmy_db_views_community_follower_approval::PendingFollowerView;
 use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::ModmailThreadView;
 use lemmy_db_views_notification::NotificationView;
 use lemmy_db_views_registration_applications::RegistrationApplicationView;
 use lemmy_db_views_report_combined::ReportCombinedViewInternal;
@@ -21,22 +23,26 @@ pub async fn get_unread_counts(
 
   let notification_count =
     NotificationView::get_unread_count(&mut context.pool(), person, show_bot_accounts).await?;
+  let modmail_count = ModmailThreadView::get_unread_count(&mut context.pool(), person.id).await?;
 
-  // Community mods get additional counts for reports and pending follows for private communities.
-  let (report_count, pending_follow_count) =
+  // Community mods get additional counts for reports, pending follows for private communities
+  // and ban appeals.
+  let (report_count, pending_follow_count, ban_appeal_count) =
     if check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool())
       .await
       .is_ok()
     {
+      let is_admin = is_admin(&local_user_view).is_ok();
       (
         Some(
           ReportCombinedViewInternal::get_report_count(&mut context.pool(), &local_user_view)
             .await?,
         ),
         Some(PendingFollowerView::count_approval_required(&mut context.pool(), person.id).await?),
+        Some(BanAppealView::get_unresolved_count(&mut context.pool(), person.id, is_admin).await?),
       )
     } else {
-      (None, None)
+      (None, None, None)
     };
 
   // Admins also get the number of unread registration applications.
@@ -56,5 +62,7 @@ pub async fn get_unread_counts(
     report_count,
     pending_follow_count,
     registration_application_count,
+    modmail_count,
+    ban_appeal_count,
   }))
 }
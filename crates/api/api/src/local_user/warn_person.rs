@@ -0,0 +1,290 @@
+use crate::{community::ban::ban_from_community, local_user::ban_person::ban_from_site};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::{DateTime, Days, Utc};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::notify_mod_action,
+  utils::{check_community_mod_action, is_admin},
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  instance::InstanceActions,
+  local_user::LocalUser,
+  modlog::{Modlog, ModlogInsertForm},
+};
+use lemmy_db_views_community::api::BanFromCommunity;
+use lemmy_db_views_community_moderator::CommunityPersonBanView;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{
+  PersonView,
+  api::{BanPerson, PersonResponse, WarnPerson},
+};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+pub async fn warn_person(
+  Json(data): Json<WarnPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonResponse>> {
+  let my_person_id = local_user_view.person.id;
+  is_valid_body_field(&data.reason, false)?;
+
+  // Check permissions and read the escalation settings of the community or site
+  let (form, threshold, window_days, ban_days) = match data.community_id {
+    Some(community_id) => {
+      let community = Community::read(&mut context.pool(), community_id).await?;
+      check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+      LocalUser::is_higher_mod_or_admin_check(
+        &mut context.pool(),
+        community_id,
+        my_person_id,
+        vec![data.person_id],
+      )
+      .await?;
+      let form =
+        ModlogInsertForm::mod_warn_user(my_person_id, community_id, data.person_id, &data.reason);
+      (
+        form,
+        community.strike_ban_threshold,
+        community.strike_window_days,
+        community.strike_ban_days,
+      )
+    }
+    None => {
+      is_admin(&local_user_view)?;
+      LocalUser::is_higher_admin_check(&mut context.pool(), my_person_id, vec![data.person_id])
+        .await?;
+      let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+      let form =
+        ModlogInsertForm::admin_warn_user(&local_user_view.person, data.person_id, &data.reason);
+      (
+        form,
+        local_site.strike_ban_threshold,
+        local_site.strike_window_days,
+        local_site.strike_ban_days,
+      )
+    }
+  };
+  // Compute the dates before writing anything, so that broken settings don't leave a half-done
+  // warning behind
+  let (since, expires_at) = strike_dates(Utc::now(), window_days, ban_days)?;
+
+  let action = Modlog::create(&mut context.pool(), &[form]).await?;
+  notify_mod_action(action, &context);
+
+  if let Some(threshold) = threshold {
+    let strikes = Modlog::count_warnings(
+      &mut context.pool(),
+      data.person_id,
+      data.community_id,
+      since,
+    )
+    .await?;
+    if strikes >= i64::from(threshold) {
+      escalate(&data, strikes, expires_at, &context, &local_user_view).await?;
+    }
+  }
+
+  let person_view = PersonView::read(
+    &mut context.pool(),
+    data.person_id,
+    Some(my_person_id),
+    local_user_view.person.instance_id,
+    true,
+  )
+  .await?;
+  Ok(Json(PersonResponse { person_view }))
+}
+
+/// Ban the person for the configured time after reaching the strike threshold. The ban is
+/// attributed to the mod who gave the last warning, and goes through the regular ban handlers so
+/// that it gets its own modlog entry, notification and federation. Users who are already banned
+/// are left alone, so that a permanent ban isn't replaced with a temporary one.
+async fn escalate(
+  data: &WarnPerson,
+  strikes: i64,
+  expires_at: DateTime<Utc>,
+  context: &Data<LemmyContext>,
+  local_user_view: &LocalUserView,
+) -> LemmyResult<()> {
+  let reason = format!("Automatically banned after {strikes} warnings");
+  let expires_at = Some(expires_at.timestamp());
+  match data.community_id {
+    Some(community_id) => {
+      let is_banned =
+        CommunityPersonBanView::check(&mut context.pool(), data.person_id, community_id)
+          .await
+          .is_err();
+      if !is_banned {
+        let ban = BanFromCommunity {
+          community_id,
+          person_id: data.person_id,
+          ban: true,
+          remove_or_restore_data: None,
+          reason,
+          expires_at,
+        };
+        ban_from_community(Json(ban), context.clone(), local_user_view.clone()).await?;
+      }
+    }
+    None => {
+      let is_banned = InstanceActions::read(
+        &mut context.pool(),
+        local_user_view.person.instance_id,
+        data.person_id,
+      )
+      .await
+      .is_ok_and(|a| a.received_ban_at.is_some());
+      if !is_banned {
+        let ban = BanPerson {
+          person_id: data.person_id,
+          ban: true,
+          remove_or_restore_data: None,
+          reason,
+          expires_at,
+        };
+        ban_from_site(Json(ban), context.clone(), local_user_view.clone()).await?;
+      }
+    }
+  }
+  Ok(())
+}
+
+/// Returns the start of the strike window and the end of the resulting ban. Fails instead of
+/// panicking if the day counts are negative or out of range.
+fn strike_dates(
+  now: DateTime<Utc>,
+  window_days: i32,
+  ban_days: i32,
+) -> LemmyResult<(DateTime<Utc>, DateTime<Utc>)> {
+  let days = |d: i32| u64::try_from(d).ok().map(Days::new);
+  let since = days(window_days).and_then(|d| now.checked_sub_days(d));
+  let expires_at = days(ban_days).and_then(|d| now.checked_add_days(d));
+  match (since, expires_at) {
+    (Some(since), Some(expires_at)) => Ok((since, expires_at)),
+    _ => Err(LemmyErrorType::InvalidStrikeSettings)?,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_db_schema::source::{
+    community::{
+      CommunityActions,
+      CommunityInsertForm,
+      CommunityModeratorForm,
+      CommunityUpdateForm,
+    },
+    instance::Instance,
+    local_user::LocalUserInsertForm,
+    person::{Person, PersonInsertForm},
+  };
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[test]
+  fn test_strike_dates() -> LemmyResult<()> {
+    let now = Utc::now();
+    let (since, expires_at) = strike_dates(now, 30, 7)?;
+    assert_eq!(now - Days::new(30), since);
+    assert_eq!(now + Days::new(7), expires_at);
+
+    assert!(strike_dates(now, -1, 7).is_err());
+    assert!(strike_dates(now, 30, -1).is_err());
+    assert!(strike_dates(now, i32::MAX, 7).is_err());
+    assert!(strike_dates(now, 30, i32::MAX).is_err());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_warnings_escalate_to_ban() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+
+    let mod_person =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "warner")).await?;
+    LocalUser::create(pool, &LocalUserInsertForm::test_form(mod_person.id), vec![]).await?;
+    let mod_view = LocalUserView::read_person(pool, mod_person.id).await?;
+    let target = Person::create(pool, &PersonInsertForm::test_form(instance.id, "warned")).await?;
+
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "strikes".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let update_form = CommunityUpdateForm {
+      strike_ban_threshold: Some(Some(2)),
+      strike_window_days: Some(30),
+      strike_ban_days: Some(7),
+      ..Default::default()
+    };
+    Community::update(pool, community.id, &update_form).await?;
+    let moderator_form = CommunityModeratorForm::new(community.id, mod_person.id);
+    CommunityActions::join(pool, &moderator_form).await?;
+
+    let warn = || {
+      Json(WarnPerson {
+        person_id: target.id,
+        community_id: Some(community.id),
+        reason: "rule violation".to_string(),
+      })
+    };
+
+    // The first warning stays below the threshold
+    warn_person(warn(), context.clone(), mod_view.clone()).await?;
+    assert!(
+      CommunityPersonBanView::check(pool, target.id, community.id)
+        .await
+        .is_ok()
+    );
+
+    // Reaching the threshold bans the user
+    warn_person(warn(), context.clone(), mod_view.clone()).await?;
+    assert!(
+      CommunityPersonBanView::check(pool, target.id, community.id)
+        .await
+        .is_err()
+    );
+
+    // After the ban is lifted, earlier warnings don't count anymore
+    let unban = BanFromCommunity {
+      community_id: community.id,
+      person_id: target.id,
+      ban: false,
+      remove_or_restore_data: None,
+      reason: "served".to_string(),
+      expires_at: None,
+    };
+    ban_from_community(Json(unban), context.clone(), mod_view.clone()).await?;
+    warn_person(warn(), context.clone(), mod_view.clone()).await?;
+    assert!(
+      CommunityPersonBanView::check(pool, target.id, community.id)
+        .await
+        .is_ok()
+    );
+    assert_eq!(
+      1,
+      Modlog::count_warnings(
+        pool,
+        target.id,
+        Some(community.id),
+        Utc::now() - Days::new(30),
+      )
+      .await?
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
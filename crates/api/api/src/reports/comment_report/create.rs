@@ -1,4 +1,4 @@
-use crate::check_report_reason;
+use crate::{check_report_reason, forward_report_to_origin, violates_instance_rules};
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use either::Either;
@@ -10,6 +10,7 @@ use lemmy_api_utils::{
     check_comment_deleted_or_removed,
     check_community_user_action,
     check_local_user_valid,
+    check_rule_in_community,
     slur_regex,
   },
 };
@@ -58,13 +59,16 @@ pub async fn create_comment_report(
 
   // Don't allow creating reports for removed / deleted comments
   check_comment_deleted_or_removed(&comment_view.comment)?;
+  let rule =
+    check_rule_in_community(data.rule_id, comment_view.community.id, &mut context.pool()).await?;
 
   let report_form = CommentReportForm {
     creator_id: person.id,
     comment_id,
     original_comment_text: comment_view.comment.content,
     reason,
-    violates_instance_rules: data.violates_instance_rules.unwrap_or_default(),
+    violates_instance_rules: violates_instance_rules(data.violates_instance_rules, &rule),
+    rule_id: data.rule_id,
   };
 
   let report = CommentReport::report(&mut context.pool(), &report_form).await?;
@@ -85,12 +89,23 @@ pub async fn create_comment_report(
     .await?;
   }
 
+  if data.forward_to_origin.unwrap_or_default() {
+    forward_report_to_origin(
+      &comment_report_view.comment_creator,
+      Some(comment_view.comment.ap_id.inner().clone()),
+      &data.reason,
+      &context,
+    )
+    .await?;
+  }
+
   ActivityChannel::submit_activity(
     SendActivityData::CreateReport {
       object_id: comment_view.comment.ap_id.inner().clone(),
       actor: local_user_view.person,
       receiver: Either::Right(comment_view.community),
       reason: data.reason.clone(),
+      rule: rule.map(|r| r.ap_id.into()),
     },
     &context,
   )?;
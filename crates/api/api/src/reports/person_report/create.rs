@@ -0,0 +1,77 @@
+use crate::{check_report_reason, forward_report_to_origin};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  plugins::plugin_hook_after,
+  utils::{check_local_user_valid, slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    person::Person,
+    person_report::{PersonReport, PersonReportForm},
+  },
+  traits::Reportable,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  ReportCombinedViewInternal,
+  api::{CreatePersonReport, PersonReportResponse},
+};
+use lemmy_db_views_site::SiteView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_email::admin::send_new_report_email_to_admins;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Creates a report on a user profile and notifies the admins
+pub async fn create_person_report(
+  Json(data): Json<CreatePersonReport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonReportResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  let reason = data.reason.trim().to_string();
+  let slur_regex = slur_regex(&context).await?;
+  check_report_reason(&reason, &slur_regex)?;
+
+  let person = &local_user_view.person;
+  let reported = Person::read(&mut context.pool(), data.person_id).await?;
+  if reported.id == person.id {
+    Err(LemmyErrorType::CouldntCreate)?
+  }
+
+  let report_form = PersonReportForm {
+    creator_id: person.id,
+    person_id: reported.id,
+    original_person_name: reported.name.clone(),
+    original_person_display_name: reported.display_name.clone(),
+    original_person_bio: reported.bio.clone(),
+    original_person_avatar: reported.avatar.clone(),
+    original_person_banner: reported.banner.clone(),
+    reason,
+  };
+
+  let report = PersonReport::report(&mut context.pool(), &report_form).await?;
+
+  let person_report_view =
+    ReportCombinedViewInternal::read_person_report(&mut context.pool(), report.id, person).await?;
+  plugin_hook_after("person_report_after_create", &person_report_view);
+
+  // Email the admins
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  if local_site.reports_email_admins {
+    send_new_report_email_to_admins(
+      &person_report_view.creator.name,
+      &person_report_view.person.name,
+      &mut context.pool(),
+      context.settings(),
+    )
+    .await?;
+  }
+
+  if data.forward_to_origin.unwrap_or_default() {
+    forward_report_to_origin(&reported, None, &data.reason, &context).await?;
+  }
+
+  Ok(Json(PersonReportResponse { person_report_view }))
+}
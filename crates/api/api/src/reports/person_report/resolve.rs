@@ -0,0 +1,28 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::{source::person_report::PersonReport, traits::Reportable};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  ReportCombinedViewInternal,
+  api::{PersonReportResponse, ResolvePersonReport},
+};
+use lemmy_utils::error::LemmyResult;
+
+/// Resolves or unresolves a person report
+pub async fn resolve_person_report(
+  Json(data): Json<ResolvePersonReport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonReportResponse>> {
+  is_admin(&local_user_view)?;
+
+  let report_id = data.report_id;
+  let person = &local_user_view.person;
+  PersonReport::update_resolved(&mut context.pool(), report_id, person.id, data.resolved).await?;
+
+  let person_report_view =
+    ReportCombinedViewInternal::read_person_report(&mut context.pool(), report_id, person).await?;
+
+  Ok(Json(PersonReportResponse { person_report_view }))
+}
@@ -1,4 +1,4 @@
-use crate::check_report_reason;
+use crate::{check_report_reason, forward_report_to_origin, violates_instance_rules};
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use either::Either;
@@ -10,6 +10,7 @@ use lemmy_api_utils::{
     check_community_user_action,
     check_local_user_valid,
     check_post_deleted_or_removed,
+    check_rule_in_community,
     slur_regex,
   },
 };
@@ -53,6 +54,8 @@ pub async fn create_post_report(
   check_community_user_action(&local_user_view, &orig_post.community, &mut context.pool()).await?;
 
   check_post_deleted_or_removed(&orig_post.post)?;
+  let rule =
+    check_rule_in_community(data.rule_id, orig_post.community.id, &mut context.pool()).await?;
 
   let report_form = PostReportForm {
     creator_id: person.id,
@@ -61,7 +64,8 @@ pub async fn create_post_report(
     original_post_url: orig_post.post.url,
     original_post_body: orig_post.post.body,
     reason,
-    violates_instance_rules: data.violates_instance_rules.unwrap_or_default(),
+    violates_instance_rules: violates_instance_rules(data.violates_instance_rules, &rule),
+    rule_id: data.rule_id,
   };
 
   let report = PostReport::report(&mut context.pool(), &report_form).await?;
@@ -82,12 +86,23 @@ pub async fn create_post_report(
     .await?;
   }
 
+  if data.forward_to_origin.unwrap_or_default() {
+    forward_report_to_origin(
+      &post_report_view.post_creator,
+      Some(orig_post.post.ap_id.inner().clone()),
+      &data.reason,
+      &context,
+    )
+    .await?;
+  }
+
   ActivityChannel::submit_activity(
     SendActivityData::CreateReport {
       object_id: orig_post.post.ap_id.inner().clone(),
       actor: local_user_view.person,
       receiver: Either::Right(orig_post.community),
       reason: data.reason.clone(),
+      rule: rule.map(|r| r.ap_id.into()),
     },
     &context,
   )?;
@@ -0,0 +1,39 @@
+use super::check_report_mod_action;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::combined::report::ReportCombined;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  ReportCombinedViewInternal,
+  api::{AssignReport, ReportCombinedResponse},
+};
+use lemmy_utils::error::LemmyResult;
+
+/// Assigns a report to a mod, or removes the assignment. The assignee needs to be able to
+/// resolve the report.
+pub async fn assign_report(
+  Json(data): Json<AssignReport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ReportCombinedResponse>> {
+  let person = &local_user_view.person;
+  let report =
+    ReportCombinedViewInternal::read(&mut context.pool(), data.report_id, person).await?;
+  check_report_mod_action(&report, &local_user_view, &mut context.pool()).await?;
+
+  if let Some(assignee_id) = data.assignee_id
+    && assignee_id != person.id
+  {
+    let assignee = LocalUserView::read_person(&mut context.pool(), assignee_id).await?;
+    check_report_mod_action(&report, &assignee, &mut context.pool()).await?;
+  }
+
+  ReportCombined::assign(&mut context.pool(), data.report_id, data.assignee_id).await?;
+
+  let report_combined_view =
+    ReportCombinedViewInternal::read(&mut context.pool(), data.report_id, person).await?;
+  Ok(Json(ReportCombinedResponse {
+    report_combined_view,
+  }))
+}
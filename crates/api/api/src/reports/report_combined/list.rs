@@ -30,6 +30,9 @@ pub async fn list_reports(
     unresolved_only: data.unresolved_only,
     show_community_rule_violations: data.show_community_rule_violations,
     my_reports_only,
+    rule_id: data.rule_id,
+    assignee_id: data.assignee_id,
+    unassigned_only: data.unassigned_only,
     page_cursor: data.page_cursor,
     limit: data.limit,
   }
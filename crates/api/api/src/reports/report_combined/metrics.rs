@@ -0,0 +1,29 @@
+use actix_web::web::{Data, Json, Query};
+use chrono::{Days, Utc};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  CommunityReportMetricsView,
+  api::{GetReportMetrics, GetReportMetricsResponse},
+};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Report metrics cover at most one year.
+const MAX_METRICS_DAYS: i32 = 365;
+
+pub async fn get_report_metrics(
+  Query(data): Query<GetReportMetrics>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetReportMetricsResponse>> {
+  is_admin(&local_user_view)?;
+
+  let days = data.days.unwrap_or(30).clamp(1, MAX_METRICS_DAYS);
+  let since = Utc::now()
+    .checked_sub_days(Days::new(days.unsigned_abs().into()))
+    .ok_or(LemmyErrorType::InvalidUnixTime)?;
+  let communities =
+    CommunityReportMetricsView::list(&mut context.pool(), since, data.limit).await?;
+
+  Ok(Json(GetReportMetricsResponse { communities }))
+}
@@ -1 +1,28 @@
+use lemmy_api_utils::utils::{check_community_mod_action, is_admin};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::ReportCombinedView;
+use lemmy_diesel_utils::connection::DbPool;
+use lemmy_utils::error::LemmyResult;
+
+pub mod assign;
 pub mod list;
+pub mod metrics;
+
+/// Post and comment reports are handled by the community mods, all other reports by admins.
+pub(crate) async fn check_report_mod_action(
+  report: &ReportCombinedView,
+  local_user_view: &LocalUserView,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  match report {
+    ReportCombinedView::Post(r) => {
+      check_community_mod_action(local_user_view, &r.community, true, pool).await
+    }
+    ReportCombinedView::Comment(r) => {
+      check_community_mod_action(local_user_view, &r.community, true, pool).await
+    }
+    ReportCombinedView::PrivateMessage(_)
+    | ReportCombinedView::Community(_)
+    | ReportCombinedView::Person(_) => is_admin(local_user_view),
+  }
+}
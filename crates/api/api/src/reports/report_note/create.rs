@@ -0,0 +1,42 @@
+use crate::reports::report_combined::check_report_mod_action;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::source::report_note::{ReportNote, ReportNoteInsertForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  ReportCombinedViewInternal,
+  ReportNoteView,
+  api::{CreateReportNote, ReportNoteResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+
+pub async fn create_report_note(
+  Json(data): Json<CreateReportNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ReportNoteResponse>> {
+  let person = &local_user_view.person;
+  let report =
+    ReportCombinedViewInternal::read(&mut context.pool(), data.report_id, person).await?;
+  check_report_mod_action(&report, &local_user_view, &mut context.pool()).await?;
+
+  let slur_regex = slur_regex(&context).await?;
+  let url_blocklist = get_url_blocklist(&context).await?;
+  check_slurs(&data.content, &slur_regex)?;
+  is_valid_body_field(&data.content, false)?;
+  let content = process_markdown(&data.content, &slur_regex, &url_blocklist, &context).await?;
+
+  let form = ReportNoteInsertForm::new(data.report_id, person.id, content);
+  let note = ReportNote::create(&mut context.pool(), &form).await?;
+  let report_note_view = ReportNoteView::read(&mut context.pool(), note.id).await?;
+
+  Ok(Json(ReportNoteResponse { report_note_view }))
+}
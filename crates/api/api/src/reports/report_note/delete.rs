@@ -0,0 +1,34 @@
+use crate::reports::report_combined::check_report_mod_action;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::report_note::ReportNote;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{ReportCombinedViewInternal, api::DeleteReportNote};
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn delete_report_note(
+  Json(data): Json<DeleteReportNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let note = ReportNote::read(&mut context.pool(), data.note_id).await?;
+  if note.creator_id != local_user_view.person.id {
+    Err(LemmyErrorType::NoReportNoteEditAllowed)?
+  }
+
+  // The author may have lost their mod rights in the meantime
+  let report = ReportCombinedViewInternal::read(
+    &mut context.pool(),
+    note.report_combined_id,
+    &local_user_view.person,
+  )
+  .await?;
+  check_report_mod_action(&report, &local_user_view, &mut context.pool()).await?;
+
+  ReportNote::delete(&mut context.pool(), data.note_id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
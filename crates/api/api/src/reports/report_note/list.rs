@@ -0,0 +1,25 @@
+use crate::reports::report_combined::check_report_mod_action;
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_report_combined::{
+  ReportCombinedViewInternal,
+  ReportNoteView,
+  api::{ListReportNotes, ListReportNotesResponse},
+};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_report_notes(
+  Query(data): Query<ListReportNotes>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListReportNotesResponse>> {
+  let report =
+    ReportCombinedViewInternal::read(&mut context.pool(), data.report_id, &local_user_view.person)
+      .await?;
+  check_report_mod_action(&report, &local_user_view, &mut context.pool()).await?;
+
+  let notes = ReportNoteView::list(&mut context.pool(), data.report_id).await?;
+
+  Ok(Json(ListReportNotesResponse { notes }))
+}
@@ -0,0 +1,21 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_views_local_image::{
+  UploaderView,
+  api::{ListTopUploaders, ListTopUploadersResponse},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn admin_list_top_uploaders(
+  Query(data): Query<ListTopUploaders>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListTopUploadersResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let uploaders = UploaderView::list_top(&mut context.pool(), data.limit).await?;
+
+  Ok(Json(ListTopUploadersResponse { uploaders }))
+}
@@ -0,0 +1,43 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{is_admin, purge_local_user_images},
+};
+use lemmy_db_schema::source::{
+  modlog::{Modlog, ModlogInsertForm},
+  person::{Person, PersonUpdateForm},
+};
+use lemmy_db_views_local_image::api::PurgeUserMedia;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn admin_purge_user_media(
+  Json(data): Json<PurgeUserMedia>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  purge_local_user_images(data.person_id, &context).await?;
+
+  // Avatar and banner are also gone now
+  let form = PersonUpdateForm {
+    avatar: Some(None),
+    banner: Some(None),
+    ..Default::default()
+  };
+  Person::update(&mut context.pool(), data.person_id, &form).await?;
+
+  // Mod tables
+  let form = ModlogInsertForm::admin_purge_user_media(
+    local_user_view.person.id,
+    data.person_id,
+    &data.reason,
+  );
+  Modlog::create(&mut context.pool(), &[form]).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
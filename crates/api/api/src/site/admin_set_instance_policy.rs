@@ -0,0 +1,57 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::{
+  federation_policy::{FederationPolicy, FederationPolicyForm},
+  instance::Instance,
+  modlog::{Modlog, ModlogInsertForm},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{AdminSetInstancePolicy, SuccessResponse};
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
+
+pub async fn admin_set_instance_policy(
+  Json(data): Json<AdminSetInstancePolicy>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_admin(&local_user_view)?;
+  is_valid_body_field(&data.reason, false)?;
+
+  let instance_id = Instance::read_or_create(&mut context.pool(), &data.instance)
+    .await?
+    .id;
+
+  let form = FederationPolicyForm {
+    instance_id,
+    silence: data.silence,
+    strip_media: data.strip_media,
+    force_nsfw: data.force_nsfw,
+    reject_reports: data.reject_reports,
+    reject_votes: data.reject_votes,
+    no_avatar: data.no_avatar,
+    reason: data.reason.clone(),
+  };
+
+  let has_restrictions = form.silence
+    || form.strip_media
+    || form.force_nsfw
+    || form.reject_reports
+    || form.reject_votes
+    || form.no_avatar;
+  if has_restrictions {
+    FederationPolicy::upsert(&mut context.pool(), &form).await?;
+  } else {
+    FederationPolicy::delete(&mut context.pool(), instance_id).await?;
+  }
+
+  let form = ModlogInsertForm::admin_set_instance_policy(
+    local_user_view.person.id,
+    instance_id,
+    has_restrictions,
+    &data.reason,
+  );
+  Modlog::create(&mut context.pool(), &[form]).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,128 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::local_user::{LocalUser, LocalUserUpdateForm};
+use lemmy_db_views_local_image::api::SetUploadQuota;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_utils::{error::LemmyResult, utils::validation::check_upload_quota_mb};
+
+pub async fn admin_set_upload_quota(
+  Json(data): Json<SetUploadQuota>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+  check_upload_quota_mb(data.upload_quota_mb)?;
+
+  let target = LocalUserView::read_person(&mut context.pool(), data.person_id).await?;
+  let form = LocalUserUpdateForm {
+    // Empty removes the custom quota, while zero prevents any uploads
+    upload_quota_mb: Some(data.upload_quota_mb),
+    ..Default::default()
+  };
+  LocalUser::update(&mut context.pool(), target.local_user.id, &form).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_api_utils::utils::check_upload_quota;
+  use lemmy_db_schema::{
+    source::{
+      images::{ImageDetailsInsertForm, LocalImage, LocalImageForm},
+      local_site::{LocalSite, LocalSiteUpdateForm},
+      person::Person,
+    },
+    test_data::TestData,
+  };
+  use lemmy_diesel_utils::traits::Crud;
+  use lemmy_utils::error::LemmyErrorType;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  const MEGABYTE: i64 = 1024 * 1024;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_upload_quota() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let data = TestData::create(&mut context.pool()).await?;
+    let admin =
+      LocalUserView::create_test_user(&mut context.pool(), "quota_admin", "", true).await?;
+    let user = LocalUserView::create_test_user(&mut context.pool(), "uploader", "", false).await?;
+    let exceeded = |res: LemmyResult<()>| {
+      res.is_err_and(|e| e.error_type == LemmyErrorType::UploadQuotaExceeded)
+    };
+
+    // Without any quota uploads are unlimited
+    check_upload_quota(&user, 100 * MEGABYTE, &context).await?;
+
+    // The quota of the site includes previous uploads
+    let site_form = LocalSiteUpdateForm {
+      upload_quota_mb: Some(Some(2)),
+      ..Default::default()
+    };
+    LocalSite::update(&mut context.pool(), &site_form).await?;
+    let image_form = LocalImageForm {
+      pictrs_alias: "quota_test".to_string(),
+      person_id: user.person.id,
+      thumbnail_for_post_id: None,
+      flagged_reason: None,
+      file_size: MEGABYTE,
+    };
+    let details_form = ImageDetailsInsertForm {
+      link: Url::parse("https://my_domain.tld/pictrs/image/quota_test")?.into(),
+      width: 1,
+      height: 1,
+      content_type: "image/png".to_string(),
+      blurhash: None,
+    };
+    LocalImage::create(&mut context.pool(), &image_form, &details_form).await?;
+    check_upload_quota(&user, MEGABYTE, &context).await?;
+    assert!(exceeded(
+      check_upload_quota(&user, MEGABYTE + 1, &context).await
+    ));
+
+    let set_quota = |upload_quota_mb| {
+      admin_set_upload_quota(
+        Json(SetUploadQuota {
+          person_id: user.person.id,
+          upload_quota_mb,
+        }),
+        Data::new(context.app_data().clone()),
+        admin.clone(),
+      )
+    };
+
+    // A custom quota of zero prevents any uploads
+    set_quota(Some(0)).await?;
+    let user = LocalUserView::read_person(&mut context.pool(), user.person.id).await?;
+    assert_eq!(Some(0), user.local_user.upload_quota_mb);
+    assert!(exceeded(check_upload_quota(&user, 0, &context).await));
+
+    // A custom quota overrides the one of the site
+    set_quota(Some(10)).await?;
+    let user = LocalUserView::read_person(&mut context.pool(), user.person.id).await?;
+    check_upload_quota(&user, 5 * MEGABYTE, &context).await?;
+
+    // Removing the custom quota falls back to the site quota
+    set_quota(None).await?;
+    let user = LocalUserView::read_person(&mut context.pool(), user.person.id).await?;
+    assert_eq!(None, user.local_user.upload_quota_mb);
+    assert!(exceeded(
+      check_upload_quota(&user, 5 * MEGABYTE, &context).await
+    ));
+
+    // Admins have no quota
+    check_upload_quota(&admin, 100 * MEGABYTE, &context).await?;
+
+    Person::delete(&mut context.pool(), user.person.id).await?;
+    Person::delete(&mut context.pool(), admin.person.id).await?;
+    data.delete(&mut context.pool()).await?;
+    Ok(())
+  }
+}
@@ -1,8 +1,13 @@
 pub mod admin_allow_instance;
 pub mod admin_block_instance;
+pub mod admin_list_top_uploaders;
 pub mod admin_list_users;
+pub mod admin_purge_user_media;
+pub mod admin_set_instance_policy;
+pub mod admin_set_upload_quota;
 pub mod federated_instances;
 pub mod list_all_media;
 pub mod mod_log;
+pub mod mod_log_export;
 pub mod purge;
 pub mod registration_applications;
@@ -1,4 +1,4 @@
-use crate::hide_modlog_names;
+use crate::{hide_modlog_names, modlog_retention};
 use actix_web::web::{Data, Json, Query};
 use lemmy_api_utils::{context::LemmyContext, utils::check_private_instance};
 use lemmy_db_views_local_user::LocalUserView;
@@ -25,6 +25,9 @@ pub async fn get_mod_log(
     data.mod_person_id
   };
 
+  let (published_after, hide_mod_names_before) =
+    modlog_retention(local_user_view.as_ref(), &local_site);
+
   let modlog = ModlogQuery {
     type_: data.type_,
     listing_type: data.listing_type,
@@ -35,8 +38,11 @@ pub async fn get_mod_log(
     post_id: data.post_id,
     comment_id: data.comment_id,
     hide_modlog_names: Some(hide_modlog_names),
+    published_after,
+    hide_mod_names_before,
     page_cursor: data.page_cursor,
     limit: data.limit,
+    ..Default::default()
   }
   .list(&mut context.pool())
   .await?;
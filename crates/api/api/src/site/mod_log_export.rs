@@ -0,0 +1,196 @@
+use crate::modlog_retention;
+use actix_web::{
+  HttpResponse,
+  http::header::{ContentDisposition, DispositionParam, DispositionType},
+  web::{Data, Query},
+};
+use chrono::{DateTime, TimeZone, Utc};
+use itertools::Itertools;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{is_admin, is_mod_or_admin},
+};
+use lemmy_db_schema::utils::FETCH_LIMIT_MAX;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modlog::{
+  ModlogView,
+  api::{ExportModlog, ModlogExportFormat},
+  impls::ModlogQuery,
+};
+use lemmy_db_views_site::SiteView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Upper limit for the number of exported entries. Larger modlogs need to be exported in multiple
+/// parts using `published_after` and `published_before`.
+const MAX_EXPORT_ENTRIES: usize = 10_000;
+
+const CSV_HEADER: &str = "id,published_at,kind,is_revert,moderator,target_person,\
+                          target_community,target_post,target_comment,target_instance,reason,\
+                          expires_at\n";
+
+/// Export the modlog for the given filters as a downloadable file, without pagination.
+pub async fn export_mod_log(
+  Query(data): Query<ExportModlog>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<HttpResponse> {
+  match data.community_id {
+    Some(community_id) => {
+      is_mod_or_admin(&mut context.pool(), &local_user_view, community_id).await?
+    }
+    None => is_admin(&local_user_view)?,
+  }
+
+  // Mods can only export what they can see in the modlog
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  let (retention_after, hide_mod_names_before) =
+    modlog_retention(Some(&local_user_view), &local_site);
+  let published_after = parse_unix_time(data.published_after)?.max(retention_after);
+  let published_before = parse_unix_time(data.published_before)?;
+
+  let mut modlog = vec![];
+  let mut page_cursor = None;
+  loop {
+    let page = ModlogQuery {
+      type_: data.type_,
+      community_id: data.community_id,
+      mod_person_id: data.mod_person_id,
+      target_person_id: data.other_person_id,
+      local_user: Some(&local_user_view.local_user),
+      post_id: data.post_id,
+      comment_id: data.comment_id,
+      published_after,
+      published_before,
+      hide_mod_names_before,
+      page_cursor,
+      limit: Some(FETCH_LIMIT_MAX.try_into()?),
+      ..Default::default()
+    }
+    .list(&mut context.pool())
+    .await?;
+    let is_last_page = page.items.len() < FETCH_LIMIT_MAX;
+    modlog.extend(page.items);
+    if modlog.len() > MAX_EXPORT_ENTRIES {
+      Err(LemmyErrorType::TooManyItems)?
+    }
+    page_cursor = page.next_page;
+    if is_last_page || page_cursor.is_none() {
+      break;
+    }
+  }
+
+  let (body, content_type, extension) = match data.format.unwrap_or_default() {
+    ModlogExportFormat::Csv => {
+      let rows = modlog.iter().map(csv_row).join("");
+      (format!("{CSV_HEADER}{rows}"), "text/csv", "csv")
+    }
+    ModlogExportFormat::JsonLines => {
+      let lines = modlog
+        .iter()
+        .map(serde_json::to_string)
+        .collect::<Result<Vec<_>, _>>()?;
+      let body = lines.into_iter().map(|l| l + "\n").join("");
+      (body, "application/x-ndjson", "jsonl")
+    }
+  };
+
+  Ok(
+    HttpResponse::Ok()
+      .content_type(content_type)
+      .insert_header(ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![DispositionParam::Filename(format!("modlog.{extension}"))],
+      })
+      .body(body),
+  )
+}
+
+fn parse_unix_time(time: Option<i64>) -> LemmyResult<Option<DateTime<Utc>>> {
+  time
+    .map(|t| {
+      Utc
+        .timestamp_opt(t, 0)
+        .single()
+        .ok_or(LemmyErrorType::InvalidUnixTime.into())
+    })
+    .transpose()
+}
+
+fn csv_row(view: &ModlogView) -> String {
+  let modlog = &view.modlog;
+  let fields = [
+    modlog.id.0.to_string(),
+    modlog.published_at.to_rfc3339(),
+    modlog.kind.to_string(),
+    modlog.is_revert.to_string(),
+    view
+      .moderator
+      .as_ref()
+      .map(|p| p.ap_id.to_string())
+      .unwrap_or_default(),
+    view
+      .target_person
+      .as_ref()
+      .map(|p| p.ap_id.to_string())
+      .unwrap_or_default(),
+    view
+      .target_community
+      .as_ref()
+      .map(|c| c.ap_id.to_string())
+      .unwrap_or_default(),
+    view
+      .target_post
+      .as_ref()
+      .map(|p| p.ap_id.to_string())
+      .unwrap_or_default(),
+    view
+      .target_comment
+      .as_ref()
+      .map(|c| c.ap_id.to_string())
+      .unwrap_or_default(),
+    view
+      .target_instance
+      .as_ref()
+      .map(|i| i.domain.clone())
+      .unwrap_or_default(),
+    modlog.reason.clone().unwrap_or_default(),
+    modlog
+      .expires_at
+      .map(|e| e.to_rfc3339())
+      .unwrap_or_default(),
+  ];
+  let row = fields.iter().map(|f| csv_escape(f)).join(",");
+  format!("{row}\n")
+}
+
+/// Quote the field if it contains any characters which have a special meaning in CSV. Fields
+/// which spreadsheet software would run as formula are prefixed with a single quote.
+fn csv_escape(field: &str) -> String {
+  let field = if field.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+    format!("'{field}")
+  } else {
+    field.to_string()
+  };
+  if field.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", field.replace('"', "\"\""))
+  } else {
+    field
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_csv_escape() {
+    assert_eq!("spam", csv_escape("spam"));
+    assert_eq!("\"spam, again\"", csv_escape("spam, again"));
+    assert_eq!("\"said \"\"hi\"\"\"", csv_escape("said \"hi\""));
+    assert_eq!("\"line\nbreak\"", csv_escape("line\nbreak"));
+    assert_eq!("'=1+1", csv_escape("=1+1"));
+    assert_eq!("'@SUM(A1)", csv_escape("@SUM(A1)"));
+    assert_eq!("\"'-2,3\"", csv_escape("-2,3"));
+  }
+}
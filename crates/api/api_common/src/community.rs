@@ -1,8 +1,10 @@
 pub use lemmy_db_schema::{
-  newtypes::{CommunityId, MultiCommunityId, TagId},
+  newtypes::{CommunityId, MultiCommunityId, RemovalReasonId, RuleId, TagId},
   source::{
     community::{Community, CommunityActions},
     multi_community::{MultiCommunity, MultiCommunityFollow},
+    removal_reason::RemovalReason,
+    rule::Rule,
     tag::{Tag, TagsView},
   },
 };
@@ -24,6 +26,8 @@ pub use lemmy_db_views_community::{
     GetRandomCommunity,
     ListCommunities,
     ListMultiCommunities,
+    ListRules,
+    ListRulesResponse,
   },
 };
 pub use lemmy_db_views_community_follower_approval::PendingFollowerView;
@@ -43,13 +47,24 @@ pub mod actions {
       AddModToCommunity,
       AddModToCommunityResponse,
       ApproveCommunityPendingFollower,
+      BackfillCommunity,
       BanFromCommunity,
       CommunityIdQuery,
       CreateCommunityTag,
+      CreateRemovalReason,
+      CreateRule,
       DeleteCommunity,
       DeleteCommunityTag,
+      DeleteRemovalReason,
+      DeleteRule,
       EditCommunity,
       EditCommunityTag,
+      EditRemovalReason,
+      EditRule,
+      ListRemovalReasons,
+      ListRemovalReasonsResponse,
+      MergeCommunity,
+      MuteFromCommunity,
       PurgeCommunity,
       RemoveCommunity,
       TransferCommunity,
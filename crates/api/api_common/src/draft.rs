@@ -0,0 +1,10 @@
+pub use lemmy_db_schema::{newtypes::DraftId, source::draft::Draft};
+pub use lemmy_db_schema_file::enums::DraftKind;
+pub use lemmy_db_views_site::api::{
+  CreateDraft,
+  DeleteDraft,
+  DraftResponse,
+  EditDraft,
+  GetDraft,
+  ListDrafts,
+};
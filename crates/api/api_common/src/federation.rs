@@ -1,14 +1,22 @@
 pub use lemmy_db_schema::{
-  newtypes::ActivityId,
+  newtypes::{ActivityId, BlocklistSubscriptionId, FederationReplayId, RelayId},
   source::{
+    blocklist_subscription::{BlocklistSubscription, BlocklistSubscriptionEntry},
     federation_allowlist::FederationAllowList,
     federation_blocklist::FederationBlockList,
+    federation_policy::FederationPolicy,
     federation_queue_state::FederationQueueState,
+    federation_replay::FederationReplay,
     instance::{Instance, InstanceActions},
+    relay::Relay,
   },
 };
-pub use lemmy_db_schema_file::{InstanceId, enums::FederationMode};
+pub use lemmy_db_schema_file::{
+  InstanceId,
+  enums::{BlocklistFormat, FederationMode, RelayMode},
+};
 pub use lemmy_db_views_site::{
+  FederationPolicyView,
   ReadableFederationState,
   api::{
     GetFederatedInstances,
@@ -21,5 +29,23 @@ pub use lemmy_db_views_site::{
 };
 
 pub mod administration {
-  pub use lemmy_db_views_site::api::{AdminAllowInstanceParams, AdminBlockInstanceParams};
+  pub use lemmy_db_views_site::api::{
+    AdminAllowInstanceParams,
+    AdminBlockInstanceParams,
+    AdminSetInstancePolicy,
+    ApplyBlocklistSubscription,
+    BlocklistSubscriptionResponse,
+    CreateBlocklistSubscription,
+    CreateFederationReplay,
+    CreateRelay,
+    DeleteBlocklistSubscription,
+    DeleteRelay,
+    EditRelay,
+    FederationReplayResponse,
+    GetBlocklistSubscription,
+    ListBlocklistSubscriptionsResponse,
+    ListFederationReplaysResponse,
+    ListRelaysResponse,
+    RelayResponse,
+  };
 }
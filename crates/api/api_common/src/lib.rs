@@ -2,11 +2,13 @@ pub mod account;
 pub mod comment;
 pub mod community;
 pub mod custom_emoji;
+pub mod draft;
 pub mod error;
 pub mod federation;
 pub mod language;
 pub mod media;
 pub mod modlog;
+pub mod modmail;
 pub mod notification;
 pub mod oauth;
 pub mod person;
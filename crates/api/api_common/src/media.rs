@@ -1,5 +1,26 @@
-pub use lemmy_db_schema::source::images::{ImageDetails, LocalImage, RemoteImage};
+pub use lemmy_db_schema::{
+  newtypes::BlockedImageId,
+  source::{
+    blocked_image::BlockedImage,
+    images::{ImageDetails, LocalImage, RemoteImage},
+  },
+};
 pub use lemmy_db_views_local_image::{
   LocalImageView,
-  api::{DeleteImageParams, ImageGetParams, ImageProxyParams, ListMedia, UploadImageResponse},
+  UploaderView,
+  api::{
+    BlockImage,
+    BlockedImageResponse,
+    DeleteImageParams,
+    ImageGetParams,
+    ImageProxyParams,
+    ListBlockedImages,
+    ListMedia,
+    ListTopUploaders,
+    ListTopUploadersResponse,
+    PurgeUserMedia,
+    SetUploadQuota,
+    UnblockImage,
+    UploadImageResponse,
+  },
 };
@@ -1,2 +1,2 @@
 pub use lemmy_db_schema::{newtypes::ModlogId, source::modlog::Modlog};
-pub use lemmy_db_views_modlog::api::GetModlog;
+pub use lemmy_db_views_modlog::api::{ExportModlog, GetModlog, ModlogExportFormat};
@@ -0,0 +1,24 @@
+pub use lemmy_db_schema::{
+  newtypes::{ModmailMessageId, ModmailThreadId},
+  source::modmail::{ModmailMessage, ModmailThread},
+};
+pub use lemmy_db_views_modmail::{
+  ModmailMessageView,
+  ModmailThreadView,
+  api::{
+    GetModmailThread,
+    GetModmailThreadResponse,
+    ListModmailThreads,
+    ModmailMessageResponse,
+    ModmailThreadResponse,
+  },
+};
+
+pub mod actions {
+  pub use lemmy_db_views_modmail::api::{
+    ArchiveModmailThread,
+    CreateModmailMessage,
+    CreateModmailThread,
+    MarkModmailThreadAsRead,
+  };
+}
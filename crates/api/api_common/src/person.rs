@@ -15,15 +15,37 @@ pub use lemmy_db_views_person::{
 
 pub mod actions {
   pub use lemmy_db_schema::newtypes::PersonContentCombinedId;
-  pub use lemmy_db_views_person::api::{BlockPerson, NotePerson};
+  pub use lemmy_db_views_person::api::{BlockPerson, FollowPerson, ListPersonFollows, NotePerson};
   pub use lemmy_db_views_person_content_combined::ListPersonContent;
 
   pub mod moderation {
     pub use lemmy_db_schema::{
-      newtypes::RegistrationApplicationId,
-      source::registration_application::RegistrationApplication,
+      newtypes::{BanAppealId, ModNoteId, RegistrationApplicationId},
+      source::{
+        ban_appeal::BanAppeal,
+        mod_note::ModNote,
+        registration_application::RegistrationApplication,
+      },
+    };
+    pub use lemmy_db_views_ban_appeal::{
+      BanAppealView,
+      api::{BanAppealResponse, CreateBanAppeal, ListBanAppeals, ResolveBanAppeal},
+    };
+    pub use lemmy_db_views_person::{
+      ModNoteView,
+      api::{
+        BanPerson,
+        CreateModNote,
+        DeleteModNote,
+        EditModNote,
+        ListModNotes,
+        ListModNotesResponse,
+        ModNoteResponse,
+        PurgePerson,
+        RestrictPerson,
+        WarnPerson,
+      },
     };
-    pub use lemmy_db_views_person::api::{BanPerson, PurgePerson};
     pub use lemmy_db_views_registration_applications::{
       RegistrationApplicationView,
       api::{GetRegistrationApplication, RegistrationApplicationResponse},
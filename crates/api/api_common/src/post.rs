@@ -1,7 +1,11 @@
 pub use lemmy_db_schema::{
   PostFeatureType,
-  newtypes::PostId,
-  source::post::{Post, PostActions, PostInsertForm, PostLikeForm},
+  RemovalNotification,
+  newtypes::{PostId, RecurringPostId},
+  source::{
+    post::{Post, PostActions, PostInsertForm, PostLikeForm},
+    recurring_post::RecurringPost,
+  },
 };
 pub use lemmy_db_schema_file::enums::{PostListingMode, PostNotificationsMode};
 pub use lemmy_db_views_post::{
@@ -13,6 +17,8 @@ pub use lemmy_db_views_post::{
     LinkMetadata,
     OpenGraphData,
     PostResponse,
+    RecurringPostResponse,
+    RecurringPostView,
   },
 };
 pub use lemmy_db_views_search_combined::api::{GetPost, GetPostResponse};
@@ -31,8 +37,12 @@ pub mod actions {
 
   pub mod moderation {
     pub use lemmy_db_views_post::api::{
+      CreateRecurringPost,
+      DeleteRecurringPost,
+      EditRecurringPost,
       FeaturePost,
       ListPostLikes,
+      ListRecurringPosts,
       LockPost,
       ModEditPost,
       PurgePost,
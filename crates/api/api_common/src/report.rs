@@ -1,31 +1,56 @@
 pub use lemmy_db_schema::{
   ReportType,
-  newtypes::{CommentReportId, CommunityReportId, PostReportId, PrivateMessageReportId},
+  newtypes::{
+    CommentReportId,
+    CommunityReportId,
+    PersonReportId,
+    PostReportId,
+    PrivateMessageReportId,
+    ReportCombinedId,
+    ReportNoteId,
+  },
   source::{
     comment_report::CommentReport,
     community_report::CommunityReport,
+    person_report::PersonReport,
     post_report::PostReport,
     private_message_report::PrivateMessageReport,
+    report_note::ReportNote,
   },
 };
 pub use lemmy_db_views_report_combined::{
   CommentReportView,
+  CommunityReportMetricsView,
   CommunityReportView,
+  PersonReportView,
   PostReportView,
   PrivateMessageReportView,
   ReportCombinedView,
+  ReportNoteView,
   api::{
+    AssignReport,
     CommentReportResponse,
     CommunityReportResponse,
     CreateCommentReport,
     CreateCommunityReport,
+    CreatePersonReport,
     CreatePostReport,
     CreatePrivateMessageReport,
+    CreateReportNote,
+    DeleteReportNote,
+    GetReportMetrics,
+    GetReportMetricsResponse,
+    ListReportNotes,
+    ListReportNotesResponse,
     ListReports,
+    PersonReportResponse,
     PostReportResponse,
     PrivateMessageReportResponse,
+    ReportCombinedResponse,
+    ReportNoteResponse,
     ResolveCommentReport,
     ResolveCommunityReport,
+    ResolvePersonReport,
     ResolvePostReport,
     ResolvePrivateMessageReport,
   },
@@ -0,0 +1,55 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  image_moderation::{ImageSource, clear_blocked_image_cache, fetch_image_hash},
+  utils::is_admin,
+};
+use lemmy_db_schema::source::blocked_image::{BlockedImage, BlockedImageForm};
+use lemmy_db_views_local_image::api::{BlockImage, BlockedImageResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use url::Url;
+
+pub async fn block_image(
+  Json(data): Json<BlockImage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BlockedImageResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  // Local images are read directly from pict-rs, for proxied images use the original url
+  let url = &data.image_url;
+  let proxied_url;
+  let source = if url.domain() == Some(&context.settings().hostname) {
+    let alias = url
+      .path_segments()
+      .and_then(|mut p| p.next_back())
+      .ok_or(LemmyErrorType::InvalidUrl)?;
+    if alias == "proxy" {
+      let original = url
+        .query_pairs()
+        .find(|(k, _)| k == "url")
+        .ok_or(LemmyErrorType::InvalidUrl)?
+        .1;
+      proxied_url = Url::parse(&original)?;
+      ImageSource::Proxied(&proxied_url)
+    } else {
+      ImageSource::Local(alias)
+    }
+  } else {
+    ImageSource::Proxied(url)
+  };
+  let hash = fetch_image_hash(&source, &context).await?;
+
+  let form = BlockedImageForm {
+    reason: data.reason,
+    ..BlockedImageForm::new(hash)
+  };
+  let blocked_image = BlockedImage::create(&mut context.pool(), &form).await?;
+  clear_blocked_image_cache();
+
+  Ok(Json(BlockedImageResponse { blocked_image }))
+}
@@ -0,0 +1,27 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  image_moderation::clear_blocked_image_cache,
+  utils::is_admin,
+};
+use lemmy_db_schema::source::blocked_image::BlockedImage;
+use lemmy_db_views_local_image::api::UnblockImage;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn unblock_image(
+  Json(data): Json<UnblockImage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  BlockedImage::delete(&mut context.pool(), data.id).await?;
+  clear_blocked_image_cache();
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,21 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::blocked_image::BlockedImage;
+use lemmy_db_views_local_image::api::ListBlockedImages;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_blocked_images(
+  Query(data): Query<ListBlockedImages>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<BlockedImage>>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let blocked_images =
+    BlockedImage::list(&mut context.pool(), data.page_cursor, data.limit).await?;
+
+  Ok(Json(blocked_images))
+}
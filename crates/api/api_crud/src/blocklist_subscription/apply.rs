@@ -0,0 +1,91 @@
+use super::build_response;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::{
+  blocklist_subscription::{BlocklistSubscription, BlocklistSubscriptionEntry},
+  federation_blocklist::{FederationBlockList, FederationBlockListForm},
+  instance::Instance,
+  modlog::{Modlog, ModlogInsertForm},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{ApplyBlocklistSubscription, BlocklistSubscriptionResponse};
+use lemmy_diesel_utils::{connection::get_conn, traits::Crud};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn apply_blocklist_subscription(
+  Json(data): Json<ApplyBlocklistSubscription>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BlocklistSubscriptionResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let allowlist = Instance::allowlist(&mut context.pool()).await?;
+  if !allowlist.is_empty() {
+    Err(LemmyErrorType::CannotCombineFederationBlocklistAndAllowlist)?;
+  }
+
+  let subscription = BlocklistSubscription::read(&mut context.pool(), data.id).await?;
+  let selected = |domain: &str| {
+    data
+      .domains
+      .as_ref()
+      .is_none_or(|d| d.iter().any(|d| d == domain))
+  };
+
+  let person_id = local_user_view.person.id;
+  let subscription_ = &subscription;
+  let pool = &mut context.pool();
+  let conn = &mut get_conn(pool).await?;
+  conn
+    .run_transaction(|conn| {
+      async move {
+        // Collect the changes first, as the modlog forms only borrow the reason.
+        let mut changes = vec![];
+        let pending_blocks =
+          BlocklistSubscriptionEntry::pending_blocks(&mut conn.into(), subscription_.id).await?;
+        for entry in pending_blocks.into_iter().filter(|e| selected(&e.domain)) {
+          let instance_id = Instance::read_or_create(&mut conn.into(), &entry.domain)
+            .await?
+            .id;
+          let form = FederationBlockListForm {
+            subscription_id: Some(subscription_.id),
+            ..FederationBlockListForm::new(instance_id, None)
+          };
+          FederationBlockList::block(&mut conn.into(), &form).await?;
+          let reason = match entry.reason {
+            Some(reason) => format!("From blocklist {}: {reason}", subscription_.url),
+            None => format!("From blocklist {}", subscription_.url),
+          };
+          changes.push((instance_id, true, reason));
+        }
+
+        // Blocks which are still listed by another subscription stay in place.
+        BlocklistSubscriptionEntry::transfer_blocks(&mut conn.into(), subscription_.id).await?;
+        let pending_unblocks =
+          BlocklistSubscriptionEntry::pending_unblocks(&mut conn.into(), subscription_.id).await?;
+        for instance in pending_unblocks.into_iter().filter(|i| selected(&i.domain)) {
+          FederationBlockList::unblock(&mut conn.into(), instance.id).await?;
+          let reason = format!("Removed from blocklist {}", subscription_.url);
+          changes.push((instance.id, false, reason));
+        }
+
+        let forms = changes
+          .iter()
+          .map(|(instance_id, block, reason)| {
+            ModlogInsertForm::admin_block_instance(person_id, *instance_id, *block, reason)
+          })
+          .collect::<Vec<_>>();
+        if !forms.is_empty() {
+          Modlog::create(&mut conn.into(), &forms).await?;
+        }
+        Ok(())
+      }
+      .scope_boxed()
+    })
+    .await?;
+
+  Ok(Json(build_response(subscription, &context).await?))
+}
@@ -0,0 +1,40 @@
+use super::build_response;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  blocklist::sync_blocklist_subscription,
+  context::LemmyContext,
+  utils::is_admin,
+};
+use lemmy_db_schema::source::blocklist_subscription::{
+  BlocklistSubscription,
+  BlocklistSubscriptionInsertForm,
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{BlocklistSubscriptionResponse, CreateBlocklistSubscription};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_url};
+use url::Url;
+
+pub async fn create_blocklist_subscription(
+  Json(data): Json<CreateBlocklistSubscription>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BlocklistSubscriptionResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+  is_valid_url(&Url::parse(&data.url)?)?;
+
+  let form = BlocklistSubscriptionInsertForm::new(data.url, data.format);
+  let subscription = BlocklistSubscription::create(&mut context.pool(), &form).await?;
+
+  // Fetch the blocklist right away so that the admin can review it. If that fails the url is
+  // most likely wrong, so don't keep the subscription.
+  if let Err(e) = sync_blocklist_subscription(&subscription, &context).await {
+    BlocklistSubscription::delete(&mut context.pool(), subscription.id).await?;
+    return Err(e);
+  }
+  let subscription = BlocklistSubscription::read(&mut context.pool(), subscription.id).await?;
+
+  Ok(Json(build_response(subscription, &context).await?))
+}
@@ -0,0 +1,18 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::blocklist_subscription::BlocklistSubscription;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::ListBlocklistSubscriptionsResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_blocklist_subscriptions(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListBlocklistSubscriptionsResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let subscriptions = BlocklistSubscription::list(&mut context.pool()).await?;
+
+  Ok(Json(ListBlocklistSubscriptionsResponse { subscriptions }))
+}
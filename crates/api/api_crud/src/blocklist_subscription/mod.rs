@@ -0,0 +1,28 @@
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::blocklist_subscription::{
+  BlocklistSubscription,
+  BlocklistSubscriptionEntry,
+};
+use lemmy_db_views_site::api::BlocklistSubscriptionResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub mod apply;
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod read;
+
+async fn build_response(
+  subscription: BlocklistSubscription,
+  context: &LemmyContext,
+) -> LemmyResult<BlocklistSubscriptionResponse> {
+  let pending_blocks =
+    BlocklistSubscriptionEntry::pending_blocks(&mut context.pool(), subscription.id).await?;
+  let pending_unblocks =
+    BlocklistSubscriptionEntry::pending_unblocks(&mut context.pool(), subscription.id).await?;
+  Ok(BlocklistSubscriptionResponse {
+    subscription,
+    pending_blocks,
+    pending_unblocks,
+  })
+}
@@ -0,0 +1,21 @@
+use super::build_response;
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::blocklist_subscription::BlocklistSubscription;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{BlocklistSubscriptionResponse, GetBlocklistSubscription};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn get_blocklist_subscription(
+  Query(data): Query<GetBlocklistSubscription>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BlocklistSubscriptionResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let subscription = BlocklistSubscription::read(&mut context.pool(), data.id).await?;
+
+  Ok(Json(build_response(subscription, &context).await?))
+}
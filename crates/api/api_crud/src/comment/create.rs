@@ -9,7 +9,7 @@ use lemmy_api_utils::{
   send_activity::{ActivityChannel, SendActivityData},
   utils::{
     check_comment_depth,
-    check_community_user_action,
+    check_community_content_action,
     check_post_deleted_or_removed,
     get_url_blocklist,
     is_mod_or_admin,
@@ -22,6 +22,7 @@ use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
   source::{
     comment::{Comment, CommentActions, CommentInsertForm, CommentLikeForm},
+    draft::Draft,
     notification::Notification,
   },
   traits::Likeable,
@@ -66,7 +67,8 @@ pub async fn create_comment(
   let post = post_view.post;
   let community_id = post_view.community.id;
 
-  check_community_user_action(&local_user_view, &post_view.community, &mut context.pool()).await?;
+  check_community_content_action(&local_user_view, &post_view.community, &mut context.pool())
+    .await?;
   check_post_deleted_or_removed(&post)?;
 
   // Fetch the parent, if it exists
@@ -96,9 +98,13 @@ pub async fn create_comment(
     check_comment_depth(parent)?;
   }
 
+  // Comments of shadow restricted users are neither federated nor generate notifications until
+  // the restriction is lifted.
+  let held_for_review = local_user_view.local_user.restricted_at.is_some();
   let mut comment_form = CommentInsertForm {
     language_id: data.language_id,
     federation_pending: Some(community_use_pending(&post_view.community, &context).await),
+    held_for_review: Some(held_for_review),
     ..CommentInsertForm::new(my_person_id, data.post_id, content.clone())
   };
   comment_form = plugin_hook_before("local_comment_before_create", comment_form).await?;
@@ -110,26 +116,30 @@ pub async fn create_comment(
     Comment::create(&mut context.pool(), &comment_form, parent_path.as_ref()).await?;
   plugin_hook_after("local_comment_after_create", &inserted_comment);
 
-  NotifyData {
-    comment: Some(inserted_comment.clone()),
-    do_send_email: !local_site.disable_email_notifications,
-    ..NotifyData::new(
-      post.clone(),
-      local_user_view.person.clone(),
-      post_view.community,
-    )
+  if !held_for_review {
+    NotifyData {
+      comment: Some(inserted_comment.clone()),
+      do_send_email: !local_site.disable_email_notifications,
+      ..NotifyData::new(
+        post.clone(),
+        local_user_view.person.clone(),
+        post_view.community,
+      )
+    }
+    .send(&context);
   }
-  .send(&context);
 
   // You like your own comment by default
   let like_form = CommentLikeForm::new(inserted_comment.id, my_person_id, Some(true));
 
   CommentActions::like(&mut context.pool(), &like_form).await?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::CreateComment(inserted_comment.clone()),
-    &context,
-  )?;
+  if !held_for_review {
+    ActivityChannel::submit_activity(
+      SendActivityData::CreateComment(inserted_comment.clone()),
+      &context,
+    )?;
+  }
 
   // Update the read comments, so your own new comment doesn't appear as a +1 unread
   update_read_comments(
@@ -155,6 +165,10 @@ pub async fn create_comment(
     .ok();
   }
 
+  if let Some(draft_id) = data.draft_id {
+    Draft::delete_for_user(&mut context.pool(), draft_id, local_user_view.local_user.id).await?;
+  }
+
   Ok(Json(
     build_comment_response(
       &context,
@@ -1,3 +1,4 @@
+use crate::removal_reason::{RemovedContent, notify_removal, render_removal_reason};
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_utils::{
@@ -5,7 +6,7 @@ use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{check_community_mod_action, check_rule_in_community},
 };
 use lemmy_db_schema::{
   source::{
@@ -60,6 +61,22 @@ pub async fn remove_comment(
   if orig_comment.comment.deleted {
     return Err(LemmyErrorType::CouldntUpdate.into());
   }
+  let rule =
+    check_rule_in_community(data.rule_id, orig_comment.community.id, &mut context.pool()).await?;
+  let content = RemovedContent {
+    post: orig_comment.post,
+    comment: Some(orig_comment.comment),
+    community: orig_comment.community,
+    author: orig_comment.creator,
+  };
+  let reason = render_removal_reason(
+    data.removal_reason_id,
+    &data.reason,
+    rule.as_ref(),
+    &content,
+    &context,
+  )
+  .await?;
 
   // Do the remove
   let removed = data.removed;
@@ -79,10 +96,11 @@ pub async fn remove_comment(
   // Mod tables
   let form = ModlogInsertForm::mod_remove_comment(
     local_user_view.person.id,
-    &orig_comment.comment,
+    &updated_comment,
     removed,
-    &data.reason,
-  );
+    &reason,
+  )
+  .with_rule(data.rule_id);
   let actions = Modlog::create(&mut context.pool(), &[form]).await?;
   notify_mod_action(actions, context.app_data());
 
@@ -92,12 +110,23 @@ pub async fn remove_comment(
     SendActivityData::RemoveComment {
       comment: updated_comment,
       moderator: local_user_view.person.clone(),
-      community: orig_comment.community,
-      reason: data.reason.clone(),
+      community: content.community.clone(),
+      reason: reason.clone(),
     },
     &context,
   )?;
 
+  if removed {
+    notify_removal(
+      data.notify_author,
+      &reason,
+      content,
+      &local_user_view,
+      &context,
+    )
+    .await?;
+  }
+
   Ok(Json(
     build_comment_response(
       &context,
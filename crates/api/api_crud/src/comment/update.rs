@@ -7,7 +7,7 @@ use lemmy_api_utils::{
   notify::NotifyData,
   plugins::{plugin_hook_after, plugin_hook_before},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_user_action, get_url_blocklist, process_markdown_opt, slur_regex},
+  utils::{check_community_content_action, get_url_blocklist, process_markdown_opt, slur_regex},
 };
 use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
@@ -39,7 +39,7 @@ pub async fn edit_comment(
   )
   .await?;
 
-  check_community_user_action(
+  check_community_content_action(
     &local_user_view,
     &orig_comment.community,
     &mut context.pool(),
@@ -77,16 +77,18 @@ pub async fn edit_comment(
 
   plugin_hook_after("local_comment_after_update", &updated_comment);
 
-  // Do the mentions / recipients
-  NotifyData {
-    comment: Some(updated_comment.clone()),
-    ..NotifyData::new(
-      orig_comment.post,
-      local_user_view.person.clone(),
-      orig_comment.community,
-    )
+  // Do the mentions / recipients, unless the comment is held for review
+  if !updated_comment.held_for_review {
+    NotifyData {
+      comment: Some(updated_comment.clone()),
+      ..NotifyData::new(
+        orig_comment.post,
+        local_user_view.person.clone(),
+        orig_comment.community,
+      )
+    }
+    .send(&context);
   }
-  .send(&context);
 
   ActivityChannel::submit_activity(
     SendActivityData::UpdateComment(updated_comment.clone()),
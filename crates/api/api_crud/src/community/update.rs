@@ -1,3 +1,4 @@
+use crate::site::not_zero;
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use chrono::Utc;
@@ -22,12 +23,15 @@ use lemmy_db_schema::source::{
 use lemmy_db_views_community::api::{CommunityResponse, EditCommunity};
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::SiteView;
-use lemmy_diesel_utils::{traits::Crud, utils::diesel_string_update};
+use lemmy_diesel_utils::{
+  traits::Crud,
+  utils::{diesel_opt_number_update, diesel_string_update},
+};
 use lemmy_utils::{
   error::{LemmyErrorType, LemmyResult},
   utils::{
     slurs::check_slurs_opt,
-    validation::{is_valid_body_field, is_valid_display_name},
+    validation::{check_strike_settings, is_valid_body_field, is_valid_display_name},
   },
 };
 
@@ -58,6 +62,11 @@ pub async fn edit_community(
   if let Some(Some(description)) = &description {
     is_valid_body_field(description, false)?;
   }
+  check_strike_settings(
+    data.strike_ban_threshold,
+    data.strike_window_days,
+    data.strike_ban_days,
+  )?;
 
   let summary = diesel_string_update(data.summary.as_deref());
 
@@ -85,6 +94,9 @@ pub async fn edit_community(
     nsfw: data.nsfw,
     posting_restricted_to_mods: data.posting_restricted_to_mods,
     visibility: data.visibility,
+    strike_ban_threshold: diesel_opt_number_update(data.strike_ban_threshold),
+    strike_window_days: not_zero(data.strike_window_days),
+    strike_ban_days: not_zero(data.strike_ban_days),
     updated_at: Some(Some(Utc::now())),
     ..Default::default()
   };
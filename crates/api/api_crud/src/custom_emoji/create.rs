@@ -1,6 +1,9 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{is_admin, update_markdown_custom_emojis},
+};
 use lemmy_db_schema::source::{
   custom_emoji::{CustomEmoji, CustomEmojiInsertForm},
   custom_emoji_keyword::CustomEmojiKeyword,
@@ -30,6 +33,7 @@ pub async fn create_custom_emoji(
   let emoji = CustomEmoji::create(&mut context.pool(), &emoji_form).await?;
 
   CustomEmojiKeyword::create_from_keywords(&mut context.pool(), emoji.id, &data.keywords).await?;
+  update_markdown_custom_emojis(&mut context.pool()).await?;
 
   let view = CustomEmojiView::get(&mut context.pool(), emoji.id).await?;
   Ok(Json(CustomEmojiResponse { custom_emoji: view }))
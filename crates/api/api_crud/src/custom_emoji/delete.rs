@@ -1,6 +1,9 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{is_admin, update_markdown_custom_emojis},
+};
 use lemmy_db_schema::source::custom_emoji::CustomEmoji;
 use lemmy_db_views_custom_emoji::api::DeleteCustomEmoji;
 use lemmy_db_views_local_user::LocalUserView;
@@ -17,6 +20,7 @@ pub async fn delete_custom_emoji(
   is_admin(&local_user_view)?;
 
   CustomEmoji::delete(&mut context.pool(), data.id).await?;
+  update_markdown_custom_emojis(&mut context.pool()).await?;
 
   Ok(Json(SuccessResponse::default()))
 }
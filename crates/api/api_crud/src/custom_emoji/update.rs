@@ -1,6 +1,9 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
-use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{is_admin, update_markdown_custom_emojis},
+};
 use lemmy_db_schema::source::{
   custom_emoji::{CustomEmoji, CustomEmojiUpdateForm},
   custom_emoji_keyword::CustomEmojiKeyword,
@@ -37,6 +40,7 @@ pub async fn edit_custom_emoji(
     CustomEmojiKeyword::delete(&mut context.pool(), data.id).await?;
     CustomEmojiKeyword::create_from_keywords(&mut context.pool(), emoji.id, keywords).await?;
   }
+  update_markdown_custom_emojis(&mut context.pool()).await?;
 
   let view = CustomEmojiView::get(&mut context.pool(), emoji.id).await?;
   Ok(Json(CustomEmojiResponse { custom_emoji: view }))
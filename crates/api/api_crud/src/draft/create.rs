@@ -0,0 +1,55 @@
+use super::{check_draft_targets, convert_scheduled_time, validate_draft_fields};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_local_user_valid};
+use lemmy_db_schema::source::draft::{Draft, DraftInsertForm};
+use lemmy_db_schema_file::enums::DraftKind;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{CreateDraft, DraftResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+const MAX_DRAFTS_PER_USER: i64 = 100;
+
+pub async fn create_draft(
+  Json(data): Json<CreateDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<DraftResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  let local_user_id = local_user_view.local_user.id;
+
+  let count = Draft::count_for_user(&mut context.pool(), local_user_id).await?;
+  if count >= MAX_DRAFTS_PER_USER {
+    Err(LemmyErrorType::TooManyDrafts)?;
+  }
+
+  validate_draft_fields(&data.body, &data.alt_text, data.kind == DraftKind::Post)?;
+  check_draft_targets(
+    data.kind,
+    data.community_id,
+    data.post_id,
+    data.parent_id,
+    data.recipient_id,
+  )?;
+
+  let draft_form = DraftInsertForm {
+    community_id: data.community_id,
+    post_id: data.post_id,
+    parent_id: data.parent_id,
+    recipient_id: data.recipient_id,
+    name: data.name,
+    url: data.url,
+    body: data.body,
+    alt_text: data.alt_text,
+    nsfw: data.nsfw,
+    language_id: data.language_id,
+    custom_thumbnail: data.custom_thumbnail,
+    scheduled_publish_time_at: convert_scheduled_time(data.scheduled_publish_time_at)?,
+    ..DraftInsertForm::new(local_user_id, data.kind)
+  };
+
+  let draft = Draft::create(&mut context.pool(), &draft_form).await?;
+
+  Ok(Json(DraftResponse { draft }))
+}
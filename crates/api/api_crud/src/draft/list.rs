@@ -0,0 +1,23 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::draft::Draft;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::ListDrafts;
+use lemmy_diesel_utils::pagination::PagedResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_drafts(
+  Query(data): Query<ListDrafts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<Draft>>> {
+  let drafts = Draft::list_for_user(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    data.page_cursor,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(drafts))
+}
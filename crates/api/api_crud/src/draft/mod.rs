@@ -0,0 +1,90 @@
+use chrono::{DateTime, TimeZone, Utc};
+use lemmy_db_schema::newtypes::{CommentId, CommunityId, PostId};
+use lemmy_db_schema_file::{PersonId, enums::DraftKind};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::{is_valid_alt_text_field, is_valid_body_field},
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod read;
+pub mod update;
+
+/// Drafts aren't published, so only check the limits which the database would otherwise reject.
+/// Everything else is validated once the draft is turned into a post, comment or message.
+fn validate_draft_fields(
+  body: &Option<String>,
+  alt_text: &Option<String>,
+  is_post: bool,
+) -> LemmyResult<()> {
+  if let Some(body) = body {
+    is_valid_body_field(body, is_post)?;
+  }
+  if let Some(alt_text) = alt_text {
+    is_valid_alt_text_field(alt_text)?;
+  }
+  Ok(())
+}
+
+/// Same rules as the `draft_kind_target_check` constraint, so that a mismatch returns a proper
+/// error instead of a database error.
+fn check_draft_targets(
+  kind: DraftKind,
+  community_id: Option<CommunityId>,
+  post_id: Option<PostId>,
+  parent_id: Option<CommentId>,
+  recipient_id: Option<PersonId>,
+) -> LemmyResult<()> {
+  let valid = match kind {
+    DraftKind::Post => post_id.is_none() && parent_id.is_none() && recipient_id.is_none(),
+    DraftKind::Comment => community_id.is_none() && post_id.is_some() && recipient_id.is_none(),
+    DraftKind::PrivateMessage => {
+      community_id.is_none() && post_id.is_none() && parent_id.is_none() && recipient_id.is_some()
+    }
+  };
+  if !valid {
+    Err(LemmyErrorType::InvalidDraftTarget)?
+  }
+  Ok(())
+}
+
+fn convert_scheduled_time(
+  scheduled_publish_time: Option<i64>,
+) -> LemmyResult<Option<DateTime<Utc>>> {
+  if let Some(scheduled_publish_time) = scheduled_publish_time {
+    let converted = Utc
+      .timestamp_opt(scheduled_publish_time, 0)
+      .single()
+      .ok_or(LemmyErrorType::InvalidUnixTime)?;
+    Ok(Some(converted))
+  } else {
+    Ok(None)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::check_draft_targets;
+  use lemmy_db_schema::newtypes::{CommentId, CommunityId, PostId};
+  use lemmy_db_schema_file::{PersonId, enums::DraftKind};
+
+  #[test]
+  fn test_check_draft_targets() {
+    let community = Some(CommunityId(1));
+    let post = Some(PostId(1));
+    let parent = Some(CommentId(1));
+    let recipient = Some(PersonId(1));
+
+    assert!(check_draft_targets(DraftKind::Post, None, None, None, None).is_ok());
+    assert!(check_draft_targets(DraftKind::Post, community, None, None, None).is_ok());
+    assert!(check_draft_targets(DraftKind::Post, community, post, None, None).is_err());
+    assert!(check_draft_targets(DraftKind::Comment, None, post, parent, None).is_ok());
+    assert!(check_draft_targets(DraftKind::Comment, None, None, parent, None).is_err());
+    assert!(check_draft_targets(DraftKind::Comment, community, post, None, None).is_err());
+    assert!(check_draft_targets(DraftKind::PrivateMessage, None, None, None, recipient).is_ok());
+    assert!(check_draft_targets(DraftKind::PrivateMessage, None, None, None, None).is_err());
+    assert!(check_draft_targets(DraftKind::PrivateMessage, None, post, None, recipient).is_err());
+  }
+}
@@ -0,0 +1,17 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::draft::Draft;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DraftResponse, GetDraft};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn get_draft(
+  Query(data): Query<GetDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<DraftResponse>> {
+  let draft =
+    Draft::read_for_user(&mut context.pool(), data.id, local_user_view.local_user.id).await?;
+
+  Ok(Json(DraftResponse { draft }))
+}
@@ -0,0 +1,52 @@
+use super::{check_draft_targets, convert_scheduled_time, validate_draft_fields};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::draft::{Draft, DraftUpdateForm};
+use lemmy_db_schema_file::enums::DraftKind;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DraftResponse, EditDraft};
+use lemmy_diesel_utils::{traits::Crud, utils::diesel_string_update};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn edit_draft(
+  Json(data): Json<EditDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<DraftResponse>> {
+  // Make sure the draft belongs to this user
+  let orig_draft =
+    Draft::read_for_user(&mut context.pool(), data.id, local_user_view.local_user.id).await?;
+
+  validate_draft_fields(
+    &data.body,
+    &data.alt_text,
+    orig_draft.kind == DraftKind::Post,
+  )?;
+  check_draft_targets(
+    orig_draft.kind,
+    data.community_id.or(orig_draft.community_id),
+    orig_draft.post_id,
+    data.parent_id.or(orig_draft.parent_id),
+    orig_draft.recipient_id,
+  )?;
+
+  let draft_form = DraftUpdateForm {
+    community_id: data.community_id.map(Some),
+    parent_id: data.parent_id.map(Some),
+    name: diesel_string_update(data.name.as_deref()),
+    url: diesel_string_update(data.url.as_deref()),
+    body: diesel_string_update(data.body.as_deref()),
+    alt_text: diesel_string_update(data.alt_text.as_deref()),
+    nsfw: data.nsfw.map(Some),
+    language_id: data.language_id.map(Some),
+    custom_thumbnail: diesel_string_update(data.custom_thumbnail.as_deref()),
+    scheduled_publish_time_at: convert_scheduled_time(data.scheduled_publish_time_at)?.map(Some),
+    updated_at: Some(Some(Utc::now())),
+  };
+
+  let draft = Draft::update(&mut context.pool(), orig_draft.id, &draft_form).await?;
+
+  Ok(Json(DraftResponse { draft }))
+}
@@ -0,0 +1,40 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::{TimeZone, Utc};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::{federation_replay::FederationReplay, instance::Instance};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{CreateFederationReplay, FederationReplayResponse};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn create_federation_replay(
+  Json(data): Json<CreateFederationReplay>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<FederationReplayResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let since = data
+    .since
+    .map(|since| {
+      Utc
+        .timestamp_opt(since, 0)
+        .single()
+        .ok_or(LemmyErrorType::InvalidUnixTime)
+    })
+    .transpose()?;
+  let instance = Instance::read_from_domain(&mut context.pool(), &data.instance).await?;
+
+  // The federation queue picks it up from the database
+  let replay = FederationReplay::schedule(
+    &mut context.pool(),
+    instance.id,
+    data.community_id,
+    since,
+    data.since_activity_id,
+  )
+  .await?;
+
+  Ok(Json(FederationReplayResponse { replay }))
+}
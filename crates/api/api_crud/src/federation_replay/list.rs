@@ -0,0 +1,18 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::federation_replay::FederationReplay;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::ListFederationReplaysResponse;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_federation_replays(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListFederationReplaysResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let replays = FederationReplay::list(&mut context.pool()).await?;
+
+  Ok(Json(ListFederationReplaysResponse { replays }))
+}
@@ -1,13 +1,23 @@
 use lemmy_api_utils::context::LemmyContext;
 use lemmy_db_schema::source::community::{Community, CommunityActions};
 
+pub mod blocked_image;
+pub mod blocklist_subscription;
 pub mod comment;
 pub mod community;
 pub mod custom_emoji;
+pub mod draft;
+pub mod federation_replay;
+pub mod mod_note;
+pub mod modmail;
 pub mod multi_community;
 pub mod oauth_provider;
 pub mod post;
 pub mod private_message;
+pub mod recurring_post;
+pub mod relay;
+pub mod removal_reason;
+pub mod rule;
 pub mod site;
 pub mod tagline;
 pub mod user;
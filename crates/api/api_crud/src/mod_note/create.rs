@@ -0,0 +1,78 @@
+use super::check_mod_note_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{
+    comment::Comment,
+    mod_note::{ModNote, ModNoteInsertForm},
+    post::Post,
+  },
+};
+use lemmy_db_schema_file::PersonId;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{
+  ModNoteView,
+  api::{CreateModNote, ModNoteResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+
+pub async fn create_mod_note(
+  Json(data): Json<CreateModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModNoteResponse>> {
+  check_mod_note_permission(data.community_id, &local_user_view, &context).await?;
+
+  // The linked post or comment must be written by the person, and belong to the community
+  if let Some(post_id) = data.post_id {
+    let post = Post::read(&mut context.pool(), post_id).await?;
+    check_item_belongs_to_note(post.creator_id, post.community_id, &data)?;
+  }
+  if let Some(comment_id) = data.comment_id {
+    let comment = Comment::read(&mut context.pool(), comment_id).await?;
+    let post = Post::read(&mut context.pool(), comment.post_id).await?;
+    check_item_belongs_to_note(comment.creator_id, post.community_id, &data)?;
+  }
+
+  let slur_regex = slur_regex(&context).await?;
+  let url_blocklist = get_url_blocklist(&context).await?;
+  check_slurs(&data.content, &slur_regex)?;
+  is_valid_body_field(&data.content, false)?;
+  let content = process_markdown(&data.content, &slur_regex, &url_blocklist, &context).await?;
+
+  let form = ModNoteInsertForm {
+    post_id: data.post_id,
+    comment_id: data.comment_id,
+    ..ModNoteInsertForm::new(
+      data.person_id,
+      local_user_view.person.id,
+      data.community_id,
+      content,
+    )
+  };
+  let note = ModNote::create(&mut context.pool(), &form).await?;
+  let mod_note_view = ModNoteView::read(&mut context.pool(), note.id).await?;
+
+  Ok(Json(ModNoteResponse { mod_note_view }))
+}
+
+fn check_item_belongs_to_note(
+  creator_id: PersonId,
+  community_id: CommunityId,
+  data: &CreateModNote,
+) -> LemmyResult<()> {
+  let community_matches = data.community_id.is_none_or(|c| c == community_id);
+  if creator_id != data.person_id || !community_matches {
+    Err(LemmyErrorType::ModNoteItemNotFromPerson)?
+  }
+  Ok(())
+}
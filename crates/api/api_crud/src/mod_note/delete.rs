@@ -0,0 +1,27 @@
+use super::check_mod_note_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::mod_note::ModNote;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::api::DeleteModNote;
+use lemmy_db_views_site::api::SuccessResponse;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn delete_mod_note(
+  Json(data): Json<DeleteModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let note = ModNote::read(&mut context.pool(), data.note_id).await?;
+  check_mod_note_permission(note.community_id, &local_user_view, &context).await?;
+
+  if note.creator_id != local_user_view.person.id {
+    Err(LemmyErrorType::NoModNoteEditAllowed)?
+  }
+
+  ModNote::delete(&mut context.pool(), data.note_id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,21 @@
+use super::check_mod_note_permission;
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{
+  ModNoteView,
+  api::{ListModNotes, ListModNotesResponse},
+};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_mod_notes(
+  Query(data): Query<ListModNotes>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListModNotesResponse>> {
+  check_mod_note_permission(data.community_id, &local_user_view, &context).await?;
+
+  let mod_notes = ModNoteView::list(&mut context.pool(), data.person_id, data.community_id).await?;
+
+  Ok(Json(ListModNotesResponse { mod_notes }))
+}
@@ -0,0 +1,28 @@
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_action, is_admin},
+};
+use lemmy_db_schema::{newtypes::CommunityId, source::community::Community};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// Community notes are shared between the mods of the community, site notes between admins.
+async fn check_mod_note_permission(
+  community_id: Option<CommunityId>,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  match community_id {
+    Some(community_id) => {
+      let community = Community::read(&mut context.pool(), community_id).await?;
+      check_community_mod_action(local_user_view, &community, true, &mut context.pool()).await
+    }
+    None => is_admin(local_user_view),
+  }
+}
@@ -0,0 +1,48 @@
+use super::check_mod_note_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::source::mod_note::{ModNote, ModNoteUpdateForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_person::{
+  ModNoteView,
+  api::{EditModNote, ModNoteResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+
+pub async fn edit_mod_note(
+  Json(data): Json<EditModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModNoteResponse>> {
+  let note = ModNote::read(&mut context.pool(), data.note_id).await?;
+  check_mod_note_permission(note.community_id, &local_user_view, &context).await?;
+
+  // Notes can be read by the whole team, but only edited by their author
+  if note.creator_id != local_user_view.person.id {
+    Err(LemmyErrorType::NoModNoteEditAllowed)?
+  }
+
+  let slur_regex = slur_regex(&context).await?;
+  let url_blocklist = get_url_blocklist(&context).await?;
+  check_slurs(&data.content, &slur_regex)?;
+  is_valid_body_field(&data.content, false)?;
+  let content = process_markdown(&data.content, &slur_regex, &url_blocklist, &context).await?;
+
+  let form = ModNoteUpdateForm {
+    content: Some(content),
+    updated_at: Some(Some(Utc::now())),
+  };
+  ModNote::update(&mut context.pool(), data.note_id, &form).await?;
+  let mod_note_view = ModNoteView::read(&mut context.pool(), data.note_id).await?;
+
+  Ok(Json(ModNoteResponse { mod_note_view }))
+}
@@ -0,0 +1,36 @@
+use super::check_modmail_thread_access;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::modmail::{ModmailThread, ModmailThreadUpdateForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailThreadView,
+  api::{ArchiveModmailThread, ModmailThreadResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Archiving only changes the mod inbox on this instance, it is not federated.
+pub async fn archive_modmail_thread(
+  Json(data): Json<ArchiveModmailThread>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModmailThreadResponse>> {
+  let (thread_view, as_mod) =
+    check_modmail_thread_access(data.thread_id, &local_user_view, &context).await?;
+  if !as_mod {
+    Err(LemmyErrorType::NotAModerator)?
+  }
+
+  let form = ModmailThreadUpdateForm {
+    archived: Some(data.archived),
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  ModmailThread::update(&mut context.pool(), thread_view.thread.id, &form).await?;
+
+  let thread_view = ModmailThreadView::read(&mut context.pool(), thread_view.thread.id).await?;
+  Ok(Json(ModmailThreadResponse { thread_view }))
+}
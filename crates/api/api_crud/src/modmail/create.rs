@@ -0,0 +1,52 @@
+use super::{process_modmail_content, send_modmail_message};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_deleted_removed, check_local_user_valid, slur_regex},
+};
+use lemmy_db_schema::source::{
+  community::Community,
+  modmail::{ModmailThread, ModmailThreadInsertForm},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailMessageView,
+  ModmailThreadView,
+  api::{CreateModmailThread, GetModmailThreadResponse},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{slurs::check_slurs, validation::is_valid_post_title},
+};
+
+pub async fn create_modmail_thread(
+  Json(data): Json<CreateModmailThread>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetModmailThreadResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  // Users who are banned from the community can still contact the mods.
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_deleted_removed(&community)?;
+
+  let slur_regex = slur_regex(&context).await?;
+  let subject = data.subject.trim().to_string();
+  is_valid_post_title(&subject)?;
+  check_slurs(&subject, &slur_regex)?;
+  let content = process_modmail_content(&data.content, &context).await?;
+
+  let form = ModmailThreadInsertForm::new(community.id, local_user_view.person.id, subject);
+  let thread = ModmailThread::create(&mut context.pool(), &form).await?;
+  let thread_view = ModmailThreadView::read(&mut context.pool(), thread.id).await?;
+
+  send_modmail_message(&thread_view, content, false, &local_user_view, &context).await?;
+
+  let thread_view = ModmailThreadView::read(&mut context.pool(), thread.id).await?;
+  let messages = ModmailMessageView::list_for_thread(&mut context.pool(), thread.id).await?;
+  Ok(Json(GetModmailThreadResponse {
+    thread_view,
+    messages,
+  }))
+}
@@ -0,0 +1,36 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_action};
+use lemmy_db_schema::source::community::Community;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailThreadView,
+  api::ListModmailThreads,
+  impls::ModmailThreadQuery,
+};
+use lemmy_diesel_utils::{pagination::PagedResponse, traits::Crud};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_modmail_threads(
+  Query(data): Query<ListModmailThreads>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<ModmailThreadView>>> {
+  if let Some(community_id) = data.community_id {
+    let community = Community::read(&mut context.pool(), community_id).await?;
+    check_community_mod_action(&local_user_view, &community, true, &mut context.pool()).await?;
+  }
+
+  let threads = ModmailThreadQuery {
+    community_id: data.community_id,
+    moderator_view: data.moderator_view,
+    archived: data.archived,
+    unread_only: data.unread_only,
+    page_cursor: data.page_cursor,
+    limit: data.limit,
+  }
+  .list(&mut context.pool(), local_user_view.person.id)
+  .await?;
+
+  Ok(Json(threads))
+}
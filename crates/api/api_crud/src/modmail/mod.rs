@@ -0,0 +1,86 @@
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::{
+  newtypes::ModmailThreadId,
+  source::modmail::{ModmailMessage, ModmailMessageInsertForm, ModmailThread},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::ModmailThreadView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
+
+pub mod archive;
+pub mod create;
+pub mod list;
+pub mod mark_read;
+pub mod read;
+pub mod reply;
+
+/// Only the creator of a thread and the mods of its community can access it. Returns the thread,
+/// and whether it is accessed on behalf of the mod team.
+async fn check_modmail_thread_access(
+  thread_id: ModmailThreadId,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<(ModmailThreadView, bool)> {
+  let thread_view = ModmailThreadView::read(&mut context.pool(), thread_id).await?;
+  if thread_view.thread.creator_id == local_user_view.person.id {
+    return Ok((thread_view, false));
+  }
+  check_community_mod_action(
+    local_user_view,
+    &thread_view.community,
+    true,
+    &mut context.pool(),
+  )
+  .await?;
+  Ok((thread_view, true))
+}
+
+/// Validate the message content, so that nothing is stored if it is rejected.
+async fn process_modmail_content(content: &str, context: &LemmyContext) -> LemmyResult<String> {
+  let slur_regex = slur_regex(context).await?;
+  let url_blocklist = get_url_blocklist(context).await?;
+  let content = process_markdown(content, &slur_regex, &url_blocklist, context).await?;
+  is_valid_body_field(&content, false)?;
+  Ok(content)
+}
+
+/// Add a message with the processed content to the thread, and send it to the community and its
+/// remote mods.
+async fn send_modmail_message(
+  thread_view: &ModmailThreadView,
+  content: String,
+  from_mod: bool,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<ModmailMessage> {
+  let form = ModmailMessageInsertForm::new(
+    thread_view.thread.id,
+    local_user_view.person.id,
+    content,
+    from_mod,
+  );
+  let message = ModmailMessage::create(&mut context.pool(), &form).await?;
+  let thread = ModmailThread::add_message(
+    &mut context.pool(),
+    thread_view.thread.id,
+    from_mod,
+    message.published_at,
+  )
+  .await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::SendModmail {
+      message: message.clone(),
+      thread,
+      community: thread_view.community.clone(),
+      actor: local_user_view.person.clone(),
+    },
+    context,
+  )?;
+  Ok(message)
+}
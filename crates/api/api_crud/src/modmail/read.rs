@@ -0,0 +1,26 @@
+use super::check_modmail_thread_access;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailMessageView,
+  api::{GetModmailThread, GetModmailThreadResponse},
+};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn get_modmail_thread(
+  Query(data): Query<GetModmailThread>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetModmailThreadResponse>> {
+  let (thread_view, _) =
+    check_modmail_thread_access(data.thread_id, &local_user_view, &context).await?;
+  let messages =
+    ModmailMessageView::list_for_thread(&mut context.pool(), thread_view.thread.id).await?;
+
+  Ok(Json(GetModmailThreadResponse {
+    thread_view,
+    messages,
+  }))
+}
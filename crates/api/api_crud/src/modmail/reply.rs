@@ -0,0 +1,31 @@
+use super::{check_modmail_thread_access, process_modmail_content, send_modmail_message};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::check_local_user_valid};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_modmail::{
+  ModmailMessageView,
+  api::{CreateModmailMessage, ModmailMessageResponse},
+};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn create_modmail_message(
+  Json(data): Json<CreateModmailMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModmailMessageResponse>> {
+  check_local_user_valid(&local_user_view)?;
+  let (thread_view, from_mod) =
+    check_modmail_thread_access(data.thread_id, &local_user_view, &context).await?;
+  let content = process_modmail_content(&data.content, &context).await?;
+
+  let message =
+    send_modmail_message(&thread_view, content, from_mod, &local_user_view, &context).await?;
+
+  Ok(Json(ModmailMessageResponse {
+    message_view: ModmailMessageView {
+      message,
+      creator: local_user_view.person,
+    },
+  }))
+}
@@ -10,7 +10,7 @@ use lemmy_api_utils::{
   request::generate_post_link_metadata,
   send_activity::SendActivityData,
   utils::{
-    check_community_user_action,
+    check_community_content_action,
     check_nsfw_allowed,
     get_url_blocklist,
     honeypot_check,
@@ -22,7 +22,10 @@ use lemmy_api_utils::{
 };
 use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
-  source::post::{Post, PostActions, PostInsertForm, PostLikeForm},
+  source::{
+    draft::Draft,
+    post::{Post, PostActions, PostInsertForm, PostLikeForm},
+  },
   traits::Likeable,
 };
 use lemmy_db_views_community::CommunityView;
@@ -89,7 +92,7 @@ pub async fn create_post(
   )
   .await?;
   let community = &community_view.community;
-  check_community_user_action(&local_user_view, community, &mut context.pool()).await?;
+  check_community_content_action(&local_user_view, community, &mut context.pool()).await?;
 
   // Ensure that all posts in NSFW communities are marked as NSFW
   let nsfw = if community.nsfw {
@@ -110,6 +113,9 @@ pub async fn create_post(
 
   let scheduled_publish_time_at =
     convert_published_time(data.scheduled_publish_time_at, &local_user_view, &context).await?;
+  // Posts of shadow restricted users are neither federated nor generate notifications until the
+  // restriction is lifted.
+  let held_for_review = local_user_view.local_user.restricted_at.is_some();
   let mut post_form = PostInsertForm {
     url,
     body,
@@ -118,6 +124,7 @@ pub async fn create_post(
     language_id: data.language_id,
     federation_pending: Some(community_use_pending(community, &context).await),
     scheduled_publish_time_at,
+    held_for_review: Some(held_for_review),
     ..PostInsertForm::new(
       data.name.trim().to_string(),
       local_user_view.person.id,
@@ -142,7 +149,7 @@ pub async fn create_post(
   }
 
   let community_id = community.id;
-  let federate_post = if scheduled_publish_time_at.is_none() {
+  let federate_post = if scheduled_publish_time_at.is_none() && !held_for_review {
     send_webmention(inserted_post.clone(), community);
     |post| Some(SendActivityData::CreatePost(post))
   } else {
@@ -163,17 +170,23 @@ pub async fn create_post(
 
   PostActions::like(&mut context.pool(), &like_form).await?;
 
-  NotifyData {
-    do_send_email: !local_site.disable_email_notifications,
-    ..NotifyData::new(
-      inserted_post.clone(),
-      local_user_view.person.clone(),
-      community.clone(),
-    )
+  if !held_for_review {
+    NotifyData {
+      do_send_email: !local_site.disable_email_notifications,
+      ..NotifyData::new(
+        inserted_post.clone(),
+        local_user_view.person.clone(),
+        community.clone(),
+      )
+    }
+    .send(&context);
   }
-  .send(&context);
 
   PostActions::mark_as_read(&mut context.pool(), person_id, &[post_id]).await?;
 
+  if let Some(draft_id) = data.draft_id {
+    Draft::delete_for_user(&mut context.pool(), draft_id, local_user_view.local_user.id).await?;
+  }
+
   build_post_response(&context, community_id, local_user_view, post_id).await
 }
@@ -1,3 +1,4 @@
+use crate::removal_reason::{RemovedContent, notify_removal, render_removal_reason};
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_utils::{
@@ -5,13 +6,14 @@ use lemmy_api_utils::{
   context::LemmyContext,
   notify::notify_mod_action,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{check_community_mod_action, check_rule_in_community},
 };
 use lemmy_db_schema::{
   source::{
     community::Community,
     local_user::LocalUser,
     modlog::{Modlog, ModlogInsertForm},
+    person::Person,
     post::{Post, PostUpdateForm},
     post_report::PostReport,
   },
@@ -44,6 +46,22 @@ pub async fn remove_post(
     vec![orig_post.creator_id],
   )
   .await?;
+  let rule = check_rule_in_community(data.rule_id, community.id, &mut context.pool()).await?;
+  let community_id = orig_post.community_id;
+  let content = RemovedContent {
+    author: Person::read(&mut context.pool(), orig_post.creator_id).await?,
+    post: orig_post,
+    comment: None,
+    community,
+  };
+  let reason = render_removal_reason(
+    data.removal_reason_id,
+    &data.reason,
+    rule.as_ref(),
+    &content,
+    &context,
+  )
+  .await?;
 
   // Update the post
   let post_id = data.post_id;
@@ -62,8 +80,8 @@ pub async fn remove_post(
     .await?;
 
   // Mod tables
-  let form =
-    ModlogInsertForm::mod_remove_post(local_user_view.person.id, &post, removed, &data.reason);
+  let form = ModlogInsertForm::mod_remove_post(local_user_view.person.id, &post, removed, &reason)
+    .with_rule(data.rule_id);
   let action = Modlog::create(&mut context.pool(), &[form]).await?;
   notify_mod_action(action, context.app_data());
 
@@ -71,11 +89,22 @@ pub async fn remove_post(
     SendActivityData::RemovePost {
       post,
       moderator: local_user_view.person.clone(),
-      reason: data.reason.clone(),
+      reason: reason.clone(),
       removed: data.removed,
     },
     &context,
   )?;
 
-  build_post_response(&context, orig_post.community_id, local_user_view, post_id).await
+  if removed {
+    notify_removal(
+      data.notify_author,
+      &reason,
+      content,
+      &local_user_view,
+      &context,
+    )
+    .await?;
+  }
+
+  build_post_response(&context, community_id, local_user_view, post_id).await
 }
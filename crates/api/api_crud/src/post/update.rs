@@ -10,7 +10,7 @@ use lemmy_api_utils::{
   request::generate_post_link_metadata,
   send_activity::SendActivityData,
   utils::{
-    check_community_user_action,
+    check_community_content_action,
     check_nsfw_allowed,
     get_url_blocklist,
     process_markdown_opt,
@@ -114,7 +114,8 @@ pub async fn edit_post(
     data.nsfw
   };
 
-  check_community_user_action(&local_user_view, &orig_post.community, &mut context.pool()).await?;
+  check_community_content_action(&local_user_view, &orig_post.community, &mut context.pool())
+    .await?;
 
   // Verify that only the creator can edit
   if !Post::is_post_creator(local_user_view.person.id, orig_post.post.creator_id) {
@@ -163,12 +164,14 @@ pub async fn edit_post(
     update_post_tags(&orig_post.post, tags, &context).await?;
   }
 
-  NotifyData::new(
-    updated_post.clone(),
-    local_user_view.person.clone(),
-    orig_post.community.clone(),
-  )
-  .send(&context);
+  if !updated_post.held_for_review {
+    NotifyData::new(
+      updated_post.clone(),
+      local_user_view.person.clone(),
+      orig_post.community.clone(),
+    )
+    .send(&context);
+  }
 
   // send out federation/webmention if necessary
   match (
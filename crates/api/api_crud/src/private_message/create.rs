@@ -15,6 +15,7 @@ use lemmy_api_utils::{
 };
 use lemmy_db_schema::{
   source::{
+    draft::Draft,
     person::PersonActions,
     private_message::{PrivateMessage, PrivateMessageInsertForm},
   },
@@ -78,6 +79,10 @@ pub async fn create_private_message(
     &context,
   )?;
 
+  if let Some(draft_id) = data.draft_id {
+    Draft::delete_for_user(&mut context.pool(), draft_id, local_user_view.local_user.id).await?;
+  }
+
   Ok(Json(PrivateMessageResponse {
     private_message_view: view,
   }))
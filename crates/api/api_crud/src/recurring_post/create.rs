@@ -0,0 +1,71 @@
+use super::{
+  build_recurring_post_view,
+  check_recurring_post_creator,
+  validate_schedule,
+  validate_tags,
+};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_action, get_url_blocklist, process_markdown_opt, slur_regex},
+};
+use lemmy_db_schema::{
+  impls::actor_language::validate_post_language,
+  source::{
+    community::Community,
+    recurring_post::{RecurringPost, RecurringPostInsertForm, RecurringPostTag},
+  },
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::api::{CreateRecurringPost, RecurringPostResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
+
+pub async fn create_recurring_post(
+  Json(data): Json<CreateRecurringPost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RecurringPostResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+
+  let creator_id = data.creator_id.unwrap_or(local_user_view.person.id);
+  check_recurring_post_creator(creator_id, community.id, &local_user_view, &context).await?;
+
+  let slur_regex = slur_regex(&context).await?;
+  let url_blocklist = get_url_blocklist(&context).await?;
+  let next_publish_at = validate_schedule(&data.schedule, &data.name, &slur_regex)?;
+  let body = process_markdown_opt(&data.body, &slur_regex, &url_blocklist, &context).await?;
+  if let Some(body) = &body {
+    is_valid_body_field(body, true)?;
+  }
+  validate_post_language(&mut context.pool(), data.language_id, community.id).await?;
+  if let Some(tags) = &data.tags {
+    validate_tags(community.id, tags, &context).await?;
+  }
+
+  let form = RecurringPostInsertForm {
+    body,
+    language_id: data.language_id,
+    nsfw: data.nsfw,
+    feature_post: data.feature_post,
+    unfeature_previous: data.unfeature_previous,
+    ..RecurringPostInsertForm::new(
+      community.id,
+      creator_id,
+      data.schedule.trim().to_string(),
+      data.name.trim().to_string(),
+      next_publish_at,
+    )
+  };
+  let recurring_post = RecurringPost::create(&mut context.pool(), &form).await?;
+
+  if let Some(tags) = &data.tags {
+    RecurringPostTag::update(&mut context.pool(), recurring_post.id, tags).await?;
+  }
+
+  Ok(Json(RecurringPostResponse {
+    recurring_post_view: build_recurring_post_view(recurring_post, &context).await?,
+  }))
+}
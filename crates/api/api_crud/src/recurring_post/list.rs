@@ -0,0 +1,48 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::{context::LemmyContext, utils::check_community_mod_action};
+use lemmy_db_schema::source::{
+  community::Community,
+  recurring_post::{RecurringPost, RecurringPostTag},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::api::{ListRecurringPosts, RecurringPostView};
+use lemmy_diesel_utils::{pagination::PagedResponse, traits::Crud};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_recurring_posts(
+  Query(data): Query<ListRecurringPosts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PagedResponse<RecurringPostView>>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, true, &mut context.pool()).await?;
+
+  let res = RecurringPost::list_for_community(
+    &mut context.pool(),
+    community.id,
+    data.page_cursor,
+    data.limit,
+  )
+  .await?;
+
+  let ids: Vec<_> = res.items.iter().map(|r| r.id).collect();
+  let tags = RecurringPostTag::read_for_recurring_posts(&mut context.pool(), &ids).await?;
+  let items = res
+    .items
+    .into_iter()
+    .map(|recurring_post| RecurringPostView {
+      tags: tags
+        .iter()
+        .filter(|t| t.recurring_post_id == recurring_post.id)
+        .map(|t| t.tag_id)
+        .collect(),
+      recurring_post,
+    })
+    .collect();
+
+  Ok(Json(PagedResponse {
+    items,
+    next_page: res.next_page,
+    prev_page: res.prev_page,
+  }))
+}
@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_is_mod_or_admin, check_local_user_valid, is_admin},
+};
+use lemmy_db_schema::{
+  newtypes::{CommunityId, TagId},
+  source::{
+    recurring_post::{RecurringPost, RecurringPostTag},
+    tag::Tag,
+  },
+};
+use lemmy_db_schema_file::PersonId;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::api::RecurringPostView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{
+    schedule::{CronSchedule, fill_date_placeholders},
+    slurs::check_slurs,
+    validation::{check_api_elements_count, is_valid_post_title},
+  },
+};
+use regex::Regex;
+use std::collections::HashSet;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// Checks the schedule and title template, and returns when the next post should be published.
+fn validate_schedule(
+  schedule: &str,
+  name: &str,
+  slur_regex: &Regex,
+) -> LemmyResult<Option<DateTime<Utc>>> {
+  let next_publish_at = CronSchedule::parse(schedule)?.next_after(Utc::now());
+
+  // Validate the title as it will look in the published post
+  check_slurs(name, slur_regex)?;
+  let title = fill_date_placeholders(name, next_publish_at.unwrap_or_else(Utc::now));
+  is_valid_post_title(&title)?;
+
+  Ok(next_publish_at)
+}
+
+/// The posts are created from the given account, so it needs to be a local mod of the community.
+/// Only admins can schedule posts in the name of somebody else.
+async fn check_recurring_post_creator(
+  creator_id: PersonId,
+  community_id: CommunityId,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if creator_id != local_user_view.person.id {
+    is_admin(local_user_view)?;
+  }
+  let creator_view = LocalUserView::read_person(&mut context.pool(), creator_id).await?;
+  check_local_user_valid(&creator_view)?;
+  check_is_mod_or_admin(&mut context.pool(), creator_id, community_id).await
+}
+
+async fn validate_tags(
+  community_id: CommunityId,
+  tag_ids: &[TagId],
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  check_api_elements_count(tag_ids.len())?;
+  let community_tags = Tag::read_for_community(&mut context.pool(), community_id)
+    .await?
+    .into_iter()
+    .map(|t| t.id)
+    .collect::<HashSet<_>>();
+  if !community_tags.is_superset(&tag_ids.iter().copied().collect()) {
+    Err(LemmyErrorType::TagNotInCommunity)?
+  }
+  Ok(())
+}
+
+async fn build_recurring_post_view(
+  recurring_post: RecurringPost,
+  context: &LemmyContext,
+) -> LemmyResult<RecurringPostView> {
+  let tags = RecurringPostTag::read_for_recurring_posts(&mut context.pool(), &[recurring_post.id])
+    .await?
+    .into_iter()
+    .map(|t| t.tag_id)
+    .collect();
+  Ok(RecurringPostView {
+    recurring_post,
+    tags,
+  })
+}
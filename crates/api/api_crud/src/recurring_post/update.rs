@@ -0,0 +1,77 @@
+use super::{
+  build_recurring_post_view,
+  check_recurring_post_creator,
+  validate_schedule,
+  validate_tags,
+};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{check_community_mod_action, get_url_blocklist, process_markdown_opt, slur_regex},
+};
+use lemmy_db_schema::{
+  impls::actor_language::validate_post_language,
+  source::{
+    community::Community,
+    recurring_post::{RecurringPost, RecurringPostTag, RecurringPostUpdateForm},
+  },
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_post::api::{EditRecurringPost, RecurringPostResponse};
+use lemmy_diesel_utils::{traits::Crud, utils::diesel_string_update};
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
+
+pub async fn edit_recurring_post(
+  Json(data): Json<EditRecurringPost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RecurringPostResponse>> {
+  let orig = RecurringPost::read(&mut context.pool(), data.id).await?;
+  let community = Community::read(&mut context.pool(), orig.community_id).await?;
+  check_community_mod_action(&local_user_view, &community, false, &mut context.pool()).await?;
+
+  if let Some(creator_id) = data.creator_id {
+    check_recurring_post_creator(creator_id, community.id, &local_user_view, &context).await?;
+  }
+
+  let slur_regex = slur_regex(&context).await?;
+  let url_blocklist = get_url_blocklist(&context).await?;
+  let schedule = data.schedule.as_deref().unwrap_or(&orig.schedule);
+  let name = data.name.as_deref().unwrap_or(&orig.name);
+  let next_publish_at = validate_schedule(schedule, name, &slur_regex)?;
+  let body = process_markdown_opt(&data.body, &slur_regex, &url_blocklist, &context).await?;
+  if let Some(body) = &body {
+    is_valid_body_field(body, true)?;
+  }
+  validate_post_language(&mut context.pool(), data.language_id, community.id).await?;
+  if let Some(tags) = &data.tags {
+    validate_tags(community.id, tags, &context).await?;
+  }
+
+  let form = RecurringPostUpdateForm {
+    creator_id: data.creator_id,
+    schedule: data.schedule.as_ref().map(|s| s.trim().to_string()),
+    name: data.name.as_ref().map(|n| n.trim().to_string()),
+    body: diesel_string_update(body.as_deref()),
+    language_id: data.language_id.map(Some),
+    nsfw: data.nsfw,
+    feature_post: data.feature_post,
+    unfeature_previous: data.unfeature_previous,
+    // Recalculate in case the schedule changed, or publishing is resumed after a pause
+    next_publish_at: Some(next_publish_at),
+    enabled: data.enabled,
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  let recurring_post = RecurringPost::update(&mut context.pool(), orig.id, &form).await?;
+
+  if let Some(tags) = &data.tags {
+    RecurringPostTag::update(&mut context.pool(), recurring_post.id, tags).await?;
+  }
+
+  Ok(Json(RecurringPostResponse {
+    recurring_post_view: build_recurring_post_view(recurring_post, &context).await?,
+  }))
+}
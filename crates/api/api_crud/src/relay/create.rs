@@ -0,0 +1,30 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::is_admin,
+};
+use lemmy_apub_objects::objects::relay::ApubRelay;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{CreateRelay, RelayResponse};
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_url};
+use url::Url;
+
+pub async fn create_relay(
+  Json(data): Json<CreateRelay>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RelayResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+  let ap_id = Url::parse(&data.ap_id)?;
+  is_valid_url(&ap_id)?;
+
+  let relay = ApubRelay::subscribe(ap_id, data.mode.unwrap_or_default(), &context).await?;
+
+  // The relay only starts sending announces once it accepts the follow
+  ActivityChannel::submit_activity(SendActivityData::FollowRelay(relay.clone(), true), &context)?;
+
+  Ok(Json(RelayResponse { relay }))
+}
@@ -0,0 +1,28 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::is_admin,
+};
+use lemmy_db_schema::source::relay::Relay;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{DeleteRelay, SuccessResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_relay(
+  Json(data): Json<DeleteRelay>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let relay = Relay::read(&mut context.pool(), data.id).await?;
+  Relay::delete(&mut context.pool(), relay.id).await?;
+
+  ActivityChannel::submit_activity(SendActivityData::FollowRelay(relay, false), &context)?;
+
+  Ok(Json(SuccessResponse::default()))
+}
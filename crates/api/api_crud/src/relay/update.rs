@@ -0,0 +1,25 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::is_admin};
+use lemmy_db_schema::source::relay::{Relay, RelayUpdateForm};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_site::api::{EditRelay, RelayResponse};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn edit_relay(
+  Json(data): Json<EditRelay>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RelayResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let form = RelayUpdateForm {
+    mode: Some(data.mode),
+    ..Default::default()
+  };
+  let relay = Relay::update(&mut context.pool(), data.id, &form).await?;
+
+  Ok(Json(RelayResponse { relay }))
+}
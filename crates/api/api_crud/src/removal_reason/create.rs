@@ -0,0 +1,38 @@
+use super::check_removal_reason_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_utils::{context::LemmyContext, utils::slur_regex};
+use lemmy_db_schema::source::removal_reason::{RemovalReason, RemovalReasonInsertForm};
+use lemmy_db_views_community::api::CreateRemovalReason;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{
+    slurs::check_slurs,
+    validation::{check_api_elements_count, is_valid_body_field, is_valid_post_title},
+  },
+};
+
+pub async fn create_removal_reason(
+  Json(data): Json<CreateRemovalReason>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RemovalReason>> {
+  check_removal_reason_permission(data.community_id, &local_user_view, &context).await?;
+
+  let existing = RemovalReason::read_for_community(&mut context.pool(), data.community_id).await?;
+  check_api_elements_count(existing.len())?;
+
+  let slur_regex = slur_regex(&context).await?;
+  let title = data.title.trim().to_string();
+  is_valid_post_title(&title)?;
+  check_slurs(&title, &slur_regex)?;
+  is_valid_body_field(&data.template, false)?;
+  check_slurs(&data.template, &slur_regex)?;
+
+  let form = RemovalReasonInsertForm::new(data.community_id, title, data.template);
+  let removal_reason = RemovalReason::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(removal_reason))
+}
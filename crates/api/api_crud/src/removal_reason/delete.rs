@@ -0,0 +1,29 @@
+use super::check_removal_reason_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::removal_reason::{RemovalReason, RemovalReasonUpdateForm};
+use lemmy_db_views_community::api::DeleteRemovalReason;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_removal_reason(
+  Json(data): Json<DeleteRemovalReason>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RemovalReason>> {
+  let removal_reason = RemovalReason::read(&mut context.pool(), data.removal_reason_id).await?;
+  check_removal_reason_permission(removal_reason.community_id, &local_user_view, &context).await?;
+
+  let form = RemovalReasonUpdateForm {
+    updated_at: Some(Some(Utc::now())),
+    deleted: Some(data.delete),
+    ..Default::default()
+  };
+  let removal_reason =
+    RemovalReason::update(&mut context.pool(), data.removal_reason_id, &form).await?;
+
+  Ok(Json(removal_reason))
+}
@@ -0,0 +1,20 @@
+use super::check_removal_reason_permission;
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::removal_reason::RemovalReason;
+use lemmy_db_views_community::api::{ListRemovalReasons, ListRemovalReasonsResponse};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_removal_reasons(
+  Query(data): Query<ListRemovalReasons>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListRemovalReasonsResponse>> {
+  check_removal_reason_permission(data.community_id, &local_user_view, &context).await?;
+
+  let removal_reasons =
+    RemovalReason::read_for_community(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListRemovalReasonsResponse { removal_reasons }))
+}
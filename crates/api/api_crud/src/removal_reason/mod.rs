@@ -0,0 +1,181 @@
+use lemmy_api_utils::{
+  context::LemmyContext,
+  notify::{NotifyData, notify_private_message},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_db_schema::{
+  RemovalNotification,
+  newtypes::{CommunityId, RemovalReasonId},
+  source::{
+    comment::{Comment, CommentInsertForm},
+    community::Community,
+    person::Person,
+    post::Post,
+    private_message::{PrivateMessage, PrivateMessageInsertForm},
+    removal_reason::RemovalReason,
+    rule::Rule,
+  },
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_private_message::PrivateMessageView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// The removed post or comment, used to fill in the removal reason and to notify the author.
+pub(crate) struct RemovedContent {
+  pub post: Post,
+  pub comment: Option<Comment>,
+  pub community: Community,
+  pub author: Person,
+}
+
+/// Fill in the template of the selected removal reason. Without a removal reason, the free-form
+/// reason is used as is.
+pub(crate) async fn render_removal_reason(
+  removal_reason_id: Option<RemovalReasonId>,
+  reason: &str,
+  rule: Option<&Rule>,
+  content: &RemovedContent,
+  context: &LemmyContext,
+) -> LemmyResult<String> {
+  let Some(removal_reason_id) = removal_reason_id else {
+    return Ok(reason.to_string());
+  };
+  let removal_reason = RemovalReason::read(&mut context.pool(), removal_reason_id).await?;
+  if removal_reason.deleted || removal_reason.community_id != content.community.id {
+    Err(LemmyErrorType::RemovalReasonNotInCommunity)?
+  }
+
+  let rendered = fill_placeholders(
+    &removal_reason.template,
+    &[
+      ("rule", rule.map(|r| r.title.as_str()).unwrap_or_default()),
+      ("post_title", &content.post.name),
+      ("community", &content.community.name),
+      ("author", &content.author.name),
+      ("reason", reason),
+    ],
+  );
+  is_valid_body_field(&rendered, false)?;
+
+  // The placeholders are filled with user provided text, so apply the same filters as for
+  // comments and private messages.
+  let slur_regex = slur_regex(context).await?;
+  let url_blocklist = get_url_blocklist(context).await?;
+  process_markdown(&rendered, &slur_regex, &url_blocklist, context).await
+}
+
+/// Replace `{name}` placeholders with the given values. This is done in a single pass, so that
+/// placeholders inside of the values (eg a post titled `{reason}`) are left as is.
+fn fill_placeholders(template: &str, values: &[(&str, &str)]) -> String {
+  let mut out = String::with_capacity(template.len());
+  let mut rest = template;
+  while let Some((before, after)) = rest.split_once('{') {
+    out.push_str(before);
+    let placeholder = after.split_once('}').and_then(|(name, remaining)| {
+      values
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| (*value, remaining))
+    });
+    match placeholder {
+      Some((value, remaining)) => {
+        out.push_str(value);
+        rest = remaining;
+      }
+      None => {
+        out.push('{');
+        rest = after;
+      }
+    }
+  }
+  out.push_str(rest);
+  out
+}
+
+/// Send the removal reason to the author of the removed post or comment, either as private
+/// message or as distinguished mod comment. The comment is a reply to the removed comment, or a
+/// top-level comment on the removed post.
+pub(crate) async fn notify_removal(
+  notify_author: Option<RemovalNotification>,
+  reason: &str,
+  content: RemovedContent,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let Some(notify_author) = notify_author else {
+    return Ok(());
+  };
+  let moderator = &local_user_view.person;
+  match notify_author {
+    RemovalNotification::PrivateMessage => {
+      let (kind, link) = match &content.comment {
+        Some(comment) => ("comment", &comment.ap_id),
+        None => ("post", &content.post.ap_id),
+      };
+      let message = format!(
+        "Your [{kind}]({link}) in [{}]({}) was removed:\n\n{reason}",
+        content.community.name, content.community.ap_id
+      );
+      let form = PrivateMessageInsertForm::new(moderator.id, content.author.id, message);
+      let private_message = PrivateMessage::create(&mut context.pool(), &form).await?;
+      let view = PrivateMessageView::read(&mut context.pool(), private_message.id).await?;
+      notify_private_message(&view, true, context);
+      ActivityChannel::submit_activity(SendActivityData::CreatePrivateMessage(view), context)?;
+    }
+    RemovalNotification::Comment => {
+      let form = CommentInsertForm {
+        distinguished: Some(true),
+        ..CommentInsertForm::new(moderator.id, content.post.id, reason.to_string())
+      };
+      let parent_path = content.comment.as_ref().map(|c| &c.path);
+      let inserted_comment = Comment::create(&mut context.pool(), &form, parent_path).await?;
+
+      NotifyData {
+        comment: Some(inserted_comment.clone()),
+        ..NotifyData::new(content.post, moderator.clone(), content.community)
+      }
+      .send(context);
+
+      ActivityChannel::submit_activity(SendActivityData::CreateComment(inserted_comment), context)?;
+    }
+  }
+  Ok(())
+}
+
+/// Removal reasons are only visible to and editable by the mods of the community.
+async fn check_removal_reason_permission(
+  community_id: CommunityId,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let community = Community::read(&mut context.pool(), community_id).await?;
+  check_community_mod_action(local_user_view, &community, false, &mut context.pool()).await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::fill_placeholders;
+
+  #[test]
+  fn test_fill_placeholders() {
+    let values = [("post_title", "{reason}"), ("reason", "spam")];
+    assert_eq!(
+      "Removed {reason}: spam",
+      fill_placeholders("Removed {post_title}: {reason}", &values)
+    );
+    assert_eq!(
+      "{unknown} {spam} {",
+      fill_placeholders("{unknown} {{reason}} {", &values)
+    );
+  }
+}
@@ -0,0 +1,47 @@
+use super::check_removal_reason_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{context::LemmyContext, utils::slur_regex};
+use lemmy_db_schema::source::removal_reason::{RemovalReason, RemovalReasonUpdateForm};
+use lemmy_db_views_community::api::EditRemovalReason;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{
+    slurs::check_slurs,
+    validation::{is_valid_body_field, is_valid_post_title},
+  },
+};
+
+pub async fn edit_removal_reason(
+  Json(data): Json<EditRemovalReason>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RemovalReason>> {
+  let removal_reason = RemovalReason::read(&mut context.pool(), data.removal_reason_id).await?;
+  check_removal_reason_permission(removal_reason.community_id, &local_user_view, &context).await?;
+
+  let slur_regex = slur_regex(&context).await?;
+  let title = data.title.as_ref().map(|t| t.trim().to_string());
+  if let Some(title) = &title {
+    is_valid_post_title(title)?;
+    check_slurs(title, &slur_regex)?;
+  }
+  if let Some(template) = &data.template {
+    is_valid_body_field(template, false)?;
+    check_slurs(template, &slur_regex)?;
+  }
+
+  let form = RemovalReasonUpdateForm {
+    title,
+    template: data.template,
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  let removal_reason =
+    RemovalReason::update(&mut context.pool(), data.removal_reason_id, &form).await?;
+
+  Ok(Json(removal_reason))
+}
@@ -0,0 +1,74 @@
+use super::{check_rule_permission, federate_rules};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use lemmy_api_utils::{context::LemmyContext, utils::slur_regex};
+use lemmy_db_schema::source::rule::{Rule, RuleInsertForm, RuleUpdateForm};
+use lemmy_db_views_community::api::CreateRule;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::{connection::get_conn, traits::Crud};
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{
+    slurs::check_slurs,
+    validation::{check_api_elements_count, is_valid_body_field, is_valid_post_title},
+  },
+};
+use url::Url;
+
+pub async fn create_rule(
+  Json(data): Json<CreateRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Rule>> {
+  let community = check_rule_permission(data.community_id, &local_user_view, &context).await?;
+
+  let existing = Rule::read_for_community(&mut context.pool(), data.community_id).await?;
+  check_api_elements_count(existing.len())?;
+
+  let slur_regex = slur_regex(&context).await?;
+  let title = data.title.trim().to_string();
+  is_valid_post_title(&title)?;
+  check_slurs(&title, &slur_regex)?;
+  if let Some(desc) = &data.description {
+    is_valid_body_field(desc, false)?;
+    check_slurs(desc, &slur_regex)?;
+  }
+
+  // By default, add the new rule at the end
+  let position = data
+    .position
+    .unwrap_or_else(|| existing.iter().map(|r| r.position + 1).max().unwrap_or(1));
+  let form = RuleInsertForm {
+    description: data.description.clone(),
+    position: Some(position),
+    ..RuleInsertForm::new(data.community_id, title)
+  };
+  let ap_id_prefix = match &community {
+    Some(community) => community.ap_id.to_string(),
+    None => context.settings().get_protocol_and_hostname(),
+  };
+
+  let pool = &mut context.pool();
+  let conn = &mut get_conn(pool).await?;
+  let rule = conn
+    .run_transaction(|conn| {
+      async move {
+        let rule = Rule::create(&mut conn.into(), &form).await?;
+
+        // Now that the id is known, set the federation id
+        let ap_id = format!("{}/rule/{}", ap_id_prefix, rule.id.0);
+        let form = RuleUpdateForm {
+          ap_id: Some(Url::parse(&ap_id)?.into()),
+          ..Default::default()
+        };
+        Rule::update(&mut conn.into(), rule.id, &form).await
+      }
+      .scope_boxed()
+    })
+    .await?;
+
+  federate_rules(community, local_user_view, &context)?;
+
+  Ok(Json(rule))
+}
@@ -0,0 +1,31 @@
+use super::{check_rule_permission, federate_rules};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::rule::{Rule, RuleUpdateForm};
+use lemmy_db_views_community::api::DeleteRule;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn delete_rule(
+  Json(data): Json<DeleteRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Rule>> {
+  let rule = Rule::read(&mut context.pool(), data.rule_id).await?;
+  let community = check_rule_permission(rule.community_id, &local_user_view, &context).await?;
+
+  // Soft delete the rule, so that reports and modlog entries can still reference it
+  let form = RuleUpdateForm {
+    updated_at: Some(Some(Utc::now())),
+    deleted: Some(data.delete),
+    ..Default::default()
+  };
+  let rule = Rule::update(&mut context.pool(), data.rule_id, &form).await?;
+
+  federate_rules(community, local_user_view, &context)?;
+
+  Ok(Json(rule))
+}
@@ -0,0 +1,14 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::rule::Rule;
+use lemmy_db_views_community::api::{ListRules, ListRulesResponse};
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_rules(
+  Query(data): Query<ListRules>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<ListRulesResponse>> {
+  let rules = Rule::read_for_community(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListRulesResponse { rules }))
+}
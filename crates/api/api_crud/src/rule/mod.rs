@@ -0,0 +1,48 @@
+use lemmy_api_utils::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, is_admin},
+};
+use lemmy_db_schema::{newtypes::CommunityId, source::community::Community};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// Community rules can be changed by mods, site rules only by admins.
+async fn check_rule_permission(
+  community_id: Option<CommunityId>,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<Option<Community>> {
+  match community_id {
+    Some(community_id) => {
+      let community = Community::read(&mut context.pool(), community_id).await?;
+      check_community_mod_action(local_user_view, &community, false, &mut context.pool()).await?;
+      Ok(Some(community))
+    }
+    None => {
+      is_admin(local_user_view)?;
+      Ok(None)
+    }
+  }
+}
+
+/// Rules are included in the federated `Group`, so send an update after any change.
+fn federate_rules(
+  community: Option<Community>,
+  local_user_view: LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if let Some(community) = community {
+    ActivityChannel::submit_activity(
+      SendActivityData::UpdateCommunity(local_user_view.person, community),
+      context,
+    )?;
+  }
+  Ok(())
+}
@@ -0,0 +1,49 @@
+use super::{check_rule_permission, federate_rules};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_utils::{context::LemmyContext, utils::slur_regex};
+use lemmy_db_schema::source::rule::{Rule, RuleUpdateForm};
+use lemmy_db_views_community::api::EditRule;
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_diesel_utils::{traits::Crud, utils::diesel_string_update};
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{
+    slurs::check_slurs,
+    validation::{is_valid_body_field, is_valid_post_title},
+  },
+};
+
+pub async fn edit_rule(
+  Json(data): Json<EditRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Rule>> {
+  let rule = Rule::read(&mut context.pool(), data.rule_id).await?;
+  let community = check_rule_permission(rule.community_id, &local_user_view, &context).await?;
+
+  let slur_regex = slur_regex(&context).await?;
+  let title = data.title.as_ref().map(|t| t.trim().to_string());
+  if let Some(title) = &title {
+    is_valid_post_title(title)?;
+    check_slurs(title, &slur_regex)?;
+  }
+  if let Some(desc) = &data.description {
+    is_valid_body_field(desc, false)?;
+    check_slurs(desc, &slur_regex)?;
+  }
+
+  let form = RuleUpdateForm {
+    title,
+    description: diesel_string_update(data.description.as_deref()),
+    position: data.position,
+    updated_at: Some(Some(Utc::now())),
+    ..Default::default()
+  };
+  let rule = Rule::update(&mut context.pool(), data.rule_id, &form).await?;
+
+  federate_rules(community, local_user_view, &context)?;
+
+  Ok(Json(rule))
+}
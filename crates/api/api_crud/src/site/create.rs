@@ -24,13 +24,21 @@ use lemmy_db_views_site::{
   SiteView,
   api::{CreateSite, SiteResponse},
 };
-use lemmy_diesel_utils::{dburl::DbUrl, traits::Crud, utils::diesel_string_update};
+use lemmy_diesel_utils::{
+  dburl::DbUrl,
+  traits::Crud,
+  utils::{diesel_opt_number_update, diesel_string_update},
+};
 use lemmy_utils::{
   error::{LemmyErrorType, LemmyResult},
   utils::{
     slurs::check_slurs,
     validation::{
       build_and_check_regex,
+      check_draft_max_age_days,
+      check_modlog_retention,
+      check_strike_settings,
+      check_upload_quota_mb,
       is_valid_body_field,
       site_name_length_check,
       summary_length_check,
@@ -104,6 +112,15 @@ pub async fn create_site(
     disallow_nsfw_content: data.disallow_nsfw_content,
     disable_email_notifications: data.disable_email_notifications,
     suggested_communities: data.suggested_communities,
+    draft_max_age_days: data.draft_max_age_days,
+    upload_quota_mb: diesel_opt_number_update(data.upload_quota_mb),
+    moderator_upload_quota_mb: diesel_opt_number_update(data.moderator_upload_quota_mb),
+    strike_ban_threshold: diesel_opt_number_update(data.strike_ban_threshold),
+    strike_window_days: not_zero(data.strike_window_days),
+    strike_ban_days: not_zero(data.strike_ban_days),
+    modlog_retention_days: diesel_opt_number_update(data.modlog_retention_days),
+    modlog_anonymize_days: diesel_opt_number_update(data.modlog_anonymize_days),
+    federation_authorized_fetch: data.federation_authorized_fetch,
     ..Default::default()
   };
 
@@ -170,6 +187,19 @@ fn validate_create_payload(local_site: &LocalSite, create_site: &CreateSite) ->
     is_valid_body_field(body, false)?;
   }
 
+  check_strike_settings(
+    create_site.strike_ban_threshold,
+    create_site.strike_window_days,
+    create_site.strike_ban_days,
+  )?;
+  check_modlog_retention(
+    create_site.modlog_retention_days,
+    create_site.modlog_anonymize_days,
+  )?;
+  check_draft_max_age_days(create_site.draft_max_age_days)?;
+  check_upload_quota_mb(create_site.upload_quota_mb)?;
+  check_upload_quota_mb(create_site.moderator_upload_quota_mb)?;
+
   application_question_check(
     &local_site.application_question,
     &create_site.application_question,
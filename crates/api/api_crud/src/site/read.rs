@@ -10,7 +10,7 @@ use lemmy_db_schema::source::{
 };
 use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_person::PersonView;
-use lemmy_db_views_site::{SiteView, api::GetSiteResponse};
+use lemmy_db_views_site::{FederationPolicyView, SiteView, api::GetSiteResponse};
 use lemmy_utils::{CacheLock, VERSION, build_cache, error::LemmyResult};
 use std::sync::LazyLock;
 
@@ -50,6 +50,7 @@ async fn read_site(context: &LemmyContext) -> LemmyResult<GetSiteResponse> {
       .await
       .ok()
       .and_then(|u| u.updated_published_duration());
+  let federation_policies = FederationPolicyView::list(&mut context.pool()).await?;
 
   Ok(GetSiteResponse {
     site_view,
@@ -64,5 +65,6 @@ async fn read_site(context: &LemmyContext) -> LemmyResult<GetSiteResponse> {
     image_upload_disabled: context.settings().pictrs()?.image_upload_disabled,
     active_plugins: plugin_metadata(),
     last_application_duration_seconds,
+    federation_policies,
   })
 }
@@ -37,6 +37,10 @@ use lemmy_utils::{
     slurs::check_slurs_opt,
     validation::{
       build_and_check_regex,
+      check_draft_max_age_days,
+      check_modlog_retention,
+      check_strike_settings,
+      check_upload_quota_mb,
       check_urls_are_valid,
       is_valid_body_field,
       site_name_length_check,
@@ -118,6 +122,15 @@ pub async fn edit_site(
     disallow_nsfw_content: data.disallow_nsfw_content,
     disable_email_notifications: data.disable_email_notifications,
     suggested_communities: data.suggested_communities,
+    draft_max_age_days: data.draft_max_age_days,
+    upload_quota_mb: diesel_opt_number_update(data.upload_quota_mb),
+    moderator_upload_quota_mb: diesel_opt_number_update(data.moderator_upload_quota_mb),
+    strike_ban_threshold: diesel_opt_number_update(data.strike_ban_threshold),
+    strike_window_days: not_zero(data.strike_window_days),
+    strike_ban_days: not_zero(data.strike_ban_days),
+    modlog_retention_days: diesel_opt_number_update(data.modlog_retention_days),
+    modlog_anonymize_days: diesel_opt_number_update(data.modlog_anonymize_days),
+    federation_authorized_fetch: data.federation_authorized_fetch,
     ..Default::default()
   };
 
@@ -217,6 +230,19 @@ fn validate_update_payload(local_site: &LocalSite, edit_site: &EditSite) -> Lemm
     is_valid_body_field(body, false)?;
   }
 
+  check_strike_settings(
+    edit_site.strike_ban_threshold,
+    edit_site.strike_window_days,
+    edit_site.strike_ban_days,
+  )?;
+  check_modlog_retention(
+    edit_site.modlog_retention_days,
+    edit_site.modlog_anonymize_days,
+  )?;
+  check_draft_max_age_days(edit_site.draft_max_age_days)?;
+  check_upload_quota_mb(edit_site.upload_quota_mb)?;
+  check_upload_quota_mb(edit_site.moderator_upload_quota_mb)?;
+
   application_question_check(
     &local_site.application_question,
     &edit_site.application_question,
@@ -299,6 +325,20 @@ mod tests {
           ..Default::default()
         },
       ),
+      (
+        "EditSite has a negative strike threshold",
+        LemmyErrorType::InvalidStrikeSettings,
+        &LocalSite {
+          private_instance: true,
+          federation_enabled: false,
+          registration_mode: RegistrationMode::Open,
+          ..Default::default()
+        },
+        &EditSite {
+          strike_ban_threshold: Some(-1),
+          ..Default::default()
+        },
+      ),
     ];
 
     invalid_payloads.iter().enumerate().for_each(
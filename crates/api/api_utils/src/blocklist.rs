@@ -0,0 +1,249 @@
+use crate::{context::LemmyContext, request::collect_bytes_until_limit};
+use lemmy_db_schema::{
+  source::blocklist_subscription::{BlocklistSubscription, BlocklistSubscriptionEntry},
+  utils::FETCH_LIMIT_MAX,
+};
+use lemmy_db_schema_file::enums::BlocklistFormat;
+use lemmy_utils::error::LemmyResult;
+use serde::{Deserialize, de::DeserializeOwned};
+use tracing::info;
+use url::Url;
+
+/// Upper limit for the number of pages fetched from a remote Lemmy instance.
+const MAX_LEMMY_PAGES: usize = 100;
+
+/// Domains are stored in a varchar(255) column.
+const MAX_DOMAIN_LENGTH: usize = 255;
+
+/// Upper limit for the size of a blocklist file, or of a single page from a Lemmy instance.
+const MAX_RESPONSE_BYTES: usize = 10 * 1024 * 1024;
+
+/// Fetch the current content of a subscribed blocklist and store it as the entries of the
+/// subscription. This doesn't block or unblock any instances, the resulting changes need to be
+/// applied by an admin.
+pub async fn sync_blocklist_subscription(
+  subscription: &BlocklistSubscription,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  info!("Syncing blocklist {}", subscription.url);
+  let url = Url::parse(&subscription.url)?;
+  let entries = match subscription.format {
+    BlocklistFormat::Csv => {
+      let bytes = fetch_limited(&url, context).await?;
+      parse_csv_blocklist(&String::from_utf8_lossy(&bytes))
+    }
+    BlocklistFormat::Lemmy => fetch_lemmy_blocklist(&url, context).await?,
+  };
+
+  let local_domain = &context.settings().hostname;
+  let entries = entries
+    .into_iter()
+    .filter(|(domain, _)| domain != local_domain && is_valid_domain(domain))
+    .map(|(domain, reason)| BlocklistSubscriptionEntry {
+      subscription_id: subscription.id,
+      domain,
+      reason,
+    })
+    .collect::<Vec<_>>();
+  BlocklistSubscriptionEntry::replace(&mut context.pool(), subscription.id, &entries).await
+}
+
+/// Download the response body, failing if it is larger than [MAX_RESPONSE_BYTES].
+async fn fetch_limited(url: &Url, context: &LemmyContext) -> LemmyResult<Vec<u8>> {
+  let res = context
+    .client()
+    .get(url.as_str())
+    .send()
+    .await?
+    .error_for_status()?;
+  let bytes = collect_bytes_until_limit(res, MAX_RESPONSE_BYTES + 1).await?;
+  if bytes.len() > MAX_RESPONSE_BYTES {
+    Err(anyhow::anyhow!(
+      "Blocklist is larger than {MAX_RESPONSE_BYTES} bytes"
+    ))?
+  }
+  Ok(bytes)
+}
+
+async fn fetch_limited_json<T: DeserializeOwned>(
+  url: &Url,
+  context: &LemmyContext,
+) -> LemmyResult<T> {
+  Ok(serde_json::from_slice(&fetch_limited(url, context).await?)?)
+}
+
+/// Entries end up as instances, so only plain hostnames are accepted. Anything with a scheme,
+/// port, path or invalid characters is skipped.
+fn is_valid_domain(domain: &str) -> bool {
+  domain.len() <= MAX_DOMAIN_LENGTH
+    && Url::parse(&format!("https://{domain}"))
+      .ok()
+      .is_some_and(|u| u.domain() == Some(domain) && u.port().is_none() && u.path() == "/")
+}
+
+#[derive(Deserialize)]
+struct FederatedInstancesPage {
+  items: Vec<FederatedInstance>,
+  next_page: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct FederatedInstance {
+  instance: InstanceDomain,
+}
+
+#[derive(Deserialize)]
+struct InstanceDomain {
+  domain: String,
+}
+
+/// Read the public list of blocked instances from another Lemmy instance. It doesn't include
+/// reasons, those are only available in the modlog.
+async fn fetch_lemmy_blocklist(
+  url: &Url,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<(String, Option<String>)>> {
+  let mut endpoint = url.join("/api/v4/federated_instances")?;
+  let mut domains = vec![];
+  let mut page_cursor = None;
+  for _ in 0..MAX_LEMMY_PAGES {
+    {
+      let mut query = endpoint.query_pairs_mut();
+      query
+        .clear()
+        .append_pair("kind", "blocked")
+        .append_pair("limit", &FETCH_LIMIT_MAX.to_string());
+      if let Some(page_cursor) = &page_cursor {
+        query.append_pair("page_cursor", page_cursor);
+      }
+    }
+    let page: FederatedInstancesPage = fetch_limited_json(&endpoint, context).await?;
+    domains.extend(page.items.into_iter().map(|i| (i.instance.domain, None)));
+    page_cursor = page.next_page;
+    if page_cursor.is_none() {
+      break;
+    }
+  }
+  Ok(domains)
+}
+
+/// Parse a blocklist in the CSV format exported by Mastodon, which is also used by FediBlock
+/// lists. The header line is optional, without it the first column is taken as domain. Entries
+/// with a severity other than `suspend` are skipped, as they don't correspond to an instance
+/// block. Obfuscated domains (containing `*`) can't be blocked and are skipped as well.
+fn parse_csv_blocklist(text: &str) -> Vec<(String, Option<String>)> {
+  let mut rows = text
+    .lines()
+    .filter(|l| !l.trim().is_empty())
+    .map(parse_csv_line)
+    .peekable();
+
+  let is_header = rows
+    .peek()
+    .and_then(|r| r.first())
+    .is_some_and(|f| f.starts_with('#') || f.eq_ignore_ascii_case("domain"));
+  let header = if is_header {
+    rows.next().unwrap_or_default()
+  } else {
+    vec![]
+  };
+  let column = |names: &[&str]| {
+    header.iter().position(|h| {
+      let h = h.trim_start_matches('#').to_lowercase();
+      names.contains(&h.as_str())
+    })
+  };
+  let domain_col = column(&["domain"]).unwrap_or(0);
+  let severity_col = column(&["severity"]);
+  let reason_col = column(&["public_comment", "comment", "reason"]);
+
+  rows
+    .filter(|row| {
+      severity_col
+        .and_then(|c| row.get(c))
+        .is_none_or(|s| s.is_empty() || s.eq_ignore_ascii_case("suspend"))
+    })
+    .filter_map(|row| {
+      let domain = row.get(domain_col)?.trim().to_lowercase();
+      if domain.is_empty() || domain.contains('*') || domain.len() > MAX_DOMAIN_LENGTH {
+        return None;
+      }
+      let reason = reason_col
+        .and_then(|c| row.get(c))
+        .filter(|r| !r.is_empty())
+        .cloned();
+      Some((domain, reason))
+    })
+    .collect()
+}
+
+/// Split a single CSV line into its fields, handling quoted fields with escaped quotes.
+fn parse_csv_line(line: &str) -> Vec<String> {
+  let mut fields = vec![];
+  let mut field = String::new();
+  let mut in_quotes = false;
+  let mut chars = line.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '"' if in_quotes && chars.peek() == Some(&'"') => {
+        field.push('"');
+        chars.next();
+      }
+      '"' => in_quotes = !in_quotes,
+      ',' if !in_quotes => fields.push(std::mem::take(&mut field).trim().to_string()),
+      c => field.push(c),
+    }
+  }
+  fields.push(field.trim().to_string());
+  fields
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{is_valid_domain, parse_csv_blocklist};
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_parse_mastodon_csv() {
+    let csv = "#domain,#severity,#reject_media,#reject_reports,#public_comment,#obfuscate\n\
+               spam.example,suspend,true,true,\"Spam, harassment\",false\n\
+               noisy.example,silence,false,false,Too noisy,false\n\
+               hid*en.example,suspend,false,false,,true\n\
+               Bad.Example,suspend,false,false,,false\n";
+    assert_eq!(
+      vec![
+        (
+          "spam.example".to_string(),
+          Some("Spam, harassment".to_string())
+        ),
+        ("bad.example".to_string(), None),
+      ],
+      parse_csv_blocklist(csv)
+    );
+  }
+
+  #[test]
+  fn test_parse_plain_csv() {
+    let csv = "spam.example\nother.example,\"With \"\"quotes\"\"\"\n";
+    assert_eq!(
+      vec![
+        ("spam.example".to_string(), None),
+        ("other.example".to_string(), None),
+      ],
+      parse_csv_blocklist(csv)
+    );
+  }
+
+  #[test]
+  fn test_is_valid_domain() {
+    assert!(is_valid_domain("example.com"));
+    assert!(is_valid_domain("sub.example-instance.social"));
+    assert!(!is_valid_domain("example.com/path"));
+    assert!(!is_valid_domain("example.com:8080"));
+    assert!(!is_valid_domain("user@example.com"));
+    assert!(!is_valid_domain("exa mple.com"));
+    assert!(!is_valid_domain("Example.com"));
+    assert!(!is_valid_domain("127.0.0.1"));
+    assert!(!is_valid_domain(&format!("{}.com", "a".repeat(300))));
+  }
+}
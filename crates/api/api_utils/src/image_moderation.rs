@@ -0,0 +1,238 @@
+use crate::{
+  context::LemmyContext,
+  plugins::{plugin_hook_before, plugin_hook_exists},
+};
+use base64::{Engine, prelude::BASE64_STANDARD};
+use image::{
+  ImageFormat,
+  Luma,
+  imageops::{FilterType, resize},
+};
+use lemmy_db_schema::source::blocked_image::BlockedImage;
+use lemmy_db_schema_file::PersonId;
+use lemmy_utils::{
+  CACHE_DURATION_FEDERATION,
+  CacheLock,
+  REQWEST_TIMEOUT,
+  error::{LemmyError, LemmyErrorType, LemmyResult},
+};
+use moka::future::Cache;
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, LazyLock};
+use url::Url;
+use urlencoding::encode;
+
+/// Size of the image which is downloaded from pict-rs to calculate the hash. The hash itself only
+/// uses 9x8 pixels, so this can be small.
+const HASH_IMAGE_SIZE: u32 = 64;
+
+/// Where pict-rs should read an image from.
+pub enum ImageSource<'a> {
+  /// Alias of an image which was uploaded to the local pict-rs
+  Local(&'a str),
+  /// Remote image which is proxied through pict-rs
+  Proxied(&'a Url),
+}
+
+impl ImageSource<'_> {
+  fn query(&self) -> String {
+    match self {
+      ImageSource::Local(alias) => format!("src={}", encode(alias)),
+      ImageSource::Proxied(url) => format!("proxy={}", encode(url.as_str())),
+    }
+  }
+}
+
+/// Data for the `image_before_upload` plugin hook. Plugins can set `rejected` to refuse the
+/// upload, or `flagged` to keep it and mark it for review by admins.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PluginImageUpload {
+  pub person_id: PersonId,
+  pub content_type: Option<String>,
+  /// The original image data, base64 encoded
+  pub data: String,
+  pub rejected: Option<String>,
+  pub flagged: Option<String>,
+}
+
+/// Calculates the difference hash of a png image. Similar images, eg resized or recompressed
+/// copies, have hashes which only differ in a few bits.
+pub fn perceptual_hash(png_data: &[u8]) -> LemmyResult<i64> {
+  let image = image::load_from_memory_with_format(png_data, ImageFormat::Png)?.to_luma8();
+  let small = resize(&image, 9, 8, FilterType::Triangle);
+
+  // Each bit says whether a pixel is darker than its right neighbour
+  let mut hash = 0u64;
+  for y in 0..8 {
+    for x in 0..8 {
+      let Luma([left]) = *small.get_pixel(x, y);
+      let Luma([right]) = *small.get_pixel(x + 1, y);
+      hash = (hash << 1) | u64::from(left < right);
+    }
+  }
+  // Postgres has no unsigned types
+  Ok(i64::from_ne_bytes(hash.to_ne_bytes()))
+}
+
+/// Number of bits which differ between two hashes.
+fn hash_distance(a: i64, b: i64) -> u32 {
+  (a ^ b).count_ones()
+}
+
+/// Lets pict-rs convert the image to a small png, and calculates its hash.
+pub async fn fetch_image_hash(
+  source: &ImageSource<'_>,
+  context: &LemmyContext,
+) -> LemmyResult<i64> {
+  let pictrs_url = context.settings().pictrs()?.url;
+  let url = format!(
+    "{pictrs_url}image/process.png?{}&thumbnail={HASH_IMAGE_SIZE}",
+    source.query()
+  );
+
+  let bytes = context
+    .pictrs_client()
+    .get(&url)
+    .timeout(REQWEST_TIMEOUT)
+    .send()
+    .await?
+    .error_for_status()?
+    .bytes()
+    .await?;
+  perceptual_hash(&bytes)
+}
+
+static BLOCKED_HASHES: CacheLock<Arc<Vec<i64>>> = LazyLock::new(|| {
+  Cache::builder()
+    .max_capacity(1)
+    .time_to_live(CACHE_DURATION_FEDERATION)
+    .build()
+});
+
+/// Hashes of all blocked images. They are compared by distance, so they can't be looked up by
+/// value and are cached instead.
+async fn blocked_hashes(context: &LemmyContext) -> LemmyResult<Arc<Vec<i64>>> {
+  Ok(
+    BLOCKED_HASHES
+      .try_get_with::<_, LemmyError>((), async {
+        Ok(Arc::new(
+          BlockedImage::read_all_hashes(&mut context.pool()).await?,
+        ))
+      })
+      .await
+      .map_err(|e| anyhow::anyhow!("Failed to read blocked images: {e}"))?,
+  )
+}
+
+/// Needs to be called after the image blocklist is changed, so that it applies right away.
+pub fn clear_blocked_image_cache() {
+  BLOCKED_HASHES.invalidate_all();
+}
+
+/// Checks if the image is similar to any image on the blocklist.
+pub async fn is_image_blocked(
+  source: &ImageSource<'_>,
+  context: &LemmyContext,
+) -> LemmyResult<bool> {
+  let blocked_hashes = blocked_hashes(context).await?;
+  // No need to fetch the image if there is nothing to compare with
+  if blocked_hashes.is_empty() {
+    return Ok(false);
+  }
+
+  let hash = fetch_image_hash(source, context).await?;
+  let max_distance = context.settings().pictrs()?.blocked_image_max_distance;
+  Ok(
+    blocked_hashes
+      .iter()
+      .any(|b| hash_distance(*b, hash) <= max_distance),
+  )
+}
+
+/// Checks a new upload against the blocklist, and passes it to plugins. Returns an error if the
+/// image should be rejected, or the flag reason if a plugin flagged it.
+pub async fn check_uploaded_image(
+  alias: &str,
+  person_id: PersonId,
+  context: &LemmyContext,
+) -> LemmyResult<Option<String>> {
+  if is_image_blocked(&ImageSource::Local(alias), context).await? {
+    Err(LemmyErrorType::BlockedImage)?
+  }
+
+  let name = "image_before_upload";
+  // Only download the image if a plugin needs it
+  if !plugin_hook_exists(name) {
+    return Ok(None);
+  }
+
+  let pictrs_url = context.settings().pictrs()?.url;
+  let res = context
+    .pictrs_client()
+    .get(format!("{pictrs_url}image/original/{alias}"))
+    .timeout(REQWEST_TIMEOUT)
+    .send()
+    .await?
+    .error_for_status()?;
+  let content_type = res
+    .headers()
+    .get(CONTENT_TYPE)
+    .and_then(|c| c.to_str().ok())
+    .map(ToString::to_string);
+  let bytes = res.bytes().await?;
+
+  let data = PluginImageUpload {
+    person_id,
+    content_type,
+    data: BASE64_STANDARD.encode(bytes),
+    rejected: None,
+    flagged: None,
+  };
+  let data = plugin_hook_before(name, data).await?;
+  if let Some(reason) = data.rejected {
+    Err(LemmyErrorType::ImageRejected(reason))?
+  }
+  Ok(data.flagged)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{hash_distance, perceptual_hash};
+  use image::{ImageBuffer, ImageFormat, Luma, imageops::FilterType};
+  use lemmy_utils::error::LemmyResult;
+  use std::io::Cursor;
+
+  fn gradient_png(width: u32, height: u32) -> LemmyResult<Vec<u8>> {
+    let image = ImageBuffer::from_fn(width, height, |x, y| {
+      let value = x * 200 / width + y * 50 / height;
+      Luma([u8::try_from(value).unwrap_or_default()])
+    });
+    let mut data = Cursor::new(vec![]);
+    image.write_to(&mut data, ImageFormat::Png)?;
+    Ok(data.into_inner())
+  }
+
+  #[test]
+  fn test_perceptual_hash() -> LemmyResult<()> {
+    let original = gradient_png(200, 100)?;
+    let hash = perceptual_hash(&original)?;
+
+    // A resized copy has a very similar hash
+    let resized = image::load_from_memory(&original)?.resize_exact(90, 45, FilterType::Nearest);
+    let mut resized_data = Cursor::new(vec![]);
+    resized.write_to(&mut resized_data, ImageFormat::Png)?;
+    let resized_hash = perceptual_hash(resized_data.get_ref())?;
+    assert!(hash_distance(hash, resized_hash) <= 4);
+
+    // A mirrored image is completely different
+    let mirrored = image::load_from_memory(&original)?.fliph();
+    let mut mirrored_data = Cursor::new(vec![]);
+    mirrored.write_to(&mut mirrored_data, ImageFormat::Png)?;
+    let mirrored_hash = perceptual_hash(mirrored_data.get_ref())?;
+    assert!(hash_distance(hash, mirrored_hash) > 32);
+
+    assert!(perceptual_hash(b"not an image").is_err());
+    Ok(())
+  }
+}
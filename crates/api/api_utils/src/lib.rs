@@ -1,6 +1,8 @@
+pub mod blocklist;
 pub mod build_response;
 pub mod claims;
 pub mod context;
+pub mod image_moderation;
 pub mod notify;
 pub mod plugins;
 pub mod request;
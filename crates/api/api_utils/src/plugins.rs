@@ -11,6 +11,7 @@ use lemmy_utils::{
   VERSION,
   error::{LemmyError, LemmyErrorType, LemmyResult},
   settings::{SETTINGS, structs::PluginSettings},
+  utils::markdown::{MarkdownInlineRule, set_plugin_inline_rules},
 };
 use serde::{Deserialize, Serialize};
 use std::{
@@ -100,6 +101,18 @@ where
   .await?
 }
 
+/// Let plugins register extra inline rules for the markdown parser. Each plugin receives the rules
+/// of previous plugins and can append its own.
+pub async fn plugin_markdown_inline_rules() -> LemmyResult<()> {
+  let rules = plugin_hook_before("markdown_inline_rules", Vec::<MarkdownInlineRule>::new()).await?;
+  set_plugin_inline_rules(rules)
+}
+
+/// Check if any plugin implements the given hook. Useful to avoid preparing expensive hook data.
+pub fn plugin_hook_exists(name: &'static str) -> bool {
+  LemmyPlugins::get_or_init().function_exists(name)
+}
+
 pub fn plugin_metadata() -> Vec<PluginMetadata> {
   static METADATA: OnceLock<Vec<PluginMetadata>> = OnceLock::new();
   if let Some(m) = METADATA.get() {
@@ -1,5 +1,6 @@
 use crate::{
   context::LemmyContext,
+  image_moderation::{ImageSource, is_image_blocked},
   send_activity::{ActivityChannel, SendActivityData},
   utils::proxy_image_link,
 };
@@ -8,7 +9,7 @@ use chrono::{DateTime, Utc};
 use encoding_rs::{Encoding, UTF_8};
 use futures::StreamExt;
 use lemmy_db_schema::source::{
-  images::{ImageDetailsInsertForm, LocalImage, LocalImageForm},
+  images::{ImageDetailsInsertForm, LocalImage, LocalImageForm, RemoteImage},
   post::{Post, PostUpdateForm},
   site::Site,
 };
@@ -25,7 +26,7 @@ use reqwest::{
   Client,
   ClientBuilder,
   Response,
-  header::{CONTENT_TYPE, LOCATION, RANGE},
+  header::{CONTENT_LENGTH, CONTENT_TYPE, LOCATION, RANGE},
   redirect::Policy,
 };
 use reqwest_middleware::ClientWithMiddleware;
@@ -166,7 +167,7 @@ pub async fn fetch_link_metadata(
   })
 }
 
-async fn collect_bytes_until_limit(
+pub(crate) async fn collect_bytes_until_limit(
   response: Response,
   requested_bytes: usize,
 ) -> Result<Vec<u8>, LemmyError> {
@@ -237,12 +238,15 @@ pub async fn generate_post_link_metadata(
       .ok()
       .or(Some(url.into()))
   } else if let (true, Some(url)) = (allow_generate_thumbnail, image_url.clone()) {
-    generate_pictrs_thumbnail(&post, &url, &context)
-      .await
-      .map_err(|e| warn!("Failed to generate thumbnail: {e}"))
-      .ok()
-      .map(Into::into)
-      .or(image_url)
+    match generate_pictrs_thumbnail(&post, &url, &context).await {
+      Ok(thumbnail_url) => Some(thumbnail_url.into()),
+      // Don't fall back to the remote url of a blocked image
+      Err(e) if e.error_type == LemmyErrorType::BlockedImage => None,
+      Err(e) => {
+        warn!("Failed to generate thumbnail: {e}");
+        image_url
+      }
+    }
   } else {
     image_url.clone()
   };
@@ -520,11 +524,21 @@ async fn generate_pictrs_thumbnail(
     .first()
     .ok_or(LemmyErrorType::PictrsResponseError(res.msg))?;
 
+  // Don't store thumbnails of blocked images
+  if is_image_blocked(&ImageSource::Local(&image.file), context).await? {
+    delete_image_alias(&image.file, context).await?;
+    Err(LemmyErrorType::BlockedImage)?
+  }
+
   let form = LocalImageForm {
     pictrs_alias: image.file.clone(),
     // For thumbnails, the person_id is the post creator
     person_id: post.creator_id,
     thumbnail_for_post_id: Some(Some(post.id)),
+    flagged_reason: None,
+    file_size: fetch_pictrs_file_size(&image.file, context)
+      .await
+      .unwrap_or_default(),
   };
   let protocol_and_hostname = context.settings().get_protocol_and_hostname();
   let thumbnail_url = image.image_url(&protocol_and_hostname)?;
@@ -536,6 +550,27 @@ async fn generate_pictrs_thumbnail(
   Ok(thumbnail_url)
 }
 
+/// Returns the size in bytes of an image stored in the local pict-rs.
+pub async fn fetch_pictrs_file_size(alias: &str, context: &LemmyContext) -> LemmyResult<i64> {
+  let pictrs_url = context.settings().pictrs()?.url;
+  let res = context
+    .pictrs_client()
+    .head(format!("{pictrs_url}image/original/{alias}"))
+    .timeout(REQWEST_TIMEOUT)
+    .send()
+    .await?
+    .error_for_status()?;
+
+  // Read the header directly, as the body of a HEAD response is always empty
+  let size = res
+    .headers()
+    .get(CONTENT_LENGTH)
+    .and_then(|l| l.to_str().ok())
+    .and_then(|l| l.parse().ok())
+    .unwrap_or_default();
+  Ok(size)
+}
+
 /// Fetches the image details for pictrs proxied images
 ///
 /// We don't need to check for image mode, as that's already been done
@@ -558,6 +593,12 @@ pub async fn fetch_pictrs_proxied_image_details(
     .error_for_status()
     .with_lemmy_type(LemmyErrorType::NotAnImageType)?;
 
+  // Blocked images must not be served through the image proxy
+  if is_image_blocked(&ImageSource::Proxied(image_url), context).await? {
+    RemoteImage::delete(&mut context.pool(), image_url.clone().into()).await?;
+    Err(LemmyErrorType::BlockedImage)?
+  }
+
   let details_url = format!("{pictrs_url}image/details/original?proxy={encoded_image_url}");
 
   let res = context
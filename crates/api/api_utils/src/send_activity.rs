@@ -6,15 +6,17 @@ use lemmy_db_schema::{
   source::{
     comment::Comment,
     community::Community,
+    modmail::{ModmailMessage, ModmailThread},
     multi_community::MultiCommunity,
     person::Person,
     post::Post,
     private_message::PrivateMessage,
+    relay::Relay,
     site::Site,
   },
 };
 use lemmy_db_schema_file::PersonId;
-use lemmy_db_views_community::api::BanFromCommunity;
+use lemmy_db_views_community::api::{BanFromCommunity, MuteFromCommunity};
 use lemmy_db_views_private_message::PrivateMessageView;
 use lemmy_diesel_utils::dburl::DbUrl;
 use lemmy_utils::error::LemmyResult;
@@ -61,6 +63,8 @@ pub enum SendActivityData {
   },
   FollowCommunity(Community, Person, bool),
   FollowMultiCommunity(MultiCommunity, Person, bool),
+  FollowPerson(Person, Person, bool),
+  FollowRelay(Relay, bool),
   AcceptFollower(CommunityId, PersonId),
   RejectFollower(CommunityId, PersonId),
   UpdateCommunity(Person, Community),
@@ -83,6 +87,12 @@ pub enum SendActivityData {
     target: Person,
     data: BanFromCommunity,
   },
+  MuteFromCommunity {
+    moderator: Person,
+    community_id: CommunityId,
+    target: Person,
+    data: MuteFromCommunity,
+  },
   BanFromSite {
     moderator: Person,
     banned_user: Person,
@@ -100,6 +110,17 @@ pub enum SendActivityData {
     actor: Person,
     receiver: Either<Site, Community>,
     reason: String,
+    /// Federation id of the violated rule
+    rule: Option<Url>,
+  },
+  /// Send a report to the admins of the instance where the reported user is registered
+  ForwardReport {
+    /// The reported user, followed by the reported content
+    object_ids: Vec<Url>,
+    /// The system account, to avoid revealing the reporter
+    actor: Person,
+    receiver: Site,
+    reason: String,
   },
   SendResolveReport {
     object_id: Url,
@@ -108,6 +129,12 @@ pub enum SendActivityData {
     receiver: Either<Site, Community>,
   },
   UpdateMultiCommunity(MultiCommunity, Person),
+  SendModmail {
+    message: ModmailMessage,
+    thread: ModmailThread,
+    community: Community,
+    actor: Person,
+  },
 }
 
 // TODO: instead of static, move this into LemmyContext. make sure that stopping the process with
@@ -1,18 +1,24 @@
 use crate::{
   claims::Claims,
   context::LemmyContext,
-  request::{delete_image_alias, fetch_pictrs_proxied_image_details, purge_image_from_pictrs_url},
+  request::{
+    delete_image_alias,
+    fetch_pictrs_proxied_image_details,
+    purge_image_from_pictrs,
+    purge_image_from_pictrs_url,
+  },
 };
 use actix_web::{HttpRequest, http::header::Header};
 use actix_web_httpauth::headers::authorization::{Authorization, Bearer};
 use chrono::{DateTime, Days, Local, TimeZone, Utc};
 use enum_map::{EnumMap, enum_map};
 use lemmy_db_schema::{
-  newtypes::{CommunityId, PostId, PostOrCommentId, TagId},
+  newtypes::{CommunityId, PostId, PostOrCommentId, RuleId, TagId},
   source::{
     comment::{Comment, CommentActions, CommentLikeForm},
     community::{Community, CommunityActions, CommunityUpdateForm},
-    images::{ImageDetails, RemoteImage},
+    custom_emoji::CustomEmoji,
+    images::{ImageDetails, LocalImage, RemoteImage},
     instance::InstanceActions,
     local_site::LocalSite,
     local_site_rate_limit::LocalSiteRateLimit,
@@ -23,6 +29,7 @@ use lemmy_db_schema::{
     post::{Post, PostActions, PostLikeForm, PostReadCommentsForm},
     private_message::PrivateMessage,
     registration_application::RegistrationApplication,
+    rule::Rule,
     site::Site,
     tag::{PostTag, Tag},
   },
@@ -55,7 +62,12 @@ use lemmy_utils::{
   settings::{SETTINGS, structs::PictrsImageMode},
   spawn_try_task,
   utils::{
-    markdown::{image_links::markdown_rewrite_image_links, markdown_check_for_blocked_urls},
+    markdown::{
+      CustomEmojiImage,
+      image_links::markdown_rewrite_image_links,
+      markdown_check_for_blocked_urls,
+      set_custom_emojis,
+    },
     slurs::remove_slurs,
     validation::{build_and_check_regex, clean_urls_in_text},
   },
@@ -260,10 +272,24 @@ pub async fn check_community_user_action(
   Ok(())
 }
 
+/// Checks that the user can create or edit posts and comments in a given community. In addition to
+/// [check_community_user_action], this also rejects users who are muted in the community.
+pub async fn check_community_content_action(
+  local_user_view: &LocalUserView,
+  community: &Community,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  check_community_user_action(local_user_view, community, pool).await?;
+  CommunityActions::check_mute(pool, local_user_view.person.id, community.id).await
+}
+
 pub fn check_community_deleted_removed(community: &Community) -> LemmyResult<()> {
   if community.deleted || community.removed {
     Err(LemmyErrorType::Deleted)?
   }
+  if community.moved_to_id.is_some() {
+    Err(LemmyErrorType::CommunityMoved)?
+  }
   Ok(())
 }
 
@@ -486,6 +512,69 @@ pub async fn purge_post_images(
   }
 }
 
+/// Returns the upload quota of the user in bytes, or `None` if their uploads are unlimited.
+pub async fn upload_quota(
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<Option<i64>> {
+  if local_user_view.local_user.admin {
+    return Ok(None);
+  }
+
+  let quota_mb = match local_user_view.local_user.upload_quota_mb {
+    Some(quota_mb) => Some(quota_mb),
+    None => {
+      let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+      let is_mod = CommunityModeratorView::is_community_moderator_of_any(
+        &mut context.pool(),
+        local_user_view.person.id,
+      )
+      .await
+      .is_ok();
+      if is_mod {
+        local_site
+          .moderator_upload_quota_mb
+          .or(local_site.upload_quota_mb)
+      } else {
+        local_site.upload_quota_mb
+      }
+    }
+  };
+  Ok(quota_mb.map(|mb| i64::from(mb) * 1024 * 1024))
+}
+
+/// Returns an error if uploading the given number of bytes would exceed the quota of the user.
+pub async fn check_upload_quota(
+  local_user_view: &LocalUserView,
+  new_bytes: i64,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if let Some(quota) = upload_quota(local_user_view, context).await? {
+    let used =
+      LocalImage::total_size_for_person(&mut context.pool(), local_user_view.person.id).await?;
+    // A quota which is already used up also rejects checks before the upload size is known
+    if used >= quota || used + new_bytes > quota {
+      Err(LemmyErrorType::UploadQuotaExceeded)?
+    }
+  }
+  Ok(())
+}
+
+/// Purges all images uploaded by a person from pict-rs, including generated thumbnails.
+pub async fn purge_local_user_images(
+  person_id: PersonId,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let pictrs_uploads = LocalImageView::get_all_by_person_id(&mut context.pool(), person_id).await?;
+
+  for upload in pictrs_uploads {
+    purge_image_from_pictrs(&upload.local_image.pictrs_alias, context)
+      .await
+      .ok();
+  }
+  Ok(())
+}
+
 /// Delete local images attributed to a person
 fn delete_local_user_images(person_id: PersonId, context: &LemmyContext) {
   let context_ = context.clone();
@@ -801,6 +890,18 @@ pub async fn process_markdown(
   }
 }
 
+/// Reload custom emojis which are rendered by the markdown parser. Needs to be called on startup
+/// and whenever emojis are changed.
+pub async fn update_markdown_custom_emojis(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+  let emojis = CustomEmoji::read_all(pool).await?;
+  set_custom_emojis(emojis.into_iter().map(|e| CustomEmojiImage {
+    shortcode: e.shortcode,
+    image_url: e.image_url.to_string(),
+    alt_text: e.alt_text,
+  }));
+  Ok(())
+}
+
 pub async fn process_markdown_opt(
   text: &Option<String>,
   slur_regex: &Regex,
@@ -973,11 +1074,33 @@ pub async fn update_post_tags(
   Ok(())
 }
 
+/// Check that a rule cited by a report or removal belongs to the given community, or is a site
+/// rule.
+pub async fn check_rule_in_community(
+  rule_id: Option<RuleId>,
+  community_id: CommunityId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<Option<Rule>> {
+  let Some(rule_id) = rule_id else {
+    return Ok(None);
+  };
+  let rule = Rule::read(pool, rule_id).await?;
+  if rule.deleted || rule.community_id.is_some_and(|c| c != community_id) {
+    Err(LemmyErrorType::RuleNotInCommunity)?
+  }
+  Ok(Some(rule))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
   use diesel_ltree::Ltree;
-  use lemmy_db_schema::newtypes::{CommentId, LanguageId};
+  use lemmy_db_schema::{
+    newtypes::{CommentId, LanguageId},
+    source::community::{CommunityInsertForm, CommunityPersonMuteForm},
+    test_data::TestData,
+  };
+  use lemmy_diesel_utils::connection::build_db_pool_for_tests;
   use pretty_assertions::assert_eq;
   use serial_test::serial;
 
@@ -1056,6 +1179,43 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_check_community_content_action() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = TestData::create(pool).await?;
+    let user = LocalUserView::create_test_user(pool, "muted_user", "", false).await?;
+    let community_form = CommunityInsertForm::new(
+      data.instance.id,
+      "mute_test".to_string(),
+      "mute_test".to_string(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+
+    // Without a mute, the user can post
+    check_community_content_action(&user, &community, pool).await?;
+
+    // While the mute is active, posting is rejected
+    let mut mute_form = CommunityPersonMuteForm::new(community.id, user.person.id);
+    mute_form.mute_expires_at = Some(Some(Utc::now() + Days::new(1)));
+    CommunityActions::mute(pool, &mute_form).await?;
+    let res = check_community_content_action(&user, &community, pool).await;
+    assert!(res.is_err_and(|e| e.error_type == LemmyErrorType::PersonIsMutedInCommunity));
+    // Muted users can still vote and read, which is checked with the regular user action
+    check_community_user_action(&user, &community, pool).await?;
+
+    // An expired mute which wasn't cleared yet is ignored
+    mute_form.mute_expires_at = Some(Some(Utc::now() - Days::new(1)));
+    CommunityActions::mute(pool, &mute_form).await?;
+    check_community_content_action(&user, &community, pool).await?;
+
+    Person::delete(pool, user.person.id).await?;
+    data.delete(pool).await?;
+    Ok(())
+  }
+
   #[test]
   fn test_comment_depth() -> LemmyResult<()> {
     let mut comment = Comment {
@@ -1082,6 +1242,7 @@ mod tests {
       unresolved_report_count: 0,
       federation_pending: false,
       locked: false,
+      held_for_review: false,
     };
     assert!(check_comment_depth(&comment).is_ok());
     comment.path = Ltree("0.123.456".to_string());
@@ -1,5 +1,6 @@
 use actix_web::{guard, web::*};
 use lemmy_api::{
+  ban_appeal::{create::create_ban_appeal, list::list_ban_appeals, resolve::resolve_ban_appeal},
   comment::{
     distinguish::distinguish_comment,
     like::like_comment,
@@ -9,10 +10,13 @@ use lemmy_api::{
   },
   community::{
     add_mod::add_mod_to_community,
+    backfill::backfill_community,
     ban::ban_from_community,
     block::user_block_community,
     follow::follow_community,
+    merge::merge_community,
     multi_community_follow::follow_multi_community,
+    mute::mute_from_community,
     pending_follows::{approve::post_pending_follows_approve, list::get_pending_follows_list},
     random::get_random_community,
     tag::{create_community_tag, delete_community_tag, edit_community_tag},
@@ -38,12 +42,14 @@ use lemmy_api::{
     change_password_after_reset::change_password_after_reset,
     donation_dialog_shown::donation_dialog_shown,
     export_data::export_data,
+    follow_person::follow_person,
     generate_totp_secret::generate_totp_secret,
     get_captcha::get_captcha,
     list_hidden::list_person_hidden,
     list_liked::list_person_liked,
     list_logins::list_logins,
     list_media::list_media,
+    list_person_follows::{list_person_followers, list_person_following},
     list_read::list_person_read,
     list_saved::list_person_saved,
     login::login,
@@ -56,12 +62,14 @@ use lemmy_api::{
     },
     resend_verification_email::resend_verification_email,
     reset_password::reset_password,
+    restrict_person::restrict_person,
     save_settings::save_user_settings,
     unread_counts::get_unread_counts,
     update_totp::edit_totp,
     user_block_instance::{user_block_instance_communities, user_block_instance_persons},
     validate_auth::validate_auth,
     verify_email::verify_email,
+    warn_person::warn_person,
   },
   post::{
     feature::feature_post,
@@ -79,17 +87,28 @@ use lemmy_api::{
   reports::{
     comment_report::{create::create_comment_report, resolve::resolve_comment_report},
     community_report::{create::create_community_report, resolve::resolve_community_report},
+    person_report::{create::create_person_report, resolve::resolve_person_report},
     post_report::{create::create_post_report, resolve::resolve_post_report},
     private_message_report::{create::create_pm_report, resolve::resolve_pm_report},
-    report_combined::list::list_reports,
+    report_combined::{assign::assign_report, list::list_reports, metrics::get_report_metrics},
+    report_note::{
+      create::create_report_note,
+      delete::delete_report_note,
+      list::list_report_notes,
+    },
   },
   site::{
     admin_allow_instance::admin_allow_instance,
     admin_block_instance::admin_block_instance,
+    admin_list_top_uploaders::admin_list_top_uploaders,
     admin_list_users::admin_list_users,
+    admin_purge_user_media::admin_purge_user_media,
+    admin_set_instance_policy::admin_set_instance_policy,
+    admin_set_upload_quota::admin_set_upload_quota,
     federated_instances::get_federated_instances,
     list_all_media::list_all_media,
     mod_log::get_mod_log,
+    mod_log_export::export_mod_log,
     purge::{
       comment::purge_comment,
       community::purge_community,
@@ -104,6 +123,14 @@ use lemmy_api::{
   },
 };
 use lemmy_api_crud::{
+  blocked_image::{create::block_image, delete::unblock_image, list::list_blocked_images},
+  blocklist_subscription::{
+    apply::apply_blocklist_subscription,
+    create::create_blocklist_subscription,
+    delete::delete_blocklist_subscription,
+    list::list_blocklist_subscriptions,
+    read::get_blocklist_subscription,
+  },
   comment::{
     create::create_comment,
     delete::delete_comment,
@@ -124,6 +151,28 @@ use lemmy_api_crud::{
     list::list_custom_emojis,
     update::edit_custom_emoji,
   },
+  draft::{
+    create::create_draft,
+    delete::delete_draft,
+    list::list_drafts,
+    read::get_draft,
+    update::edit_draft,
+  },
+  federation_replay::{create::create_federation_replay, list::list_federation_replays},
+  mod_note::{
+    create::create_mod_note,
+    delete::delete_mod_note,
+    list::list_mod_notes,
+    update::edit_mod_note,
+  },
+  modmail::{
+    archive::archive_modmail_thread,
+    create::create_modmail_thread,
+    list::list_modmail_threads,
+    mark_read::mark_modmail_thread_as_read,
+    read::get_modmail_thread,
+    reply::create_modmail_message,
+  },
   multi_community::{
     create::create_multi_community,
     create_entry::create_multi_community_entry,
@@ -148,6 +197,20 @@ use lemmy_api_crud::{
     delete::delete_private_message,
     update::edit_private_message,
   },
+  recurring_post::{
+    create::create_recurring_post,
+    delete::delete_recurring_post,
+    list::list_recurring_posts,
+    update::edit_recurring_post,
+  },
+  relay::{create::create_relay, delete::delete_relay, list::list_relays, update::edit_relay},
+  removal_reason::{
+    create::create_removal_reason,
+    delete::delete_removal_reason,
+    list::list_removal_reasons,
+    update::edit_removal_reason,
+  },
+  rule::{create::create_rule, delete::delete_rule, list::list_rules, update::edit_rule},
   site::{create::create_site, read::get_site, update::edit_site},
   tagline::{
     create::create_tagline,
@@ -202,6 +265,7 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/banner", delete().to(delete_site_banner)),
       )
       .route("/modlog", get().to(get_mod_log))
+      .route("/modlog/export", get().to(export_mod_log))
       .service(
         resource("/search")
           .wrap(rate_limit.search())
@@ -232,8 +296,11 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           // Mod Actions
           .route("/remove", post().to(remove_community))
           .route("/transfer", post().to(transfer_community))
+          .route("/merge", post().to(merge_community))
           .route("/ban_user", post().to(ban_from_community))
+          .route("/mute_user", post().to(mute_from_community))
           .route("/mod", post().to(add_mod_to_community))
+          .route("/backfill", post().to(backfill_community))
           .route("/icon", post().to(upload_community_icon))
           .route("/icon", delete().to(delete_community_icon))
           .route("/banner", post().to(upload_community_banner))
@@ -242,6 +309,20 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/tag", put().to(edit_community_tag))
           .route("/tag", delete().to(delete_community_tag))
           .route("/notifications", post().to(edit_community_notifications))
+          .service(
+            scope("/recurring_post")
+              .route("", post().to(create_recurring_post))
+              .route("", put().to(edit_recurring_post))
+              .route("", delete().to(delete_recurring_post))
+              .route("/list", get().to(list_recurring_posts)),
+          )
+          .service(
+            scope("/removal_reason")
+              .route("", post().to(create_removal_reason))
+              .route("", put().to(edit_removal_reason))
+              .route("", delete().to(delete_removal_reason))
+              .route("/list", get().to(list_removal_reasons)),
+          )
           .service(
             scope("/pending_follows")
               .route("/list", get().to(get_pending_follows_list))
@@ -325,11 +406,29 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("/report", post().to(create_pm_report))
           .route("/report/resolve", put().to(resolve_pm_report)),
       )
+      // Mod mail
+      .service(
+        scope("/modmail")
+          .route("", post().to(create_modmail_thread))
+          .route("", get().to(get_modmail_thread))
+          .route("/message", post().to(create_modmail_message))
+          .route("/archive", post().to(archive_modmail_thread))
+          .route("/mark_as_read", post().to(mark_modmail_thread_as_read))
+          .route("/list", get().to(list_modmail_threads)),
+      )
       // Reports
       .service(
         scope("/report")
           .wrap(rate_limit.message())
-          .route("/list", get().to(list_reports)),
+          .route("/list", get().to(list_reports))
+          .route("/assign", put().to(assign_report))
+          .route("/metrics", get().to(get_report_metrics))
+          .service(
+            scope("/note")
+              .route("", post().to(create_report_note))
+              .route("", delete().to(delete_report_note))
+              .route("/list", get().to(list_report_notes)),
+          ),
       )
       // User
       .service(
@@ -366,6 +465,14 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
               .route("/mark_as_read/all", post().to(mark_all_notifications_read))
               .route("/mark_as_read", post().to(mark_notification_as_read)),
           )
+          .service(
+            scope("/draft")
+              .route("", get().to(get_draft))
+              .route("", post().to(create_draft))
+              .route("", put().to(edit_draft))
+              .route("", delete().to(delete_draft))
+              .route("/list", get().to(list_drafts)),
+          )
           .route("", delete().to(delete_account))
           .route("/login/list", get().to(list_logins))
           .route("/validate_auth", get().to(validate_auth))
@@ -407,7 +514,20 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
         scope("/person")
           .route("", get().to(read_person))
           .route("/content", get().to(list_person_content))
-          .route("/note", post().to(user_note_person)),
+          .route("/follow", post().to(follow_person))
+          .route("/followers", get().to(list_person_followers))
+          .route("/following", get().to(list_person_following))
+          .route("/note", post().to(user_note_person))
+          .route("/warn", post().to(warn_person))
+          .route("/report", post().to(create_person_report))
+          .route("/report/resolve", put().to(resolve_person_report))
+          .service(
+            scope("/mod_note")
+              .route("", post().to(create_mod_note))
+              .route("", put().to(edit_mod_note))
+              .route("", delete().to(delete_mod_note))
+              .route("/list", get().to(list_mod_notes)),
+          ),
       )
       // Admin Actions
       .service(
@@ -433,12 +553,46 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
               .route("", delete().to(delete_tagline))
               .route("/list", get().to(list_taglines)),
           )
+          .service(
+            scope("/blocked_image")
+              .route("", post().to(block_image))
+              .route("", delete().to(unblock_image))
+              .route("/list", get().to(list_blocked_images)),
+          )
           .route("/ban", post().to(ban_from_site))
+          .route("/restrict", post().to(restrict_person))
           .route("/users", get().to(admin_list_users))
+          .service(
+            scope("/media")
+              .route("/top_uploaders", get().to(admin_list_top_uploaders))
+              .route("/purge_user", post().to(admin_purge_user_media))
+              .route("/upload_quota", put().to(admin_set_upload_quota)),
+          )
           .service(
             scope("/instance")
               .route("/block", post().to(admin_block_instance))
-              .route("/allow", post().to(admin_allow_instance)),
+              .route("/allow", post().to(admin_allow_instance))
+              .route("/policy", put().to(admin_set_instance_policy)),
+          )
+          .service(
+            scope("/blocklist_subscription")
+              .route("", post().to(create_blocklist_subscription))
+              .route("", get().to(get_blocklist_subscription))
+              .route("", delete().to(delete_blocklist_subscription))
+              .route("/list", get().to(list_blocklist_subscriptions))
+              .route("/apply", post().to(apply_blocklist_subscription)),
+          )
+          .service(
+            scope("/relay")
+              .route("", post().to(create_relay))
+              .route("", put().to(edit_relay))
+              .route("", delete().to(delete_relay))
+              .route("/list", get().to(list_relays)),
+          )
+          .service(
+            scope("/federation_replay")
+              .route("", post().to(create_federation_replay))
+              .route("/list", get().to(list_federation_replays)),
           ),
       )
       .service(
@@ -448,6 +602,19 @@ pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
           .route("", delete().to(delete_custom_emoji))
           .route("/list", get().to(list_custom_emojis)),
       )
+      .service(
+        scope("/ban_appeal")
+          .route("", post().to(create_ban_appeal))
+          .route("/list", get().to(list_ban_appeals))
+          .route("/resolve", put().to(resolve_ban_appeal)),
+      )
+      .service(
+        scope("/rule")
+          .route("", post().to(create_rule))
+          .route("", put().to(edit_rule))
+          .route("", delete().to(delete_rule))
+          .route("/list", get().to(list_rules)),
+      )
       .service(
         scope("/oauth_provider")
           .route("", post().to(create_oauth_provider))
@@ -0,0 +1,442 @@
+use crate::types::{
+  Account,
+  AccountId,
+  MediaAttachment,
+  Mention,
+  Notification,
+  PreviewCard,
+  Status,
+  StatusId,
+};
+use lemmy_db_schema::source::{community::Community, person::Person};
+use lemmy_db_schema_file::enums::NotificationType;
+use lemmy_db_views_comment::CommentView;
+use lemmy_db_views_notification::{NotificationData, NotificationView};
+use lemmy_db_views_post::PostView;
+use lemmy_diesel_utils::dburl::DbUrl;
+use lemmy_utils::utils::markdown::markdown_to_html;
+
+/// Local accounts are addressed by name only, remote ones with the domain of their instance.
+fn convert_acct(name: &str, ap_id: &DbUrl, local: bool) -> String {
+  match ap_id.inner().domain() {
+    Some(domain) if !local => format!("{name}@{domain}"),
+    _ => name.to_string(),
+  }
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+    .replace('\'', "&#39;")
+}
+
+pub(crate) fn convert_person(person: Person) -> Account {
+  let avatar = person.avatar.map(|a| a.to_string()).unwrap_or_default();
+  let header = person.banner.map(|b| b.to_string()).unwrap_or_default();
+  Account {
+    id: AccountId::Person(person.id).to_string(),
+    acct: convert_acct(&person.name, &person.ap_id, person.local),
+    url: person.ap_id.to_string(),
+    display_name: person.display_name.unwrap_or_else(|| person.name.clone()),
+    username: person.name,
+    note: person
+      .bio
+      .as_deref()
+      .map(markdown_to_html)
+      .unwrap_or_default(),
+    avatar_static: avatar.clone(),
+    avatar,
+    header_static: header.clone(),
+    header,
+    locked: false,
+    bot: person.bot_account,
+    group: false,
+    discoverable: true,
+    created_at: person.published_at,
+    followers_count: 0,
+    following_count: 0,
+    statuses_count: person.post_count + person.comment_count,
+    last_status_at: None,
+    emojis: vec![],
+    fields: vec![],
+  }
+}
+
+pub(crate) fn convert_community(community: Community) -> Account {
+  let avatar = community.icon.map(|i| i.to_string()).unwrap_or_default();
+  let header = community.banner.map(|b| b.to_string()).unwrap_or_default();
+  Account {
+    id: AccountId::Community(community.id).to_string(),
+    acct: convert_acct(&community.name, &community.ap_id, community.local),
+    url: community.ap_id.to_string(),
+    username: community.name,
+    display_name: community.title,
+    note: community
+      .description
+      .as_deref()
+      .map(markdown_to_html)
+      .unwrap_or_default(),
+    avatar_static: avatar.clone(),
+    avatar,
+    header_static: header.clone(),
+    header,
+    locked: false,
+    bot: false,
+    group: true,
+    discoverable: true,
+    created_at: community.published_at,
+    followers_count: community.subscribers,
+    following_count: 0,
+    statuses_count: community.posts,
+    last_status_at: None,
+    emojis: vec![],
+    fields: vec![],
+  }
+}
+
+/// Mention of the community, so that clients can link to it from each status.
+fn community_mention(community: &Community) -> Mention {
+  Mention {
+    id: AccountId::Community(community.id).to_string(),
+    username: community.name.clone(),
+    acct: convert_acct(&community.name, &community.ap_id, community.local),
+    url: community.ap_id.to_string(),
+  }
+}
+
+/// Mastodon statuses have no title, so it is prepended to the content together with the link.
+pub(crate) fn convert_post_view(post_view: PostView) -> Status {
+  let PostView {
+    post,
+    creator,
+    community,
+    post_actions,
+    ..
+  } = post_view;
+  let id = StatusId::Post(post.id).to_string();
+  let content = if post.deleted || post.removed {
+    String::new()
+  } else {
+    let mut content = format!("<p><strong>{}</strong></p>", escape_html(&post.name));
+    if let Some(url) = &post.url {
+      let url = escape_html(url.as_str());
+      content.push_str(&format!("<p><a href=\"{url}\">{url}</a></p>"));
+    }
+    if let Some(body) = &post.body {
+      content.push_str(&markdown_to_html(body));
+    }
+    content
+  };
+  let is_image = post
+    .url_content_type
+    .as_deref()
+    .is_some_and(|t| t.starts_with("image/"));
+  let media_attachments = match &post.url {
+    Some(url) if is_image => vec![MediaAttachment {
+      id: id.clone(),
+      type_: "image",
+      url: url.to_string(),
+      preview_url: post.thumbnail_url.as_ref().unwrap_or(url).to_string(),
+      remote_url: None,
+      description: post.alt_text.clone(),
+      blurhash: None,
+    }],
+    _ => vec![],
+  };
+  let card = match &post.url {
+    Some(url) if !is_image => Some(PreviewCard {
+      url: url.to_string(),
+      title: post.embed_title.clone().unwrap_or_default(),
+      description: post.embed_description.clone().unwrap_or_default(),
+      type_: if post.embed_video_url.is_some() {
+        "video"
+      } else {
+        "link"
+      },
+      image: post.thumbnail_url.as_ref().map(ToString::to_string),
+      author_name: String::new(),
+      author_url: String::new(),
+      provider_name: url.inner().domain().unwrap_or_default().to_string(),
+      provider_url: String::new(),
+      html: String::new(),
+      width: 0,
+      height: 0,
+      embed_url: post
+        .embed_video_url
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_default(),
+    }),
+    _ => None,
+  };
+  Status {
+    id,
+    uri: post.ap_id.to_string(),
+    url: post.ap_id.to_string(),
+    created_at: post.published_at,
+    edited_at: post.updated_at,
+    account: convert_person(creator),
+    content,
+    visibility: "public",
+    sensitive: post.nsfw,
+    spoiler_text: String::new(),
+    media_attachments,
+    mentions: vec![community_mention(&community)],
+    tags: vec![],
+    emojis: vec![],
+    reblogs_count: 0,
+    favourites_count: post.upvotes,
+    replies_count: post.comments,
+    in_reply_to_id: None,
+    in_reply_to_account_id: None,
+    reblog: None,
+    poll: None,
+    card,
+    language: None,
+    favourited: post_actions
+      .as_ref()
+      .and_then(|a| a.vote_is_upvote)
+      .unwrap_or_default(),
+    reblogged: false,
+    muted: false,
+    bookmarked: post_actions.and_then(|a| a.saved_at).is_some(),
+    pinned: post.featured_community,
+  }
+}
+
+/// Top-level comments are replies to the post, others to their parent comment.
+pub(crate) fn convert_comment_view(comment_view: CommentView) -> Status {
+  let CommentView {
+    comment,
+    creator,
+    post,
+    community,
+    comment_actions,
+    ..
+  } = comment_view;
+  let (in_reply_to_id, in_reply_to_account_id) = match comment.parent_comment_id() {
+    Some(parent_id) => (StatusId::Comment(parent_id), None),
+    None => (
+      StatusId::Post(post.id),
+      Some(AccountId::Person(post.creator_id).to_string()),
+    ),
+  };
+  let content = if comment.deleted || comment.removed {
+    String::new()
+  } else {
+    markdown_to_html(&comment.content)
+  };
+  Status {
+    id: StatusId::Comment(comment.id).to_string(),
+    uri: comment.ap_id.to_string(),
+    url: comment.ap_id.to_string(),
+    created_at: comment.published_at,
+    edited_at: comment.updated_at,
+    account: convert_person(creator),
+    content,
+    visibility: "public",
+    sensitive: post.nsfw,
+    spoiler_text: String::new(),
+    media_attachments: vec![],
+    mentions: vec![community_mention(&community)],
+    tags: vec![],
+    emojis: vec![],
+    reblogs_count: 0,
+    favourites_count: comment.upvotes,
+    replies_count: comment.child_count,
+    in_reply_to_id: Some(in_reply_to_id.to_string()),
+    in_reply_to_account_id,
+    reblog: None,
+    poll: None,
+    card: None,
+    language: None,
+    favourited: comment_actions
+      .as_ref()
+      .and_then(|a| a.vote_is_upvote)
+      .unwrap_or_default(),
+    reblogged: false,
+    muted: false,
+    bookmarked: comment_actions.and_then(|a| a.saved_at).is_some(),
+    pinned: false,
+  }
+}
+
+/// Private messages and mod actions have no equivalent in Mastodon notifications, so they are
+/// skipped.
+pub(crate) fn convert_notification(view: NotificationView) -> Option<Notification> {
+  let type_ = match view.notification.kind {
+    NotificationType::Mention | NotificationType::Reply => "mention",
+    NotificationType::Subscribed => "status",
+    NotificationType::PrivateMessage | NotificationType::ModAction => return None,
+  };
+  let (account, status) = match view.data {
+    NotificationData::Comment(c) => (convert_person(c.creator.clone()), convert_comment_view(c)),
+    NotificationData::Post(p) => (convert_person(p.creator.clone()), convert_post_view(p)),
+    NotificationData::PrivateMessage(_) | NotificationData::ModAction(_) => return None,
+  };
+  Some(Notification {
+    id: view.notification.id.0.to_string(),
+    type_,
+    created_at: view.notification.published_at,
+    account,
+    status: Some(status),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_api_utils::context::LemmyContext;
+  use lemmy_db_schema::{
+    source::{
+      comment::{Comment, CommentInsertForm},
+      community::CommunityInsertForm,
+      post::{Post, PostInsertForm},
+    },
+    test_data::TestData,
+  };
+  use lemmy_diesel_utils::traits::Crud;
+  use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[test]
+  fn test_escape_html() {
+    assert_eq!(
+      "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;",
+      escape_html("<a href=\"x\">Tom & Jerry's</a>")
+    );
+  }
+
+  #[test]
+  fn test_convert_acct() -> LemmyResult<()> {
+    let ap_id: DbUrl = Url::parse("https://example.com/u/alice")?.into();
+    assert_eq!("alice", convert_acct("alice", &ap_id, true));
+    assert_eq!("alice@example.com", convert_acct("alice", &ap_id, false));
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_convert_post_and_comment() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+    let data = TestData::create(pool).await?;
+
+    let community_form = CommunityInsertForm::new(
+      data.instance.id,
+      "mastodon_convert".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm {
+      url: Some(Url::parse("https://example.com/image.png")?.into()),
+      url_content_type: Some("image/png".to_string()),
+      body: Some("**bold**".to_string()),
+      ..PostInsertForm::new("Tom & Jerry".into(), data.person.id, community.id)
+    };
+    let post = Post::create(pool, &post_form).await?;
+    let comment_form = CommentInsertForm::new(data.person.id, post.id, "reply".into());
+    let comment = Comment::create(pool, &comment_form, None).await?;
+
+    let post_view = PostView::read(pool, post.id, None, data.instance.id, false).await?;
+    let status = convert_post_view(post_view);
+    assert_eq!(StatusId::Post(post.id).to_string(), status.id);
+    assert!(status.content.contains("<strong>Tom &amp; Jerry</strong>"));
+    assert!(status.content.contains("<strong>bold</strong>"));
+    assert_eq!(1, status.media_attachments.len());
+    assert!(status.card.is_none());
+    assert_eq!(data.person.name, status.account.acct);
+    assert_eq!(
+      "mastodon_convert",
+      status
+        .mentions
+        .first()
+        .map(|m| m.acct.as_str())
+        .unwrap_or_default()
+    );
+
+    let comment_view = CommentView::read(pool, comment.id, None, data.instance.id).await?;
+    let status = convert_comment_view(comment_view);
+    assert_eq!(StatusId::Comment(comment.id).to_string(), status.id);
+    assert_eq!(
+      Some(StatusId::Post(post.id).to_string()),
+      status.in_reply_to_id
+    );
+    assert_eq!(
+      Some(AccountId::Person(data.person.id).to_string()),
+      status.in_reply_to_account_id
+    );
+    assert!(status.content.contains("reply"));
+
+    Community::delete(pool, community.id).await?;
+    data.delete(pool).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_convert_link_post_and_nested_comment() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+    let data = TestData::create(pool).await?;
+
+    // Remote communities are addressed with their domain
+    let community_form = CommunityInsertForm {
+      ap_id: Some(Url::parse("https://remote.example.com/c/links")?.into()),
+      local: Some(false),
+      ..CommunityInsertForm::new(
+        data.instance.id,
+        "links".to_string(),
+        "Links".to_owned(),
+        "pubkey".to_string(),
+      )
+    };
+    let community = Community::create(pool, &community_form).await?;
+    let account = convert_community(community.clone());
+    assert_eq!("links@remote.example.com", account.acct);
+    assert_eq!("Links", account.display_name);
+    assert!(account.group);
+
+    // Links which aren't images are shown as preview card
+    let post_form = PostInsertForm {
+      url: Some(Url::parse("https://example.com/article")?.into()),
+      embed_title: Some("Article".to_string()),
+      ..PostInsertForm::new("Read this".into(), data.person.id, community.id)
+    };
+    let post = Post::create(pool, &post_form).await?;
+    let post_view = PostView::read(pool, post.id, None, data.instance.id, false).await?;
+    let status = convert_post_view(post_view);
+    assert!(status.media_attachments.is_empty());
+    let card = status.card.ok_or(LemmyErrorType::NotFound)?;
+    assert_eq!("Article", card.title);
+    assert_eq!("link", card.type_);
+    assert_eq!("example.com", card.provider_name);
+
+    // Replies to comments reference the parent comment, and deleted content is hidden
+    let parent_form = CommentInsertForm::new(data.person.id, post.id, "parent".into());
+    let parent = Comment::create(pool, &parent_form, None).await?;
+    let child_form = CommentInsertForm {
+      deleted: Some(true),
+      ..CommentInsertForm::new(data.person.id, post.id, "child".into())
+    };
+    let child = Comment::create(pool, &child_form, Some(&parent.path)).await?;
+    let comment_view = CommentView::read(pool, child.id, None, data.instance.id).await?;
+    let status = convert_comment_view(comment_view);
+    assert_eq!(
+      Some(StatusId::Comment(parent.id).to_string()),
+      status.in_reply_to_id
+    );
+    assert_eq!(None, status.in_reply_to_account_id);
+    assert_eq!("", status.content);
+
+    Community::delete(pool, community.id).await?;
+    data.delete(pool).await?;
+    Ok(())
+  }
+}
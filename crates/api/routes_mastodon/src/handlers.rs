@@ -0,0 +1,1222 @@
+use crate::{
+  convert::{
+    convert_comment_view,
+    convert_community,
+    convert_notification,
+    convert_person,
+    convert_post_view,
+    escape_html,
+  },
+  types::{
+    Account,
+    AccountId,
+    AccountSource,
+    Application,
+    AuthorizeForm,
+    AuthorizeQuery,
+    Context,
+    CreateApplication,
+    CreateStatus,
+    CreateToken,
+    CredentialAccount,
+    Instance,
+    InstanceStats,
+    List,
+    LookupQuery,
+    Relationship,
+    RevokeToken,
+    Status,
+    StatusId,
+    TimelineQuery,
+    Token,
+  },
+};
+use activitypub_federation::config::Data as ApubData;
+use actix_web::{
+  Either,
+  HttpRequest,
+  HttpResponse,
+  cookie::{Cookie, SameSite},
+  http::header::{LINK, LOCATION},
+  web::*,
+};
+use chrono::Utc;
+use lemmy_api::{
+  comment::{like::like_comment, save::save_comment},
+  community::follow::follow_community,
+  federation::{
+    list_comments::list_comments,
+    list_person_content::list_person_content,
+    list_posts::list_posts,
+    read_community::get_community,
+    read_person::read_person,
+  },
+  local_user::{
+    login::check_login,
+    notifications::{
+      list::list_notifications,
+      mark_all_read::mark_all_notifications_read,
+      mark_notification_read::mark_notification_as_read,
+    },
+  },
+  post::{like::like_post, save::save_post},
+};
+use lemmy_api_crud::{
+  comment::{create::create_comment, delete::delete_comment, read::get_comment},
+  post::{delete::delete_post, read::get_post},
+  site::read::get_site,
+  user::my_user::get_my_user,
+};
+use lemmy_api_utils::{claims::Claims, context::LemmyContext};
+use lemmy_db_schema::{
+  newtypes::{CommentId, CommunityId, NotificationId},
+  source::{
+    comment::Comment,
+    login_token::LoginToken,
+    mastodon_application::{
+      MastodonApplication,
+      MastodonApplicationInsertForm,
+      MastodonAuthorizationCode,
+      MastodonAuthorizationCodeInsertForm,
+    },
+  },
+  utils::FETCH_LIMIT_MAX,
+};
+use lemmy_db_schema_file::enums::{
+  CommentSortType,
+  CommunityFollowerState,
+  ListingType,
+  PostSortType,
+  RegistrationMode,
+};
+use lemmy_db_views_comment::api::{
+  CreateComment,
+  CreateCommentLike,
+  DeleteComment,
+  GetComment,
+  GetComments,
+  SaveComment,
+};
+use lemmy_db_views_community::{
+  CommunityView,
+  api::{FollowCommunity, GetCommunity},
+};
+use lemmy_db_views_local_user::LocalUserView;
+use lemmy_db_views_notification::{ListNotifications, api::MarkNotificationAsRead};
+use lemmy_db_views_person::api::GetPersonDetails;
+use lemmy_db_views_person_content_combined::ListPersonContent;
+use lemmy_db_views_post::api::{CreatePostLike, DeletePost, GetPosts, SavePost};
+use lemmy_db_views_post_comment_combined::PostCommentCombinedView;
+use lemmy_db_views_search_combined::api::GetPost;
+use lemmy_db_views_site::api::Login;
+use lemmy_diesel_utils::{pagination::PaginationCursor, traits::Crud};
+use lemmy_utils::{
+  VERSION,
+  error::{LemmyErrorType, LemmyResult},
+};
+use serde::Serialize;
+use serde_json::{Value, json};
+use url::{Url, form_urlencoded};
+use uuid::Uuid;
+
+/// Redirect uri for clients which can't receive a redirect, the code is shown to the user instead.
+const OOB_REDIRECT_URI: &str = "urn:ietf:wg:oauth:2.0:oob";
+
+/// Holds the token which protects the login form against cross site request forgery.
+const CSRF_COOKIE_NAME: &str = "mastodon_oauth_csrf";
+
+/// Mastodon clients send request bodies either as json or as form data.
+fn body<T>(data: Either<Json<T>, Form<T>>) -> T {
+  match data {
+    Either::Left(Json(data)) => data,
+    Either::Right(Form(data)) => data,
+  }
+}
+
+/// Builds a list response, with a `Link` header pointing to the next page if there is one.
+fn paged_response<T: Serialize>(
+  req: &HttpRequest,
+  items: Vec<T>,
+  next_page: Option<PaginationCursor>,
+) -> LemmyResult<HttpResponse> {
+  let mut res = HttpResponse::Ok();
+  let next_page = serde_json::to_value(next_page)?;
+  if let Some(next_page) = next_page.as_str() {
+    let mut url = req.full_url();
+    let query = url
+      .query_pairs()
+      .filter(|(key, _)| key != "page_cursor")
+      .map(|(key, value)| (key.into_owned(), value.into_owned()))
+      .collect::<Vec<_>>();
+    url
+      .query_pairs_mut()
+      .clear()
+      .extend_pairs(query)
+      .append_pair("page_cursor", next_page);
+    res.insert_header((LINK, format!("<{url}>; rel=\"next\"")));
+  }
+  Ok(res.json(items))
+}
+
+fn convert_combined(view: PostCommentCombinedView) -> Status {
+  match view {
+    PostCommentCombinedView::Post(p) => convert_post_view(p),
+    PostCommentCombinedView::Comment(c) => convert_comment_view(c),
+  }
+}
+
+fn convert_relationship(id: AccountId, community_view: Option<CommunityView>) -> Relationship {
+  let follow_state = community_view
+    .and_then(|c| c.community_actions)
+    .and_then(|a| a.follow_state);
+  Relationship {
+    id: id.to_string(),
+    following: follow_state == Some(CommunityFollowerState::Accepted),
+    showing_reblogs: false,
+    notifying: false,
+    followed_by: false,
+    blocking: false,
+    blocked_by: false,
+    muting: false,
+    muting_notifications: false,
+    requested: matches!(
+      follow_state,
+      Some(CommunityFollowerState::Pending | CommunityFollowerState::ApprovalRequired)
+    ),
+    domain_blocking: false,
+    endorsed: false,
+    note: String::new(),
+  }
+}
+
+pub(crate) async fn get_instance(context: Data<LemmyContext>) -> LemmyResult<Json<Instance>> {
+  let site_view = get_site(None, context.clone()).await?.0.site_view;
+  let site = site_view.site;
+  let local_site = site_view.local_site;
+  let description = site
+    .summary
+    .clone()
+    .or(site.description.clone())
+    .unwrap_or_default();
+  Ok(Json(Instance {
+    uri: context.settings().hostname.clone(),
+    title: site.name,
+    short_description: description.clone(),
+    description,
+    email: String::new(),
+    version: format!("4.2.0 (compatible; Lemmy {})", *VERSION),
+    urls: json!({}),
+    stats: InstanceStats {
+      user_count: local_site.users,
+      status_count: local_site.posts + local_site.comments,
+      domain_count: 0,
+    },
+    thumbnail: site.banner.or(site.icon).map(|u| u.to_string()),
+    languages: vec![],
+    registrations: local_site.registration_mode != RegistrationMode::Closed,
+    approval_required: local_site.registration_mode == RegistrationMode::RequireApplication,
+    invites_enabled: false,
+    configuration: json!({
+      "statuses": {
+        "max_characters": 10000,
+        "max_media_attachments": 0,
+      },
+    }),
+    contact_account: None,
+    rules: vec![],
+  }))
+}
+
+pub(crate) async fn verify_credentials(
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CredentialAccount>> {
+  let source = AccountSource {
+    note: local_user_view.person.bio.clone().unwrap_or_default(),
+    fields: vec![],
+    privacy: "public",
+    sensitive: local_user_view.local_user.show_nsfw,
+    language: None,
+  };
+  Ok(Json(CredentialAccount {
+    account: convert_person(local_user_view.person),
+    source,
+  }))
+}
+
+async fn read_account(
+  id: AccountId,
+  context: ApubData<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<(Account, Option<CommunityView>)> {
+  match id {
+    AccountId::Person(person_id) => {
+      let data = GetPersonDetails {
+        person_id: Some(person_id),
+        username: None,
+      };
+      let res = read_person(Query(data), context, local_user_view).await?.0;
+      Ok((convert_person(res.person_view.person), None))
+    }
+    AccountId::Community(community_id) => {
+      let data = GetCommunity {
+        id: Some(community_id),
+        name: None,
+      };
+      let res = get_community(Query(data), context, local_user_view)
+        .await?
+        .0;
+      Ok((
+        convert_community(res.community_view.community.clone()),
+        Some(res.community_view),
+      ))
+    }
+  }
+}
+
+pub(crate) async fn get_account(
+  id: Path<String>,
+  context: ApubData<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<Account>> {
+  let id = id.parse()?;
+  Ok(Json(read_account(id, context, local_user_view).await?.0))
+}
+
+/// Usernames of persons and communities can overlap, in that case the person is returned.
+pub(crate) async fn lookup_account(
+  Query(data): Query<LookupQuery>,
+  context: ApubData<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<Account>> {
+  let acct = data.acct.trim_start_matches('@').to_string();
+  let person = GetPersonDetails {
+    person_id: None,
+    username: Some(acct.clone()),
+  };
+  let res = read_person(
+    Query(person),
+    context.reset_request_count(),
+    local_user_view.clone(),
+  )
+  .await;
+  if let Ok(res) = res {
+    return Ok(Json(convert_person(res.0.person_view.person)));
+  }
+  let community = GetCommunity {
+    id: None,
+    name: Some(acct),
+  };
+  let res = get_community(Query(community), context, local_user_view)
+    .await?
+    .0;
+  Ok(Json(convert_community(res.community_view.community)))
+}
+
+pub(crate) async fn account_statuses(
+  id: Path<String>,
+  Query(query): Query<TimelineQuery>,
+  req: HttpRequest,
+  context: ApubData<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<HttpResponse> {
+  match id.parse::<AccountId>()? {
+    AccountId::Person(person_id) => {
+      let data = ListPersonContent {
+        person_id: Some(person_id),
+        page_cursor: query.page_cursor,
+        limit: query.limit,
+        ..Default::default()
+      };
+      let res = list_person_content(Query(data), context, local_user_view)
+        .await?
+        .0;
+      let statuses = res.items.into_iter().map(convert_combined).collect();
+      paged_response(&req, statuses, res.next_page)
+    }
+    AccountId::Community(community_id) => {
+      let data = GetPosts {
+        community_id: Some(community_id),
+        ..timeline_query(query)
+      };
+      posts_timeline(data, req, context, local_user_view).await
+    }
+  }
+}
+
+/// Only communities can be followed.
+async fn follow_account(
+  id: &str,
+  follow: bool,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Relationship>> {
+  let id = id.parse::<AccountId>()?;
+  let AccountId::Community(community_id) = id else {
+    return Err(LemmyErrorType::NotFound.into());
+  };
+  let data = FollowCommunity {
+    community_id,
+    follow,
+  };
+  let res = follow_community(Json(data), context, local_user_view)
+    .await?
+    .0;
+  Ok(Json(convert_relationship(id, Some(res.community_view))))
+}
+
+pub(crate) async fn follow(
+  id: Path<String>,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Relationship>> {
+  follow_account(&id, true, context, local_user_view).await
+}
+
+pub(crate) async fn unfollow(
+  id: Path<String>,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Relationship>> {
+  follow_account(&id, false, context, local_user_view).await
+}
+
+/// Ids are passed as `id[]=...`, which isn't supported by the query extractor.
+pub(crate) async fn relationships(
+  req: HttpRequest,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Vec<Relationship>>> {
+  let ids = form_urlencoded::parse(req.query_string().as_bytes())
+    .filter(|(key, _)| key == "id[]" || key == "id")
+    .filter_map(|(_, value)| value.parse::<AccountId>().ok())
+    .collect::<Vec<_>>();
+  let mut relationships = vec![];
+  for id in ids {
+    let community_view = match id {
+      AccountId::Community(_) => {
+        read_account(
+          id,
+          context.reset_request_count(),
+          Some(local_user_view.clone()),
+        )
+        .await?
+        .1
+      }
+      AccountId::Person(_) => None,
+    };
+    relationships.push(convert_relationship(id, community_view));
+  }
+  Ok(Json(relationships))
+}
+
+fn timeline_query(query: TimelineQuery) -> GetPosts {
+  GetPosts {
+    sort: Some(PostSortType::New),
+    page_cursor: query.page_cursor,
+    limit: query.limit,
+    ..Default::default()
+  }
+}
+
+async fn posts_timeline(
+  data: GetPosts,
+  req: HttpRequest,
+  context: ApubData<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<HttpResponse> {
+  let res = list_posts(Query(data), context, local_user_view).await?.0;
+  let statuses = res.items.into_iter().map(convert_post_view).collect();
+  paged_response(&req, statuses, res.next_page)
+}
+
+pub(crate) async fn home_timeline(
+  Query(query): Query<TimelineQuery>,
+  req: HttpRequest,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<HttpResponse> {
+  let data = GetPosts {
+    type_: Some(ListingType::Subscribed),
+    ..timeline_query(query)
+  };
+  posts_timeline(data, req, context, Some(local_user_view)).await
+}
+
+pub(crate) async fn public_timeline(
+  Query(query): Query<TimelineQuery>,
+  req: HttpRequest,
+  context: ApubData<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<HttpResponse> {
+  let type_ = if query.local.unwrap_or_default() {
+    ListingType::Local
+  } else {
+    ListingType::All
+  };
+  let data = GetPosts {
+    type_: Some(type_),
+    ..timeline_query(query)
+  };
+  posts_timeline(data, req, context, local_user_view).await
+}
+
+/// Each followed community is a list, with the community id as list id.
+pub(crate) async fn list_timeline(
+  id: Path<i32>,
+  Query(query): Query<TimelineQuery>,
+  req: HttpRequest,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<HttpResponse> {
+  let data = GetPosts {
+    community_id: Some(CommunityId(id.into_inner())),
+    ..timeline_query(query)
+  };
+  posts_timeline(data, req, context, Some(local_user_view)).await
+}
+
+pub(crate) async fn get_lists(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Vec<List>>> {
+  let my_user = get_my_user(local_user_view, context).await?.0;
+  let lists = my_user
+    .follows
+    .into_iter()
+    .map(|f| List {
+      id: f.community.id.0.to_string(),
+      title: f.community.title,
+      replies_policy: "none",
+    })
+    .collect();
+  Ok(Json(lists))
+}
+
+async fn read_status(
+  id: StatusId,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Status> {
+  match id {
+    StatusId::Post(post_id) => {
+      let data = GetPost {
+        id: Some(post_id),
+        comment_id: None,
+      };
+      let res = get_post(Query(data), context, local_user_view).await?.0;
+      Ok(convert_post_view(res.post_view))
+    }
+    StatusId::Comment(comment_id) => {
+      let data = GetComment { id: comment_id };
+      let res = get_comment(Query(data), context, local_user_view).await?.0;
+      Ok(convert_comment_view(res.comment_view))
+    }
+  }
+}
+
+pub(crate) async fn get_status(
+  id: Path<String>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<Status>> {
+  Ok(Json(
+    read_status(id.parse()?, context, local_user_view).await?,
+  ))
+}
+
+/// The thread of a status. Ancestors of a comment are its post and parent comments, descendants
+/// are limited to the first page of replies.
+pub(crate) async fn status_context(
+  id: Path<String>,
+  context: Data<LemmyContext>,
+  apub_context: ApubData<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<Context>> {
+  let id = id.parse()?;
+  let mut ancestors = vec![];
+  let mut data = GetComments {
+    sort: Some(CommentSortType::Old),
+    limit: Some(FETCH_LIMIT_MAX.try_into()?),
+    ..Default::default()
+  };
+  match id {
+    StatusId::Post(post_id) => data.post_id = Some(post_id),
+    StatusId::Comment(comment_id) => {
+      let comment = Comment::read(&mut context.pool(), comment_id).await?;
+      let post_id = StatusId::Post(comment.post_id);
+      ancestors.push(read_status(post_id, context.clone(), local_user_view.clone()).await?);
+      let parent_ids = comment
+        .path
+        .0
+        .split('.')
+        .skip(1)
+        .filter_map(|id| id.parse().ok().map(CommentId))
+        .filter(|id| id != &comment_id);
+      for parent_id in parent_ids {
+        let parent_id = StatusId::Comment(parent_id);
+        ancestors.push(read_status(parent_id, context.clone(), local_user_view.clone()).await?);
+      }
+      data.parent_id = Some(comment_id);
+    }
+  }
+  let descendants = list_comments(Query(data), apub_context, local_user_view)
+    .await?
+    .0
+    .items
+    .into_iter()
+    .filter(|c| StatusId::Comment(c.comment.id) != id)
+    .map(convert_comment_view)
+    .collect();
+  Ok(Json(Context {
+    ancestors,
+    descendants,
+  }))
+}
+
+/// Posts need a title and community, so only replies can be created.
+pub(crate) async fn create_status(
+  data: Either<Json<CreateStatus>, Form<CreateStatus>>,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Status>> {
+  let data = body(data);
+  let in_reply_to_id = data.in_reply_to_id.ok_or(LemmyErrorType::NoIdGiven)?;
+  let (post_id, parent_id) = match in_reply_to_id.parse::<StatusId>()? {
+    StatusId::Post(post_id) => (post_id, None),
+    StatusId::Comment(comment_id) => {
+      let comment = Comment::read(&mut context.pool(), comment_id).await?;
+      (comment.post_id, Some(comment_id))
+    }
+  };
+  let data = CreateComment {
+    content: data.status,
+    post_id,
+    parent_id,
+    language_id: None,
+    draft_id: None,
+  };
+  let res = Box::pin(create_comment(Json(data), context, local_user_view)).await?;
+  Ok(Json(convert_comment_view(res.0.comment_view)))
+}
+
+/// Favourites are upvotes, there is no equivalent for downvotes.
+async fn vote_status(
+  id: &str,
+  is_upvote: Option<bool>,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Status>> {
+  let status = match id.parse::<StatusId>()? {
+    StatusId::Post(post_id) => {
+      let data = CreatePostLike { post_id, is_upvote };
+      let res = like_post(Json(data), context, local_user_view).await?;
+      convert_post_view(res.0.post_view)
+    }
+    StatusId::Comment(comment_id) => {
+      let data = CreateCommentLike {
+        comment_id,
+        is_upvote,
+      };
+      let res = like_comment(Json(data), context, local_user_view).await?;
+      convert_comment_view(res.0.comment_view)
+    }
+  };
+  Ok(Json(status))
+}
+
+pub(crate) async fn favourite(
+  id: Path<String>,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Status>> {
+  vote_status(&id, Some(true), context, local_user_view).await
+}
+
+pub(crate) async fn unfavourite(
+  id: Path<String>,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Status>> {
+  vote_status(&id, None, context, local_user_view).await
+}
+
+/// Bookmarks are saved posts and comments.
+async fn save_status(
+  id: &str,
+  save: bool,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Status>> {
+  let status = match id.parse::<StatusId>()? {
+    StatusId::Post(post_id) => {
+      let data = SavePost { post_id, save };
+      let res = save_post(Json(data), context, local_user_view).await?;
+      convert_post_view(res.0.post_view)
+    }
+    StatusId::Comment(comment_id) => {
+      let data = SaveComment { comment_id, save };
+      let res = save_comment(Json(data), context, local_user_view).await?;
+      convert_comment_view(res.0.comment_view)
+    }
+  };
+  Ok(Json(status))
+}
+
+pub(crate) async fn bookmark(
+  id: Path<String>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Status>> {
+  save_status(&id, true, context, local_user_view).await
+}
+
+pub(crate) async fn unbookmark(
+  id: Path<String>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Status>> {
+  save_status(&id, false, context, local_user_view).await
+}
+
+pub(crate) async fn delete_status(
+  id: Path<String>,
+  context: ApubData<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Status>> {
+  let status = match id.parse::<StatusId>()? {
+    StatusId::Post(post_id) => {
+      let data = DeletePost {
+        post_id,
+        deleted: true,
+      };
+      let res = delete_post(Json(data), context, local_user_view).await?;
+      convert_post_view(res.0.post_view)
+    }
+    StatusId::Comment(comment_id) => {
+      let data = DeleteComment {
+        comment_id,
+        deleted: true,
+      };
+      let res = delete_comment(Json(data), context, local_user_view).await?;
+      convert_comment_view(res.0.comment_view)
+    }
+  };
+  Ok(Json(status))
+}
+
+pub(crate) async fn notifications(
+  Query(query): Query<TimelineQuery>,
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<HttpResponse> {
+  let data = ListNotifications {
+    page_cursor: query.page_cursor,
+    limit: query.limit,
+    ..Default::default()
+  };
+  let res = list_notifications(Query(data), context, local_user_view)
+    .await?
+    .0;
+  let notifications = res
+    .items
+    .into_iter()
+    .filter_map(convert_notification)
+    .collect();
+  paged_response(&req, notifications, res.next_page)
+}
+
+pub(crate) async fn dismiss_notification(
+  id: Path<i32>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Value>> {
+  let data = MarkNotificationAsRead {
+    notification_id: NotificationId(id.into_inner()),
+    read: true,
+  };
+  mark_notification_as_read(Json(data), context, local_user_view).await?;
+  Ok(Json(json!({})))
+}
+
+pub(crate) async fn clear_notifications(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<Value>> {
+  mark_all_notifications_read(context, local_user_view).await?;
+  Ok(Json(json!({})))
+}
+
+/// Endpoints for features which Lemmy doesn't have, but which clients request on startup.
+pub(crate) async fn empty_list() -> Json<Vec<Value>> {
+  Json(vec![])
+}
+
+/// Applications are stored so that authorization codes are only sent to their registered
+/// redirect uris.
+pub(crate) async fn create_application(
+  data: Either<Json<CreateApplication>, Form<CreateApplication>>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<Application>> {
+  let data = body(data);
+  let redirect_uris = parse_redirect_uris(&data.redirect_uris)?;
+  // Mastodon defaults to read access if no scopes are given
+  let scopes = data.scopes.as_deref().unwrap_or("read").to_string();
+  check_full_scope(&scopes)?;
+  let form = MastodonApplicationInsertForm::new(
+    Uuid::new_v4().to_string(),
+    Uuid::new_v4().to_string().into(),
+    data.client_name,
+    data.website,
+    redirect_uris,
+    scopes,
+  );
+  let app = MastodonApplication::create(&mut context.pool(), &form).await?;
+  Ok(Json(Application {
+    id: app.id.0.to_string(),
+    name: app.name,
+    website: app.website,
+    redirect_uri: data.redirect_uris,
+    scopes: app
+      .scopes
+      .split_whitespace()
+      .map(ToString::to_string)
+      .collect(),
+    client_id: app.client_id,
+    client_secret: app.client_secret.into_inner(),
+  }))
+}
+
+/// Scopes are not enforced, a token always grants full access to the account. So only clients
+/// which request read and write access are accepted, instead of giving them more access than the
+/// user agreed to.
+fn check_full_scope(scopes: &str) -> LemmyResult<()> {
+  let scopes: Vec<_> = scopes.split_whitespace().collect();
+  if !scopes.contains(&"read") || !scopes.contains(&"write") {
+    Err(LemmyErrorType::OauthInsufficientScope)?
+  }
+  Ok(())
+}
+
+/// Read the application and check its secret.
+async fn read_application_with_secret(
+  client_id: &str,
+  client_secret: &str,
+  context: &LemmyContext,
+) -> LemmyResult<MastodonApplication> {
+  let app = MastodonApplication::read_from_client_id(&mut context.pool(), client_id).await?;
+  if *app.client_secret != client_secret {
+    Err(LemmyErrorType::OauthAuthorizationInvalid)?
+  }
+  Ok(app)
+}
+
+/// Clients may register multiple redirect uris, separated by whitespace.
+fn parse_redirect_uris(redirect_uris: &str) -> LemmyResult<Vec<String>> {
+  let redirect_uris: Vec<String> = redirect_uris
+    .split_whitespace()
+    .map(ToString::to_string)
+    .collect();
+  if redirect_uris.is_empty() {
+    Err(LemmyErrorType::InvalidUrl)?
+  }
+  for uri in &redirect_uris {
+    if uri != OOB_REDIRECT_URI {
+      Url::parse(uri)?;
+    }
+  }
+  Ok(redirect_uris)
+}
+
+/// Read the application, and check that the redirect uri was registered for it.
+async fn read_application(
+  client_id: &str,
+  redirect_uri: &str,
+  context: &LemmyContext,
+) -> LemmyResult<MastodonApplication> {
+  let app = MastodonApplication::read_from_client_id(&mut context.pool(), client_id).await?;
+  if !app.redirect_uris.iter().any(|u| u == redirect_uri) {
+    Err(LemmyErrorType::OauthAuthorizationInvalid)?
+  }
+  Ok(app)
+}
+
+fn html_page(title: &str, body: &str) -> HttpResponse {
+  HttpResponse::Ok()
+    .content_type("text/html; charset=utf-8")
+    .body(format!(
+      "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+       <meta name=\"viewport\" content=\"width=device-width\"><title>{title}</title></head>\
+       <body><h1>{title}</h1>{body}</body></html>"
+    ))
+}
+
+/// The login form includes a random token which must match the cookie, so that other sites can't
+/// submit it. It also shows which application requests access, and with which scopes.
+pub(crate) async fn authorize_form(
+  Query(query): Query<AuthorizeQuery>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let app = read_application(&query.client_id, &query.redirect_uri, &context).await?;
+  let scopes = query.scope.unwrap_or_else(|| app.scopes.clone());
+  check_full_scope(&scopes)?;
+  let csrf_token = Uuid::new_v4().to_string();
+  let title = escape_html(&format!("Login to {}", context.settings().hostname));
+  let app_name = escape_html(&app.name);
+  let scopes = escape_html(&scopes);
+  let client_id = escape_html(&query.client_id);
+  let redirect_uri = escape_html(&query.redirect_uri);
+  let state = escape_html(&query.state.unwrap_or_default());
+  let mut res = html_page(
+    &title,
+    &format!(
+      "<p><strong>{app_name}</strong> requests full access to your account \
+       (scopes: {scopes}).</p>\
+       <form method=\"post\">\
+       <p><label>Username or email <input name=\"username\" required></label></p>\
+       <p><label>Password <input name=\"password\" type=\"password\" required></label></p>\
+       <p><label>2FA token <input name=\"totp_2fa_token\"></label></p>\
+       <input type=\"hidden\" name=\"client_id\" value=\"{client_id}\">\
+       <input type=\"hidden\" name=\"redirect_uri\" value=\"{redirect_uri}\">\
+       <input type=\"hidden\" name=\"state\" value=\"{state}\">\
+       <input type=\"hidden\" name=\"csrf_token\" value=\"{csrf_token}\">\
+       <p><button type=\"submit\">Authorize</button></p></form>"
+    ),
+  );
+  let cookie = Cookie::build(CSRF_COOKIE_NAME, csrf_token)
+    .path("/oauth")
+    .http_only(true)
+    .secure(true)
+    .same_site(SameSite::Strict)
+    .finish();
+  res.add_cookie(&cookie)?;
+  Ok(res)
+}
+
+/// After login a short lived authorization code is sent to the application, which exchanges it
+/// for a login token.
+pub(crate) async fn authorize(
+  Form(data): Form<AuthorizeForm>,
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let csrf_cookie = req.cookie(CSRF_COOKIE_NAME);
+  if csrf_cookie.is_none_or(|c| c.value() != data.csrf_token) {
+    Err(LemmyErrorType::OauthAuthorizationInvalid)?
+  }
+  let app = read_application(&data.client_id, &data.redirect_uri, &context).await?;
+  let login = Login {
+    username_or_email: data.username.into(),
+    password: data.password.into(),
+    totp_2fa_token: data.totp_2fa_token.filter(|t| !t.is_empty()),
+    stay_logged_in: Some(true),
+  };
+  let local_user_view = check_login(&login, &context).await?;
+
+  let code = Uuid::new_v4().to_string();
+  let form = MastodonAuthorizationCodeInsertForm::new(
+    code.clone().into(),
+    app.id,
+    local_user_view.local_user.id,
+    data.redirect_uri.clone(),
+  );
+  MastodonAuthorizationCode::create(&mut context.pool(), &form).await?;
+
+  if data.redirect_uri == OOB_REDIRECT_URI {
+    let body = format!(
+      "<p>Copy this code into your application:</p><textarea readonly>{}</textarea>",
+      escape_html(&code)
+    );
+    return Ok(html_page("Authorization code", &body));
+  }
+  let mut redirect = Url::parse(&data.redirect_uri)?;
+  redirect.query_pairs_mut().append_pair("code", &code);
+  if let Some(state) = &data.state {
+    redirect.query_pairs_mut().append_pair("state", state);
+  }
+  Ok(
+    HttpResponse::Found()
+      .insert_header((LOCATION, redirect.as_str()))
+      .finish(),
+  )
+}
+
+pub(crate) async fn create_token(
+  data: Either<Json<CreateToken>, Form<CreateToken>>,
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<Token>> {
+  let data = body(data);
+  let app = read_application_with_secret(&data.client_id, &data.client_secret, &context).await?;
+  if let Some(scope) = &data.scope {
+    check_full_scope(scope)?;
+  }
+  let local_user_id = match data.grant_type.as_str() {
+    "authorization_code" => {
+      let code = data.code.ok_or(LemmyErrorType::OauthAuthorizationInvalid)?;
+      let code = MastodonAuthorizationCode::read_and_delete(&mut context.pool(), &code).await?;
+      if code.application_id != app.id || Some(&code.redirect_uri) != data.redirect_uri.as_ref() {
+        Err(LemmyErrorType::OauthAuthorizationInvalid)?
+      }
+      code.local_user_id
+    }
+    "password" => {
+      let login = Login {
+        username_or_email: data.username.ok_or(LemmyErrorType::IncorrectLogin)?.into(),
+        password: data.password.ok_or(LemmyErrorType::IncorrectLogin)?.into(),
+        totp_2fa_token: None,
+        stay_logged_in: Some(true),
+      };
+      check_login(&login, &context).await?.local_user.id
+    }
+    _ => Err(LemmyErrorType::OauthAuthorizationInvalid)?,
+  };
+  let access_token = Claims::generate(local_user_id, Some(true), req, &context).await?;
+  Ok(Json(Token {
+    access_token: access_token.into_inner(),
+    token_type: "Bearer",
+    scope: app.scopes,
+    created_at: Utc::now().timestamp(),
+  }))
+}
+
+/// Like in Mastodon, tokens can only be revoked with the credentials of a registered application.
+pub(crate) async fn revoke_token(
+  data: Either<Json<RevokeToken>, Form<RevokeToken>>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<Value>> {
+  let data = body(data);
+  read_application_with_secret(&data.client_id, &data.client_secret, &context).await?;
+  LoginToken::invalidate(&mut context.pool(), &data.token).await?;
+  Ok(Json(json!({})))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use actix_web::test::TestRequest;
+  use lemmy_db_schema::{
+    source::{
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    test_data::TestData,
+  };
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  const REDIRECT_URI: &str = "https://app.example.com/callback";
+
+  fn token_form(app: &Application, code: &str) -> Either<Json<CreateToken>, Form<CreateToken>> {
+    Either::Right(Form(CreateToken {
+      grant_type: "authorization_code".to_string(),
+      client_id: app.client_id.clone(),
+      client_secret: app.client_secret.clone(),
+      redirect_uri: Some(REDIRECT_URI.to_string()),
+      code: Some(code.to_string()),
+      username: None,
+      password: None,
+      scope: None,
+    }))
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_oauth_flow() -> LemmyResult<()> {
+    let apub_context = LemmyContext::init_test_context().await;
+    let context = Data::new(apub_context.app_data().clone());
+    let pool = &mut context.pool();
+    let data = TestData::create(pool).await?;
+    let person = Person::create(
+      pool,
+      &PersonInsertForm::test_form(data.instance.id, "oauth"),
+    )
+    .await?;
+    let local_user_form = LocalUserInsertForm {
+      password_encrypted: Some("password123".to_string()),
+      accepted_application: Some(true),
+      ..LocalUserInsertForm::test_form(person.id)
+    };
+    let local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    // Invalid redirect uris are rejected
+    let app = CreateApplication {
+      client_name: "app".to_string(),
+      redirect_uris: "not a url".to_string(),
+      scopes: Some("read write follow".to_string()),
+      website: None,
+    };
+    assert!(
+      create_application(Either::Left(Json(app.clone())), context.clone())
+        .await
+        .is_err()
+    );
+
+    // Tokens grant full access, so apps requesting less are rejected
+    let read_only = CreateApplication {
+      redirect_uris: REDIRECT_URI.to_string(),
+      scopes: None,
+      ..app.clone()
+    };
+    assert!(
+      create_application(Either::Left(Json(read_only)), context.clone())
+        .await
+        .is_err()
+    );
+    let app = create_application(
+      Either::Left(Json(CreateApplication {
+        redirect_uris: REDIRECT_URI.to_string(),
+        ..app
+      })),
+      context.clone(),
+    )
+    .await?
+    .0;
+
+    // The form is only shown for registered redirect uris
+    let query = |redirect_uri: &str, scope: Option<&str>| {
+      Query(AuthorizeQuery {
+        client_id: app.client_id.clone(),
+        redirect_uri: redirect_uri.to_string(),
+        scope: scope.map(ToString::to_string),
+        state: Some("state".to_string()),
+      })
+    };
+    assert!(
+      authorize_form(query("https://attacker.example.com", None), context.clone())
+        .await
+        .is_err()
+    );
+    assert!(
+      authorize_form(query(REDIRECT_URI, Some("read")), context.clone())
+        .await
+        .is_err()
+    );
+    let res = authorize_form(query(REDIRECT_URI, None), context.clone()).await?;
+    let cookie = res
+      .cookies()
+      .find(|c| c.name() == CSRF_COOKIE_NAME)
+      .ok_or(LemmyErrorType::NotFound)?
+      .into_owned();
+    // The form names the application and its scopes
+    let page = actix_web::body::to_bytes(res.into_body())
+      .await
+      .ok()
+      .ok_or(LemmyErrorType::NotFound)?;
+    let page = String::from_utf8_lossy(&page);
+    assert!(page.contains("<strong>app</strong>"));
+    assert!(page.contains("read write follow"));
+
+    let form = |csrf_token: &str, redirect_uri: &str| {
+      Form(AuthorizeForm {
+        username: "oauth".to_string(),
+        password: "password123".to_string(),
+        totp_2fa_token: None,
+        client_id: app.client_id.clone(),
+        redirect_uri: redirect_uri.to_string(),
+        state: Some("state".to_string()),
+        csrf_token: csrf_token.to_string(),
+      })
+    };
+
+    // Submitting without the csrf cookie fails
+    let req = TestRequest::default().to_http_request();
+    assert!(
+      authorize(form(cookie.value(), REDIRECT_URI), req, context.clone())
+        .await
+        .is_err()
+    );
+
+    // Submitting with a different redirect uri fails
+    let req = TestRequest::default()
+      .cookie(cookie.clone())
+      .to_http_request();
+    let res = authorize(
+      form(cookie.value(), "https://attacker.example.com"),
+      req,
+      context.clone(),
+    )
+    .await;
+    assert!(res.is_err());
+
+    // Successful login redirects with a code, which is not a login token
+    let req = TestRequest::default()
+      .cookie(cookie.clone())
+      .to_http_request();
+    let res = authorize(form(cookie.value(), REDIRECT_URI), req, context.clone()).await?;
+    let location = res
+      .headers()
+      .get(LOCATION)
+      .and_then(|l| l.to_str().ok())
+      .ok_or(LemmyErrorType::NotFound)?;
+    let location = Url::parse(location)?;
+    assert!(location.as_str().starts_with(REDIRECT_URI));
+    let params: Vec<_> = location.query_pairs().into_owned().collect();
+    let code = params
+      .iter()
+      .find(|(k, _)| k == "code")
+      .map(|(_, v)| v.clone())
+      .ok_or(LemmyErrorType::NotFound)?;
+    assert!(params.contains(&("state".to_string(), "state".to_string())));
+    assert!(Claims::validate(&code, &context).await.is_err());
+
+    // The code can't be exchanged with a wrong secret
+    let wrong_secret = Application {
+      client_secret: "wrong".to_string(),
+      ..app.clone()
+    };
+    let req = TestRequest::default().to_http_request();
+    assert!(
+      create_token(token_form(&wrong_secret, &code), req, context.clone())
+        .await
+        .is_err()
+    );
+
+    // Exchange the code for a new login token
+    let req = TestRequest::default().to_http_request();
+    let token = create_token(token_form(&app, &code), req, context.clone())
+      .await?
+      .0;
+    assert_eq!(
+      local_user.id,
+      Claims::validate(&token.access_token, &context).await?
+    );
+
+    // The code can only be used once
+    let req = TestRequest::default().to_http_request();
+    assert!(
+      create_token(token_form(&app, &code), req, context.clone())
+        .await
+        .is_err()
+    );
+
+    // Revoking the token requires the client credentials
+    let revoke = |client_secret: &str| {
+      Either::Left(Json(RevokeToken {
+        client_id: app.client_id.clone(),
+        client_secret: client_secret.to_string(),
+        token: token.access_token.clone(),
+      }))
+    };
+    assert!(
+      revoke_token(revoke("wrong"), context.clone())
+        .await
+        .is_err()
+    );
+    assert!(
+      Claims::validate(&token.access_token, &context)
+        .await
+        .is_ok()
+    );
+    revoke_token(revoke(&app.client_secret), context.clone()).await?;
+    assert!(
+      Claims::validate(&token.access_token, &context)
+        .await
+        .is_err()
+    );
+
+    Person::delete(pool, person.id).await?;
+    data.delete(pool).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,97 @@
+use crate::handlers::{
+  account_statuses,
+  authorize,
+  authorize_form,
+  bookmark,
+  clear_notifications,
+  create_application,
+  create_status,
+  create_token,
+  delete_status,
+  dismiss_notification,
+  empty_list,
+  favourite,
+  follow,
+  get_account,
+  get_instance,
+  get_lists,
+  get_status,
+  home_timeline,
+  list_timeline,
+  lookup_account,
+  notifications,
+  public_timeline,
+  relationships,
+  revoke_token,
+  status_context,
+  unbookmark,
+  unfavourite,
+  unfollow,
+  verify_credentials,
+};
+use actix_web::{guard, web::*};
+use lemmy_utils::rate_limit::RateLimit;
+
+mod convert;
+mod handlers;
+mod types;
+
+pub fn config(cfg: &mut ServiceConfig, rate_limit: &RateLimit) {
+  cfg
+    .service(
+      scope("/api/v1")
+        .wrap(rate_limit.message())
+        .route("/instance", get().to(get_instance))
+        .route("/apps", post().to(create_application))
+        .route("/custom_emojis", get().to(empty_list))
+        .route("/announcements", get().to(empty_list))
+        .route("/filters", get().to(empty_list))
+        .service(
+          scope("/accounts")
+            .route("/verify_credentials", get().to(verify_credentials))
+            .route("/lookup", get().to(lookup_account))
+            .route("/relationships", get().to(relationships))
+            .route("/{id}", get().to(get_account))
+            .route("/{id}/statuses", get().to(account_statuses))
+            .route("/{id}/follow", post().to(follow))
+            .route("/{id}/unfollow", post().to(unfollow)),
+        )
+        .service(
+          scope("/timelines")
+            .route("/home", get().to(home_timeline))
+            .route("/public", get().to(public_timeline))
+            .route("/list/{id}", get().to(list_timeline)),
+        )
+        .route("/lists", get().to(get_lists))
+        .service(
+          resource("/statuses")
+            .guard(guard::Post())
+            .wrap(rate_limit.comment())
+            .route(post().to(create_status)),
+        )
+        .service(
+          scope("/statuses")
+            .route("/{id}", get().to(get_status))
+            .route("/{id}", delete().to(delete_status))
+            .route("/{id}/context", get().to(status_context))
+            .route("/{id}/favourite", post().to(favourite))
+            .route("/{id}/unfavourite", post().to(unfavourite))
+            .route("/{id}/bookmark", post().to(bookmark))
+            .route("/{id}/unbookmark", post().to(unbookmark)),
+        )
+        .service(
+          scope("/notifications")
+            .route("", get().to(notifications))
+            .route("/clear", post().to(clear_notifications))
+            .route("/{id}/dismiss", post().to(dismiss_notification)),
+        ),
+    )
+    .service(
+      scope("/oauth")
+        .wrap(rate_limit.register())
+        .route("/authorize", get().to(authorize_form))
+        .route("/authorize", post().to(authorize))
+        .route("/token", post().to(create_token))
+        .route("/revoke", post().to(revoke_token)),
+    );
+}
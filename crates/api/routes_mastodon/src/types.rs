@@ -0,0 +1,340 @@
+//! Entities of the Mastodon client API. Only the fields which are used by common clients are
+//! included, see https://docs.joinmastodon.org/entities/ for the full definitions.
+use chrono::{DateTime, Utc};
+use lemmy_db_schema::newtypes::{CommentId, CommunityId, PostId};
+use lemmy_db_schema_file::PersonId;
+use lemmy_diesel_utils::pagination::PaginationCursor;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{fmt::Display, str::FromStr};
+
+/// Mastodon has no equivalent to posts and comments, both are represented as statuses. The type is
+/// encoded in the id, which is an opaque string for clients.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum StatusId {
+  Post(PostId),
+  Comment(CommentId),
+}
+
+impl Display for StatusId {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      StatusId::Post(id) => write!(f, "p{}", id.0),
+      StatusId::Comment(id) => write!(f, "c{}", id.0),
+    }
+  }
+}
+
+impl FromStr for StatusId {
+  type Err = LemmyError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (prefix, id) = s.split_at_checked(1).ok_or(LemmyErrorType::NotFound)?;
+    let id = id.parse().map_err(|_| LemmyErrorType::NotFound)?;
+    match prefix {
+      "p" => Ok(StatusId::Post(PostId(id))),
+      "c" => Ok(StatusId::Comment(CommentId(id))),
+      _ => Err(LemmyErrorType::NotFound.into()),
+    }
+  }
+}
+
+/// Persons and communities are both represented as accounts, with `group: true` for communities.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum AccountId {
+  Person(PersonId),
+  Community(CommunityId),
+}
+
+impl Display for AccountId {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      AccountId::Person(id) => write!(f, "u{}", id.0),
+      AccountId::Community(id) => write!(f, "g{}", id.0),
+    }
+  }
+}
+
+impl FromStr for AccountId {
+  type Err = LemmyError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (prefix, id) = s.split_at_checked(1).ok_or(LemmyErrorType::NotFound)?;
+    let id = id.parse().map_err(|_| LemmyErrorType::NotFound)?;
+    match prefix {
+      "u" => Ok(AccountId::Person(PersonId(id))),
+      "g" => Ok(AccountId::Community(CommunityId(id))),
+      _ => Err(LemmyErrorType::NotFound.into()),
+    }
+  }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct Account {
+  pub id: String,
+  pub username: String,
+  /// The username for local accounts, `username@domain` for remote ones.
+  pub acct: String,
+  pub url: String,
+  pub display_name: String,
+  /// Profile description as HTML.
+  pub note: String,
+  pub avatar: String,
+  pub avatar_static: String,
+  pub header: String,
+  pub header_static: String,
+  pub locked: bool,
+  pub bot: bool,
+  pub group: bool,
+  pub discoverable: bool,
+  pub created_at: DateTime<Utc>,
+  pub followers_count: i32,
+  pub following_count: i32,
+  pub statuses_count: i32,
+  pub last_status_at: Option<DateTime<Utc>>,
+  pub emojis: Vec<Value>,
+  pub fields: Vec<Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct Status {
+  pub id: String,
+  pub uri: String,
+  pub url: String,
+  pub created_at: DateTime<Utc>,
+  pub edited_at: Option<DateTime<Utc>>,
+  pub account: Account,
+  /// Status text as HTML.
+  pub content: String,
+  pub visibility: &'static str,
+  pub sensitive: bool,
+  pub spoiler_text: String,
+  pub media_attachments: Vec<MediaAttachment>,
+  pub mentions: Vec<Mention>,
+  pub tags: Vec<Value>,
+  pub emojis: Vec<Value>,
+  pub reblogs_count: i32,
+  pub favourites_count: i32,
+  pub replies_count: i32,
+  pub in_reply_to_id: Option<String>,
+  pub in_reply_to_account_id: Option<String>,
+  pub reblog: Option<Value>,
+  pub poll: Option<Value>,
+  pub card: Option<PreviewCard>,
+  pub language: Option<String>,
+  pub favourited: bool,
+  pub reblogged: bool,
+  pub muted: bool,
+  pub bookmarked: bool,
+  pub pinned: bool,
+}
+
+/// The account of the logged in user, with the source of its profile for editing.
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct CredentialAccount {
+  #[serde(flatten)]
+  pub account: Account,
+  pub source: AccountSource,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct AccountSource {
+  pub note: String,
+  pub fields: Vec<Value>,
+  pub privacy: &'static str,
+  pub sensitive: bool,
+  pub language: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct Mention {
+  pub id: String,
+  pub username: String,
+  pub acct: String,
+  pub url: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct MediaAttachment {
+  pub id: String,
+  #[serde(rename = "type")]
+  pub type_: &'static str,
+  pub url: String,
+  pub preview_url: String,
+  pub remote_url: Option<String>,
+  pub description: Option<String>,
+  pub blurhash: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct PreviewCard {
+  pub url: String,
+  pub title: String,
+  pub description: String,
+  #[serde(rename = "type")]
+  pub type_: &'static str,
+  pub image: Option<String>,
+  pub author_name: String,
+  pub author_url: String,
+  pub provider_name: String,
+  pub provider_url: String,
+  pub html: String,
+  pub width: i32,
+  pub height: i32,
+  pub embed_url: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct Notification {
+  pub id: String,
+  #[serde(rename = "type")]
+  pub type_: &'static str,
+  pub created_at: DateTime<Utc>,
+  pub account: Account,
+  pub status: Option<Status>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct Context {
+  pub ancestors: Vec<Status>,
+  pub descendants: Vec<Status>,
+}
+
+/// Followed communities are listed as lists, so that clients can show a timeline per community.
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct List {
+  pub id: String,
+  pub title: String,
+  pub replies_policy: &'static str,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct Relationship {
+  pub id: String,
+  pub following: bool,
+  pub showing_reblogs: bool,
+  pub notifying: bool,
+  pub followed_by: bool,
+  pub blocking: bool,
+  pub blocked_by: bool,
+  pub muting: bool,
+  pub muting_notifications: bool,
+  pub requested: bool,
+  pub domain_blocking: bool,
+  pub endorsed: bool,
+  pub note: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct Instance {
+  pub uri: String,
+  pub title: String,
+  pub short_description: String,
+  pub description: String,
+  pub email: String,
+  /// Clients parse the version for feature detection, so a compatible Mastodon version is given.
+  pub version: String,
+  pub urls: Value,
+  pub stats: InstanceStats,
+  pub thumbnail: Option<String>,
+  pub languages: Vec<String>,
+  pub registrations: bool,
+  pub approval_required: bool,
+  pub invites_enabled: bool,
+  pub configuration: Value,
+  pub contact_account: Option<Account>,
+  pub rules: Vec<Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct InstanceStats {
+  pub user_count: i32,
+  pub status_count: i32,
+  pub domain_count: i32,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct Application {
+  pub id: String,
+  pub name: String,
+  pub website: Option<String>,
+  pub redirect_uri: String,
+  pub scopes: Vec<String>,
+  pub client_id: String,
+  pub client_secret: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct Token {
+  pub access_token: String,
+  pub token_type: &'static str,
+  pub scope: String,
+  pub created_at: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct CreateApplication {
+  pub client_name: String,
+  pub redirect_uris: String,
+  pub scopes: Option<String>,
+  pub website: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct AuthorizeQuery {
+  pub client_id: String,
+  pub redirect_uri: String,
+  pub scope: Option<String>,
+  pub state: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct AuthorizeForm {
+  pub username: String,
+  pub password: String,
+  pub totp_2fa_token: Option<String>,
+  pub client_id: String,
+  pub redirect_uri: String,
+  pub state: Option<String>,
+  pub csrf_token: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct CreateToken {
+  pub grant_type: String,
+  pub client_id: String,
+  pub client_secret: String,
+  pub redirect_uri: Option<String>,
+  pub code: Option<String>,
+  pub username: Option<String>,
+  pub password: Option<String>,
+  pub scope: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct CreateStatus {
+  pub status: String,
+  pub in_reply_to_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub(crate) struct TimelineQuery {
+  pub local: Option<bool>,
+  pub limit: Option<i64>,
+  /// Lemmy can't paginate by status id, so the `Link` header of responses contains a cursor
+  /// instead of `max_id`.
+  pub page_cursor: Option<PaginationCursor>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct RevokeToken {
+  pub client_id: String,
+  pub client_secret: String,
+  pub token: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct LookupQuery {
+  pub acct: String,
+}
@@ -5,6 +5,7 @@ use crate::protocol::{
     collection_add::CollectionAdd,
     collection_remove::CollectionRemove,
     lock::{LockPageOrNote, UndoLockPageOrNote},
+    modmail::CreateModmail,
     report::Report,
     resolve_report::ResolveReport,
     update::Update,
@@ -17,6 +18,7 @@ use crate::protocol::{
     reject::RejectFollow,
     undo_follow::UndoFollow,
   },
+  relay::{accept::AcceptRelayFollow, announce::RelayAnnounce, reject::RejectRelayFollow},
   voting::{undo_vote::UndoVote, vote::Vote},
 };
 use activitypub_federation::{config::Data, traits::Activity};
@@ -40,11 +42,16 @@ use url::Url;
 #[enum_delegate::implement(Activity)]
 pub enum SharedInboxActivities {
   Follow(Follow),
+  /// Relay activities need to come before the regular ones, which would also parse them
+  AcceptRelayFollow(AcceptRelayFollow),
+  RejectRelayFollow(RejectRelayFollow),
   AcceptFollow(AcceptFollow),
   RejectFollow(RejectFollow),
   UndoFollow(UndoFollow),
   Report(Report),
   ResolveReport(ResolveReport),
+  CreateModmail(CreateModmail),
+  RelayAnnounce(RelayAnnounce),
   AnnounceActivity(AnnounceActivity),
   /// This is a catch-all and needs to be last
   RawAnnouncableActivities(RawAnnouncableActivities),
@@ -69,6 +76,7 @@ pub enum AnnouncableActivities {
   UndoLock(UndoLockPageOrNote),
   Report(Report),
   ResolveReport(ResolveReport),
+  CreateModmail(CreateModmail),
   // For compatibility with Pleroma/Mastodon (send only)
   Page(Page),
 }
@@ -92,6 +100,7 @@ impl InCommunity for AnnouncableActivities {
       UndoLock(a) => a.object.community(context).await,
       Report(a) => a.community(context).await,
       ResolveReport(a) => a.object.community(context).await,
+      CreateModmail(a) => a.community(context).await,
       Page(_) => Err(LemmyErrorType::NotFound.into()),
     }
   }
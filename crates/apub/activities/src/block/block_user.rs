@@ -13,6 +13,7 @@ use activitypub_federation::{
   kinds::activity::BlockType,
   traits::{Activity, Actor, Object},
 };
+use anyhow::anyhow;
 use chrono::{DateTime, Utc};
 use lemmy_api_utils::{
   context::LemmyContext,
@@ -20,7 +21,7 @@ use lemmy_api_utils::{
   utils::{remove_or_restore_user_data, remove_or_restore_user_data_in_community},
 };
 use lemmy_apub_objects::{
-  objects::person::ApubPerson,
+  objects::{community::ApubCommunity, person::ApubPerson},
   utils::functions::{
     verify_is_public,
     verify_mod_action,
@@ -31,7 +32,7 @@ use lemmy_apub_objects::{
 use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
-    community::{CommunityActions, CommunityPersonBanForm},
+    community::{CommunityActions, CommunityPersonBanForm, CommunityPersonMuteForm},
     instance::{InstanceActions, InstanceBanForm},
     modlog::{Modlog, ModlogInsertForm},
   },
@@ -59,6 +60,7 @@ impl BlockUser {
       target: target.id().clone().into(),
       kind: BlockType::Block,
       remove_data,
+      mute: None,
       summary: Some(reason),
       id: generate_activity_id(BlockType::Block, context)?,
       end_time: expires,
@@ -98,6 +100,24 @@ impl BlockUser {
       }
     }
   }
+
+  /// Same as a community ban, but with the `mute` flag set and without removing data.
+  pub async fn send_mute(
+    community: &ApubCommunity,
+    user: &ApubPerson,
+    mod_: &ApubPerson,
+    reason: String,
+    expires: Option<DateTime<Utc>>,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let target = SiteOrCommunity::Right(community.clone());
+    let mut block = BlockUser::new(&target, user, mod_, None, reason, expires, context).await?;
+    block.mute = Some(true);
+
+    let activity = AnnouncableActivities::BlockUser(block);
+    let inboxes = ActivitySendTargets::to_inbox(user.shared_inbox_or_inbox());
+    send_activity_in_community(activity, mod_, community, inboxes, true, context).await
+  }
 }
 
 #[async_trait::async_trait]
@@ -117,6 +137,9 @@ impl Activity for BlockUser {
     match self.target.dereference(context).await? {
       SiteOrCommunity::Left(_site) => {
         verify_is_public(&self.to, &self.cc)?;
+        if self.mute.unwrap_or(false) {
+          Err(anyhow!("Users can only be muted in communities"))?
+        }
       }
       SiteOrCommunity::Right(community) => {
         verify_visibility(&self.to, &self.cc, &community)?;
@@ -158,6 +181,24 @@ impl Activity for BlockUser {
         let action = Modlog::create(&mut context.pool(), &[form]).await?;
         notify_mod_action(action.clone(), context);
       }
+      SiteOrCommunity::Right(community) if self.mute.unwrap_or(false) => {
+        let form = CommunityPersonMuteForm {
+          mute_expires_at: Some(expires_at),
+          ..CommunityPersonMuteForm::new(community.id, blocked_person.id)
+        };
+        CommunityActions::mute(&mut context.pool(), &form).await?;
+
+        let form = ModlogInsertForm::mod_mute_from_community(
+          mod_person.id,
+          community.id,
+          blocked_person.id,
+          true,
+          expires_at,
+          &reason,
+        );
+        let action = Modlog::create(&mut context.pool(), &[form]).await?;
+        notify_mod_action(action, context);
+      }
       SiteOrCommunity::Right(community) => {
         let community_user_ban_form = CommunityPersonBanForm {
           ban_expires_at: Some(expires_at),
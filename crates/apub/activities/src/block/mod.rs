@@ -10,7 +10,7 @@ use lemmy_db_schema::{
   newtypes::CommunityId,
   source::{comment::Comment, community::Community, person::Person, post::Post, site::Site},
 };
-use lemmy_db_views_community::api::BanFromCommunity;
+use lemmy_db_views_community::api::{BanFromCommunity, MuteFromCommunity};
 use lemmy_diesel_utils::{connection::DbPool, traits::Crud};
 use lemmy_utils::error::LemmyResult;
 use url::Url;
@@ -60,6 +60,7 @@ pub(crate) async fn send_ban_from_site(
       &banned_user.into(),
       &moderator.into(),
       remove_or_restore_data.unwrap_or(false),
+      false,
       reason.clone(),
       &context,
     )
@@ -96,6 +97,7 @@ pub(crate) async fn send_ban_from_community(
       &banned_person.into(),
       &mod_.into(),
       data.remove_or_restore_data.unwrap_or(false),
+      false,
       data.reason.clone(),
       &context,
     )
@@ -103,6 +105,42 @@ pub(crate) async fn send_ban_from_community(
   }
 }
 
+pub(crate) async fn send_mute_from_community(
+  mod_: Person,
+  community_id: CommunityId,
+  muted_person: Person,
+  data: MuteFromCommunity,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let community: ApubCommunity = Community::read(&mut context.pool(), community_id)
+    .await?
+    .into();
+  let expires_at = check_expire_time(data.expires_at)?;
+
+  if data.mute {
+    BlockUser::send_mute(
+      &community,
+      &muted_person.into(),
+      &mod_.into(),
+      data.reason,
+      expires_at,
+      &context,
+    )
+    .await
+  } else {
+    UndoBlockUser::send(
+      &SiteOrCommunity::Right(community),
+      &muted_person.into(),
+      &mod_.into(),
+      false,
+      true,
+      data.reason,
+      &context,
+    )
+    .await
+  }
+}
+
 fn to(target: &SiteOrCommunity) -> LemmyResult<Vec<Url>> {
   Ok(if let SiteOrCommunity::Right(c) = target {
     generate_to(c)?
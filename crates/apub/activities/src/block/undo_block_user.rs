@@ -26,7 +26,7 @@ use lemmy_apub_objects::{
 use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
-    community::{CommunityActions, CommunityPersonBanForm},
+    community::{CommunityActions, CommunityPersonBanForm, CommunityPersonMuteForm},
     instance::{InstanceActions, InstanceBanForm},
     modlog::{Modlog, ModlogInsertForm},
   },
@@ -41,10 +41,12 @@ impl UndoBlockUser {
     user: &ApubPerson,
     mod_: &ApubPerson,
     restore_data: bool,
+    mute: bool,
     reason: String,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
-    let block = BlockUser::new(target, user, mod_, None, reason, None, context).await?;
+    let mut block = BlockUser::new(target, user, mod_, None, reason, None, context).await?;
+    block.mute = mute.then_some(true);
     let to = to(target)?;
 
     let id = generate_activity_id(UndoType::Undo, context)?;
@@ -123,6 +125,22 @@ impl Activity for UndoBlockUser {
         let action = Modlog::create(&mut context.pool(), &[form]).await?;
         notify_mod_action(action.clone(), context.app_data());
       }
+      SiteOrCommunity::Right(community) if self.object.mute.unwrap_or(false) => {
+        verify_visibility(&self.to, &self.cc, &community)?;
+        let form = CommunityPersonMuteForm::new(community.id, blocked_person.id);
+        CommunityActions::unmute(&mut context.pool(), &form).await?;
+
+        let form = ModlogInsertForm::mod_mute_from_community(
+          mod_person.id,
+          community.id,
+          blocked_person.id,
+          false,
+          expires_at,
+          &reason,
+        );
+        let action = Modlog::create(&mut context.pool(), &[form]).await?;
+        notify_mod_action(action, context.app_data());
+      }
       SiteOrCommunity::Right(community) => {
         verify_visibility(&self.to, &self.cc, &community)?;
         let community_user_ban_form = CommunityPersonBanForm::new(community.id, blocked_person.id);
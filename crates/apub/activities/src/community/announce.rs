@@ -5,6 +5,7 @@ use crate::{
   protocol::{
     IdOrNestedObject,
     community::announce::{AnnounceActivity, RawAnnouncableActivities},
+    create_or_update::note::CreateOrUpdateNote,
   },
   send_lemmy_activity,
 };
@@ -23,7 +24,7 @@ use lemmy_apub_objects::{
 };
 use lemmy_db_schema::source::{activity::ActivitySendTargets, community::CommunityActions};
 use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult, UntranslatedError};
-use serde_json::Value;
+use serde_json::{Value, from_value, to_value};
 use url::Url;
 
 #[async_trait::async_trait]
@@ -106,6 +107,51 @@ impl AnnounceActivity {
     })
   }
 
+  /// Announce which is only addressed to the given actors instead of the public and the community
+  /// followers, for activities which must stay private.
+  pub fn new_private(
+    object: RawAnnouncableActivities,
+    community: &ApubCommunity,
+    to: Vec<Url>,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<AnnounceActivity> {
+    Ok(AnnounceActivity {
+      to,
+      cc: vec![],
+      ..AnnounceActivity::new(object, community, context)?
+    })
+  }
+
+  /// Import the post or comment from an announce in a community outbox. Unlike regular receiving,
+  /// this doesn't require a local follower of the community and doesn't generate notifications,
+  /// as the content is usually old. Only content of the given community is imported, so that an
+  /// outbox can't inject content into other communities.
+  pub async fn import(
+    self,
+    community: &ApubCommunity,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    if self.actor.inner() != community.ap_id.inner() {
+      Err(UntranslatedError::InvalidCommunity)?
+    }
+    let inner: AnnouncableActivities = self.object.object(context).await?.try_into()?;
+    if inner.community(context).await?.id != community.id {
+      Err(UntranslatedError::InvalidCommunity)?
+    }
+    match inner {
+      AnnouncableActivities::CreateOrUpdatePost(create) => {
+        create.verify(context).await?;
+        create.import(context).await
+      }
+      AnnouncableActivities::CreateOrUpdateNoteWrapper(wrapper) => {
+        let create: CreateOrUpdateNote = from_value(to_value(wrapper)?)?;
+        create.verify(context).await?;
+        create.import(context).await
+      }
+      _ => Ok(()),
+    }
+  }
+
   pub async fn send(
     object: RawAnnouncableActivities,
     community: &ApubCommunity,
@@ -162,7 +208,10 @@ impl Activity for AnnounceActivity {
     }
 
     let community = object.community(context).await?;
-    verify_visibility(&self.to, &self.cc, &community)?;
+    // Mod mail is only addressed to the mods and the thread creator
+    if !matches!(object, AnnouncableActivities::CreateModmail(_)) {
+      verify_visibility(&self.to, &self.cc, &community)?;
+    }
     can_accept_activity_in_community(&Some(community), context).await?;
 
     // verify here in order to avoid fetching the object twice over http
@@ -211,3 +260,92 @@ async fn can_accept_activity_in_community(
   }
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_apub_objects::utils::test::{
+    file_to_json_object,
+    parse_lemmy_community,
+    parse_lemmy_person,
+  };
+  use lemmy_db_schema::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+    post::Post,
+  };
+  use lemmy_diesel_utils::{dburl::DbUrl, traits::Crud};
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_import_outbox_item() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let (person, _) = parse_lemmy_person(&context).await?;
+    let community = parse_lemmy_community(&context).await?;
+    let form = CommunityInsertForm::new(
+      person.instance_id,
+      "other_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let other_community: ApubCommunity =
+      Community::create(&mut context.pool(), &form).await?.into();
+
+    // Post in the community which is being backfilled
+    let page: Value = file_to_json_object("../apub/assets/lemmy/objects/page.json")?;
+    let create = json!({
+      "id": "https://enterprise.lemmy.ml/activities/create/1",
+      "actor": "https://enterprise.lemmy.ml/u/picard",
+      "to": ["https://www.w3.org/ns/activitystreams#Public"],
+      "cc": [community.ap_id],
+      "type": "Create",
+      "object": page,
+    });
+    let announce = |actor: &DbUrl| -> LemmyResult<AnnounceActivity> {
+      Ok(from_value(json!({
+        "id": "https://enterprise.lemmy.ml/activities/announce/1",
+        "actor": actor,
+        "to": ["https://www.w3.org/ns/activitystreams#Public"],
+        "cc": [],
+        "type": "Announce",
+        "object": create,
+      }))?)
+    };
+    let invalid = |res: LemmyResult<()>| {
+      res.is_err_and(|e| {
+        e.error_type == LemmyErrorType::UntranslatedError(Some(UntranslatedError::InvalidCommunity))
+      })
+    };
+
+    // The announce must come from the community which is being backfilled
+    let res = announce(&community.ap_id)?
+      .import(&other_community, &context)
+      .await;
+    assert!(invalid(res));
+
+    // The announced content must also belong to that community
+    let res = announce(&other_community.ap_id)?
+      .import(&other_community, &context)
+      .await;
+    assert!(invalid(res));
+    let post_id = DbUrl::from(Url::parse("https://enterprise.lemmy.ml/post/55143")?);
+    assert!(
+      Post::read_from_apub_id(&mut context.pool(), post_id.clone())
+        .await?
+        .is_none()
+    );
+
+    announce(&community.ap_id)?
+      .import(&community, &context)
+      .await?;
+    let post = Post::read_from_apub_id(&mut context.pool(), post_id).await?;
+    assert!(post.is_some_and(|p| p.community_id == community.id));
+    assert_eq!(0, context.request_count());
+
+    Instance::delete_all(&mut context.pool()).await?;
+    Ok(())
+  }
+}
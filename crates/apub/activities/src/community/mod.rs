@@ -30,6 +30,7 @@ pub mod announce;
 pub mod collection_add;
 pub mod collection_remove;
 pub mod lock;
+pub mod modmail;
 pub mod report;
 pub mod resolve_report;
 pub mod update;
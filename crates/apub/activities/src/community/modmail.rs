@@ -0,0 +1,205 @@
+use crate::{
+  activity_lists::AnnouncableActivities,
+  generate_activity_id,
+  protocol::community::{
+    announce::AnnounceActivity,
+    modmail::{CreateModmail, Modmail, ModmailType},
+  },
+  send_lemmy_activity,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::activity::CreateType,
+  protocol::{values::MediaTypeMarkdown, verification::verify_domains_match},
+  traits::{Activity, Actor, Object},
+};
+use lemmy_api_utils::{
+  context::LemmyContext,
+  utils::{get_url_blocklist, process_markdown, slur_regex},
+};
+use lemmy_apub_objects::{
+  objects::{community::ApubCommunity, person::ApubPerson},
+  utils::{
+    functions::verify_mod_action,
+    markdown_links::markdown_rewrite_remote_links,
+    protocol::InCommunity,
+  },
+};
+use lemmy_db_schema::source::{
+  activity::ActivitySendTargets,
+  community::Community,
+  instance::InstanceActions,
+  modmail::{ModmailMessage, ModmailMessageInsertForm, ModmailThread, ModmailThreadInsertForm},
+  person::Person,
+};
+use lemmy_db_views_community_moderator::CommunityModeratorView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::truncate_modmail_subject},
+};
+use url::Url;
+
+impl CreateModmail {
+  pub(crate) async fn send(
+    message: ModmailMessage,
+    thread: ModmailThread,
+    community: Community,
+    actor: Person,
+    context: Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let actor: ApubPerson = actor.into();
+    let community: ApubCommunity = community.into();
+    let create = CreateModmail {
+      actor: actor.id().clone().into(),
+      to: [community.id().clone().into()],
+      object: Modmail {
+        kind: ModmailType::Modmail,
+        id: message.ap_id.into(),
+        attributed_to: actor.id().clone().into(),
+        context: thread.ap_id.clone().into(),
+        name: thread.subject.clone(),
+        content: message.content,
+        media_type: Some(MediaTypeMarkdown::Markdown),
+        from_mod: message.from_mod,
+        published: Some(message.published_at),
+      },
+      kind: CreateType::Create,
+      id: generate_activity_id(CreateType::Create, &context)?,
+    };
+    let inboxes = modmail_inboxes(&thread, &community, &context).await?;
+    send_lemmy_activity(&context, create, &actor, inboxes, true).await
+  }
+}
+
+/// Mod mail is sent to the community, which forwards it to remote mods and to the thread creator.
+/// If the community is local, it is sent to them directly.
+async fn modmail_inboxes(
+  thread: &ModmailThread,
+  community: &ApubCommunity,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<ActivitySendTargets> {
+  if !community.local {
+    return Ok(ActivitySendTargets::to_inbox(
+      community.shared_inbox_or_inbox(),
+    ));
+  }
+
+  let mut inboxes = ActivitySendTargets::empty();
+  for person in modmail_recipients(thread, community, context).await? {
+    inboxes.add_inbox(person.inbox_url.into());
+  }
+  Ok(inboxes)
+}
+
+/// The mods of the community and the creator of the thread.
+async fn modmail_recipients(
+  thread: &ModmailThread,
+  community: &ApubCommunity,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<Vec<Person>> {
+  let moderators = CommunityModeratorView::for_community(&mut context.pool(), community.id).await?;
+  let mut recipients: Vec<_> = moderators.into_iter().map(|m| m.moderator).collect();
+  recipients.push(Person::read(&mut context.pool(), thread.creator_id).await?);
+  Ok(recipients)
+}
+
+#[async_trait::async_trait]
+impl Activity for CreateModmail {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    verify_domains_match(self.actor.inner(), &self.object.id)?;
+    verify_domains_match(self.actor.inner(), self.object.attributed_to.inner())?;
+    let slur_regex = slur_regex(context).await?;
+    check_slurs(&self.object.name, &slur_regex)?;
+
+    // Users who are banned from the community can still write to the mods, so only check for
+    // site bans here.
+    let actor = self.actor.dereference(context).await?;
+    InstanceActions::check_ban(&mut context.pool(), actor.id, actor.instance_id).await?;
+
+    if self.object.from_mod {
+      let community = self.community(context).await?;
+      verify_mod_action(&self.actor, &community, context).await?;
+    }
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    let actor = self.actor.dereference(context).await?;
+    let community = self.community(context).await?;
+
+    let thread_id = self.object.context.clone().into();
+    let thread = match ModmailThread::read_from_ap_id(&mut context.pool(), &thread_id).await? {
+      Some(thread) => thread,
+      // Only users can open new threads, and the thread belongs to their instance
+      None if !self.object.from_mod => {
+        verify_domains_match(self.actor.inner(), &self.object.context)?;
+        let subject = truncate_modmail_subject(self.object.name.trim());
+        let form = ModmailThreadInsertForm {
+          ap_id: Some(thread_id),
+          local: Some(false),
+          published_at: self.object.published,
+          ..ModmailThreadInsertForm::new(community.id, actor.id, subject)
+        };
+        ModmailThread::create(&mut context.pool(), &form).await?
+      }
+      None => Err(LemmyErrorType::NotFound)?,
+    };
+
+    // Besides the mods, only the thread creator can write in the thread
+    if thread.community_id != community.id
+      || (!self.object.from_mod && thread.creator_id != actor.id)
+    {
+      Err(LemmyErrorType::NotFound)?
+    }
+
+    let slur_regex = slur_regex(context).await?;
+    let url_blocklist = get_url_blocklist(context).await?;
+    let content =
+      process_markdown(&self.object.content, &slur_regex, &url_blocklist, context).await?;
+    let content = markdown_rewrite_remote_links(content, context).await;
+
+    let form = ModmailMessageInsertForm {
+      ap_id: Some(self.object.id.clone().into()),
+      local: Some(false),
+      published_at: self.object.published,
+      ..ModmailMessageInsertForm::new(thread.id, actor.id, content, self.object.from_mod)
+    };
+    let Some(message) = ModmailMessage::insert_apub(&mut context.pool(), &form).await? else {
+      // Message was already received before
+      return Ok(());
+    };
+    ModmailThread::add_message(
+      &mut context.pool(),
+      thread.id,
+      message.from_mod,
+      message.published_at,
+    )
+    .await?;
+
+    if community.local {
+      // forward to remote mods and to the thread creator, without addressing it to the public
+      let inboxes = modmail_inboxes(&thread, &community, context).await?;
+      let to = modmail_recipients(&thread, &community, context)
+        .await?
+        .into_iter()
+        .map(|p| p.ap_id.into())
+        .collect();
+      let announce = AnnouncableActivities::CreateModmail(self);
+      let announce = AnnounceActivity::new_private(announce.try_into()?, &community, to, context)?;
+      send_lemmy_activity(context, announce, &community, inboxes, true).await?;
+    }
+    Ok(())
+  }
+}
@@ -1,6 +1,7 @@
 use super::{local_community, report_inboxes};
 use crate::{
   activity_lists::AnnouncableActivities,
+  check_federation_policy,
   generate_activity_id,
   protocol::community::{
     announce::AnnounceActivity,
@@ -9,10 +10,11 @@ use crate::{
   send_lemmy_activity,
 };
 use activitypub_federation::{
+  activity_sending::ActivitySendTargets,
   config::Data,
   fetch::object_id::ObjectId,
   kinds::activity::FlagType,
-  traits::{Activity, Object},
+  traits::{Activity, Actor, Object},
 };
 use either::Either;
 use lemmy_api_utils::{
@@ -34,14 +36,19 @@ use lemmy_apub_objects::{
   utils::functions::verify_person_in_site_or_community,
 };
 use lemmy_db_schema::{
+  newtypes::CommunityId,
   source::{
     comment_report::{CommentReport, CommentReportForm},
     community_report::{CommunityReport, CommunityReportForm},
+    person_report::{PersonReport, PersonReportForm},
+    post::Post,
     post_report::{PostReport, PostReportForm},
+    rule::Rule,
   },
   traits::Reportable,
 };
-use lemmy_utils::error::{LemmyError, LemmyResult};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
 use url::Url;
 
 impl Report {
@@ -50,6 +57,7 @@ impl Report {
     actor: &ApubPerson,
     receiver: &Either<ApubSite, ApubCommunity>,
     reason: Option<String>,
+    rule: Option<Url>,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<Self> {
     let kind = FlagType::Flag;
@@ -60,6 +68,7 @@ impl Report {
       object: ReportObject::Lemmy(object_id.clone()),
       summary: reason,
       content: None,
+      rule,
       kind,
       id: id.clone(),
       audience: receiver.as_ref().right().map(|c| c.ap_id.clone().into()),
@@ -71,13 +80,81 @@ impl Report {
     actor: &ApubPerson,
     receiver: &Either<ApubSite, ApubCommunity>,
     reason: String,
+    rule: Option<Url>,
     context: Data<LemmyContext>,
   ) -> LemmyResult<()> {
-    let report = Self::new(&object_id, actor, receiver, Some(reason), &context)?;
+    let report = Self::new(&object_id, actor, receiver, Some(reason), rule, &context)?;
     let inboxes = report_inboxes(object_id, receiver, actor, &context).await?;
 
     send_lemmy_activity(&context, report, actor, inboxes, false).await
   }
+
+  /// Send a report directly to the admins of a remote instance. The object uses the Mastodon
+  /// format, so that it is also understood by other platforms. The actor is the system account
+  /// rather than the reporter.
+  pub(crate) async fn send_to_site(
+    object_ids: Vec<Url>,
+    actor: &ApubPerson,
+    site: &ApubSite,
+    reason: String,
+    context: Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let kind = FlagType::Flag;
+    let id = generate_activity_id(kind.clone(), &context)?;
+    let report = Report {
+      actor: actor.id().clone().into(),
+      to: [site.id().clone().into()],
+      object: ReportObject::Mastodon(object_ids),
+      summary: Some(reason.clone()),
+      content: Some(reason),
+      rule: None,
+      kind,
+      id,
+      audience: None,
+    };
+    let inboxes = ActivitySendTargets::to_inbox(site.shared_inbox_or_inbox());
+
+    send_lemmy_activity(&context, report, actor, inboxes, false).await
+  }
+}
+
+impl Report {
+  /// Read the rule referenced by the report. Unknown rules or rules of another community are
+  /// ignored, as the report is still valid without them.
+  async fn read_rule(
+    &self,
+    community_id: CommunityId,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<Option<Rule>> {
+    let Some(rule) = &self.rule else {
+      return Ok(None);
+    };
+    let rule = Rule::read_from_ap_id(&mut context.pool(), &rule.clone().into()).await?;
+    Ok(rule.filter(|r| r.community_id.is_none_or(|c| c == community_id)))
+  }
+
+  /// Reports which only reference a user and no content are stored as person report. These are
+  /// only accepted when sent to the site.
+  async fn receive_person_report(
+    &self,
+    actor: &ApubPerson,
+    reason: String,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let person = self.object.dereference_person(context).await?;
+    let report_form = PersonReportForm {
+      creator_id: actor.id,
+      person_id: person.id,
+      original_person_name: person.name.clone(),
+      original_person_display_name: person.display_name.clone(),
+      original_person_bio: person.bio.clone(),
+      original_person_avatar: person.avatar.clone(),
+      original_person_banner: person.banner.clone(),
+      reason,
+    };
+    PersonReport::report(&mut context.pool(), &report_form).await?;
+    Ok(())
+  }
 }
 
 #[async_trait::async_trait]
@@ -94,17 +171,30 @@ impl Activity for Report {
   }
 
   async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
-    let receiver = self.to[0].dereference(context).await?;
+    let receiver = self.receiver(context).await?;
     verify_person_in_site_or_community(&self.actor, &receiver, context).await?;
+    check_federation_policy(&self.actor, |p| p.reject_reports, context).await?;
     Ok(())
   }
 
   async fn receive(self, context: &Data<Self::DataType>) -> LemmyResult<()> {
     let actor = self.actor.dereference(context).await?;
     let reason = self.reason()?;
-    match self.object.dereference(context).await? {
+    let receiver = self.receiver(context).await?;
+    // Reports sent to the site are meant for the admins
+    let to_site = receiver.is_left();
+    let object = match self.object.dereference(context).await {
+      Ok(object) => object,
+      // The reported content may be unknown, eg if it was deleted in the meantime
+      Err(e) if to_site && e.error_type == LemmyErrorType::NotFound => {
+        return self.receive_person_report(&actor, reason, context).await;
+      }
+      Err(e) => return Err(e),
+    };
+    match object {
       ReportableObjects::Left(PostOrComment::Left(post)) => {
         check_post_deleted_or_removed(&post)?;
+        let rule = self.read_rule(post.community_id, context).await?;
 
         let report_form = PostReportForm {
           creator_id: actor.id,
@@ -113,19 +203,25 @@ impl Activity for Report {
           original_post_url: post.url.clone(),
           reason,
           original_post_body: post.body.clone(),
-          violates_instance_rules: false,
+          violates_instance_rules: to_site
+            || rule.as_ref().is_some_and(|r| r.community_id.is_none()),
+          rule_id: rule.map(|r| r.id),
         };
         PostReport::report(&mut context.pool(), &report_form).await?;
       }
       ReportableObjects::Left(PostOrComment::Right(comment)) => {
         check_comment_deleted_or_removed(&comment)?;
+        let post = Post::read(&mut context.pool(), comment.post_id).await?;
+        let rule = self.read_rule(post.community_id, context).await?;
 
         let report_form = CommentReportForm {
           creator_id: actor.id,
           comment_id: comment.id,
           original_comment_text: comment.content.clone(),
           reason,
-          violates_instance_rules: false,
+          violates_instance_rules: to_site
+            || rule.as_ref().is_some_and(|r| r.community_id.is_none()),
+          rule_id: rule.map(|r| r.id),
         };
         CommentReport::report(&mut context.pool(), &report_form).await?;
       }
@@ -146,7 +242,6 @@ impl Activity for Report {
       }
     };
 
-    let receiver = self.to[0].dereference(context).await?;
     if let Some(community) = local_community(&receiver) {
       // forward to remote mods
       let object_id = self.object.object_id(context).await?;
@@ -159,3 +254,89 @@ impl Activity for Report {
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use diesel::{ExpressionMethods, QueryDsl};
+  use diesel_async::RunQueryDsl;
+  use lemmy_db_schema::{
+    source::{
+      federation_policy::{FederationPolicy, FederationPolicyForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    test_data::TestData,
+  };
+  use lemmy_db_schema_file::{PersonId, schema::person_report};
+  use lemmy_diesel_utils::connection::get_conn;
+  use lemmy_utils::error::UntranslatedError;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_receive_mastodon_flag() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let data = TestData::create(&mut context.pool()).await?;
+
+    // Mastodon sends reports from its instance actor, which is stored as a bot account
+    let mastodon = Instance::read_or_create(&mut context.pool(), "mastodon.example").await?;
+    let actor_form = PersonInsertForm {
+      ap_id: Some(Url::parse("https://mastodon.example/actor")?.into()),
+      local: Some(false),
+      bot_account: Some(true),
+      ..PersonInsertForm::test_form(mastodon.id, "mastodon.example")
+    };
+    let actor = Person::create(&mut context.pool(), &actor_form).await?;
+
+    // The report is addressed to the reported account and doesn't reference any known content
+    let reported: Url = data.person.ap_id.clone().into();
+    let report = Report {
+      actor: ObjectId::from(actor.ap_id.inner().clone()),
+      to: [ObjectId::from(reported.clone())],
+      object: ReportObject::Mastodon(vec![
+        reported,
+        Url::parse("https://mastodon.example/posts/1")?,
+      ]),
+      summary: None,
+      content: Some("spam account".to_string()),
+      rule: None,
+      kind: FlagType::Flag,
+      id: Url::parse("https://mastodon.example/ccb4f39a")?,
+      audience: None,
+    };
+    report.verify(&context).await?;
+    report.clone().receive(&context).await?;
+    // Receiving the same report again must not fail
+    report.clone().receive(&context).await?;
+
+    let conn = &mut get_conn(&mut context.pool()).await?;
+    let reports: Vec<(PersonId, String)> = person_report::table
+      .filter(person_report::creator_id.eq(actor.id))
+      .select((person_report::person_id, person_report::reason))
+      .load(conn)
+      .await?;
+    assert_eq!(vec![(data.person.id, "spam account".to_string())], reports);
+
+    // Reports are rejected once the instance has a policy against them
+    let policy_form = FederationPolicyForm {
+      instance_id: mastodon.id,
+      silence: false,
+      strip_media: false,
+      force_nsfw: false,
+      reject_reports: true,
+      reject_votes: false,
+      no_avatar: false,
+      reason: "report spam".to_string(),
+    };
+    FederationPolicy::upsert(&mut context.pool(), &policy_form).await?;
+    let rejected = UntranslatedError::RejectedByFederationPolicy;
+    let res = report.verify(&context).await;
+    assert!(res.is_err_and(|e| e.error_type == LemmyErrorType::UntranslatedError(Some(rejected))));
+
+    Instance::delete(&mut context.pool(), mastodon.id).await?;
+    data.delete(&mut context.pool()).await?;
+    Ok(())
+  }
+}
@@ -48,7 +48,7 @@ impl ResolveReport {
   ) -> LemmyResult<()> {
     let kind = ResolveType::Resolve;
     let id = generate_activity_id(kind.clone(), &context)?;
-    let object = Report::new(&object_id, report_creator, receiver, None, &context)?;
+    let object = Report::new(&object_id, report_creator, receiver, None, None, &context)?;
     let resolve = ResolveReport {
       actor: actor.id().clone().into(),
       to: [receiver.id().clone().into()],
@@ -78,7 +78,7 @@ impl Activity for ResolveReport {
 
   async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
     self.object.verify(context).await?;
-    let receiver = self.object.to[0].dereference(context).await?;
+    let receiver = self.object.receiver(context).await?;
     verify_person_in_site_or_community(&self.actor, &receiver, context).await?;
     verify_urls_match(self.to[0].inner(), self.object.to[0].inner())?;
     verify_mod_or_admin_action(&self.actor, &receiver, context).await?;
@@ -101,7 +101,7 @@ impl Activity for ResolveReport {
       }
     };
 
-    let receiver = self.object.to[0].dereference(context).await?;
+    let receiver = self.object.receiver(context).await?;
     if let Some(community) = local_community(&receiver) {
       // forward to remote mods
       let object_id = self.object.object.object_id(context).await?;
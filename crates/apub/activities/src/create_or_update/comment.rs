@@ -18,8 +18,14 @@ use lemmy_api_utils::{
 };
 use lemmy_apub_objects::{
   objects::{comment::ApubComment, community::ApubCommunity, person::ApubPerson},
+  protocol::note::Note,
   utils::{
-    functions::{generate_to, verify_person_in_community, verify_visibility},
+    functions::{
+      generate_to,
+      verify_person_in_community,
+      verify_person_not_muted,
+      verify_visibility,
+    },
     protocol::InCommunity,
   },
 };
@@ -36,10 +42,37 @@ use lemmy_db_schema_file::PersonId;
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::error::{LemmyError, LemmyResult};
-use serde_json::{from_value, to_value};
 use url::Url;
 
 impl CreateOrUpdateNote {
+  pub async fn new(
+    comment: ApubComment,
+    actor: &ApubPerson,
+    community: &ApubCommunity,
+    kind: CreateOrUpdateType,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<CreateOrUpdateNote> {
+    let id = generate_activity_id(kind.clone(), context)?;
+    let note = comment.into_json(context).await?;
+    Ok(CreateOrUpdateNote {
+      actor: actor.id().clone().into(),
+      to: generate_to(community)?,
+      cc: note.cc.clone(),
+      tag: note.tag.clone(),
+      object: note,
+      kind,
+      id,
+      audience: Some(community.ap_id.clone().into()),
+    })
+  }
+
+  /// Store the comment without generating any notifications. Used to import historic comments
+  /// from a community outbox, after the activity was verified.
+  pub(crate) async fn import(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    insert_comment(self.object, context).await?;
+    Ok(())
+  }
+
   pub(crate) async fn send(
     comment: Comment,
     person_id: PersonId,
@@ -55,27 +88,14 @@ impl CreateOrUpdateNote {
       .await?
       .into();
 
-    let id = generate_activity_id(kind.clone(), &context)?;
-    let note = ApubComment(comment).into_json(&context).await?;
-
-    let create_or_update = CreateOrUpdateNote {
-      actor: person.id().clone().into(),
-      to: generate_to(&community)?,
-      cc: note.cc.clone(),
-      tag: note.tag.clone(),
-      object: note,
-      kind,
-      id: id.clone(),
-      audience: Some(community.ap_id.clone().into()),
-    };
+    let create_or_update =
+      CreateOrUpdateNote::new(ApubComment(comment), &person, &community, kind, &context).await?;
 
     let inboxes = tagged_user_inboxes(&create_or_update.tag, &context).await?;
 
     // AnnouncableActivities doesnt contain Comment activity but only NoteWrapper,
-    // to be able to handle both comment and private message. So to send this out we need
-    // to convert this to NoteWrapper, by serializing and then deserializing again.
-    let converted = from_value(to_value(create_or_update)?)?;
-    let activity = AnnouncableActivities::CreateOrUpdateNoteWrapper(converted);
+    // to be able to handle both comment and private message.
+    let activity = AnnouncableActivities::CreateOrUpdateNoteWrapper(create_or_update.try_into()?);
     send_activity_in_community(activity, &person, &community, inboxes, false, &context).await
   }
 }
@@ -99,6 +119,7 @@ impl Activity for CreateOrUpdateNote {
     verify_visibility(&self.to, &self.cc, &community)?;
 
     verify_person_in_community(&self.actor, &community, context).await?;
+    verify_person_not_muted(&self.actor, &community, context).await?;
     verify_domains_match(self.actor.inner(), self.object.id.inner())?;
     check_community_deleted_or_removed(&community)?;
     check_post_deleted_or_removed(&post)?;
@@ -123,14 +144,7 @@ impl Activity for CreateOrUpdateNote {
       check_is_mod_or_admin(&mut context.pool(), creator.id, post.community_id).await?;
     }
 
-    let comment = ApubComment::from_json(self.object, context).await?;
-
-    // author likes their own comment by default
-    let like_form = CommentLikeForm::new(comment.id, comment.creator_id, Some(true));
-    CommentActions::like(&mut context.pool(), &like_form).await?;
-
-    // Calculate initial hot_rank
-    Comment::update_hot_rank(&mut context.pool(), comment.id).await?;
+    let comment = insert_comment(self.object, context).await?;
 
     let do_send_email =
       self.kind == CreateOrUpdateType::Create && !site_view.local_site.disable_email_notifications;
@@ -147,3 +161,16 @@ impl Activity for CreateOrUpdateNote {
     Ok(())
   }
 }
+
+/// Store a received comment, along with the vote of its author.
+async fn insert_comment(note: Note, context: &Data<LemmyContext>) -> LemmyResult<ApubComment> {
+  let comment = ApubComment::from_json(note, context).await?;
+
+  // author likes their own comment by default
+  let like_form = CommentLikeForm::new(comment.id, comment.creator_id, Some(true));
+  CommentActions::like(&mut context.pool(), &like_form).await?;
+
+  // Calculate initial hot_rank
+  Comment::update_hot_rank(&mut context.pool(), comment.id).await?;
+  Ok(comment)
+}
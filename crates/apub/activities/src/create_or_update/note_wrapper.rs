@@ -64,3 +64,12 @@ impl InCommunity for CreateOrUpdateNoteWrapper {
     comment.community(context).await
   }
 }
+
+/// Comment activities are sent and announced as wrapper, together with private messages.
+impl TryFrom<CreateOrUpdateNote> for CreateOrUpdateNoteWrapper {
+  type Error = serde_json::Error;
+
+  fn try_from(value: CreateOrUpdateNote) -> Result<Self, Self::Error> {
+    from_value(to_value(value)?)
+  }
+}
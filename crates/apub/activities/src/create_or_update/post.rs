@@ -19,8 +19,15 @@ use lemmy_apub_objects::{
     person::ApubPerson,
     post::{ApubPost, post_nsfw, update_apub_post_tags},
   },
+  protocol::page::Page,
   utils::{
-    functions::{generate_to, verify_mod_action, verify_person_in_community, verify_visibility},
+    functions::{
+      generate_to,
+      verify_mod_action,
+      verify_person_in_community,
+      verify_person_not_muted,
+      verify_visibility,
+    },
     protocol::InCommunity,
   },
 };
@@ -58,6 +65,14 @@ impl CreateOrUpdatePage {
     })
   }
 
+  /// Store the post without generating any notifications. Used to import historic posts from a
+  /// community outbox, after the activity was verified.
+  pub(crate) async fn import(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    verify_urls_match(self.actor.inner(), self.object.creator()?.inner())?;
+    insert_post(self.object, context).await?;
+    Ok(())
+  }
+
   pub(crate) async fn send(
     post: Post,
     person_id: PersonId,
@@ -96,6 +111,7 @@ impl Activity for CreateOrUpdatePage {
     let community = self.community(context).await?;
     verify_visibility(&self.to, &self.cc, &community)?;
     verify_person_in_community(&self.actor, &community, context).await?;
+    verify_person_not_muted(&self.actor, &community, context).await?;
     check_community_deleted_or_removed(&community)?;
     verify_domains_match(self.actor.inner(), self.object.id.inner())?;
     ApubPost::verify(&self.object, self.actor.inner(), context).await?;
@@ -136,14 +152,7 @@ impl Activity for CreateOrUpdatePage {
     verify_urls_match(self.actor.inner(), self.object.creator()?.inner())?;
     let site_view = SiteView::read_local(&mut context.pool()).await?;
 
-    let post = ApubPost::from_json(self.object.clone(), context).await?;
-
-    // author likes their own post by default
-    let like_form = PostLikeForm::new(post.id, post.creator_id, Some(true));
-    PostActions::like(&mut context.pool(), &like_form).await?;
-
-    // Calculate initial hot_rank for post
-    Post::update_ranks(&mut context.pool(), post.id).await?;
+    let post = insert_post(self.object.clone(), context).await?;
 
     let do_send_email =
       self.kind == CreateOrUpdateType::Create && !site_view.local_site.disable_email_notifications;
@@ -161,3 +170,16 @@ impl Activity for CreateOrUpdatePage {
     Ok(())
   }
 }
+
+/// Store a received post, along with the vote of its author.
+pub(crate) async fn insert_post(page: Page, context: &Data<LemmyContext>) -> LemmyResult<ApubPost> {
+  let post = ApubPost::from_json(page, context).await?;
+
+  // author likes their own post by default
+  let like_form = PostLikeForm::new(post.id, post.creator_id, Some(true));
+  PostActions::like(&mut context.pool(), &like_form).await?;
+
+  // Calculate initial hot_rank for post
+  Post::update_ranks(&mut context.pool(), post.id).await?;
+  Ok(post)
+}
@@ -9,9 +9,10 @@ use activitypub_federation::{
   protocol::verification::verify_urls_match,
   traits::{Activity, Actor, Object},
 };
+use either::Either::*;
 use lemmy_api_utils::context::LemmyContext;
 use lemmy_db_schema::{
-  source::{activity::ActivitySendTargets, community::CommunityActions},
+  source::{activity::ActivitySendTargets, community::CommunityActions, person::PersonActions},
   traits::Followable,
 };
 use lemmy_utils::error::{LemmyError, LemmyResult, UntranslatedError};
@@ -57,13 +58,18 @@ impl Activity for AcceptFollow {
   }
 
   async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
-    let community = self.actor.dereference(context).await?;
+    let target = self.actor.dereference(context).await?;
     let actor = self.object.actor.dereference(context).await?;
     let person = actor.left().ok_or(UntranslatedError::Unreachable)?;
     // This will throw an error if no follow was requested
-    let community_id = community.id;
-    let person_id = person.id;
-    CommunityActions::follow_accepted(&mut context.pool(), community_id, person_id).await?;
+    match target {
+      Left(user) => {
+        PersonActions::follow_accepted(&mut context.pool(), user.id, person.id).await?;
+      }
+      Right(community) => {
+        CommunityActions::follow_accepted(&mut context.pool(), community.id, person.id).await?;
+      }
+    }
 
     Ok(())
   }
@@ -11,7 +11,7 @@ use activitypub_federation::{
 };
 use either::Either::*;
 use lemmy_api_utils::context::LemmyContext;
-use lemmy_apub_objects::objects::{CommunityOrMulti, person::ApubPerson};
+use lemmy_apub_objects::objects::{UserOrCommunityOrMulti, person::ApubPerson};
 use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
@@ -21,7 +21,7 @@ use lemmy_db_schema::{
     multi_community::{MultiCommunity, MultiCommunityFollowForm},
     person::{PersonActions, PersonFollowerForm},
   },
-  traits::Followable,
+  traits::{Blockable, Followable},
 };
 use lemmy_db_schema_file::enums::{CommunityFollowerState, CommunityVisibility};
 use lemmy_db_views_community_moderator::CommunityPersonBanView;
@@ -31,7 +31,7 @@ use url::Url;
 impl Follow {
   pub(in crate::following) fn new(
     actor: &ApubPerson,
-    target: &CommunityOrMulti,
+    target: &UserOrCommunityOrMulti,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<Follow> {
     Ok(Follow {
@@ -45,7 +45,7 @@ impl Follow {
 
   pub async fn send(
     actor: &ApubPerson,
-    target: &CommunityOrMulti,
+    target: &UserOrCommunityOrMulti,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
     let follow = Follow::new(actor, target, context)?;
@@ -104,6 +104,8 @@ impl Activity for Follow {
 
     match object {
       Left(u) => {
+        PersonActions::read_block(&mut context.pool(), u.id, person.id).await?;
+        InstanceActions::read_persons_block(&mut context.pool(), u.id, person.instance_id).await?;
         let form = PersonFollowerForm::new(u.id, person.id, false);
         PersonActions::follow(&mut context.pool(), &form).await?;
         AcceptFollow::send(self, context).await?;
@@ -9,9 +9,10 @@ use activitypub_federation::{
   protocol::verification::verify_urls_match,
   traits::{Activity, Actor, Object},
 };
+use either::Either::*;
 use lemmy_api_utils::context::LemmyContext;
 use lemmy_db_schema::{
-  source::{activity::ActivitySendTargets, community::CommunityActions},
+  source::{activity::ActivitySendTargets, community::CommunityActions, person::PersonActions},
   traits::Followable,
 };
 use lemmy_utils::error::{LemmyError, LemmyResult, UntranslatedError};
@@ -57,12 +58,19 @@ impl Activity for RejectFollow {
   }
 
   async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
-    let community = self.actor.dereference(context).await?;
+    let target = self.actor.dereference(context).await?;
     let actor = self.object.actor.dereference(context).await?;
     let person = actor.left().ok_or(UntranslatedError::Unreachable)?;
 
     // remove the follow
-    CommunityActions::unfollow(&mut context.pool(), person.id, community.id).await?;
+    match target {
+      Left(user) => {
+        PersonActions::unfollow(&mut context.pool(), person.id, user.id).await?;
+      }
+      Right(community) => {
+        CommunityActions::unfollow(&mut context.pool(), person.id, community.id).await?;
+      }
+    }
 
     Ok(())
   }
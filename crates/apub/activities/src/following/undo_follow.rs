@@ -11,7 +11,7 @@ use activitypub_federation::{
 };
 use either::Either::*;
 use lemmy_api_utils::context::LemmyContext;
-use lemmy_apub_objects::objects::{CommunityOrMulti, person::ApubPerson};
+use lemmy_apub_objects::objects::{UserOrCommunityOrMulti, person::ApubPerson};
 use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
@@ -29,7 +29,7 @@ use url::Url;
 impl UndoFollow {
   pub async fn send(
     actor: &ApubPerson,
-    target: &CommunityOrMulti,
+    target: &UserOrCommunityOrMulti,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
     let object = Follow::new(actor, target, context)?;
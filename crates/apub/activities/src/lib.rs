@@ -1,5 +1,5 @@
 use crate::{
-  block::{send_ban_from_community, send_ban_from_site},
+  block::{send_ban_from_community, send_ban_from_site, send_mute_from_community},
   community::{
     collection_add::{send_add_mod_to_community, send_feature_post},
     lock::send_lock,
@@ -15,9 +15,10 @@ use crate::{
   following::send_follow,
   protocol::{
     CreateOrUpdateType,
-    community::{report::Report, resolve_report::ResolveReport},
+    community::{modmail::CreateModmail, report::Report, resolve_report::ResolveReport},
     create_or_update::{note::CreateOrUpdateNote, page::CreateOrUpdatePage},
   },
+  relay::send_follow_relay,
   voting::send_like_activity,
 };
 use activitypub_federation::{
@@ -39,6 +40,7 @@ use lemmy_apub_objects::{
 use lemmy_db_schema::source::{
   activity::{ActivitySendTargets, SentActivity, SentActivityForm},
   community::Community,
+  federation_policy::FederationPolicy,
   instance::InstanceActions,
 };
 use lemmy_db_views_post::PostView;
@@ -57,6 +59,7 @@ pub mod create_or_update;
 pub mod deletion;
 pub mod following;
 pub mod protocol;
+pub mod relay;
 pub mod voting;
 
 const MOD_ACTION_DEFAULT_REASON: &str = "No reason provided";
@@ -72,6 +75,20 @@ async fn verify_person(
   Ok(())
 }
 
+/// Rejects the activity if the actor's instance has a federation policy matching `rejects`.
+async fn check_federation_policy(
+  person_id: &ObjectId<ApubPerson>,
+  rejects: fn(&FederationPolicy) -> bool,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let person = person_id.dereference(context).await?;
+  let policy = FederationPolicy::read_for_instance(&mut context.pool(), person.instance_id).await?;
+  if policy.as_ref().is_some_and(rejects) {
+    Err(UntranslatedError::RejectedByFederationPolicy)?
+  }
+  Ok(())
+}
+
 pub(crate) fn check_community_deleted_or_removed(community: &Community) -> LemmyResult<()> {
   if community.deleted || community.removed {
     Err(UntranslatedError::CannotCreatePostOrCommentInDeletedOrRemovedCommunity)?
@@ -150,10 +167,33 @@ pub async fn handle_outgoing_activities(context: Data<LemmyContext>) {
   }
 }
 
+/// Content of shadow restricted users is not federated at all until the restriction is lifted,
+/// this includes later edits, deletions and mod actions.
+fn is_held_for_review(data: &SendActivityData) -> bool {
+  use SendActivityData::*;
+  match data {
+    CreatePost(post)
+    | UpdatePost(post)
+    | DeletePost(post, _, _)
+    | RemovePost { post, .. }
+    | LockPost(post, _, _, _)
+    | FeaturePost(post, _, _) => post.held_for_review,
+    CreateComment(comment)
+    | UpdateComment(comment)
+    | DeleteComment(comment, _, _)
+    | RemoveComment { comment, .. }
+    | LockComment(comment, _, _, _) => comment.held_for_review,
+    _ => false,
+  }
+}
+
 pub async fn match_outgoing_activities(
   data: SendActivityData,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
+  if is_held_for_review(&data) {
+    return Ok(());
+  }
   let context = context.clone();
   Box::pin(async {
     use SendActivityData::*;
@@ -265,11 +305,27 @@ pub async fn match_outgoing_activities(
         .await
       }
       FollowCommunity(community, person, follow) => {
-        send_follow(Either::Left(community.into()), person, follow, &context).await
+        send_follow(
+          Either::Right(Either::Left(community.into())),
+          person,
+          follow,
+          &context,
+        )
+        .await
       }
       FollowMultiCommunity(multi, person, follow) => {
-        send_follow(Either::Right(multi.into()), person, follow, &context).await
+        send_follow(
+          Either::Right(Either::Right(multi.into())),
+          person,
+          follow,
+          &context,
+        )
+        .await
+      }
+      FollowPerson(target, person, follow) => {
+        send_follow(Either::Left(target.into()), person, follow, &context).await
       }
+      FollowRelay(relay, follow) => send_follow_relay(relay, follow, &context).await,
       UpdateCommunity(actor, community) => send_update_community(community, actor, context).await,
       DeleteCommunity(actor, community, removed) => {
         let deletable = DeletableObjects::Community(community.clone().into());
@@ -304,6 +360,12 @@ pub async fn match_outgoing_activities(
         target,
         data,
       } => send_ban_from_community(moderator, community_id, target, data, context).await,
+      MuteFromCommunity {
+        moderator,
+        community_id,
+        target,
+        data,
+      } => send_mute_from_community(moderator, community_id, target, data, context).await,
       BanFromSite {
         moderator,
         banned_user,
@@ -338,16 +400,24 @@ pub async fn match_outgoing_activities(
         actor,
         receiver,
         reason,
+        rule,
       } => {
         Report::send(
           ObjectId::from(object_id),
           &actor.into(),
           &receiver.map_either(Into::into, Into::into),
           reason,
+          rule,
           context,
         )
         .await
       }
+      ForwardReport {
+        object_ids,
+        actor,
+        receiver,
+        reason,
+      } => Report::send_to_site(object_ids, &actor.into(), &receiver.into(), reason, context).await,
       SendResolveReport {
         object_id,
         actor,
@@ -372,6 +442,12 @@ pub async fn match_outgoing_activities(
       UpdateMultiCommunity(multi, actor) => {
         send_update_multi_community(multi, actor, context).await
       }
+      SendModmail {
+        message,
+        thread,
+        community,
+        actor,
+      } => CreateModmail::send(message, thread, community, actor, context).await,
     }
   })
   .await?;
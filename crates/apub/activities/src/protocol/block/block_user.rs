@@ -35,6 +35,13 @@ pub struct BlockUser {
   /// Quick and dirty solution.
   /// TODO: send a separate Delete activity instead
   pub(crate) remove_data: Option<bool>,
+  /// Lemmy extension to mute the user instead of banning them. A muted user can still read and
+  /// vote in the community, but can't post or comment. Only valid for community targets.
+  ///
+  /// Older Lemmy versions and other platforms ignore this field, so for them a mute is a regular
+  /// temporary community ban. This is an acceptable downgrade because `remove_data` is never set
+  /// for mutes, and the ban ends together with the mute (or with the corresponding `Undo`).
+  pub(crate) mute: Option<bool>,
   /// block reason, written to mod log
   pub(crate) summary: Option<String>,
   pub(crate) end_time: Option<DateTime<Utc>>,
@@ -2,6 +2,7 @@ pub mod announce;
 pub mod collection_add;
 pub mod collection_remove;
 pub mod lock;
+pub mod modmail;
 pub mod report;
 pub mod resolve_report;
 pub mod update;
@@ -14,6 +15,7 @@ mod tests {
     collection_add::CollectionAdd,
     collection_remove::CollectionRemove,
     lock::{LockPageOrNote, UndoLockPageOrNote},
+    modmail::CreateModmail,
     report::Report,
     update::Update,
   };
@@ -62,6 +64,9 @@ mod tests {
     test_parse_lemmy_item::<ResolveReport>(
       "../apub/assets/lemmy/activities/community/resolve_report_page.json",
     )?;
+    test_parse_lemmy_item::<CreateModmail>(
+      "../apub/assets/lemmy/activities/community/create_modmail.json",
+    )?;
 
     Ok(())
   }
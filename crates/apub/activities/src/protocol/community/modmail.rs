@@ -0,0 +1,62 @@
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  kinds::activity::CreateType,
+  protocol::{helpers::deserialize_one, values::MediaTypeMarkdown},
+};
+use chrono::{DateTime, Utc};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::{
+  objects::{community::ApubCommunity, person::ApubPerson},
+  utils::protocol::InCommunity,
+};
+use lemmy_utils::error::LemmyResult;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use strum::Display;
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Display)]
+pub enum ModmailType {
+  Modmail,
+}
+
+/// A message between a user and the mod team of a community, lemmy extension. Mod mail can't be
+/// fetched, so the message is always embedded in the activity.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Modmail {
+  #[serde(rename = "type")]
+  pub(crate) kind: ModmailType,
+  pub(crate) id: Url,
+  pub(crate) attributed_to: ObjectId<ApubPerson>,
+  /// Federation id of the thread
+  pub(crate) context: Url,
+  /// Subject of the thread
+  pub(crate) name: String,
+  pub(crate) content: String,
+  pub(crate) media_type: Option<MediaTypeMarkdown>,
+  /// True if the message was sent on behalf of the mod team
+  #[serde(default)]
+  pub(crate) from_mod: bool,
+  pub(crate) published: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateModmail {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubCommunity>; 1],
+  pub(crate) object: Modmail,
+  #[serde(rename = "type")]
+  pub(crate) kind: CreateType,
+  pub(crate) id: Url,
+}
+
+impl InCommunity for CreateModmail {
+  async fn community(&self, context: &Data<LemmyContext>) -> LemmyResult<ApubCommunity> {
+    self.to[0].dereference(context).await
+  }
+}
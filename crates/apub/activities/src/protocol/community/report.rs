@@ -10,6 +10,7 @@ use lemmy_apub_objects::{
   objects::{ReportableObjects, community::ApubCommunity, instance::ApubSite, person::ApubPerson},
   utils::protocol::InCommunity,
 };
+use lemmy_db_schema::source::site::Site;
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -17,6 +18,7 @@ use url::Url;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Report {
+  /// A person, or the instance actor for reports sent by Mastodon
   pub(crate) actor: ObjectId<ApubPerson>,
   #[serde(deserialize_with = "deserialize_one")]
   pub(crate) to: [ObjectId<Either<ApubSite, ApubCommunity>>; 1],
@@ -25,6 +27,8 @@ pub struct Report {
   pub(crate) summary: Option<String>,
   /// Report reason as sent by Mastodon
   pub(crate) content: Option<String>,
+  /// Community or site rule which was violated, lemmy extension
+  pub(crate) rule: Option<Url>,
   #[serde(rename = "type")]
   pub(crate) kind: FlagType,
   pub(crate) id: Url,
@@ -32,6 +36,23 @@ pub struct Report {
 }
 
 impl Report {
+  /// The community or site which receives the report. Mastodon addresses reports to the reported
+  /// account instead, these are handled like reports sent to the site of that account.
+  pub(crate) async fn receiver(
+    &self,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<Either<ApubSite, ApubCommunity>> {
+    let to = &self.to[0];
+    if let Ok(receiver) = to.dereference(context).await {
+      return Ok(receiver);
+    }
+    let person = ObjectId::<ApubPerson>::from(to.inner().clone())
+      .dereference(context)
+      .await?;
+    let site = Site::read_from_instance_id(&mut context.pool(), person.instance_id).await?;
+    Ok(Either::Left(site.into()))
+  }
+
   pub fn reason(&self) -> LemmyResult<String> {
     self
       .summary
@@ -70,6 +91,24 @@ impl ReportObject {
     }
   }
 
+  /// The reported user, for reports which don't reference any content.
+  pub(crate) async fn dereference_person(
+    &self,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<ApubPerson> {
+    let objects = match self {
+      ReportObject::Lemmy(l) => vec![l.inner().clone()],
+      ReportObject::Mastodon(objects) => objects.clone(),
+    };
+    for o in objects {
+      let deref = ObjectId::<ApubPerson>::from(o).dereference(context).await;
+      if deref.is_ok() {
+        return deref;
+      }
+    }
+    Err(LemmyErrorType::NotFound.into())
+  }
+
   pub(crate) async fn object_id(
     &self,
     context: &Data<LemmyContext>,
@@ -97,7 +136,7 @@ impl InCommunity for Report {
     if let Some(audience) = &self.audience {
       return audience.dereference(context).await;
     }
-    match self.to[0].dereference(context).await? {
+    match self.receiver(context).await? {
       Either::Left(_) => Err(LemmyErrorType::NotFound.into()),
       Either::Right(c) => Ok(c),
     }
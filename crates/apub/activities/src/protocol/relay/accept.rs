@@ -0,0 +1,15 @@
+use crate::protocol::relay::follow::FollowRelay;
+use activitypub_federation::{fetch::object_id::ObjectId, kinds::activity::AcceptType};
+use lemmy_apub_objects::objects::relay::ApubRelay;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptRelayFollow {
+  pub(crate) actor: ObjectId<ApubRelay>,
+  pub(crate) object: FollowRelay,
+  #[serde(rename = "type")]
+  pub(crate) kind: AcceptType,
+  pub(crate) id: Url,
+}
@@ -0,0 +1,24 @@
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::activity::AnnounceType,
+  protocol::helpers::deserialize_one_or_many,
+};
+use lemmy_apub_objects::objects::relay::ApubRelay;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Announce of a post from a LitePub relay. Unlike community announces, the object is always
+/// given by its id.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayAnnounce {
+  pub(crate) actor: ObjectId<ApubRelay>,
+  #[serde(deserialize_with = "deserialize_one_or_many", default)]
+  pub(crate) to: Vec<Url>,
+  pub(crate) object: Url,
+  #[serde(deserialize_with = "deserialize_one_or_many", default)]
+  pub(crate) cc: Vec<Url>,
+  #[serde(rename = "type")]
+  pub(crate) kind: AnnounceType,
+  pub(crate) id: Url,
+}
@@ -0,0 +1,35 @@
+use crate::protocol::IdOrNestedObject;
+use activitypub_federation::kinds::activity::CreateType;
+use lemmy_apub_objects::utils::protocol::Id;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Create activity forwarded by a Mastodon-style relay. The relay passes on the original activity,
+/// but signs the request with its own key. So only the object id is used, and the object is
+/// fetched again from its origin.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayedCreate {
+  pub(crate) actor: Url,
+  pub(crate) object: IdOrNestedObject<RelayedObject>,
+  #[serde(rename = "type")]
+  pub(crate) kind: CreateType,
+  pub(crate) id: Url,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RelayedObject {
+  pub(crate) id: Url,
+}
+
+impl Id for RelayedObject {
+  fn id(&self) -> &Url {
+    &self.id
+  }
+}
+
+impl RelayedCreate {
+  pub fn actor(&self) -> &Url {
+    &self.actor
+  }
+}
@@ -0,0 +1,23 @@
+use crate::protocol::relay::PublicCollection;
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::activity::FollowType,
+  protocol::helpers::deserialize_skip_error,
+};
+use lemmy_apub_objects::objects::{instance::ApubSite, relay::ApubRelay};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Subscribes the site actor to a relay.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowRelay {
+  pub(crate) actor: ObjectId<ApubSite>,
+  /// Optional, for compatibility with platforms that always expect recipient field
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) to: Option<[ObjectId<ApubRelay>; 1]>,
+  pub(crate) object: PublicCollection,
+  #[serde(rename = "type")]
+  pub(crate) kind: FollowType,
+  pub(crate) id: Url,
+}
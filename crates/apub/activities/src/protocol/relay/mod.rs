@@ -0,0 +1,69 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+pub(crate) mod accept;
+pub mod announce;
+pub mod create;
+pub mod follow;
+pub(crate) mod reject;
+pub mod undo_follow;
+
+const PUBLIC: &str = "https://www.w3.org/ns/activitystreams#Public";
+
+/// The public collection, which is the object of relay follows. Deserialization fails for any
+/// other value, so that relay activities can be told apart from regular follows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PublicCollection;
+
+impl Serialize for PublicCollection {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(PUBLIC)
+  }
+}
+
+impl<'de> Deserialize<'de> for PublicCollection {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    match value.as_str() {
+      PUBLIC | "as:Public" | "Public" => Ok(PublicCollection),
+      _ => Err(D::Error::custom("not the public collection")),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    activity_lists::SharedInboxActivities,
+    protocol::relay::{accept::AcceptRelayFollow, announce::RelayAnnounce, create::RelayedCreate},
+  };
+  use lemmy_apub_objects::utils::test::test_json;
+  use lemmy_utils::error::LemmyResult;
+
+  #[test]
+  fn test_parse_relay_activities() -> LemmyResult<()> {
+    test_json::<AcceptRelayFollow>("../apub/assets/pleroma/activities/relay_accept.json")?;
+    test_json::<RelayAnnounce>("../apub/assets/pleroma/activities/relay_announce.json")?;
+    test_json::<RelayedCreate>("../apub/assets/mastodon/activities/create_note.json")?;
+
+    // Make sure that relay activities are not parsed as regular follows or announces
+    let accept =
+      test_json::<SharedInboxActivities>("../apub/assets/pleroma/activities/relay_accept.json")?;
+    assert!(matches!(
+      accept.inner(),
+      SharedInboxActivities::AcceptRelayFollow(_)
+    ));
+    let announce =
+      test_json::<SharedInboxActivities>("../apub/assets/pleroma/activities/relay_announce.json")?;
+    assert!(matches!(
+      announce.inner(),
+      SharedInboxActivities::RelayAnnounce(_)
+    ));
+    let accept =
+      test_json::<SharedInboxActivities>("../apub/assets/lemmy/activities/following/accept.json")?;
+    assert!(matches!(
+      accept.inner(),
+      SharedInboxActivities::AcceptFollow(_)
+    ));
+    Ok(())
+  }
+}
@@ -0,0 +1,22 @@
+use crate::protocol::relay::follow::FollowRelay;
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::activity::UndoType,
+  protocol::helpers::deserialize_skip_error,
+};
+use lemmy_apub_objects::objects::{instance::ApubSite, relay::ApubRelay};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoFollowRelay {
+  pub(crate) actor: ObjectId<ApubSite>,
+  /// Optional, for compatibility with platforms that always expect recipient field
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) to: Option<[ObjectId<ApubRelay>; 1]>,
+  pub(crate) object: FollowRelay,
+  #[serde(rename = "type")]
+  pub(crate) kind: UndoType,
+  pub(crate) id: Url,
+}
@@ -0,0 +1,192 @@
+use crate::{
+  create_or_update::post::insert_post,
+  protocol::{
+    IdOrNestedObject,
+    community::announce::AnnounceActivity,
+    relay::{announce::RelayAnnounce, create::RelayedCreate},
+  },
+};
+use activitypub_federation::{
+  config::Data,
+  fetch::{fetch_object_http, object_id::ObjectId},
+  traits::{Activity, Object},
+};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::{
+  objects::post::ApubPost,
+  protocol::page::Page,
+  utils::functions::{check_apub_id_valid, local_site_data_cached},
+};
+use lemmy_db_schema::source::{activity::ReceivedActivity, relay::Relay};
+use lemmy_db_schema_file::enums::{CommunityFollowerState, RelayMode};
+use lemmy_utils::error::{LemmyError, LemmyResult};
+use tracing::debug;
+use url::Url;
+
+#[async_trait::async_trait]
+impl Activity for RelayAnnounce {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, _context: &Data<LemmyContext>) -> LemmyResult<()> {
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    let relay = Relay::read_from_apub_id(&mut context.pool(), &self.actor.clone().into()).await?;
+    let Some(relay) = relay else {
+      // Some platforms announce community activities by id, which looks the same
+      let announce = AnnounceActivity {
+        actor: self.actor.into_inner().into(),
+        to: self.to,
+        object: IdOrNestedObject::Id(self.object),
+        cc: self.cc,
+        kind: self.kind,
+        id: self.id,
+      };
+      return announce.receive(context).await;
+    };
+    receive_relayed_post(relay, &self.object, context).await
+  }
+}
+
+/// Handles a Create activity which a Mastodon-style relay forwarded as is. The request signature
+/// must already be verified to be from the relay.
+pub async fn receive_relayed_create(
+  activity: RelayedCreate,
+  relay: Relay,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  ReceivedActivity::create(&mut context.pool(), &activity.id.into()).await?;
+  receive_relayed_post(relay, activity.object.id(), context).await
+}
+
+async fn receive_relayed_post(
+  relay: Relay,
+  object_id: &Url,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  if relay.follow_state != CommunityFollowerState::Accepted {
+    return Ok(());
+  }
+
+  // Most relayed content is unrelated to communities, so errors are expected here and
+  // shouldn't be sent back to the relay.
+  let (imported, discovered) = match import_post(object_id, relay.mode, context).await {
+    Ok(res) => res,
+    Err(e) => {
+      debug!("Ignoring relayed object {object_id}: {e}");
+      (false, false)
+    }
+  };
+  Relay::record_announce(&mut context.pool(), relay.id, imported, discovered).await
+}
+
+/// Fetch and store a relayed post. Returns whether the post was imported, and whether its
+/// community was unknown before.
+async fn import_post(
+  object_id: &Url,
+  mode: RelayMode,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<(bool, bool)> {
+  let post_id = ObjectId::<ApubPost>::from(object_id.clone());
+  if post_id.dereference_local(context).await.is_ok() {
+    return Ok((false, false));
+  }
+
+  // Check the domain before fetching anything from it
+  let local_site_data = local_site_data_cached(&mut context.pool()).await?;
+  check_apub_id_valid(object_id, &local_site_data)?;
+
+  let page: Page = fetch_object_http(object_id, context).await?.object;
+  import_page(page, object_id, mode, context).await
+}
+
+/// Store a fetched post, unless the relay mode excludes its community.
+async fn import_page(
+  page: Page,
+  object_id: &Url,
+  mode: RelayMode,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<(bool, bool)> {
+  let discovered = page.known_community(context).await.is_err();
+  if discovered && mode == RelayMode::KnownCommunities {
+    return Ok((false, false));
+  }
+
+  // This also fetches the community if necessary
+  ApubPost::verify(&page, object_id, context).await?;
+  insert_post(page, context).await?;
+  Ok((true, discovered))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_apub_objects::utils::test::{
+    file_to_json_object,
+    parse_lemmy_community,
+    parse_lemmy_person,
+  };
+  use lemmy_db_schema::source::{
+    federation_blocklist::{FederationBlockList, FederationBlockListForm},
+    instance::Instance,
+  };
+  use lemmy_utils::error::{LemmyErrorType, UntranslatedError};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_import_relayed_post() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    parse_lemmy_person(&context).await?;
+    let page: Page = file_to_json_object("../apub/assets/lemmy/objects/page.json")?;
+    let url = Url::parse("https://enterprise.lemmy.ml/post/55143")?;
+    let post_id = ObjectId::<ApubPost>::from(url.clone());
+
+    // The community isn't known yet, so the post is skipped
+    let res = import_page(page.clone(), &url, RelayMode::KnownCommunities, &context).await?;
+    assert_eq!((false, false), res);
+    assert!(post_id.dereference_local(&context).await.is_err());
+
+    parse_lemmy_community(&context).await?;
+    let res = import_page(page, &url, RelayMode::KnownCommunities, &context).await?;
+    assert_eq!((true, false), res);
+    assert!(post_id.dereference_local(&context).await.is_ok());
+
+    // Posts which are already stored are not fetched again
+    let res = import_post(&url, RelayMode::Discover, &context).await?;
+    assert_eq!((false, false), res);
+    assert_eq!(0, context.request_count());
+
+    Instance::delete_all(&mut context.pool()).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_import_relayed_post_blocked_instance() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let instance = Instance::read_or_create(&mut context.pool(), "blocked.example").await?;
+    let form = FederationBlockListForm::new(instance.id, None);
+    FederationBlockList::block(&mut context.pool(), &form).await?;
+
+    let url = Url::parse("https://blocked.example/post/1")?;
+    let res = import_post(&url, RelayMode::Discover, &context).await;
+    let blocked = UntranslatedError::DomainBlocked("blocked.example".to_string());
+    assert!(res.is_err_and(|e| e.error_type == LemmyErrorType::UntranslatedError(Some(blocked))));
+    assert_eq!(0, context.request_count());
+
+    Instance::delete_all(&mut context.pool()).await?;
+    Ok(())
+  }
+}
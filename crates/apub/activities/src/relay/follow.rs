@@ -0,0 +1,64 @@
+use crate::{
+  generate_activity_id,
+  protocol::relay::{PublicCollection, follow::FollowRelay},
+  send_lemmy_activity,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::activity::FollowType,
+  traits::{Activity, Actor, Object},
+};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::objects::{instance::ApubSite, relay::ApubRelay};
+use lemmy_db_schema::source::activity::ActivitySendTargets;
+use lemmy_utils::error::{LemmyError, LemmyResult, UntranslatedError};
+use url::Url;
+
+impl FollowRelay {
+  pub(crate) fn new(
+    site: &ApubSite,
+    relay: &ApubRelay,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<FollowRelay> {
+    Ok(FollowRelay {
+      actor: site.id().clone().into(),
+      to: Some([relay.id().clone().into()]),
+      object: PublicCollection,
+      kind: FollowType::Follow,
+      id: generate_activity_id(FollowType::Follow, context)?,
+    })
+  }
+
+  pub(crate) async fn send(
+    site: &ApubSite,
+    relay: &ApubRelay,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let follow = FollowRelay::new(site, relay, context)?;
+    let inbox = ActivitySendTargets::to_inbox(relay.shared_inbox_or_inbox());
+    send_lemmy_activity(context, follow, site, inbox, true).await
+  }
+}
+
+/// Only sent, relays don't follow instances.
+#[async_trait::async_trait]
+impl Activity for FollowRelay {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, _context: &Data<LemmyContext>) -> LemmyResult<()> {
+    Ok(())
+  }
+
+  async fn receive(self, _context: &Data<LemmyContext>) -> LemmyResult<()> {
+    Err(UntranslatedError::Unreachable.into())
+  }
+}
@@ -0,0 +1,30 @@
+use crate::protocol::relay::{follow::FollowRelay, undo_follow::UndoFollowRelay};
+use activitypub_federation::config::Data;
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::objects::{instance::ApubSite, relay::ApubRelay};
+use lemmy_db_schema::source::relay::Relay;
+use lemmy_db_views_site::SiteView;
+use lemmy_utils::error::LemmyResult;
+
+pub(crate) mod accept;
+pub(crate) mod announce;
+pub(crate) mod follow;
+pub(crate) mod reject;
+pub(crate) mod undo_follow;
+
+pub use announce::receive_relayed_create;
+
+/// Relays are followed by the site actor, as they forward content for the whole instance.
+pub async fn send_follow_relay(
+  relay: Relay,
+  follow: bool,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let site: ApubSite = SiteView::read_local(&mut context.pool()).await?.site.into();
+  let relay: ApubRelay = relay.into();
+  if follow {
+    FollowRelay::send(&site, &relay, context).await
+  } else {
+    UndoFollowRelay::send(&site, &relay, context).await
+  }
+}
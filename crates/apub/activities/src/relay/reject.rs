@@ -0,0 +1,41 @@
+use crate::protocol::relay::reject::RejectRelayFollow;
+use activitypub_federation::{config::Data, traits::Activity};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::relay::{Relay, RelayUpdateForm};
+use lemmy_db_schema_file::enums::CommunityFollowerState;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyError, LemmyResult, UntranslatedError};
+use url::Url;
+
+#[async_trait::async_trait]
+impl Activity for RejectRelayFollow {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    if !self.object.actor.is_local(context) {
+      Err(UntranslatedError::InvalidFollow(
+        "Not a local object".to_string(),
+      ))?
+    }
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    let relay = self.actor.dereference(context).await?;
+    let form = RelayUpdateForm {
+      follow_state: Some(CommunityFollowerState::Denied),
+      ..Default::default()
+    };
+    Relay::update(&mut context.pool(), relay.id, &form).await?;
+    Ok(())
+  }
+}
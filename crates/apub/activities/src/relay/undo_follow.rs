@@ -0,0 +1,57 @@
+use crate::{
+  generate_activity_id,
+  protocol::relay::{follow::FollowRelay, undo_follow::UndoFollowRelay},
+  send_lemmy_activity,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::activity::UndoType,
+  traits::{Activity, Actor, Object},
+};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_objects::objects::{instance::ApubSite, relay::ApubRelay};
+use lemmy_db_schema::source::activity::ActivitySendTargets;
+use lemmy_utils::error::{LemmyError, LemmyResult, UntranslatedError};
+use url::Url;
+
+impl UndoFollowRelay {
+  pub(crate) async fn send(
+    site: &ApubSite,
+    relay: &ApubRelay,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<()> {
+    let object = FollowRelay::new(site, relay, context)?;
+    let undo = UndoFollowRelay {
+      actor: site.id().clone().into(),
+      to: Some([relay.id().clone().into()]),
+      object,
+      kind: UndoType::Undo,
+      id: generate_activity_id(UndoType::Undo, context)?,
+    };
+    let inbox = ActivitySendTargets::to_inbox(relay.shared_inbox_or_inbox());
+    send_lemmy_activity(context, undo, site, inbox, true).await
+  }
+}
+
+/// Only sent, relays don't follow instances.
+#[async_trait::async_trait]
+impl Activity for UndoFollowRelay {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, _context: &Data<LemmyContext>) -> LemmyResult<()> {
+    Ok(())
+  }
+
+  async fn receive(self, _context: &Data<LemmyContext>) -> LemmyResult<()> {
+    Err(UntranslatedError::Unreachable.into())
+  }
+}
@@ -1,4 +1,5 @@
 use crate::{
+  check_federation_policy,
   generate_activity_id,
   protocol::voting::vote::{Vote, VoteType},
   voting::{undo_vote_comment, undo_vote_post, vote_comment, vote_post},
@@ -52,6 +53,7 @@ impl Activity for Vote {
   async fn verify(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
     let community = self.community(context).await?;
     verify_person_in_community(&self.actor, &community, context).await?;
+    check_federation_policy(&self.actor, |p| p.reject_votes, context).await?;
     Ok(())
   }
 
@@ -91,3 +93,70 @@ impl Activity for Vote {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_db_schema::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      federation_policy::{FederationPolicy, FederationPolicyForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    test_data::TestData,
+  };
+  use lemmy_diesel_utils::traits::Crud;
+  use lemmy_utils::error::{LemmyErrorType, UntranslatedError};
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_vote_rejected_by_federation_policy() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let data = TestData::create(&mut context.pool()).await?;
+
+    let remote = Instance::read_or_create(&mut context.pool(), "votes.example").await?;
+    let voter_form = PersonInsertForm {
+      ap_id: Some(Url::parse("https://votes.example/u/voter")?.into()),
+      local: Some(false),
+      ..PersonInsertForm::test_form(remote.id, "voter")
+    };
+    let voter = Person::create(&mut context.pool(), &voter_form).await?;
+    let community_form = CommunityInsertForm::new(
+      data.instance.id,
+      "voted".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(&mut context.pool(), &community_form).await?;
+
+    let vote = Vote {
+      actor: ObjectId::from(voter.ap_id.inner().clone()),
+      object: ObjectId::parse("https://votes.example/post/1")?,
+      kind: VoteType::Like,
+      id: Url::parse("https://votes.example/activities/like/1")?,
+      audience: Some(ObjectId::from(community.ap_id.inner().clone())),
+    };
+    vote.verify(&context).await?;
+
+    let policy_form = FederationPolicyForm {
+      instance_id: remote.id,
+      silence: false,
+      strip_media: false,
+      force_nsfw: false,
+      reject_reports: false,
+      reject_votes: true,
+      no_avatar: false,
+      reason: "vote brigading".to_string(),
+    };
+    FederationPolicy::upsert(&mut context.pool(), &policy_form).await?;
+    let rejected = UntranslatedError::RejectedByFederationPolicy;
+    let res = vote.verify(&context).await;
+    assert!(res.is_err_and(|e| e.error_type == LemmyErrorType::UntranslatedError(Some(rejected))));
+
+    Instance::delete(&mut context.pool(), remote.id).await?;
+    data.delete(&mut context.pool()).await?;
+    Ok(())
+  }
+}
@@ -1,7 +1,10 @@
-use crate::{is_new_instance, protocol::collections::group_outbox::GroupOutbox};
+use crate::{
+  is_new_instance,
+  protocol::collections::group_outbox::{GroupOutbox, GroupOutboxPage},
+};
 use activitypub_federation::{
   config::Data,
-  kinds::collection::OrderedCollectionType,
+  kinds::collection::{OrderedCollectionPageType, OrderedCollectionType},
   protocol::verification::verify_domains_match,
   traits::{Activity, Collection},
 };
@@ -12,19 +15,184 @@ use lemmy_apub_activities::{
   protocol::{
     CreateOrUpdateType,
     community::announce::AnnounceActivity,
-    create_or_update::page::CreateOrUpdatePage,
+    create_or_update::{note::CreateOrUpdateNote, page::CreateOrUpdatePage},
   },
 };
 use lemmy_apub_objects::objects::community::ApubCommunity;
-use lemmy_db_schema::{source::site::Site, utils::FETCH_LIMIT_MAX};
-use lemmy_db_schema_file::enums::PostSortType;
-use lemmy_db_views_post::impls::PostQuery;
-use lemmy_utils::error::{LemmyError, LemmyResult};
+use lemmy_db_schema::{newtypes::PostId, source::site::Site, utils::FETCH_LIMIT_MAX};
+use lemmy_db_schema_file::enums::{CommentSortType, PostSortType};
+use lemmy_db_views_comment::{CommentView, impls::CommentQuery};
+use lemmy_db_views_post::{PostView, impls::PostQuery};
+use lemmy_diesel_utils::pagination::PaginationCursor;
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+use serde_json::{from_value, to_value};
 use url::Url;
 
+/// Number of posts in each page of the paginated outbox.
+const OUTBOX_PAGE_SIZE: i64 = 20;
+
 #[derive(Clone, Debug)]
 pub(crate) struct ApubCommunityOutbox(());
 
+impl ApubCommunityOutbox {
+  /// Returns a single page of the outbox. Unlike the collection itself, the paginated outbox also
+  /// includes comments, so that the full history of a community can be fetched. Each page of
+  /// posts is followed by pages with the comments of these posts, one post after another. Comments
+  /// are listed from oldest to newest, so that parents always come before their replies.
+  pub(crate) async fn read_local_page(
+    owner: &ApubCommunity,
+    cursor: Option<PaginationCursor>,
+    post_id: Option<PostId>,
+    comment_cursor: Option<PaginationCursor>,
+    data: &Data<LemmyContext>,
+  ) -> LemmyResult<GroupOutboxPage> {
+    let site = Site::read_local(&mut data.pool()).await?;
+    let part_of: Url = generate_outbox_url(&owner.ap_id)?.into();
+    let id = outbox_page_url(&part_of, cursor.clone(), post_id, comment_cursor.clone())?;
+
+    let posts = Box::pin(
+      PostQuery {
+        community_id: Some(owner.id),
+        sort: Some(PostSortType::New),
+        page_cursor: cursor.clone(),
+        limit: Some(OUTBOX_PAGE_SIZE),
+        ..Default::default()
+      }
+      .list(&site, &mut data.pool()),
+    )
+    .await?;
+    // Posts without comments don't get a comments page
+    let with_comments: Vec<_> = posts
+      .items
+      .iter()
+      .filter(|p| p.post.comments > 0)
+      .map(|p| p.post.id)
+      .collect();
+
+    let mut ordered_items = vec![];
+    let next_comments = match post_id {
+      None => {
+        for post_view in posts.items {
+          // ignore errors, in particular if post creator was deleted
+          if let Ok(announce) = announce_post(post_view, owner, data).await {
+            ordered_items.push(announce);
+          }
+        }
+        with_comments.first().map(|p| (*p, None))
+      }
+      Some(post_id) => {
+        // Only comments of posts on the given page can be listed
+        if !posts.items.iter().any(|p| p.post.id == post_id) {
+          Err(LemmyErrorType::NotFound)?
+        }
+        let comments = CommentQuery {
+          post_id: Some(post_id),
+          sort: Some(CommentSortType::Old),
+          page_cursor: comment_cursor,
+          limit: Some(FETCH_LIMIT_MAX.try_into()?),
+          ..Default::default()
+        }
+        .list(&site, &mut data.pool())
+        .await?;
+        let is_last_page = comments.items.len() < FETCH_LIMIT_MAX;
+        for comment_view in comments.items {
+          if let Ok(announce) = announce_comment(comment_view, owner, data).await {
+            ordered_items.push(announce);
+          }
+        }
+        match comments.next_page {
+          Some(next) if !is_last_page => Some((post_id, Some(next))),
+          // continue with the comments of the next post
+          _ => with_comments
+            .iter()
+            .skip_while(|p| **p != post_id)
+            .nth(1)
+            .map(|p| (*p, None)),
+        }
+      }
+    };
+
+    let next = match (next_comments, posts.next_page) {
+      (Some((post_id, comment_cursor)), _) => Some(outbox_page_url(
+        &part_of,
+        cursor,
+        Some(post_id),
+        comment_cursor,
+      )?),
+      (None, Some(next)) => Some(outbox_page_url(&part_of, Some(next), None, None)?),
+      (None, None) => None,
+    };
+    Ok(GroupOutboxPage {
+      r#type: OrderedCollectionPageType::OrderedCollectionPage,
+      id,
+      part_of,
+      ordered_items,
+      next,
+    })
+  }
+}
+
+/// Url of an outbox page, in the same format as the query parameters of the outbox handler.
+fn outbox_page_url(
+  outbox: &Url,
+  cursor: Option<PaginationCursor>,
+  post_id: Option<PostId>,
+  comment_cursor: Option<PaginationCursor>,
+) -> LemmyResult<Url> {
+  let mut url = outbox.clone();
+  url.query_pairs_mut().append_pair("page", "true");
+  if let Some(cursor) = cursor {
+    let cursor: String = from_value(to_value(cursor)?)?;
+    url.query_pairs_mut().append_pair("cursor", &cursor);
+  }
+  if let Some(post_id) = post_id {
+    url
+      .query_pairs_mut()
+      .append_pair("post_id", &post_id.0.to_string());
+  }
+  if let Some(comment_cursor) = comment_cursor {
+    let comment_cursor: String = from_value(to_value(comment_cursor)?)?;
+    url
+      .query_pairs_mut()
+      .append_pair("comment_cursor", &comment_cursor);
+  }
+  Ok(url)
+}
+
+async fn announce_post(
+  post_view: PostView,
+  community: &ApubCommunity,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<AnnounceActivity> {
+  let create = CreateOrUpdatePage::new(
+    post_view.post.into(),
+    &post_view.creator.into(),
+    community,
+    CreateOrUpdateType::Create,
+    context,
+  )
+  .await?;
+  let announcable = AnnouncableActivities::CreateOrUpdatePost(create);
+  AnnounceActivity::new(announcable.try_into()?, community, context)
+}
+
+async fn announce_comment(
+  comment_view: CommentView,
+  community: &ApubCommunity,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<AnnounceActivity> {
+  let create = CreateOrUpdateNote::new(
+    comment_view.comment.into(),
+    &comment_view.creator.into(),
+    community,
+    CreateOrUpdateType::Create,
+    context,
+  )
+  .await?;
+  let announcable = AnnouncableActivities::CreateOrUpdateNoteWrapper(create.try_into()?);
+  AnnounceActivity::new(announcable.try_into()?, community, context)
+}
+
 #[async_trait::async_trait]
 impl Collection for ApubCommunityOutbox {
   type Owner = ApubCommunity;
@@ -50,25 +218,16 @@ impl Collection for ApubCommunityOutbox {
     let mut ordered_items = vec![];
     for post_view in post_views {
       // ignore errors, in particular if post creator was deleted
-      if let Ok(create) = CreateOrUpdatePage::new(
-        post_view.post.into(),
-        &post_view.creator.into(),
-        owner,
-        CreateOrUpdateType::Create,
-        data,
-      )
-      .await
-      {
-        let announcable = AnnouncableActivities::CreateOrUpdatePost(create);
-        if let Ok(announce) = AnnounceActivity::new(announcable.try_into()?, owner, data) {
-          ordered_items.push(announce);
-        }
+      if let Ok(announce) = announce_post(post_view, owner, data).await {
+        ordered_items.push(announce);
       }
     }
 
+    let id: Url = generate_outbox_url(&owner.ap_id)?.into();
     Ok(GroupOutbox {
       r#type: OrderedCollectionType::OrderedCollection,
-      id: generate_outbox_url(&owner.ap_id)?.into(),
+      first: Some(outbox_page_url(&id, None)?),
+      id,
       total_items: owner.posts,
       ordered_items,
     })
@@ -1,12 +1,16 @@
 use crate::{
   collections::community_moderators::handle_community_moderators,
   is_new_instance,
-  protocol::collections::url_collection::UrlCollection,
+  protocol::collections::{
+    group_outbox::{GroupOutbox, GroupOutboxPage},
+    url_collection::UrlCollection,
+  },
 };
 use activitypub_federation::{
   actix_web::response::create_http_response,
   config::Data,
-  fetch::{collection_id::CollectionId, object_id::ObjectId},
+  fetch::{collection_id::CollectionId, fetch_object_http, object_id::ObjectId},
+  protocol::verification::verify_domains_match,
 };
 use actix_web::HttpResponse;
 use community_featured::ApubCommunityFeatured;
@@ -14,19 +18,33 @@ use community_follower::ApubCommunityFollower;
 use community_moderators::ApubCommunityModerators;
 use community_outbox::ApubCommunityOutbox;
 use lemmy_api_utils::context::LemmyContext;
+use lemmy_apub_activities::protocol::community::announce::AnnounceActivity;
 use lemmy_apub_objects::{
   objects::{community::ApubCommunity, person::ApubPerson},
   protocol::group::Group,
   utils::protocol::{AttributedTo, PersonOrGroupType},
 };
-use lemmy_db_schema::source::{comment::Comment, post::Post};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{comment::Comment, post::Post},
+};
 use lemmy_utils::{FEDERATION_CONTEXT, error::LemmyResult, spawn_try_task};
+use std::{
+  collections::HashSet,
+  sync::{LazyLock, Mutex},
+  time::Duration,
+};
+use tokio::time::sleep;
+use tracing::info;
 
 pub(crate) mod community_featured;
 pub(crate) mod community_follower;
 pub(crate) mod community_moderators;
 pub(crate) mod community_outbox;
 
+/// Time to wait between fetching pages of a remote outbox during backfill.
+const BACKFILL_PAGE_INTERVAL: Duration = Duration::from_secs(2);
+
 pub fn fetch_community_collections(
   community: ApubCommunity,
   group: Group,
@@ -66,6 +84,70 @@ pub fn fetch_community_collections(
   });
 }
 
+/// Walk through the paginated outbox of a remote community and import the posts and comments, up
+/// to the given number of pages. Waits between pages and handles items one by one, so that the
+/// remote instance isn't overloaded. Only one backfill per community can run at a time.
+pub fn backfill_community(community: ApubCommunity, max_pages: i32, context: Data<LemmyContext>) {
+  static RUNNING: LazyLock<Mutex<HashSet<CommunityId>>> = LazyLock::new(Default::default);
+  let community_id = community.id;
+  if !RUNNING.lock().is_ok_and(|mut r| r.insert(community_id)) {
+    return;
+  }
+  spawn_try_task(async move {
+    let res = backfill_outbox(&community, max_pages, &context).await;
+    if let Ok(mut running) = RUNNING.lock() {
+      running.remove(&community_id);
+    }
+    res
+  });
+}
+
+async fn backfill_outbox(
+  community: &ApubCommunity,
+  max_pages: i32,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let group: Group = fetch_object_http(&community.ap_id, context).await?.object;
+  let outbox: GroupOutbox = fetch_object_http(&group.outbox, context).await?.object;
+  verify_domains_match(community.ap_id.inner(), &outbox.id)?;
+
+  // Older versions don't support pagination, so only the newest posts can be imported
+  let Some(mut next) = outbox.first else {
+    import_outbox_items(outbox.ordered_items, community, context).await;
+    return Ok(());
+  };
+  for _ in 0..max_pages {
+    let page: GroupOutboxPage = fetch_object_http(&next, context).await?.object;
+    verify_domains_match(community.ap_id.inner(), &page.id)?;
+    let count = page.ordered_items.len();
+    import_outbox_items(page.ordered_items, community, context).await;
+    info!("Backfilled {count} items from {}", page.id);
+
+    match page.next {
+      Some(n) if n != next => next = n,
+      _ => break,
+    }
+    sleep(BACKFILL_PAGE_INTERVAL).await;
+  }
+  Ok(())
+}
+
+/// Import outbox items in order, so that posts exist before their comments are imported. Errors
+/// are ignored, as the outbox may contain items which we can't parse.
+async fn import_outbox_items(
+  items: Vec<AnnounceActivity>,
+  community: &ApubCommunity,
+  context: &Data<LemmyContext>,
+) {
+  for announce in items {
+    // Each item may require fetching the author and parent objects
+    announce
+      .import(community, &context.reset_request_count())
+      .await
+      .ok();
+  }
+}
+
 impl UrlCollection {
   pub(crate) async fn new_response(
     post: &Post,
@@ -28,6 +28,9 @@ async fn get_comment(
   let id = CommentId(info.comment_id.parse::<i32>()?);
   // Can't use CommentView here because it excludes deleted/removed/local-only items
   let comment: ApubComment = Comment::read(&mut context.pool(), id).await?.into();
+  if comment.held_for_review {
+    return Err(LemmyErrorType::NotFound.into());
+  }
   let post = Post::read(&mut context.pool(), comment.post_id).await?;
   let community = Community::read(&mut context.pool(), post.community_id).await?;
   check_community_content_fetchable(&community, request, context).await?;
@@ -27,14 +27,16 @@ use lemmy_apub_objects::{
     multi_community::ApubMultiCommunity,
     multi_community_collection::ApubFeedCollection,
   },
-  protocol::tags::CommunityTag,
+  protocol::{rules::CommunityRule, tags::CommunityTag},
 };
 use lemmy_db_schema::{
-  source::{community::Community, multi_community::MultiCommunity, tag::Tag},
+  newtypes::{PostId, RuleId},
+  source::{community::Community, multi_community::MultiCommunity, rule::Rule, tag::Tag},
   traits::ApubActor,
 };
 use lemmy_db_schema_file::enums::CommunityVisibility;
 use lemmy_db_views_community_follower_approval::PendingFollowerView;
+use lemmy_diesel_utils::pagination::PaginationCursor;
 use lemmy_utils::{
   FEDERATION_CONTEXT,
   error::{LemmyErrorType, LemmyResult},
@@ -119,10 +121,20 @@ async fn check_is_follower(
   }
 }
 
+#[derive(Deserialize, Clone, Default)]
+pub(crate) struct CommunityOutboxQuery {
+  page: Option<bool>,
+  cursor: Option<PaginationCursor>,
+  post_id: Option<PostId>,
+  comment_cursor: Option<PaginationCursor>,
+}
+
 /// Returns the community outbox, which is populated by a maximum of 20 posts (but no other
-/// activities like votes or comments).
+/// activities like votes or comments). With `page=true` a single page of the paginated outbox is
+/// returned instead, which also contains comments.
 pub(crate) async fn get_apub_community_outbox(
   info: Path<CommunityPath>,
+  query: Query<CommunityOutboxQuery>,
   context: Data<LemmyContext>,
   request: HttpRequest,
 ) -> LemmyResult<HttpResponse> {
@@ -132,6 +144,18 @@ pub(crate) async fn get_apub_community_outbox(
       .ok_or(LemmyErrorType::NotFound)?
       .into();
   check_community_content_fetchable(&community, &request, &context).await?;
+  let query = query.into_inner();
+  if query.page.unwrap_or_default() {
+    let page = ApubCommunityOutbox::read_local_page(
+      &community,
+      query.cursor,
+      query.post_id,
+      query.comment_cursor,
+      &context,
+    )
+    .await?;
+    return Ok(create_http_response(page, &FEDERATION_CONTEXT)?);
+  }
   let outbox = ApubCommunityOutbox::read_local(&community, &context).await?;
   Ok(create_http_response(outbox, &FEDERATION_CONTEXT)?)
 }
@@ -226,15 +250,48 @@ pub(crate) async fn get_apub_community_tag_http(
   Ok(create_http_response(tag, &FEDERATION_CONTEXT)?)
 }
 
+#[derive(Deserialize, Clone)]
+pub(crate) struct CommunityRulePath {
+  community_name: String,
+  rule_id: i32,
+}
+
+/// Return a community rule, which is referenced by reports.
+pub(crate) async fn get_apub_community_rule_http(
+  info: Path<CommunityRulePath>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let community: ApubCommunity =
+    Community::read_from_name(&mut context.pool(), &info.community_name, None, true)
+      .await?
+      .ok_or(LemmyErrorType::NotFound)?
+      .into();
+
+  check_community_fetchable(&community)?;
+
+  let rule = Rule::read_for_community(&mut context.pool(), Some(community.id))
+    .await?
+    .into_iter()
+    .find(|r| r.id == RuleId(info.rule_id))
+    .ok_or(LemmyErrorType::NotFound)?;
+
+  Ok(create_http_response(
+    CommunityRule::to_json(rule),
+    &FEDERATION_CONTEXT,
+  )?)
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
 
   use super::*;
+  use crate::protocol::collections::group_outbox::GroupOutboxPage;
   use activitypub_federation::protocol::tombstone::Tombstone;
   use actix_web::{body::to_bytes, test::TestRequest};
   use lemmy_apub_objects::protocol::group::Group;
   use lemmy_db_schema::{
     source::{
+      comment::{Comment, CommentInsertForm},
       community::CommunityInsertForm,
       person::{Person, PersonInsertForm},
       post::{Post, PostInsertForm},
@@ -310,7 +367,13 @@ pub(crate) mod tests {
     assert_eq!(200, res.status());
     let res = get_apub_community_moderators(path.clone().into(), context.clone()).await?;
     assert_eq!(200, res.status());
-    let res = get_apub_community_outbox(path, context.clone(), request).await?;
+    let res = get_apub_community_outbox(
+      path,
+      Query(CommunityOutboxQuery::default()),
+      context.clone(),
+      request,
+    )
+    .await?;
     assert_eq!(200, res.status());
 
     data.delete(&mut context.pool()).await?;
@@ -340,7 +403,13 @@ pub(crate) mod tests {
     assert!(res.is_err());
     let res = get_apub_community_moderators(path.clone().into(), context.clone()).await;
     assert!(res.is_err());
-    let res = get_apub_community_outbox(path, context.clone(), request).await;
+    let res = get_apub_community_outbox(
+      path,
+      Query(CommunityOutboxQuery::default()),
+      context.clone(),
+      request,
+    )
+    .await;
     assert!(res.is_err());
 
     data.delete(&mut context.pool()).await?;
@@ -366,7 +435,13 @@ pub(crate) mod tests {
     assert!(res.is_err());
     let res = get_apub_community_moderators(path.clone().into(), context.clone()).await;
     assert!(res.is_err());
-    let res = get_apub_community_outbox(path, context.clone(), request).await;
+    let res = get_apub_community_outbox(
+      path,
+      Query(CommunityOutboxQuery::default()),
+      context.clone(),
+      request,
+    )
+    .await;
     assert!(res.is_err());
 
     data.delete(&mut context.pool()).await?;
@@ -388,8 +463,62 @@ pub(crate) mod tests {
     let form = PostInsertForm::new("title".to_string(), person.id, community.id);
     Post::create(&mut context.pool(), &form).await?;
 
-    let res = get_apub_community_outbox(path, context.clone(), request).await?;
+    let res = get_apub_community_outbox(
+      path.clone(),
+      Query(CommunityOutboxQuery::default()),
+      context.clone(),
+      request.clone(),
+    )
+    .await?;
+    assert_eq!(200, res.status());
+
+    // the paginated outbox also needs to skip the post
+    let query = Query(CommunityOutboxQuery {
+      page: Some(true),
+      ..Default::default()
+    });
+    let res = get_apub_community_outbox(path, query, context.clone(), request).await?;
     assert_eq!(200, res.status());
+    let page: GroupOutboxPage = decode_response(res).await?;
+    assert_eq!(Some("page=true"), page.id.query());
+
+    data.delete(&mut context.pool()).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_outbox_comment_pages() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let (data, community, path) = init(false, CommunityVisibility::Public, &context).await?;
+    let request = TestRequest::default().to_http_request();
+
+    let form = PostInsertForm::new("title".to_string(), data.person.id, community.id);
+    let post = Post::create(&mut context.pool(), &form).await?;
+    let form = PostInsertForm::new("no comments".to_string(), data.person.id, community.id);
+    Post::create(&mut context.pool(), &form).await?;
+    for i in 0..2 {
+      let form = CommentInsertForm::new(data.person.id, post.id, format!("comment {i}"));
+      Comment::create(&mut context.pool(), &form, None).await?;
+    }
+
+    // the first page only contains posts, and links to the comments of the commented post
+    let query = Query(CommunityOutboxQuery {
+      page: Some(true),
+      ..Default::default()
+    });
+    let res =
+      get_apub_community_outbox(path.clone(), query, context.clone(), request.clone()).await?;
+    let page: GroupOutboxPage = decode_response(res).await?;
+    assert_eq!(2, page.ordered_items.len());
+    let next = page.next.ok_or(LemmyErrorType::NotFound)?;
+
+    let query = Query::<CommunityOutboxQuery>::from_query(next.query().unwrap_or_default())?;
+    assert_eq!(Some(post.id), query.post_id);
+    let res = get_apub_community_outbox(path, query, context.clone(), request).await?;
+    let page: GroupOutboxPage = decode_response(res).await?;
+    assert_eq!(2, page.ordered_items.len());
+    assert_eq!(None, page.next);
 
     data.delete(&mut context.pool()).await?;
     Ok(())
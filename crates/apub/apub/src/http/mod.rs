@@ -10,21 +10,32 @@ use activitypub_federation::{
 use actix_web::{
   HttpRequest,
   HttpResponse,
+  body::MessageBody,
+  dev::{ServiceRequest, ServiceResponse},
+  middleware::Next,
   web::{self, Bytes},
 };
 use either::Either;
 use lemmy_api_utils::{context::LemmyContext, plugins::plugin_hook_after};
-use lemmy_apub_activities::activity_lists::SharedInboxActivities;
-use lemmy_apub_objects::objects::{SiteOrMultiOrCommunityOrUser, UserOrCommunity};
+use lemmy_apub_activities::{
+  activity_lists::SharedInboxActivities,
+  protocol::relay::create::RelayedCreate,
+  relay::receive_relayed_create,
+};
+use lemmy_apub_objects::{
+  objects::{SiteOrMultiOrCommunityOrUser, UserOrCommunityOrRelay, relay::ApubRelay},
+  utils::functions::{check_apub_id_valid, local_site_data_cached},
+};
 use lemmy_db_schema::source::{
   activity::{ReceivedActivity, SentActivity},
   community::Community,
+  relay::Relay,
 };
 use lemmy_db_schema_file::{InstanceId, enums::CommunityVisibility};
 use lemmy_db_views_community_follower_approval::PendingFollowerView;
 use lemmy_utils::{
   FEDERATION_CONTEXT,
-  error::{LemmyErrorExt, LemmyErrorType, LemmyResult, UntranslatedError},
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType, LemmyResult, UntranslatedError},
 };
 use serde::Deserialize;
 use std::time::Duration;
@@ -46,10 +57,7 @@ pub async fn shared_inbox(
   body: Bytes,
   data: Data<LemmyContext>,
 ) -> LemmyResult<HttpResponse> {
-  let receive_fut =
-    receive_activity_with_hook::<SharedInboxActivities, UserOrCommunity, LemmyContext>(
-      request, body, Dummy, &data,
-    );
+  let receive_fut = receive_shared_inbox(request, body, &data);
   // Set a timeout shorter than `REQWEST_TIMEOUT` for processing incoming activities. This is to
   // avoid taking a long time to process an incoming activity when a required data fetch times out.
   // In this case our own instance would timeout and be marked as dead by the sender. Better to
@@ -59,13 +67,69 @@ pub async fn shared_inbox(
     .with_lemmy_type(UntranslatedError::InboxTimeout.into())?
 }
 
+async fn receive_shared_inbox(
+  request: HttpRequest,
+  body: Bytes,
+  data: &Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  // Mastodon-style relays forward the original activity, signed with the relay key
+  if let Ok(activity) = serde_json::from_slice::<RelayedCreate>(&body)
+    && let Some(relay) = forwarding_relay(&request, &activity, data).await?
+  {
+    receive_relayed_create(activity, relay.0, data).await?;
+    return Ok(HttpResponse::Ok().finish());
+  }
+  receive_activity_with_hook::<SharedInboxActivities, UserOrCommunityOrRelay, LemmyContext>(
+    request, body, Dummy, data,
+  )
+  .await
+}
+
+/// Returns the relay which forwarded the activity, if the request is signed by a known relay
+/// instead of the activity actor.
+async fn forwarding_relay(
+  request: &HttpRequest,
+  activity: &RelayedCreate,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<Option<ApubRelay>> {
+  let Some(mut key_id) = request
+    .headers()
+    .get("signature")
+    .and_then(|h| h.to_str().ok())
+    .and_then(signature_key_id)
+  else {
+    return Ok(None);
+  };
+  if key_id.domain() == activity.actor().domain() {
+    return Ok(None);
+  }
+  key_id.set_fragment(None);
+  if Relay::read_from_apub_id(&mut context.pool(), &key_id.into())
+    .await?
+    .is_none()
+  {
+    return Ok(None);
+  }
+  // The relay is stored already, so this only verifies the signature
+  let relay = signing_actor::<ApubRelay>(request, None, context).await?;
+  Ok(Some(relay))
+}
+
+/// Reads the key id from a `Signature` header, like `keyId="https://relay.example/actor#main-key"`.
+fn signature_key_id(header: &str) -> Option<Url> {
+  header
+    .split(',')
+    .find_map(|param| param.trim().strip_prefix("keyId="))
+    .and_then(|key_id| Url::parse(key_id.trim_matches('"')).ok())
+}
+
 struct Dummy;
 
-impl ReceiveActivityHook<SharedInboxActivities, UserOrCommunity, LemmyContext> for Dummy {
+impl ReceiveActivityHook<SharedInboxActivities, UserOrCommunityOrRelay, LemmyContext> for Dummy {
   async fn hook(
     self,
     activity: &SharedInboxActivities,
-    _actor: &UserOrCommunity,
+    _actor: &UserOrCommunityOrRelay,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
     // Store received activities in the database. This ensures that the same activity doesn't get
@@ -159,6 +223,50 @@ async fn check_community_content_fetchable(
   }
 }
 
+/// Middleware for the apub routes of communities, users, posts and comments. With authorized fetch
+/// enabled, these require a valid HTTP signature from an actor whose instance isn't blocked (or is
+/// allowed, if there is an allowlist). Unsigned requests are rejected, so blocked instances can't
+/// read the content anonymously either.
+pub(crate) async fn authorized_fetch(
+  mut req: ServiceRequest,
+  next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+  check_authorized_fetch(&mut req, false).await?;
+  next.call(req).await
+}
+
+/// Same as [authorized_fetch], but for actor documents of users and communities. These are also
+/// served for unsigned requests, because other platforms fetch them without signature to get the
+/// key for verifying activities which we send. Requests signed by blocked instances are still
+/// rejected.
+pub(crate) async fn authorized_fetch_actor(
+  mut req: ServiceRequest,
+  next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+  check_authorized_fetch(&mut req, true).await?;
+  next.call(req).await
+}
+
+async fn check_authorized_fetch(
+  req: &mut ServiceRequest,
+  allow_unsigned: bool,
+) -> Result<(), actix_web::Error> {
+  let context = req.extract::<Data<LemmyContext>>().await?;
+  let local_site_data = local_site_data_cached(&mut context.pool()).await?;
+  if !local_site_data.authorized_fetch() {
+    return Ok(());
+  }
+  if allow_unsigned && !req.headers().contains_key("signature") {
+    return Ok(());
+  }
+  let signing_actor = signing_actor::<SiteOrMultiOrCommunityOrUser>(req.request(), None, &context)
+    .await
+    .map_err(|_| LemmyError::from(LemmyErrorType::NotFound))?;
+  check_apub_id_valid(signing_actor.id(), &local_site_data)
+    .map_err(|_| LemmyError::from(LemmyErrorType::NotFound))?;
+  Ok(())
+}
+
 pub(in crate::http) fn get_instance_id(s: &SiteOrMultiOrCommunityOrUser) -> InstanceId {
   use Either::*;
   match s {
@@ -168,3 +276,20 @@ pub(in crate::http) fn get_instance_id(s: &SiteOrMultiOrCommunityOrUser) -> Inst
     Right(Right(c)) => c.instance_id,
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_signature_key_id() -> LemmyResult<()> {
+    let header = r#"keyId="https://relay.example/actor#main-key",algorithm="rsa-sha256",headers="(request-target) host date digest",signature="abc=""#;
+    assert_eq!(
+      Some(Url::parse("https://relay.example/actor#main-key")?),
+      signature_key_id(header)
+    );
+    assert_eq!(None, signature_key_id(r#"algorithm="rsa-sha256""#));
+    Ok(())
+  }
+}
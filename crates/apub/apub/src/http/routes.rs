@@ -1,4 +1,6 @@
 use crate::http::{
+  authorized_fetch,
+  authorized_fetch_actor,
   comment::{get_apub_comment, get_apub_comment_context},
   community::{
     get_apub_community_featured,
@@ -6,6 +8,7 @@ use crate::http::{
     get_apub_community_http,
     get_apub_community_moderators,
     get_apub_community_outbox,
+    get_apub_community_rule_http,
     get_apub_community_tag_http,
     get_apub_person_multi_community,
     get_apub_person_multi_community_follows,
@@ -14,11 +17,16 @@ use crate::http::{
   person::{get_apub_person_http, get_apub_person_outbox},
   post::{get_apub_post, get_apub_post_context},
   shared_inbox,
-  site::{get_apub_site_http, get_apub_site_outbox},
+  site::{get_apub_site_http, get_apub_site_outbox, get_apub_site_rule_http},
 };
 use actix_web::{
+  FromRequest,
+  Handler,
+  Responder,
+  dev::HttpServiceFactory,
   guard::{Guard, GuardContext},
   http::{Method, header},
+  middleware::from_fn,
   web,
 };
 
@@ -26,54 +34,50 @@ pub fn config(cfg: &mut web::ServiceConfig) {
   cfg
     .route("/", web::get().to(get_apub_site_http))
     .route("/site_outbox", web::get().to(get_apub_site_outbox))
-    .route(
-      "/c/{community_name}",
-      web::get().to(get_apub_community_http),
-    )
-    .route(
+    .service(signed_get("/rule/{rule_id}", get_apub_site_rule_http))
+    .service(actor_get("/c/{community_name}", get_apub_community_http))
+    .service(signed_get(
       "/c/{community_name}/followers",
-      web::get().to(get_apub_community_followers),
-    )
-    .route(
+      get_apub_community_followers,
+    ))
+    .service(signed_get(
       "/c/{community_name}/outbox",
-      web::get().to(get_apub_community_outbox),
-    )
-    .route(
+      get_apub_community_outbox,
+    ))
+    .service(signed_get(
       "/c/{community_name}/featured",
-      web::get().to(get_apub_community_featured),
-    )
-    .route(
+      get_apub_community_featured,
+    ))
+    .service(signed_get(
       "/c/{community_name}/moderators",
-      web::get().to(get_apub_community_moderators),
-    )
-    .route(
+      get_apub_community_moderators,
+    ))
+    .service(signed_get(
       "/c/{community_name}/tag/{tag_name}",
-      web::get().to(get_apub_community_tag_http),
-    )
-    .route("/u/{user_name}", web::get().to(get_apub_person_http))
-    .route(
-      "/u/{user_name}/outbox",
-      web::get().to(get_apub_person_outbox),
-    )
-    .route(
+      get_apub_community_tag_http,
+    ))
+    .service(signed_get(
+      "/c/{community_name}/rule/{rule_id}",
+      get_apub_community_rule_http,
+    ))
+    .service(actor_get("/u/{user_name}", get_apub_person_http))
+    .service(signed_get("/u/{user_name}/outbox", get_apub_person_outbox))
+    .service(signed_get(
       "/m/{multi_name}",
-      web::get().to(get_apub_person_multi_community),
-    )
-    .route(
+      get_apub_person_multi_community,
+    ))
+    .service(signed_get(
       "/m/{multi_name}/following",
-      web::get().to(get_apub_person_multi_community_follows),
-    )
-    .route("/post/{post_id}", web::get().to(get_apub_post))
-    .route(
-      "/post/{post_id}/context",
-      web::get().to(get_apub_post_context),
-    )
-    .route("/comment/{comment_id}", web::get().to(get_apub_comment))
-    .route(
+      get_apub_person_multi_community_follows,
+    ))
+    .service(signed_get("/post/{post_id}", get_apub_post))
+    .service(signed_get("/post/{post_id}/context", get_apub_post_context))
+    .service(signed_get("/comment/{comment_id}", get_apub_comment))
+    .service(signed_get(
       "/comment/{comment_id}/context",
-      web::get().to(get_apub_comment_context),
-    )
-    .route("/activities/{type_}/{id}", web::get().to(get_activity));
+      get_apub_comment_context,
+    ))
+    .service(signed_get("/activities/{type_}/{id}", get_activity));
 
   cfg.service(
     web::scope("")
@@ -82,6 +86,32 @@ pub fn config(cfg: &mut web::ServiceConfig) {
   );
 }
 
+/// A GET route which requires a signed request if authorized fetch is enabled. Only the site actor
+/// is left out, as remote instances need to fetch its key to verify our own signed fetches.
+fn signed_get<F, Args>(path: &str, handler: F) -> impl HttpServiceFactory
+where
+  F: Handler<Args>,
+  Args: FromRequest + 'static,
+  F::Output: Responder + 'static,
+{
+  web::resource(path)
+    .wrap(from_fn(authorized_fetch))
+    .route(web::get().to(handler))
+}
+
+/// A GET route for a user or community actor, which can also be fetched without signature so that
+/// remote instances can read the public key.
+fn actor_get<F, Args>(path: &str, handler: F) -> impl HttpServiceFactory
+where
+  F: Handler<Args>,
+  Args: FromRequest + 'static,
+  F::Output: Responder + 'static,
+{
+  web::resource(path)
+    .wrap(from_fn(authorized_fetch_actor))
+    .route(web::get().to(handler))
+}
+
 /// Without this, things like webfinger or RSS feeds stop working, as all requests seem to get
 /// routed into the inbox service (because it covers the root path). So we filter out anything that
 /// definitely can't be an inbox request (based on Accept header and request method).
@@ -98,3 +128,81 @@ impl Guard for InboxRequestGuard {
     false
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use activitypub_federation::config::FederationMiddleware;
+  use actix_web::{
+    App,
+    dev::{Service, ServiceResponse},
+    http::StatusCode,
+    test::{self, TestRequest},
+  };
+  use lemmy_api_utils::context::LemmyContext;
+  use lemmy_db_schema::{
+    source::local_site::{LocalSite, LocalSiteUpdateForm},
+    test_data::TestData,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use serial_test::serial;
+
+  async fn fetch_status<S, R, B>(app: &S, req: R) -> StatusCode
+  where
+    S: Service<R, Response = ServiceResponse<B>, Error = actix_web::Error>,
+  {
+    match test::try_call_service(app, req).await {
+      Ok(res) => res.status(),
+      Err(e) => e.as_response_error().status_code(),
+    }
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_authorized_fetch() -> LemmyResult<()> {
+    let config = LemmyContext::init_test_federation_config().await;
+    let context = config.to_request_data();
+    let data = TestData::create(&mut context.pool()).await?;
+    let app = test::init_service(
+      App::new()
+        .wrap(FederationMiddleware::new(config))
+        .service(actor_get("/u/{user_name}", get_apub_person_http))
+        .service(signed_get("/u/{user_name}/outbox", get_apub_person_outbox)),
+    )
+    .await;
+    let actor_path = format!("/u/{}", data.person.name);
+    let outbox_path = format!("{actor_path}/outbox");
+    let get = |path: &str| TestRequest::get().uri(path).to_request();
+
+    // Without authorized fetch, unsigned requests are accepted
+    assert_eq!(StatusCode::OK, fetch_status(&app, get(&actor_path)).await);
+    assert_eq!(StatusCode::OK, fetch_status(&app, get(&outbox_path)).await);
+
+    let form = LocalSiteUpdateForm {
+      federation_authorized_fetch: Some(true),
+      ..Default::default()
+    };
+    LocalSite::update(&mut context.pool(), &form).await?;
+
+    // Actors can still be fetched without signature to read the key, but not other content
+    assert_eq!(StatusCode::OK, fetch_status(&app, get(&actor_path)).await);
+    assert_eq!(
+      StatusCode::NOT_FOUND,
+      fetch_status(&app, get(&outbox_path)).await
+    );
+
+    // Signed requests are always verified, also for actors
+    let invalid_signature = TestRequest::get()
+      .uri(&actor_path)
+      .insert_header(("signature", "keyId=\"invalid\",signature=\"invalid\""))
+      .to_request();
+    assert_eq!(
+      StatusCode::NOT_FOUND,
+      fetch_status(&app, invalid_signature).await
+    );
+
+    LocalSite::delete(&mut context.pool()).await?;
+    data.delete(&mut context.pool()).await?;
+    Ok(())
+  }
+}
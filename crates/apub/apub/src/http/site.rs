@@ -1,10 +1,21 @@
 use crate::protocol::collections::url_collection::UrlCollection;
-use activitypub_federation::{config::Data, traits::Object};
-use actix_web::HttpResponse;
+use activitypub_federation::{
+  actix_web::response::create_http_response,
+  config::Data,
+  traits::Object,
+};
+use actix_web::{HttpResponse, web::Path};
 use lemmy_api_utils::context::LemmyContext;
-use lemmy_apub_objects::objects::instance::ApubSite;
-use lemmy_db_schema::source::site::Site;
-use lemmy_utils::{FEDERATION_CONTEXT, error::LemmyResult};
+use lemmy_apub_objects::{objects::instance::ApubSite, protocol::rules::CommunityRule};
+use lemmy_db_schema::{
+  newtypes::RuleId,
+  source::{rule::Rule, site::Site},
+};
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::{
+  FEDERATION_CONTEXT,
+  error::{LemmyErrorType, LemmyResult},
+};
 
 pub(crate) async fn get_apub_site_http(context: Data<LemmyContext>) -> LemmyResult<HttpResponse> {
   let site: ApubSite = Site::read_local(&mut context.pool()).await?.into();
@@ -19,3 +30,19 @@ pub(crate) async fn get_apub_site_outbox(context: Data<LemmyContext>) -> LemmyRe
   );
   UrlCollection::new_empty_response(outbox_id)
 }
+
+/// Return a site rule, which is referenced by reports.
+pub(crate) async fn get_apub_site_rule_http(
+  rule_id: Path<i32>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let rule = Rule::read(&mut context.pool(), RuleId(rule_id.into_inner())).await?;
+  if rule.community_id.is_some() || rule.deleted {
+    Err(LemmyErrorType::NotFound)?
+  }
+
+  Ok(create_http_response(
+    CommunityRule::to_json(rule),
+    &FEDERATION_CONTEXT,
+  )?)
+}
@@ -1,6 +1,7 @@
-use activitypub_federation::kinds::collection::OrderedCollectionType;
+use activitypub_federation::kinds::collection::{OrderedCollectionPageType, OrderedCollectionType};
 use lemmy_apub_activities::protocol::community::announce::AnnounceActivity;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de::DeserializeOwned};
+use serde_json::Value;
 use url::Url;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -9,5 +10,40 @@ pub struct GroupOutbox {
   pub(crate) r#type: OrderedCollectionType,
   pub(crate) id: Url,
   pub(crate) total_items: i32,
+  /// Only contains the newest posts, kept for compatibility with older Lemmy versions.
   pub(crate) ordered_items: Vec<AnnounceActivity>,
+  /// Link to the paginated outbox, which also includes comments and allows walking back through
+  /// the whole history of the community.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) first: Option<Url>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupOutboxPage {
+  pub(crate) r#type: OrderedCollectionPageType,
+  pub(crate) id: Url,
+  pub(crate) part_of: Url,
+  /// Either posts, or comments of a single post in the order they were published.
+  #[serde(deserialize_with = "deserialize_skip_item_errors", default)]
+  pub(crate) ordered_items: Vec<AnnounceActivity>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) next: Option<Url>,
+}
+
+/// Like [deserialize_skip_error](activitypub_federation::protocol::helpers::deserialize_skip_error)
+/// but for each list item, so that a single activity which can't be parsed doesn't make the whole
+/// page unusable.
+fn deserialize_skip_item_errors<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+  T: DeserializeOwned,
+  D: Deserializer<'de>,
+{
+  let items = Vec::<Value>::deserialize(deserializer)?;
+  Ok(
+    items
+      .into_iter()
+      .filter_map(|i| serde_json::from_value(i).ok())
+      .collect(),
+  )
 }
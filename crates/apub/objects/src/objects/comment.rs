@@ -39,13 +39,14 @@ use lemmy_api_utils::{
 use lemmy_db_schema::source::{
   comment::{Comment, CommentInsertForm, CommentUpdateForm},
   community::Community,
+  federation_policy::FederationPolicy,
   person::Person,
   post::Post,
 };
 use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::{
   error::{LemmyError, LemmyResult, UntranslatedError},
-  utils::markdown::markdown_to_html,
+  utils::markdown::{image_links::markdown_strip_images, markdown_to_html},
 };
 use std::ops::Deref;
 use url::Url;
@@ -205,6 +206,13 @@ impl Object for ApubComment {
     let slur_regex = slur_regex(context).await?;
     let url_blocklist = get_url_blocklist(context).await?;
     let content = append_attachments_to_comment(content, &note.attachment, context).await?;
+    let policy =
+      FederationPolicy::read_for_instance(&mut context.pool(), creator.instance_id).await?;
+    let content = if policy.is_some_and(|p| p.strip_media) {
+      markdown_strip_images(content)
+    } else {
+      content
+    };
     let content = process_markdown(&content, &slur_regex, &url_blocklist, context).await?;
     let content = markdown_rewrite_remote_links(content, context).await;
     let language_id = Some(
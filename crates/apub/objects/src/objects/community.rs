@@ -1,6 +1,6 @@
 use crate::{
   objects::instance::fetch_instance_actor_for_object,
-  protocol::{group::Group, tags::CommunityTag},
+  protocol::{group::Group, rules::CommunityRule, tags::CommunityTag},
   utils::{
     functions::{
       GetActorType,
@@ -36,6 +36,8 @@ use lemmy_db_schema::{
   source::{
     actor_language::CommunityLanguage,
     community::{Community, CommunityInsertForm, CommunityUpdateForm},
+    federation_policy::FederationPolicy,
+    rule::Rule,
     tag::Tag,
   },
   traits::ApubActor,
@@ -59,6 +61,11 @@ pub static FETCH_COMMUNITY_COLLECTIONS: OnceLock<
   fn(ApubCommunity, Group, Data<LemmyContext>) -> (),
 > = OnceLock::new();
 
+/// Imports the history of a remote community from its outbox, with the given maximum number of
+/// pages. Implemented in the main apub crate.
+pub static BACKFILL_COMMUNITY: OnceLock<fn(ApubCommunity, i32, Data<LemmyContext>)> =
+  OnceLock::new();
+
 #[derive(Clone, Debug)]
 pub struct ApubCommunity(Community);
 
@@ -118,6 +125,11 @@ impl Object for ApubCommunity {
     let langs = CommunityLanguage::read(&mut data.pool(), community_id).await?;
     let language = LanguageTag::new_multiple(langs, &mut data.pool()).await?;
     let post_tags = Tag::read_for_community(&mut data.pool(), community_id).await?;
+    let rules = Rule::read_for_community(&mut data.pool(), Some(community_id)).await?;
+    let moved_to = match self.moved_to_id {
+      Some(id) => Some(Community::read(&mut data.pool(), id).await?.ap_id.into()),
+      None => None,
+    };
     let group = Group {
       kind: GroupType::Group,
       id: self.id().clone().into(),
@@ -146,6 +158,8 @@ impl Object for ApubCommunity {
       manually_approves_followers: Some(self.visibility == CommunityVisibility::Private),
       discoverable: Some(self.visibility != CommunityVisibility::Unlisted),
       tag: post_tags.into_iter().map(CommunityTag::to_json).collect(),
+      rules: rules.into_iter().map(CommunityRule::to_json).collect(),
+      moved_to,
     };
     Ok(group)
   }
@@ -170,12 +184,20 @@ impl Object for ApubCommunity {
   }
 
   /// Converts a `Group` to `Community`, inserts it into the database and updates moderators.
-  async fn from_json(group: Group, context: &Data<Self::DataType>) -> LemmyResult<ApubCommunity> {
+  async fn from_json(
+    mut group: Group,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<ApubCommunity> {
     let local_site = SiteView::read_local(&mut context.pool())
       .await
       .ok()
       .map(|s| s.local_site);
     let instance_id = fetch_instance_actor_for_object(&group.id, context).await?;
+    let policy = FederationPolicy::read_for_instance(&mut context.pool(), instance_id).await?;
+    let strip_media = policy.as_ref().is_some_and(|p| p.strip_media);
+    if policy.as_ref().is_some_and(|p| p.force_nsfw) {
+      group.sensitive = Some(true);
+    }
 
     let slur_regex = slur_regex(context).await?;
     let url_blocklist = get_url_blocklist(context).await?;
@@ -183,8 +205,10 @@ impl Object for ApubCommunity {
     let description =
       process_markdown_opt(&description, &slur_regex, &url_blocklist, context).await?;
     let description = markdown_rewrite_remote_links_opt(description, context).await;
-    let icon = proxy_image_link_opt_apub(group.icon.clone().map(|i| i.url), context).await?;
-    let banner = proxy_image_link_opt_apub(group.image.clone().map(|i| i.url), context).await?;
+    let icon = group.icon.clone().filter(|_| !strip_media).map(|i| i.url);
+    let icon = proxy_image_link_opt_apub(icon, context).await?;
+    let banner = group.image.clone().filter(|_| !strip_media).map(|i| i.url);
+    let banner = proxy_image_link_opt_apub(banner, context).await?;
     let visibility = Some(community_visibility(&group));
 
     // If NSFW is not allowed, then remove NSFW communities
@@ -192,6 +216,15 @@ impl Object for ApubCommunity {
       .err()
       .map(|_| true);
 
+    // Only follow the redirect if the new community is already known, to avoid fetching chains
+    // of communities.
+    let moved_to_id = match &group.moved_to {
+      Some(moved_to) if moved_to != &group.id => {
+        moved_to.dereference_local(context).await.ok().map(|c| c.id)
+      }
+      _ => None,
+    };
+
     let form = CommunityInsertForm {
       published_at: group.published,
       updated_at: group.updated,
@@ -223,6 +256,7 @@ impl Object for ApubCommunity {
       posting_restricted_to_mods: group.posting_restricted_to_mods,
       featured_url: group.featured.clone().clone().map(Into::into),
       visibility,
+      moved_to_id,
       ..CommunityInsertForm::new(
         instance_id,
         group.preferred_username.clone(),
@@ -248,6 +282,18 @@ impl Object for ApubCommunity {
     let existing_tags = Tag::read_for_community(&mut context.pool(), community.id).await?;
     Tag::update_many(&mut context.pool(), new_tags, existing_tags).await?;
 
+    // Rules are stored by their id, so they must belong to the same domain as the community.
+    for rule in &group.rules {
+      verify_domains_match(&rule.id, group.id.inner())?;
+    }
+    let new_rules = group
+      .rules
+      .iter()
+      .map(|r| r.to_insert_form(community.id))
+      .collect();
+    let existing_rules = Rule::read_for_community(&mut context.pool(), Some(community.id)).await?;
+    Rule::update_many(&mut context.pool(), community.id, new_rules, existing_rules).await?;
+
     let community: ApubCommunity = community.into();
 
     // These collections are not necessary for Lemmy to work, so ignore errors. Reset request count
@@ -6,6 +6,7 @@ pub mod multi_community_collection;
 pub mod person;
 pub mod post;
 pub mod private_message;
+pub mod relay;
 
 use comment::ApubComment;
 use community::ApubCommunity;
@@ -14,6 +15,7 @@ use instance::ApubSite;
 use multi_community::ApubMultiCommunity;
 use person::ApubPerson;
 use post::ApubPost;
+use relay::ApubRelay;
 
 // TODO: some of these are redundant?
 
@@ -25,6 +27,9 @@ pub type ReportableObjects = Either<PostOrComment, ApubCommunity>;
 
 pub type UserOrCommunity = Either<ApubPerson, ApubCommunity>;
 
+/// Actors which can send activities to the shared inbox.
+pub type UserOrCommunityOrRelay = Either<UserOrCommunity, ApubRelay>;
+
 pub type SiteOrMultiOrCommunityOrUser =
   Either<Either<ApubSite, ApubMultiCommunity>, UserOrCommunity>;
 
@@ -28,7 +28,10 @@ use lemmy_api_utils::{
   },
 };
 use lemmy_db_schema::{
-  source::person::{Person as DbPerson, PersonInsertForm, PersonUpdateForm},
+  source::{
+    federation_policy::FederationPolicy,
+    person::{Person as DbPerson, PersonInsertForm, PersonUpdateForm},
+  },
   traits::ApubActor,
 };
 use lemmy_db_schema_file::enums::ActorType;
@@ -150,8 +153,13 @@ impl Object for ApubPerson {
     let bio = read_from_string_or_source_opt(&person.summary, &None, &person.source);
     let bio = process_markdown_opt(&bio, &slur_regex, &url_blocklist, context).await?;
     let bio = markdown_rewrite_remote_links_opt(bio, context).await;
-    let avatar = proxy_image_link_opt_apub(person.icon.map(|i| i.url), context).await?;
-    let banner = proxy_image_link_opt_apub(person.image.map(|i| i.url), context).await?;
+    let policy = FederationPolicy::read_for_instance(&mut context.pool(), instance_id).await?;
+    let strip_media = policy.as_ref().is_some_and(|p| p.strip_media);
+    let no_avatar = strip_media || policy.as_ref().is_some_and(|p| p.no_avatar);
+    let avatar = person.icon.filter(|_| !no_avatar).map(|i| i.url);
+    let avatar = proxy_image_link_opt_apub(avatar, context).await?;
+    let banner = person.image.filter(|_| !strip_media).map(|i| i.url);
+    let banner = proxy_image_link_opt_apub(banner, context).await?;
 
     let person_form = PersonInsertForm {
       name: person.preferred_username,
@@ -164,7 +172,10 @@ impl Object for ApubPerson {
       ap_id: Some(person.id.into()),
       bio,
       local: Some(false),
-      bot_account: Some(person.kind == UserTypes::Service),
+      bot_account: Some(matches!(
+        person.kind,
+        UserTypes::Service | UserTypes::Application
+      )),
       private_key: None,
       public_key: person.public_key.public_key_pem,
       last_refreshed_at: Some(Utc::now()),
@@ -178,7 +189,18 @@ impl Object for ApubPerson {
       matrix_user_id: person.matrix_user_id,
       instance_id,
     };
-    let person = DbPerson::upsert(&mut context.pool(), &person_form).await?;
+    let mut person = DbPerson::upsert(&mut context.pool(), &person_form).await?;
+
+    // The upsert leaves existing images in place, so remove those which were fetched before the
+    // policy was set.
+    if (no_avatar && person.avatar.is_some()) || (strip_media && person.banner.is_some()) {
+      let form = PersonUpdateForm {
+        avatar: no_avatar.then_some(None),
+        banner: strip_media.then_some(None),
+        ..Default::default()
+      };
+      person = DbPerson::update(&mut context.pool(), person.id, &form).await?;
+    }
 
     Ok(person.into())
   }
@@ -216,7 +238,7 @@ pub(crate) mod tests {
     utils::test::{file_to_json_object, parse_lemmy_person},
   };
   use activitypub_federation::fetch::object_id::ObjectId;
-  use lemmy_db_schema::source::instance::Instance;
+  use lemmy_db_schema::source::{federation_policy::FederationPolicyForm, instance::Instance};
   use pretty_assertions::assert_eq;
   use serial_test::serial;
 
@@ -234,6 +256,49 @@ pub(crate) mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_parse_person_no_avatar_policy() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let (person, _) = parse_lemmy_person(&context).await?;
+    assert!(person.avatar.is_some());
+    assert!(person.banner.is_some());
+
+    let form = FederationPolicyForm {
+      instance_id: person.instance_id,
+      silence: false,
+      strip_media: false,
+      force_nsfw: false,
+      reject_reports: false,
+      reject_votes: false,
+      no_avatar: true,
+      reason: "avatars".to_string(),
+    };
+    FederationPolicy::upsert(&mut context.pool(), &form).await?;
+
+    // Refetching removes the avatar which was stored before the policy was set
+    let json = file_to_json_object("../apub/assets/lemmy/objects/person.json")?;
+    let person = ApubPerson::from_json(json, &context).await?;
+    assert!(person.avatar.is_none());
+    assert!(person.banner.is_some());
+
+    // Stripping media also removes the banner
+    let form = FederationPolicyForm {
+      no_avatar: false,
+      strip_media: true,
+      ..form
+    };
+    FederationPolicy::upsert(&mut context.pool(), &form).await?;
+    let json = file_to_json_object("../apub/assets/lemmy/objects/person.json")?;
+    let person = ApubPerson::from_json(json, &context).await?;
+    assert!(person.avatar.is_none());
+    assert!(person.banner.is_none());
+    assert_eq!(context.request_count(), 0);
+
+    Instance::delete_all(&mut context.pool()).await?;
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_parse_pleroma_person() -> LemmyResult<()> {
@@ -39,6 +39,7 @@ use lemmy_api_utils::{
 };
 use lemmy_db_schema::source::{
   community::Community,
+  federation_policy::FederationPolicy,
   local_site::LocalSite,
   person::Person,
   post::{Post, PostInsertForm, PostUpdateForm},
@@ -51,7 +52,7 @@ use lemmy_utils::{
   error::{LemmyError, LemmyResult},
   spawn_try_task,
   utils::{
-    markdown::markdown_to_html,
+    markdown::{image_links::markdown_strip_images, markdown_to_html},
     slurs::check_slurs_opt,
     validation::{is_url_blocked, is_valid_url},
   },
@@ -200,13 +201,19 @@ impl Object for ApubPost {
     Ok(())
   }
 
-  async fn from_json(page: Page, context: &Data<Self::DataType>) -> LemmyResult<ApubPost> {
+  async fn from_json(mut page: Page, context: &Data<Self::DataType>) -> LemmyResult<ApubPost> {
     let local_site = SiteView::read_local(&mut context.pool())
       .await
       .ok()
       .map(|s| s.local_site);
     let creator = page.creator()?.dereference(context).await?;
-    let community = page.community(context).await?;
+    let mut community = page.community(context).await?;
+    // New posts in a merged community go to the community which it was merged into
+    if let Some(moved_to_id) = community.moved_to_id {
+      community = Community::read(&mut context.pool(), moved_to_id)
+        .await?
+        .into();
+    }
 
     // Prevent posts from non-mod users in local, restricted community. If its a remote community
     // then its possible that the restricted setting was enabled recently, so existing user posts
@@ -219,6 +226,13 @@ impl Object for ApubPost {
       )
       .await?;
     }
+    let policy =
+      FederationPolicy::read_for_instance(&mut context.pool(), creator.instance_id).await?;
+    let strip_media = policy.as_ref().is_some_and(|p| p.strip_media);
+    if policy.as_ref().is_some_and(|p| p.force_nsfw) {
+      page.sensitive = Some(true);
+    }
+
     let mut name = page
       .name
       .clone()
@@ -244,7 +258,10 @@ impl Object for ApubPost {
       name = name.chars().take(MAX_TITLE_LENGTH).collect();
     }
 
-    let first_attachment = page.attachment.first();
+    let first_attachment = page
+      .attachment
+      .first()
+      .filter(|a| !(strip_media && matches!(a, Attachment::Image(_))));
     let url = if let Some(attachment) = first_attachment.cloned() {
       Some(attachment.url())
     } else if page.kind == PageType::Video {
@@ -273,6 +290,13 @@ impl Object for ApubPost {
     let slur_regex = slur_regex(context).await?;
 
     let body = read_from_string_or_source_opt(&page.content, &page.media_type, &page.source);
+    let body = body.map(|b| {
+      if strip_media {
+        markdown_strip_images(b)
+      } else {
+        b
+      }
+    });
     let body = process_markdown_opt(&body, &slur_regex, &url_blocklist, context).await?;
     let body = markdown_rewrite_remote_links_opt(body, context).await;
     let language_id = Some(
@@ -301,7 +325,17 @@ impl Object for ApubPost {
     form = plugin_hook_before("federated_post_after_receive", form).await?;
 
     let timestamp = page.updated.or(page.published).unwrap_or_else(Utc::now);
-    let post = Post::insert_apub(&mut context.pool(), timestamp, &form).await?;
+    let mut post = Post::insert_apub(&mut context.pool(), timestamp, &form).await?;
+
+    // The upsert leaves an existing thumbnail in place, so remove it if it was fetched before the
+    // policy was set.
+    if strip_media && post.thumbnail_url.is_some() {
+      let form = PostUpdateForm {
+        thumbnail_url: Some(None),
+        ..Default::default()
+      };
+      post = Post::update(&mut context.pool(), post.id, &form).await?;
+    }
     plugin_hook_after("federated_post_after_receive", &post);
 
     update_apub_post_tags(&page, &post, context).await?;
@@ -309,8 +343,10 @@ impl Object for ApubPost {
     let post_ = post.clone();
     let context_ = context.clone();
 
-    // Avoid regenerating metadata if the post already existed with the same url
-    let no_generate_metadata = orig_post.ok().flatten().is_some_and(|p| p.url == post.url);
+    // Avoid regenerating metadata if the post already existed with the same url, and never fetch
+    // thumbnails for instances whose media is stripped
+    let no_generate_metadata =
+      strip_media || orig_post.ok().flatten().is_some_and(|p| p.url == post.url);
     if !no_generate_metadata {
       // Generates a post thumbnail in background task, because some sites can be very slow to
       // respond.
@@ -376,7 +412,7 @@ mod tests {
     objects::ApubPerson,
     utils::test::{file_to_json_object, parse_lemmy_community, parse_lemmy_person},
   };
-  use lemmy_db_schema::source::instance::Instance;
+  use lemmy_db_schema::source::{federation_policy::FederationPolicyForm, instance::Instance};
   use pretty_assertions::assert_eq;
   use serial_test::serial;
 
@@ -404,6 +440,49 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_parse_post_media_policy() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let (person, _) = parse_lemmy_person(&context).await?;
+    parse_lemmy_community(&context).await?;
+
+    let form = FederationPolicyForm {
+      instance_id: person.instance_id,
+      silence: false,
+      strip_media: true,
+      force_nsfw: true,
+      reject_reports: false,
+      reject_votes: false,
+      no_avatar: false,
+      reason: "media".to_string(),
+    };
+    FederationPolicy::upsert(&mut context.pool(), &form).await?;
+
+    let mut json: Page = file_to_json_object("../apub/assets/lemmy/objects/page.json")?;
+    let image_url = Url::parse("https://enterprise.lemmy.ml/pictrs/image/eOtYb9iEiB.png")?;
+    json.attachment = vec![Attachment::new(
+      image_url,
+      Some("image/png".to_string()),
+      None,
+    )];
+    if let Some(source) = json.source.as_mut() {
+      source.content = "Look at this ![image](https://enterprise.lemmy.ml/image.png)".to_string();
+    }
+    let post = ApubPost::from_json(json, &context).await?;
+
+    assert!(post.url.is_none());
+    assert!(post.thumbnail_url.is_none());
+    let body = post.body.clone().unwrap_or_default();
+    assert!(body.contains("[image]"));
+    assert!(!body.contains("!["));
+    assert!(post.nsfw);
+    assert_eq!(context.request_count(), 0);
+
+    Instance::delete_all(&mut context.pool()).await?;
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_convert_mastodon_post_title() -> LemmyResult<()> {
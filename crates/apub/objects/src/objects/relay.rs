@@ -0,0 +1,147 @@
+use crate::{protocol::relay::RelayActor, utils::functions::check_apub_id_valid_with_strictness};
+use activitypub_federation::{
+  config::Data,
+  fetch::fetch_object_http,
+  protocol::verification::{verify_domains_match, verify_is_remote_object},
+  traits::{Actor, Object},
+};
+use chrono::{DateTime, Utc};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::source::{
+  instance::Instance,
+  relay::{Relay, RelayInsertForm, RelayUpdateForm},
+};
+use lemmy_db_schema_file::enums::RelayMode;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult, UntranslatedError};
+use std::ops::Deref;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub struct ApubRelay(pub Relay);
+
+impl Deref for ApubRelay {
+  type Target = Relay;
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<Relay> for ApubRelay {
+  fn from(r: Relay) -> Self {
+    ApubRelay(r)
+  }
+}
+
+impl ApubRelay {
+  /// Fetch the relay actor and store it. The follow needs to be sent separately.
+  pub async fn subscribe(
+    ap_id: Url,
+    mode: RelayMode,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<Relay> {
+    let actor: RelayActor = fetch_object_http(&ap_id, context).await?.object;
+    ApubRelay::verify(&actor, &ap_id, context).await?;
+
+    let domain = ap_id.domain().ok_or(UntranslatedError::UrlWithoutDomain)?;
+    let instance = Instance::read_or_create(&mut context.pool(), domain).await?;
+    let form = RelayInsertForm::new(
+      actor.id.into(),
+      actor.shared_inbox_or_inbox().into(),
+      actor.public_key.public_key_pem,
+      instance.id,
+      mode,
+    );
+    Relay::create(&mut context.pool(), &form).await
+  }
+}
+
+#[async_trait::async_trait]
+impl Object for ApubRelay {
+  type DataType = LemmyContext;
+  type Kind = RelayActor;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    self.ap_id.inner()
+  }
+
+  fn last_refreshed_at(&self) -> Option<DateTime<Utc>> {
+    Some(self.last_refreshed_at)
+  }
+
+  async fn read_from_id(
+    object_id: Url,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<Option<Self>> {
+    Ok(
+      Relay::read_from_apub_id(&mut context.pool(), &object_id.into())
+        .await?
+        .map(Into::into),
+    )
+  }
+
+  async fn delete(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    Relay::delete(&mut context.pool(), self.id).await?;
+    Ok(())
+  }
+
+  /// Relays are always remote, so there is nothing to serve.
+  async fn into_json(self, _context: &Data<Self::DataType>) -> LemmyResult<RelayActor> {
+    Err(LemmyErrorType::NotFound.into())
+  }
+
+  async fn verify(
+    actor: &RelayActor,
+    expected_domain: &Url,
+    context: &Data<Self::DataType>,
+  ) -> LemmyResult<()> {
+    check_apub_id_valid_with_strictness(actor.id.inner(), false, context).await?;
+    verify_domains_match(actor.id.inner(), expected_domain)?;
+    verify_is_remote_object(&actor.id, context)?;
+    Ok(())
+  }
+
+  /// Only updates relays which the admins subscribed to. Other actors are never stored as relay,
+  /// so that their activities can't be mistaken for relayed ones.
+  async fn from_json(actor: RelayActor, context: &Data<Self::DataType>) -> LemmyResult<Self> {
+    let relay = Relay::read_from_apub_id(&mut context.pool(), &actor.id.clone().into())
+      .await?
+      .ok_or(LemmyErrorType::NotFound)?;
+    let form = RelayUpdateForm {
+      inbox_url: Some(actor.shared_inbox_or_inbox().into()),
+      public_key: Some(actor.public_key.public_key_pem),
+      last_refreshed_at: Some(Utc::now()),
+      ..Default::default()
+    };
+    Ok(
+      Relay::update(&mut context.pool(), relay.id, &form)
+        .await?
+        .into(),
+    )
+  }
+}
+
+impl Actor for ApubRelay {
+  fn public_key_pem(&self) -> &str {
+    &self.public_key
+  }
+
+  fn private_key_pem(&self) -> Option<String> {
+    None
+  }
+
+  fn inbox(&self) -> Url {
+    self.inbox_url.clone().into()
+  }
+}
+
+impl RelayActor {
+  fn shared_inbox_or_inbox(&self) -> Url {
+    self
+      .endpoints
+      .as_ref()
+      .map(|e| e.shared_inbox.clone())
+      .unwrap_or(self.inbox.clone())
+  }
+}
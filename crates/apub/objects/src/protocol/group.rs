@@ -1,6 +1,6 @@
 use crate::{
   objects::community::ApubCommunity,
-  protocol::tags::CommunityTag,
+  protocol::{rules::CommunityRule, tags::CommunityTag},
   utils::protocol::{AttributedTo, Endpoints, ImageObject, LanguageTag, Source},
 };
 use activitypub_federation::{
@@ -64,4 +64,10 @@ pub struct Group {
   pub(crate) discoverable: Option<bool>,
   #[serde(deserialize_with = "deserialize_skip_error", default)]
   pub(crate) tag: Vec<CommunityTag>,
+  // lemmy extension
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) rules: Vec<CommunityRule>,
+  /// Set if the community was merged into another one
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) moved_to: Option<ObjectId<ApubCommunity>>,
 }
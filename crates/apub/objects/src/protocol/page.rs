@@ -168,6 +168,40 @@ impl Page {
         .ok_or_else(|| UntranslatedError::PageDoesNotSpecifyCreator.into()),
     }
   }
+
+  /// Candidates for the community of the post, in order of preference.
+  fn community_ids(&self) -> Vec<ObjectId<ApubCommunity>> {
+    if let Some(audience) = &self.audience {
+      return vec![audience.clone()];
+    }
+    match &self.attributed_to {
+      AttributedTo::Lemmy(_) => self
+        .to
+        .iter()
+        .merge(self.cc.iter())
+        // to and cc fields can also contain this value to indicate a public object.
+        // Skip it to avoid unnecessary http requests.
+        .filter(|cid| cid.as_str() != "https://www.w3.org/ns/activitystreams#Public")
+        .map(|cid| ObjectId::from(cid.clone()))
+        .collect(),
+      AttributedTo::Peertube(p) => p
+        .iter()
+        .filter(|a| a.kind == PersonOrGroupType::Group)
+        .map(|a| ObjectId::from(a.id.clone().into_inner()))
+        .take(1)
+        .collect(),
+    }
+  }
+
+  /// Like `community()`, but without fetching communities which aren't stored locally yet.
+  pub async fn known_community(&self, context: &Data<LemmyContext>) -> LemmyResult<ApubCommunity> {
+    for cid in self.community_ids() {
+      if let Ok(c) = cid.dereference_local(context).await {
+        return Ok(c);
+      }
+    }
+    Err(LemmyErrorType::NotFound.into())
+  }
 }
 
 impl Attachment {
@@ -217,36 +251,26 @@ impl InCommunity for Page {
     if let Some(audience) = &self.audience {
       return audience.dereference(context).await;
     }
-    let community = match &self.attributed_to {
+    match &self.attributed_to {
       AttributedTo::Lemmy(_) => {
-        let mut iter = self.to.iter().merge(self.cc.iter());
-        loop {
-          if let Some(cid) = iter.next() {
-            // to and cc fields can also contain this value to indicate a public object.
-            // Skip it to avoid unnecessary http requests.
-            if cid.as_str() == "https://www.w3.org/ns/activitystreams#Public" {
-              continue;
-            }
-            let cid = ObjectId::<ApubCommunity>::from(cid.clone());
-            if let Ok(c) = cid.dereference(context).await {
-              break c;
-            }
-          } else {
-            Err(LemmyErrorType::NotFound)?;
+        for cid in self.community_ids() {
+          if let Ok(c) = cid.dereference(context).await {
+            return Ok(c);
           }
         }
+        Err(LemmyErrorType::NotFound.into())
       }
-      AttributedTo::Peertube(p) => {
-        p.iter()
-          .find(|a| a.kind == PersonOrGroupType::Group)
-          .map(|a| ObjectId::<ApubCommunity>::from(a.id.clone().into_inner()))
+      // There is only a single channel, so return the actual error if it can't be fetched
+      AttributedTo::Peertube(_) => {
+        self
+          .community_ids()
+          .into_iter()
+          .next()
           .ok_or(LemmyErrorType::NotFound)?
           .dereference(context)
-          .await?
+          .await
       }
-    };
-
-    Ok(community)
+    }
   }
 }
 
@@ -19,6 +19,8 @@ pub enum UserTypes {
   Person,
   Service,
   Organization,
+  /// Instance actor, eg sent by Mastodon as the actor of reports
+  Application,
 }
 
 #[skip_serializing_none]
@@ -0,0 +1,23 @@
+use crate::{objects::relay::ApubRelay, utils::protocol::Endpoints};
+use activitypub_federation::{fetch::object_id::ObjectId, protocol::public_key::PublicKey};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum RelayTypes {
+  Application,
+  Service,
+}
+
+/// The actor of a LitePub or Mastodon compatible relay. Only the fields needed for following it
+/// and verifying its signatures are parsed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayActor {
+  #[serde(rename = "type")]
+  pub(crate) kind: RelayTypes,
+  pub(crate) id: ObjectId<ApubRelay>,
+  pub(crate) inbox: Url,
+  pub(crate) endpoints: Option<Endpoints>,
+  pub(crate) public_key: PublicKey,
+}
@@ -0,0 +1,49 @@
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::rule::{Rule, RuleInsertForm},
+};
+use lemmy_utils::utils::validation::truncate_rule_title;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+enum CommunityRuleType {
+  #[default]
+  Rule,
+}
+
+/// A numbered community rule. Reports from remote users reference the rule by its id.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommunityRule {
+  #[serde(rename = "type")]
+  kind: CommunityRuleType,
+  pub id: Url,
+  /// Rule title
+  pub name: String,
+  /// Rule description
+  pub content: Option<String>,
+  pub position: Option<i32>,
+}
+
+impl CommunityRule {
+  pub fn to_json(rule: Rule) -> Self {
+    CommunityRule {
+      kind: Default::default(),
+      id: rule.ap_id.into(),
+      name: rule.title,
+      content: rule.description,
+      position: Some(rule.position),
+    }
+  }
+
+  pub fn to_insert_form(&self, community_id: CommunityId) -> RuleInsertForm {
+    RuleInsertForm {
+      ap_id: Some(self.id.clone().into()),
+      description: self.content.clone(),
+      position: self.position,
+      deleted: Some(false),
+      ..RuleInsertForm::new(Some(community_id), truncate_rule_title(&self.name))
+    }
+  }
+}
@@ -13,7 +13,7 @@ use either::Either;
 use html2md::parse_html;
 use lemmy_api_utils::{context::LemmyContext, utils::check_is_mod_or_admin};
 use lemmy_db_schema::source::{
-  community::Community,
+  community::{Community, CommunityActions},
   instance::{Instance, InstanceActions},
   local_site::LocalSite,
 };
@@ -64,6 +64,15 @@ pub struct LocalSiteData {
   blocked_instances: Vec<Instance>,
 }
 
+impl LocalSiteData {
+  pub fn authorized_fetch(&self) -> bool {
+    self
+      .local_site
+      .as_ref()
+      .is_some_and(|l| l.federation_authorized_fetch)
+  }
+}
+
 pub async fn local_site_data_cached(pool: &mut DbPool<'_>) -> LemmyResult<Arc<LocalSiteData>> {
   // All incoming and outgoing federation actions read the blocklist/allowlist and slur filters
   // multiple times. This causes a huge number of database reads if we hit the db directly. So we
@@ -221,6 +230,16 @@ pub async fn verify_person_in_community(
   CommunityPersonBanView::check(&mut context.pool(), person_id, community_id).await
 }
 
+/// Check that the person isn't muted in the community, which only allows them to read and vote.
+pub async fn verify_person_not_muted(
+  person_id: &ObjectId<ApubPerson>,
+  community: &ApubCommunity,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let person = person_id.dereference(context).await?;
+  CommunityActions::check_mute(&mut context.pool(), person.id, community.id).await
+}
+
 /// Fetches the person and community or site to verify their type, then checks if person is banned
 /// from local site or community.
 pub async fn verify_person_in_site_or_community(
@@ -1,11 +1,24 @@
-use crate::{util::CancellableTask, worker::InstanceWorker};
+use crate::{replay::ReplayWorker, util::CancellableTask, worker::InstanceWorker};
 use activitypub_federation::config::FederationConfig;
+use chrono::Utc;
 use lemmy_api_utils::context::LemmyContext;
-use lemmy_db_schema::source::instance::Instance;
+use lemmy_db_schema::{
+  newtypes::{ActivityId, FederationReplayId},
+  source::{
+    federation_queue_state::FederationQueueState,
+    federation_replay::{FederationReplay, FederationReplayUpdateForm},
+    instance::Instance,
+  },
+};
 use lemmy_db_schema_file::InstanceId;
+use lemmy_diesel_utils::traits::Crud;
 use lemmy_utils::{error::LemmyResult, settings::structs::FederationWorkerConfig};
 use stats::receive_print_stats;
-use std::{collections::HashMap, time::Duration};
+use std::{
+  cmp::min,
+  collections::{HashMap, HashSet},
+  time::Duration,
+};
 use tokio::{
   sync::mpsc::{UnboundedSender, unbounded_channel},
   task::JoinHandle,
@@ -16,6 +29,7 @@ use tracing::info;
 use util::FederationQueueStateWithDomain;
 
 mod inboxes;
+mod replay;
 mod send;
 mod stats;
 mod util;
@@ -38,6 +52,7 @@ pub struct Opts {
 pub struct SendManager {
   opts: Opts,
   workers: HashMap<InstanceId, CancellableTask>,
+  replays: HashMap<FederationReplayId, CancellableTask>,
   context: FederationConfig<LemmyContext>,
   stats_sender: UnboundedSender<FederationQueueStateWithDomain>,
   exit_print: JoinHandle<()>,
@@ -58,6 +73,7 @@ impl SendManager {
     Self {
       opts,
       workers: HashMap::new(),
+      replays: HashMap::new(),
       stats_sender,
       exit_print: tokio::spawn(receive_print_stats(
         context.inner_pool().clone(),
@@ -104,8 +120,8 @@ impl SendManager {
       self.opts.process_count, process_index
     );
     let local_domain = self.context.settings().get_hostname_without_port()?;
-    let mut pool = self.context.pool();
     loop {
+      let mut pool = self.context.pool();
       let mut total_count = 0;
       let mut dead_count = 0;
       let mut disallowed_count = 0;
@@ -131,26 +147,7 @@ impl SendManager {
             // worker already running
             continue;
           }
-          // create new worker
-          let context = self.context.clone();
-          let stats_sender = self.stats_sender.clone();
-          let federation_worker_config = self.federation_worker_config.clone();
-
-          self.workers.insert(
-            instance.id,
-            CancellableTask::spawn(WORKER_EXIT_TIMEOUT, move |stop| {
-              // if the instance worker ends unexpectedly due to internal/db errors, this lambda is
-              // rerun by cancellabletask.
-              let instance = instance.clone();
-              InstanceWorker::init_and_loop(
-                instance,
-                context.clone(),
-                federation_worker_config.clone(),
-                stop,
-                stats_sender.clone(),
-              )
-            }),
-          );
+          self.spawn_worker(instance);
         } else if !should_federate
           && let Some(worker) = self.workers.remove(&instance.id)
           && let Err(e) = worker.cancel().await
@@ -162,6 +159,10 @@ impl SendManager {
       tracing::info!(
         "Federating to {worker_count}/{total_count} instances ({dead_count} dead, {disallowed_count} disallowed)"
       );
+      // Runs after the workers are updated, so that it knows which instances are federated with
+      if let Err(e) = self.handle_replays(process_index).await {
+        tracing::error!("failed to handle federation replays: {e}");
+      }
       tokio::select! {
         () = sleep(INSTANCES_RECHECK_DELAY) => {},
         _ = cancel.cancelled() => { return Ok(()) }
@@ -169,6 +170,144 @@ impl SendManager {
     }
   }
 
+  /// Start a worker which sends the queued activities to the instance.
+  fn spawn_worker(&mut self, instance: Instance) {
+    let context = self.context.clone();
+    let stats_sender = self.stats_sender.clone();
+    let federation_worker_config = self.federation_worker_config.clone();
+
+    self.workers.insert(
+      instance.id,
+      CancellableTask::spawn(WORKER_EXIT_TIMEOUT, move |stop| {
+        // if the instance worker ends unexpectedly due to internal/db errors, this lambda is
+        // rerun by cancellabletask.
+        let instance = instance.clone();
+        InstanceWorker::init_and_loop(
+          instance,
+          context.clone(),
+          federation_worker_config.clone(),
+          stop,
+          stats_sender.clone(),
+        )
+      }),
+    );
+  }
+
+  /// Start and track replays which were requested by an admin. Rewinding the queue of an instance
+  /// restarts its worker, resending the activities of a single community runs in a separate task.
+  async fn handle_replays(&mut self, process_index: i32) -> LemmyResult<()> {
+    let unfinished: Vec<_> = FederationReplay::list_unfinished(&mut self.context.pool())
+      .await?
+      .into_iter()
+      .filter(|r| r.instance_id.inner() % self.opts.process_count == process_index)
+      .collect();
+
+    // stop the tasks of replays which are finished
+    let unfinished_ids: HashSet<_> = unfinished.iter().map(|r| r.id).collect();
+    let finished: Vec<_> = self
+      .replays
+      .keys()
+      .filter(|id| !unfinished_ids.contains(id))
+      .copied()
+      .collect();
+    for id in finished {
+      self.stop_replay(id).await;
+    }
+
+    // Only send to instances with a running worker. Replays to blocked, dead or unknown instances
+    // would never finish, so they are stopped with an error instead. Rewinding a queue restarts the
+    // worker, so the list is taken beforehand.
+    let federated: HashSet<_> = self.workers.keys().copied().collect();
+    for replay in unfinished {
+      if !federated.contains(&replay.instance_id) {
+        self.stop_replay(replay.id).await;
+        let form = FederationReplayUpdateForm {
+          finished_at: Some(Some(Utc::now())),
+          error: Some(Some(
+            "Instance is blocked, dead or not federated with".to_string(),
+          )),
+          ..Default::default()
+        };
+        FederationReplay::update(&mut self.context.pool(), replay.id, &form).await?;
+        continue;
+      }
+      if let Some(community_id) = replay.community_id {
+        if self.replays.contains_key(&replay.id) {
+          continue;
+        }
+        let context = self.context.clone();
+        self.replays.insert(
+          replay.id,
+          CancellableTask::spawn(WORKER_EXIT_TIMEOUT, move |stop| {
+            ReplayWorker::init_and_loop(replay.id, community_id, context.clone(), stop)
+          }),
+        );
+      } else {
+        self.rewind_queue(replay).await?;
+      }
+    }
+    Ok(())
+  }
+
+  async fn stop_replay(&mut self, id: FederationReplayId) {
+    if let Some(task) = self.replays.remove(&id)
+      && let Err(e) = task.cancel().await
+    {
+      tracing::error!("error stopping replay: {e}");
+    }
+  }
+
+  /// Set the queue of the instance back to the start of the replay, then track its progress until
+  /// the queue is past the end of the replay.
+  async fn rewind_queue(&mut self, replay: FederationReplay) -> LemmyResult<()> {
+    let form = if replay.started_at.is_none() {
+      // The worker keeps its state in memory, so it needs to be stopped before changing the state,
+      // and started again afterwards.
+      if let Some(worker) = self.workers.remove(&replay.instance_id)
+        && let Err(e) = worker.cancel().await
+      {
+        tracing::error!("error stopping worker: {e}");
+      }
+      let mut state =
+        FederationQueueState::load(&mut self.context.pool(), replay.instance_id).await?;
+      let rewind_to = ActivityId(replay.start_id.0 - 1);
+      // the queue may be behind the start of the replay already
+      state.last_successful_id = Some(
+        state
+          .last_successful_id
+          .map_or(rewind_to, |last| min(last, rewind_to)),
+      );
+      state.fail_count = 0;
+      state.last_retry_at = None;
+      FederationQueueState::upsert(&mut self.context.pool(), &state).await?;
+      let instance = Instance::read(&mut self.context.pool(), replay.instance_id).await?;
+      self.spawn_worker(instance);
+      info!(
+        "Rewound federation queue of instance {} to activity {}",
+        replay.instance_id.inner(),
+        replay.start_id.0
+      );
+      FederationReplayUpdateForm {
+        last_sent_id: Some(state.last_successful_id),
+        started_at: Some(Some(Utc::now())),
+        ..Default::default()
+      }
+    } else {
+      let state = FederationQueueState::load(&mut self.context.pool(), replay.instance_id).await?;
+      let last_sent_id = state
+        .last_successful_id
+        .map(|last| min(last, replay.end_id));
+      let finished = last_sent_id == Some(replay.end_id);
+      FederationReplayUpdateForm {
+        last_sent_id: Some(last_sent_id),
+        finished_at: finished.then(|| Some(Utc::now())),
+        ..Default::default()
+      }
+    };
+    FederationReplay::update(&mut self.context.pool(), replay.id, &form).await?;
+    Ok(())
+  }
+
   pub async fn cancel(self) -> LemmyResult<()> {
     drop(self.stats_sender);
     tracing::warn!(
@@ -182,6 +321,7 @@ impl SendManager {
       self
         .workers
         .into_values()
+        .chain(self.replays.into_values())
         .map(util::CancellableTask::cancel),
     )
     .await;
@@ -199,19 +339,22 @@ mod test {
   use activitypub_federation::config::Data;
   use chrono::DateTime;
   use lemmy_db_schema::source::{
+    activity::{SentActivity, SentActivityForm},
     federation_allowlist::{FederationAllowList, FederationAllowListForm},
     federation_blocklist::{FederationBlockList, FederationBlockListForm},
     instance::InstanceForm,
     person::{Person, PersonInsertForm},
   };
-  use lemmy_diesel_utils::traits::Crud;
+  use lemmy_db_schema_file::enums::ActorType;
   use lemmy_utils::error::LemmyError;
+  use serde_json::json;
   use serial_test::serial;
   use std::{
     collections::HashSet,
     sync::{Arc, Mutex},
   };
   use tokio::spawn;
+  use url::Url;
 
   struct TestData {
     send_manager: SendManager,
@@ -356,6 +499,51 @@ mod test {
     Ok(())
   }
 
+  /// Rewinding the queue of an instance should reset its state and restart the worker right away
+  #[tokio::test]
+  #[serial]
+  async fn test_send_manager_rewind() -> LemmyResult<()> {
+    let mut data = TestData::init(1, 1).await?;
+
+    let instance_id = data.instances[0].id;
+    let form = SentActivityForm {
+      ap_id: Url::parse("http://local.com/activity/rewind")?.into(),
+      data: json!({}),
+      sensitive: false,
+      actor_apub_id: Url::parse("http://local.com/u/alice")?.into(),
+      actor_type: ActorType::Person,
+      send_all_instances: false,
+      send_community_followers_of: None,
+      send_inboxes: vec![],
+    };
+    let activity = SentActivity::create(&mut data.context.pool(), form).await?;
+    let state = FederationQueueState {
+      instance_id,
+      last_successful_id: Some(activity.id),
+      last_successful_published_time_at: None,
+      fail_count: 3,
+      last_retry_at: None,
+    };
+    FederationQueueState::upsert(&mut data.context.pool(), &state).await?;
+    let replay = FederationReplay::schedule(
+      &mut data.context.pool(),
+      instance_id,
+      None,
+      None,
+      Some(activity.id),
+    )
+    .await?;
+
+    data.run().await?;
+    assert!(data.send_manager.workers.contains_key(&instance_id));
+    let replay = FederationReplay::read(&mut data.context.pool(), replay.id).await?;
+    assert!(replay.started_at.is_some());
+    assert_eq!(Some(ActivityId(activity.id.0 - 1)), replay.last_sent_id);
+
+    data.cleanup().await?;
+    Ok(())
+  }
+
   /// Mark instance as dead, there should be no worker created for it
   #[tokio::test]
   #[serial]
@@ -0,0 +1,177 @@
+use crate::send::{SendActivityResult, SendRetryTask};
+use activitypub_federation::config::FederationConfig;
+use chrono::{TimeZone, Utc};
+use lemmy_api_utils::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::{ActivityId, CommunityId, FederationReplayId},
+  source::{
+    activity::SentActivity,
+    federation_replay::{FederationReplay, FederationReplayUpdateForm},
+    instance::Instance,
+  },
+};
+use lemmy_db_views_community_follower::CommunityFollowerView;
+use lemmy_diesel_utils::traits::Crud;
+use lemmy_utils::error::LemmyResult;
+use reqwest::Url;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio_util::sync::CancellationToken;
+
+/// How many activities are loaded from the database at once. Progress is saved after each batch.
+const REPLAY_BATCH_SIZE: i64 = 100;
+
+/// Resends the activities of a single local community to one instance, independently of the
+/// regular queue of that instance. Activities are sent one after another, and failed sends are
+/// retried the same way as in the `InstanceWorker`.
+pub(crate) struct ReplayWorker {
+  replay: FederationReplay,
+  community_id: CommunityId,
+  domain: String,
+  federation_lib_config: FederationConfig<LemmyContext>,
+  stop: CancellationToken,
+}
+
+impl ReplayWorker {
+  pub(crate) async fn init_and_loop(
+    replay_id: FederationReplayId,
+    community_id: CommunityId,
+    config: FederationConfig<LemmyContext>,
+    stop: CancellationToken,
+  ) -> LemmyResult<()> {
+    let context = config.to_request_data();
+    // read again in case the task was restarted, to continue where it stopped
+    let replay = FederationReplay::read(&mut context.pool(), replay_id).await?;
+    let instance = Instance::read(&mut context.pool(), replay.instance_id).await?;
+    let mut worker = ReplayWorker {
+      replay,
+      community_id,
+      domain: instance.domain,
+      federation_lib_config: config,
+      stop,
+    };
+    if worker.replay.finished_at.is_none() {
+      worker.replay().await?;
+    }
+
+    // CancellableTask restarts tasks which exit on their own, so wait until the SendManager
+    // notices that the replay is finished.
+    worker.stop.cancelled().await;
+    Ok(())
+  }
+
+  async fn replay(&mut self) -> LemmyResult<()> {
+    let context = self.federation_lib_config.to_request_data();
+    let inbox_urls = self.inbox_urls().await?;
+    if self.replay.started_at.is_none() {
+      self.save_progress(true, false).await?;
+    }
+    if inbox_urls.is_empty() {
+      tracing::warn!(
+        "{}: nobody follows community {} anymore, nothing to replay",
+        self.domain,
+        self.community_id.0
+      );
+      return self.save_progress(false, true).await;
+    }
+
+    let (mut report, mut receive_send_result) = unbounded_channel();
+    while !self.stop.is_cancelled() {
+      let after = self
+        .replay
+        .last_sent_id
+        .unwrap_or(ActivityId(self.replay.start_id.0 - 1));
+      let activities = SentActivity::list_for_community(
+        &mut context.pool(),
+        self.community_id,
+        after,
+        self.replay.end_id,
+        REPLAY_BATCH_SIZE,
+      )
+      .await?;
+      if activities.is_empty() {
+        tracing::info!(
+          "{}: finished replay of {} activities from community {}",
+          self.domain,
+          self.replay.activities_sent,
+          self.community_id.0
+        );
+        return self.save_progress(false, true).await;
+      }
+
+      for activity in activities {
+        let res = SendRetryTask {
+          activity: &activity,
+          object: &activity.data,
+          inbox_urls: inbox_urls.clone(),
+          report: &mut report,
+          initial_fail_count: 0,
+          domain: self.domain.clone(),
+          context: self.federation_lib_config.to_request_data(),
+          stop: self.stop.clone(),
+        }
+        .send_retry_loop()
+        .await;
+        let mut delivered = false;
+        while let Ok(result) = receive_send_result.try_recv() {
+          if let SendActivityResult::Success(s) = result {
+            delivered = !s.was_skipped;
+          }
+        }
+        if let Err(e) = res {
+          // Same as in the InstanceWorker, retrying won't help here
+          tracing::warn!(
+            "replaying {} errored internally, skipping: {e:?}",
+            activity.ap_id
+          );
+        } else if !delivered {
+          // cancelled while retrying, send it again on restart
+          break;
+        }
+        if delivered {
+          self.replay.activities_sent += 1;
+        }
+        self.replay.last_sent_id = Some(activity.id);
+      }
+      self.save_progress(false, false).await?;
+      tracing::info!(
+        "{}: replayed {} activities from community {}, up to id {:?} of {}",
+        self.domain,
+        self.replay.activities_sent,
+        self.community_id.0,
+        self.replay.last_sent_id,
+        self.replay.end_id.0
+      );
+    }
+    Ok(())
+  }
+
+  /// Inboxes on the target instance which follow the community.
+  async fn inbox_urls(&self) -> LemmyResult<Vec<Url>> {
+    let context = self.federation_lib_config.to_request_data();
+    let mut inbox_urls: Vec<Url> = CommunityFollowerView::get_instance_followed_community_inboxes(
+      &mut context.pool(),
+      self.replay.instance_id,
+      Utc.timestamp_nanos(0),
+    )
+    .await?
+    .into_iter()
+    .filter(|(community_id, _)| *community_id == self.community_id)
+    .map(|(_, inbox)| inbox.into())
+    .collect();
+    inbox_urls.sort();
+    inbox_urls.dedup();
+    Ok(inbox_urls)
+  }
+
+  async fn save_progress(&mut self, started: bool, finished: bool) -> LemmyResult<()> {
+    let form = FederationReplayUpdateForm {
+      last_sent_id: Some(self.replay.last_sent_id),
+      activities_sent: Some(self.replay.activities_sent),
+      started_at: started.then(|| Some(Utc::now())),
+      finished_at: finished.then(|| Some(Utc::now())),
+    };
+    let context = self.federation_lib_config.to_request_data();
+    self.replay = FederationReplay::update(&mut context.pool(), self.replay.id, &form).await?;
+    Ok(())
+  }
+}
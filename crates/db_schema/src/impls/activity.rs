@@ -1,9 +1,14 @@
 use crate::{
   diesel::OptionalExtension,
-  newtypes::ActivityId,
+  newtypes::{ActivityId, CommunityId},
   source::activity::{ReceivedActivity, SentActivity, SentActivityForm},
 };
-use diesel::{ExpressionMethods, QueryDsl, dsl::insert_into};
+use chrono::{DateTime, Utc};
+use diesel::{
+  ExpressionMethods,
+  QueryDsl,
+  dsl::{insert_into, max, min},
+};
 use diesel_async::RunQueryDsl;
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -40,6 +45,58 @@ impl SentActivity {
       .await
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
+
+  /// The id of the newest activity.
+  pub async fn latest_id(pool: &mut DbPool<'_>) -> LemmyResult<Option<ActivityId>> {
+    use lemmy_db_schema_file::schema::sent_activity::dsl::{id, sent_activity};
+    let conn = &mut get_conn(pool).await?;
+    sent_activity
+      .select(max(id))
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// The id of the first activity which was published after the given time.
+  pub async fn first_id_since(
+    pool: &mut DbPool<'_>,
+    since: DateTime<Utc>,
+  ) -> LemmyResult<Option<ActivityId>> {
+    use lemmy_db_schema_file::schema::sent_activity::dsl::{id, published_at, sent_activity};
+    let conn = &mut get_conn(pool).await?;
+    sent_activity
+      .filter(published_at.ge(since))
+      .select(min(id))
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Activities which were sent to the followers of a local community, with ids in the range
+  /// `(after, until]`.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    after: ActivityId,
+    until: ActivityId,
+    limit: i64,
+  ) -> LemmyResult<Vec<Self>> {
+    use lemmy_db_schema_file::schema::sent_activity::dsl::{
+      id,
+      send_community_followers_of,
+      sent_activity,
+    };
+    let conn = &mut get_conn(pool).await?;
+    sent_activity
+      .filter(send_community_followers_of.eq(community_id))
+      .filter(id.gt(after))
+      .filter(id.le(until))
+      .order_by(id)
+      .limit(limit)
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
 }
 
 impl ReceivedActivity {
@@ -0,0 +1,66 @@
+use crate::{
+  newtypes::{BanAppealId, CommunityId},
+  source::ban_appeal::{BanAppeal, BanAppealInsertForm, BanAppealUpdateForm},
+};
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{PersonId, schema::ban_appeal};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for BanAppeal {
+  type InsertForm = BanAppealInsertForm;
+  type UpdateForm = BanAppealUpdateForm;
+  type IdType = BanAppealId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(ban_appeal::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    appeal_id: BanAppealId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(ban_appeal::table.find(appeal_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl BanAppeal {
+  /// The appeal against a specific ban, if the user submitted one.
+  pub async fn read_for_ban(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: Option<CommunityId>,
+    banned_at: DateTime<Utc>,
+  ) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = ban_appeal::table
+      .filter(ban_appeal::person_id.eq(person_id))
+      .filter(ban_appeal::banned_at.eq(banned_at))
+      .into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(ban_appeal::community_id.eq(community_id)),
+      None => query.filter(ban_appeal::community_id.is_null()),
+    };
+    query
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
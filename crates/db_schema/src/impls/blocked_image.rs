@@ -0,0 +1,96 @@
+use crate::{
+  newtypes::BlockedImageId,
+  source::blocked_image::{BlockedImage, BlockedImageForm, blocked_image_keys as key},
+  utils::limit_fetch,
+};
+use diesel::{QueryDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use i_love_jesus::SortDirection;
+use lemmy_db_schema_file::schema::blocked_image;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  pagination::{
+    CursorData,
+    PagedResponse,
+    PaginationCursor,
+    PaginationCursorConversion,
+    paginate_response,
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for BlockedImage {
+  type InsertForm = BlockedImageForm;
+  type UpdateForm = BlockedImageForm;
+  type IdType = BlockedImageId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(blocked_image::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    blocked_image_id: BlockedImageId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(blocked_image::table.find(blocked_image_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl PaginationCursorConversion for BlockedImage {
+  type PaginatedType = BlockedImage;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    BlockedImage::read(pool, BlockedImageId(cursor.id()?)).await
+  }
+}
+
+impl BlockedImage {
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    page_cursor: Option<PaginationCursor>,
+    limit: Option<i64>,
+  ) -> LemmyResult<PagedResponse<Self>> {
+    let limit = limit_fetch(limit, None)?;
+    let query = blocked_image::table.limit(limit).into_boxed();
+    let paginated_query = Self::paginate(query, &page_cursor, SortDirection::Desc, pool, None)
+      .await?
+      .then_order_by(key::published_at)
+      .then_order_by(key::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    paginate_response(res, limit, page_cursor)
+  }
+
+  /// Returns the hashes of all blocked images, to compare them with new images.
+  pub async fn read_all_hashes(pool: &mut DbPool<'_>) -> LemmyResult<Vec<i64>> {
+    let conn = &mut get_conn(pool).await?;
+    blocked_image::table
+      .select(blocked_image::hash)
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
@@ -0,0 +1,287 @@
+use crate::{
+  newtypes::BlocklistSubscriptionId,
+  source::{
+    blocklist_subscription::{
+      BlocklistSubscription,
+      BlocklistSubscriptionEntry,
+      BlocklistSubscriptionInsertForm,
+      BlocklistSubscriptionUpdateForm,
+    },
+    instance::Instance,
+  },
+};
+use diesel::{
+  ExpressionMethods,
+  NullableExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+  delete,
+  insert_into,
+  sql_query,
+  sql_types::Int4,
+};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use lemmy_db_schema_file::schema::{
+  blocklist_subscription,
+  blocklist_subscription_entry,
+  federation_blocklist,
+  instance,
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+  utils::now,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+/// Postgres limits the number of bind parameters per query, so large blocklists are inserted in
+/// chunks.
+const ENTRY_INSERT_CHUNK_SIZE: usize = 1000;
+
+impl Crud for BlocklistSubscription {
+  type InsertForm = BlocklistSubscriptionInsertForm;
+  type UpdateForm = BlocklistSubscriptionUpdateForm;
+  type IdType = BlocklistSubscriptionId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(blocklist_subscription::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id: BlocklistSubscriptionId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(blocklist_subscription::table.find(id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl BlocklistSubscription {
+  pub async fn list(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    blocklist_subscription::table
+      .order_by(blocklist_subscription::id)
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl BlocklistSubscriptionEntry {
+  /// Replace the entries of the subscription with the current content of the blocklist, and
+  /// mark it as synced.
+  pub async fn replace(
+    pool: &mut DbPool<'_>,
+    subscription_id: BlocklistSubscriptionId,
+    entries: &[Self],
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .run_transaction(|conn| {
+        async move {
+          delete(
+            blocklist_subscription_entry::table
+              .filter(blocklist_subscription_entry::subscription_id.eq(subscription_id)),
+          )
+          .execute(conn)
+          .await
+          .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+
+          for chunk in entries.chunks(ENTRY_INSERT_CHUNK_SIZE) {
+            insert_into(blocklist_subscription_entry::table)
+              .values(chunk)
+              .on_conflict_do_nothing()
+              .execute(conn)
+              .await
+              .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+          }
+
+          diesel::update(blocklist_subscription::table.find(subscription_id))
+            .set(blocklist_subscription::synced_at.eq(now().nullable()))
+            .execute(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+          Ok(())
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Entries of the blocklist which aren't blocked yet.
+  pub async fn pending_blocks(
+    pool: &mut DbPool<'_>,
+    subscription_id: BlocklistSubscriptionId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let blocked_domains = federation_blocklist::table
+      .inner_join(instance::table)
+      .select(instance::domain);
+    blocklist_subscription_entry::table
+      .filter(blocklist_subscription_entry::subscription_id.eq(subscription_id))
+      .filter(blocklist_subscription_entry::domain.ne_all(blocked_domains))
+      .order_by(blocklist_subscription_entry::domain)
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Instances which were blocked from this subscription, but are not in the blocklist anymore.
+  /// Domains which are still listed by another subscription are not included, their blocks are
+  /// handed over with [`Self::transfer_blocks`] instead.
+  pub async fn pending_unblocks(
+    pool: &mut DbPool<'_>,
+    subscription_id: BlocklistSubscriptionId,
+  ) -> LemmyResult<Vec<Instance>> {
+    let conn = &mut get_conn(pool).await?;
+    let listed_domains =
+      blocklist_subscription_entry::table.select(blocklist_subscription_entry::domain);
+    federation_blocklist::table
+      .inner_join(instance::table)
+      .filter(federation_blocklist::subscription_id.eq(subscription_id))
+      .filter(instance::domain.ne_all(listed_domains))
+      .select(Instance::as_select())
+      .order_by(instance::domain)
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Hand blocks from this subscription over to another subscription which still lists the
+  /// domain, so that they are only lifted once no subscription lists it anymore.
+  pub async fn transfer_blocks(
+    pool: &mut DbPool<'_>,
+    subscription_id: BlocklistSubscriptionId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    sql_query(
+      "UPDATE federation_blocklist SET subscription_id = other.subscription_id
+      FROM instance, LATERAL (
+        SELECT min(subscription_id) AS subscription_id
+        FROM blocklist_subscription_entry
+        WHERE domain = instance.domain AND subscription_id <> $1
+      ) other
+      WHERE federation_blocklist.instance_id = instance.id
+        AND federation_blocklist.subscription_id = $1
+        AND other.subscription_id IS NOT NULL
+        AND NOT EXISTS (
+          SELECT 1 FROM blocklist_subscription_entry
+          WHERE subscription_id = $1 AND domain = instance.domain
+        )",
+    )
+    .bind::<Int4, _>(subscription_id)
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use crate::source::federation_blocklist::{FederationBlockList, FederationBlockListForm};
+  use lemmy_db_schema_file::enums::BlocklistFormat;
+  use lemmy_diesel_utils::connection::build_db_pool_for_tests;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  fn entry(subscription_id: BlocklistSubscriptionId, domain: &str) -> BlocklistSubscriptionEntry {
+    BlocklistSubscriptionEntry {
+      subscription_id,
+      domain: domain.to_string(),
+      reason: None,
+    }
+  }
+
+  fn domains(instances: Vec<Instance>) -> Vec<String> {
+    instances.into_iter().map(|i| i.domain).collect()
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_pending_blocks_and_unblocks() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let form = BlocklistSubscriptionInsertForm::new("https://a.xyz".into(), BlocklistFormat::Csv);
+    let sub_a = BlocklistSubscription::create(pool, &form).await?;
+    let form = BlocklistSubscriptionInsertForm::new("https://b.xyz".into(), BlocklistFormat::Csv);
+    let sub_b = BlocklistSubscription::create(pool, &form).await?;
+
+    let entries_a = [entry(sub_a.id, "tld1.xyz"), entry(sub_a.id, "tld2.xyz")];
+    BlocklistSubscriptionEntry::replace(pool, sub_a.id, &entries_a).await?;
+    BlocklistSubscriptionEntry::replace(pool, sub_b.id, &[entry(sub_b.id, "tld2.xyz")]).await?;
+
+    // Nothing is blocked yet
+    let pending = BlocklistSubscriptionEntry::pending_blocks(pool, sub_a.id).await?;
+    assert_eq!(entries_a.to_vec(), pending);
+    for e in pending {
+      let instance = Instance::read_or_create(pool, &e.domain).await?;
+      let form = FederationBlockListForm {
+        subscription_id: Some(sub_a.id),
+        ..FederationBlockListForm::new(instance.id, None)
+      };
+      FederationBlockList::block(pool, &form).await?;
+    }
+
+    // A manual block is never lifted by a subscription
+    let manual = Instance::read_or_create(pool, "tld3.xyz").await?;
+    FederationBlockList::block(pool, &FederationBlockListForm::new(manual.id, None)).await?;
+
+    // Domains which are already blocked are not pending, for either subscription
+    assert!(
+      BlocklistSubscriptionEntry::pending_blocks(pool, sub_a.id)
+        .await?
+        .is_empty()
+    );
+    assert!(
+      BlocklistSubscriptionEntry::pending_blocks(pool, sub_b.id)
+        .await?
+        .is_empty()
+    );
+    assert!(
+      BlocklistSubscriptionEntry::pending_unblocks(pool, sub_a.id)
+        .await?
+        .is_empty()
+    );
+
+    // Drop all domains from the first blocklist. The second domain is still listed by the other
+    // subscription, so only the first one is unblocked.
+    BlocklistSubscriptionEntry::replace(pool, sub_a.id, &[]).await?;
+    let pending = BlocklistSubscriptionEntry::pending_unblocks(pool, sub_a.id).await?;
+    assert_eq!(vec!["tld1.xyz".to_string()], domains(pending));
+
+    // After handing the block over, it is lifted once the other blocklist drops the domain
+    assert_eq!(
+      1,
+      BlocklistSubscriptionEntry::transfer_blocks(pool, sub_a.id).await?
+    );
+    let pending = BlocklistSubscriptionEntry::pending_unblocks(pool, sub_a.id).await?;
+    assert_eq!(vec!["tld1.xyz".to_string()], domains(pending));
+    assert!(
+      BlocklistSubscriptionEntry::pending_unblocks(pool, sub_b.id)
+        .await?
+        .is_empty()
+    );
+    BlocklistSubscriptionEntry::replace(pool, sub_b.id, &[]).await?;
+    let pending = BlocklistSubscriptionEntry::pending_unblocks(pool, sub_b.id).await?;
+    assert_eq!(vec!["tld2.xyz".to_string()], domains(pending));
+
+    BlocklistSubscription::delete(pool, sub_a.id).await?;
+    BlocklistSubscription::delete(pool, sub_b.id).await?;
+    Instance::delete_all(pool).await?;
+
+    Ok(())
+  }
+}
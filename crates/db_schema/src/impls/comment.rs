@@ -75,6 +75,26 @@ impl Comment {
       .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
 
+  /// Publish all comments which were held back while the creator was shadow restricted. Returns
+  /// the released comments in the order they were written.
+  pub async fn release_held_for_creator(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+  ) -> LemmyResult<Vec<Comment>> {
+    let conn = &mut get_conn(pool).await?;
+    let mut comments: Vec<Comment> = diesel::update(
+      comment::table
+        .filter(comment::creator_id.eq(creator_id))
+        .filter(comment::held_for_review),
+    )
+    .set(comment::held_for_review.eq(false))
+    .get_results(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    comments.sort_by_key(|c| c.published_at);
+    Ok(comments)
+  }
+
   /// Diesel can't update from join unfortunately, so you'll need to loop over these
   async fn creator_comments_in_community(
     pool: &mut DbPool<'_>,
@@ -489,6 +509,7 @@ mod tests {
       unresolved_report_count: 0,
       federation_pending: false,
       locked: false,
+      held_for_review: false,
     };
 
     let child_comment_form = CommentInsertForm::new(
@@ -638,6 +659,64 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_held_for_review_counts() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "thommy_comment_held");
+    let inserted_person = Person::create(pool, &new_person).await?;
+    let new_community = CommunityInsertForm::new(
+      inserted_instance.id,
+      "TIL_comment_held".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &new_community).await?;
+    let new_post = PostInsertForm::new(
+      "A test post".into(),
+      inserted_person.id,
+      inserted_community.id,
+    );
+    let inserted_post = Post::create(pool, &new_post).await?;
+
+    let comment_form = CommentInsertForm::new(
+      inserted_person.id,
+      inserted_post.id,
+      "A test comment".into(),
+    );
+    let inserted_comment = Comment::create(pool, &comment_form, None).await?;
+    let held_comment_form = CommentInsertForm {
+      held_for_review: Some(true),
+      ..CommentInsertForm::new(inserted_person.id, inserted_post.id, "Held".into())
+    };
+    Comment::create(pool, &held_comment_form, Some(&inserted_comment.path)).await?;
+
+    // Held comments are not counted
+    let post = Post::read(pool, inserted_post.id).await?;
+    assert_eq!(1, post.comments);
+    let parent = Comment::read(pool, inserted_comment.id).await?;
+    assert_eq!(0, parent.child_count);
+    let person = Person::read(pool, inserted_person.id).await?;
+    assert_eq!(1, person.comment_count);
+
+    // Counted once they are released
+    let released = Comment::release_held_for_creator(pool, inserted_person.id).await?;
+    assert_eq!(1, released.len());
+    let post = Post::read(pool, inserted_post.id).await?;
+    assert_eq!(2, post.comments);
+    let parent = Comment::read(pool, inserted_comment.id).await?;
+    assert_eq!(1, parent.child_count);
+    let person = Person::read(pool, inserted_person.id).await?;
+    assert_eq!(2, person.comment_count);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_update_children() -> LemmyResult<()> {
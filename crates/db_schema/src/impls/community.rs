@@ -11,6 +11,7 @@ use crate::{
       CommunityInsertForm,
       CommunityModeratorForm,
       CommunityPersonBanForm,
+      CommunityPersonMuteForm,
       CommunityUpdateForm,
     },
     post::Post,
@@ -27,9 +28,11 @@ use diesel::{
   dsl::{exists, insert_into, not},
   expression::SelectableHelper,
   select,
+  sql_query,
+  sql_types::{Int4, Text},
   update,
 };
-use diesel_async::RunQueryDsl;
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
 use diesel_uplete::{UpleteCount, uplete};
 use lemmy_db_schema_file::{
   PersonId,
@@ -281,6 +284,132 @@ impl Community {
       .await
       .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
+
+  /// Moves the posts, tags, subscribers, moderators and bans of a community into another one, and
+  /// marks it as moved. Existing subscribers and moderators of the target community are kept.
+  pub async fn merge(
+    pool: &mut DbPool<'_>,
+    source_id: CommunityId,
+    target_id: CommunityId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .run_transaction(|conn| {
+        async move {
+          update(post::table.filter(post::community_id.eq(source_id)))
+            .set(post::community_id.eq(target_id))
+            .execute(conn)
+            .await?;
+
+          // Tags which also exist in the target community are replaced by those, the others get
+          // a new federation id under the target community.
+          let target_ap_id: DbUrl = community::table
+            .find(target_id)
+            .select(community::ap_id)
+            .first(conn)
+            .await?;
+          sql_query(
+            "UPDATE post_tag SET tag_id = target_tag.id
+            FROM tag source_tag, tag target_tag
+            WHERE post_tag.tag_id = source_tag.id
+              AND source_tag.community_id = $1
+              AND target_tag.community_id = $2
+              AND target_tag.name = source_tag.name",
+          )
+          .bind::<Int4, _>(source_id)
+          .bind::<Int4, _>(target_id)
+          .execute(conn)
+          .await?;
+          sql_query(
+            "DELETE FROM tag source_tag USING tag target_tag
+            WHERE source_tag.community_id = $1
+              AND target_tag.community_id = $2
+              AND target_tag.name = source_tag.name",
+          )
+          .bind::<Int4, _>(source_id)
+          .bind::<Int4, _>(target_id)
+          .execute(conn)
+          .await?;
+          sql_query(
+            "UPDATE tag SET community_id = $2, ap_id = $3 || '/tag/' || name
+            WHERE community_id = $1",
+          )
+          .bind::<Int4, _>(source_id)
+          .bind::<Int4, _>(target_id)
+          .bind::<Text, _>(target_ap_id.to_string())
+          .execute(conn)
+          .await?;
+
+          // Raw `sql_query` is used because Diesel has no support for upserts from a select.
+          // Users who blocked the target community don't get subscribed, and moved moderators
+          // are added after the existing ones.
+          sql_query(
+            "INSERT INTO community_actions
+              (person_id, community_id, followed_at, follow_state, follow_approver_id)
+            SELECT person_id, $2, followed_at, follow_state, follow_approver_id
+            FROM community_actions
+            WHERE community_id = $1 AND followed_at IS NOT NULL
+            ON CONFLICT (person_id, community_id) DO UPDATE SET
+              followed_at = excluded.followed_at,
+              follow_state = excluded.follow_state,
+              follow_approver_id = excluded.follow_approver_id
+            WHERE community_actions.followed_at IS NULL
+              AND community_actions.blocked_at IS NULL",
+          )
+          .bind::<Int4, _>(source_id)
+          .bind::<Int4, _>(target_id)
+          .execute(conn)
+          .await?;
+
+          sql_query(
+            "INSERT INTO community_actions (person_id, community_id, became_moderator_at)
+            SELECT person_id, $2, now()
+            FROM community_actions
+            WHERE community_id = $1 AND became_moderator_at IS NOT NULL
+            ON CONFLICT (person_id, community_id) DO UPDATE SET
+              became_moderator_at = excluded.became_moderator_at
+            WHERE community_actions.became_moderator_at IS NULL",
+          )
+          .bind::<Int4, _>(source_id)
+          .bind::<Int4, _>(target_id)
+          .execute(conn)
+          .await?;
+
+          // Banned users stay banned in the target community, unless they are already banned
+          // there.
+          sql_query(
+            "INSERT INTO community_actions
+              (person_id, community_id, received_ban_at, ban_expires_at)
+            SELECT person_id, $2, received_ban_at, ban_expires_at
+            FROM community_actions
+            WHERE community_id = $1 AND received_ban_at IS NOT NULL
+            ON CONFLICT (person_id, community_id) DO UPDATE SET
+              received_ban_at = excluded.received_ban_at,
+              ban_expires_at = excluded.ban_expires_at
+            WHERE community_actions.received_ban_at IS NULL",
+          )
+          .bind::<Int4, _>(source_id)
+          .bind::<Int4, _>(target_id)
+          .execute(conn)
+          .await?;
+
+          uplete(community_actions::table.filter(community_actions::community_id.eq(source_id)))
+            .set_null(community_actions::followed_at)
+            .set_null(community_actions::follow_state)
+            .set_null(community_actions::follow_approver_id)
+            .get_result::<UpleteCount>(conn)
+            .await?;
+
+          update(community::table.find(source_id))
+            .set(community::moved_to_id.eq(target_id))
+            .get_result::<Self>(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
 }
 
 impl CommunityActions {
@@ -298,6 +427,59 @@ impl CommunityActions {
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
 
+  /// Mute a user in the community, so that they can still read and vote but not post or comment.
+  pub async fn mute(pool: &mut DbPool<'_>, form: &CommunityPersonMuteForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_actions::table)
+      .values(form)
+      .on_conflict((
+        community_actions::community_id,
+        community_actions::person_id,
+      ))
+      .do_update()
+      .set(form)
+      .returning(Self::as_select())
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  pub async fn unmute(
+    pool: &mut DbPool<'_>,
+    form: &CommunityPersonMuteForm,
+  ) -> LemmyResult<UpleteCount> {
+    let conn = &mut get_conn(pool).await?;
+    uplete(community_actions::table.find((form.person_id, form.community_id)))
+      .set_null(community_actions::received_mute_at)
+      .set_null(community_actions::mute_expires_at)
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  /// Check that the user isn't muted in the community. Expired mutes are ignored, as they are
+  /// only cleared periodically.
+  pub async fn check_mute(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: CommunityId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    let find_action = community_actions::table
+      .find((person_id, community_id))
+      .filter(community_actions::received_mute_at.is_not_null())
+      .filter(
+        community_actions::mute_expires_at
+          .is_null()
+          .or(community_actions::mute_expires_at.gt(Utc::now())),
+      );
+    select(not(exists(find_action)))
+      .get_result::<bool>(conn)
+      .await?
+      .then_some(())
+      .ok_or(LemmyErrorType::PersonIsMutedInCommunity.into())
+  }
+
   pub async fn delete_mods_for_community(
     pool: &mut DbPool<'_>,
     for_community_id: CommunityId,
@@ -520,7 +702,7 @@ impl Followable for CommunityActions {
   }
   async fn follow_accepted(
     pool: &mut DbPool<'_>,
-    community_id: CommunityId,
+    community_id: Self::IdType,
     person_id: PersonId,
   ) -> LemmyResult<Self> {
     let conn = &mut get_conn(pool).await?;
@@ -697,6 +879,7 @@ mod tests {
       local_user::LocalUser,
       person::{Person, PersonInsertForm},
       post::{Post, PostInsertForm},
+      tag::{PostTag, Tag, TagInsertForm},
     },
     traits::{Bannable, Followable},
     utils::RANK_DEFAULT,
@@ -767,6 +950,10 @@ mod tests {
       unresolved_report_count: 0,
       interactions_month: 0,
       local_removed: false,
+      strike_ban_threshold: None,
+      strike_window_days: 30,
+      strike_ban_days: 7,
+      moved_to_id: None,
     };
 
     let community_follower_form = CommunityFollowerForm::new(
@@ -994,6 +1181,108 @@ mod tests {
     let after_delete = Community::read(pool, inserted_community.id).await;
     assert!(after_delete.is_err());
 
+    Ok(())
+  }
+  #[tokio::test]
+  #[serial]
+  async fn test_merge() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "merge_person");
+    let person = Person::create(pool, &person_form).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "merge_person_2");
+    let another_person = Person::create(pool, &person_form).await?;
+
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "merge_source".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let source = Community::create(pool, &community_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "merge_target".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let target = Community::create(pool, &community_form).await?;
+
+    // Both persons follow the source, one of them also follows the target
+    for p in [&person, &another_person] {
+      let form = CommunityFollowerForm::new(source.id, p.id, CommunityFollowerState::Accepted);
+      CommunityActions::follow(pool, &form).await?;
+    }
+    let form = CommunityFollowerForm::new(target.id, person.id, CommunityFollowerState::Accepted);
+    CommunityActions::follow(pool, &form).await?;
+    let form = CommunityModeratorForm::new(source.id, another_person.id);
+    CommunityActions::join(pool, &form).await?;
+
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "merge_banned");
+    let banned_person = Person::create(pool, &person_form).await?;
+    let form = CommunityPersonBanForm::new(source.id, banned_person.id);
+    CommunityActions::ban(pool, &form).await?;
+
+    let post_form = PostInsertForm::new("A test post".into(), person.id, source.id);
+    let post = Post::create(pool, &post_form).await?;
+
+    // A tag which exists in both communities is merged, the other one is moved
+    let tag_form = |community: &Community, name: &str| -> LemmyResult<TagInsertForm> {
+      Ok(TagInsertForm {
+        ap_id: Url::parse(&format!("{}/tag/{name}", community.ap_id))?.into(),
+        name: name.to_string(),
+        display_name: None,
+        description: None,
+        community_id: community.id,
+        deleted: None,
+        color: None,
+      })
+    };
+    let source_news = Tag::create(pool, &tag_form(&source, "news")?).await?;
+    let source_art = Tag::create(pool, &tag_form(&source, "art")?).await?;
+    let target_news = Tag::create(pool, &tag_form(&target, "news")?).await?;
+    PostTag::update(pool, &post, &[source_news.id, source_art.id]).await?;
+
+    let merged = Community::merge(pool, source.id, target.id).await?;
+    assert_eq!(Some(target.id), merged.moved_to_id);
+
+    let mut post_tags = Tag::read_for_post(pool, post.id).await?;
+    post_tags.sort_by_key(|t| t.name.clone());
+    assert_eq!(2, post_tags.len());
+    assert_eq!(source_art.id, post_tags[0].id);
+    assert_eq!(target.id, post_tags[0].community_id);
+    assert_eq!(
+      format!("{}/tag/art", target.ap_id),
+      post_tags[0].ap_id.to_string()
+    );
+    assert_eq!(target_news.id, post_tags[1].id);
+    assert_eq!(2, Tag::read_for_community(pool, target.id).await?.len());
+
+    let ban = CommunityActions::read(pool, target.id, banned_person.id).await?;
+    assert!(ban.received_ban_at.is_some());
+
+    let post = Post::read(pool, post.id).await?;
+    assert_eq!(target.id, post.community_id);
+
+    let source = Community::read(pool, source.id).await?;
+    assert_eq!(0, source.subscribers);
+    assert_eq!(0, source.posts);
+    let target = Community::read(pool, target.id).await?;
+    assert_eq!(2, target.subscribers);
+    assert_eq!(1, target.posts);
+
+    let moderated =
+      CommunityActions::get_person_moderated_communities(pool, another_person.id).await?;
+    assert!(moderated.contains(&target.id));
+
+    Person::delete(pool, person.id).await?;
+    Person::delete(pool, another_person.id).await?;
+    Person::delete(pool, banned_person.id).await?;
+    Community::delete(pool, source.id).await?;
+    Community::delete(pool, target.id).await?;
+
     Ok(())
   }
 }
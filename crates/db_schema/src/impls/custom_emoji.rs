@@ -45,6 +45,16 @@ impl Crud for CustomEmoji {
   }
 }
 
+impl CustomEmoji {
+  pub async fn read_all(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    custom_emoji
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
 impl CustomEmojiKeyword {
   pub async fn create_from_keywords(
     pool: &mut DbPool<'_>,
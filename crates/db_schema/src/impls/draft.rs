@@ -0,0 +1,230 @@
+use crate::{
+  newtypes::{DraftId, LocalUserId},
+  source::draft::{Draft, DraftInsertForm, DraftUpdateForm, draft_keys as key},
+  utils::limit_fetch,
+};
+use diesel::{ExpressionMethods, QueryDsl, dsl::IntervalDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use i_love_jesus::SortDirection;
+use lemmy_db_schema_file::schema::draft;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  pagination::{
+    CursorData,
+    PagedResponse,
+    PaginationCursor,
+    PaginationCursorConversion,
+    paginate_response,
+  },
+  traits::Crud,
+  utils::{functions::coalesce, now},
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for Draft {
+  type InsertForm = DraftInsertForm;
+  type UpdateForm = DraftUpdateForm;
+  type IdType = DraftId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(draft::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    draft_id: DraftId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(draft::table.find(draft_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl PaginationCursorConversion for Draft {
+  type PaginatedType = Draft;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    Draft::read(pool, DraftId(cursor.id()?)).await
+  }
+}
+
+impl Draft {
+  /// Reads a draft, but only if it belongs to the given user.
+  pub async fn read_for_user(
+    pool: &mut DbPool<'_>,
+    draft_id: DraftId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    draft::table
+      .find(draft_id)
+      .filter(draft::local_user_id.eq(local_user_id))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Deletes a draft, but only if it belongs to the given user.
+  pub async fn delete_for_user(
+    pool: &mut DbPool<'_>,
+    draft_id: DraftId,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      draft::table
+        .find(draft_id)
+        .filter(draft::local_user_id.eq(local_user_id)),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)
+  }
+
+  pub async fn list_for_user(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    page_cursor: Option<PaginationCursor>,
+    limit: Option<i64>,
+  ) -> LemmyResult<PagedResponse<Self>> {
+    let limit = limit_fetch(limit, None)?;
+    let query = draft::table
+      .filter(draft::local_user_id.eq(local_user_id))
+      .limit(limit)
+      .into_boxed();
+    let paginated_query = Self::paginate(query, &page_cursor, SortDirection::Desc, pool, None)
+      .await?
+      .then_order_by(key::published_at)
+      .then_order_by(key::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    paginate_response(res, limit, page_cursor)
+  }
+
+  pub async fn count_for_user(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    draft::table
+      .filter(draft::local_user_id.eq(local_user_id))
+      .count()
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Deletes all drafts which weren't created or edited during the given number of days.
+  pub async fn delete_older_than(pool: &mut DbPool<'_>, days: i32) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      draft::table.filter(coalesce(draft::updated_at, draft::published_at).lt(now() - days.days())),
+    )
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::Deleted)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    draft::{Draft, DraftInsertForm, DraftUpdateForm},
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+  };
+  use lemmy_db_schema_file::enums::DraftKind;
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "draft_writer");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    let other_person_form = PersonInsertForm::test_form(inserted_instance.id, "draft_snooper");
+    let other_person = Person::create(pool, &other_person_form).await?;
+    let other_local_user_form = LocalUserInsertForm::test_form(other_person.id);
+    let other_local_user = LocalUser::create(pool, &other_local_user_form, vec![]).await?;
+
+    let draft_form = DraftInsertForm {
+      name: Some("A draft post".to_string()),
+      ..DraftInsertForm::new(inserted_local_user.id, DraftKind::Post)
+    };
+    let inserted_draft = Draft::create(pool, &draft_form).await?;
+    assert_eq!(Some("A draft post".to_string()), inserted_draft.name);
+
+    // A comment draft always needs a post
+    let invalid_form = DraftInsertForm::new(inserted_local_user.id, DraftKind::Comment);
+    assert!(Draft::create(pool, &invalid_form).await.is_err());
+
+    let update_form = DraftUpdateForm {
+      body: Some(Some("Some body".to_string())),
+      ..Default::default()
+    };
+    let updated_draft = Draft::update(pool, inserted_draft.id, &update_form).await?;
+    assert_eq!(Some("Some body".to_string()), updated_draft.body);
+
+    // Other users can't see or delete the draft
+    assert!(
+      Draft::read_for_user(pool, inserted_draft.id, other_local_user.id)
+        .await
+        .is_err()
+    );
+    assert_eq!(
+      0,
+      Draft::delete_for_user(pool, inserted_draft.id, other_local_user.id).await?
+    );
+
+    let list = Draft::list_for_user(pool, inserted_local_user.id, None, None).await?;
+    assert_eq!(vec![updated_draft], list.items);
+    assert_eq!(
+      1,
+      Draft::count_for_user(pool, inserted_local_user.id).await?
+    );
+    assert_eq!(0, Draft::count_for_user(pool, other_local_user.id).await?);
+
+    // Recently edited drafts are kept
+    assert_eq!(0, Draft::delete_older_than(pool, 1).await?);
+
+    assert_eq!(
+      1,
+      Draft::delete_for_user(pool, inserted_draft.id, inserted_local_user.id).await?
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
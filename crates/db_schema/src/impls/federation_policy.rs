@@ -0,0 +1,74 @@
+use crate::source::federation_policy::{FederationPolicy, FederationPolicyForm};
+use chrono::Utc;
+use diesel::{ExpressionMethods, QueryDsl, delete, dsl::insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{InstanceId, schema::federation_policy};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::{
+  CACHE_DURATION_FEDERATION,
+  CacheLock,
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+};
+use moka::future::Cache;
+use std::{
+  collections::HashMap,
+  sync::{Arc, LazyLock},
+};
+
+/// All federation policies, keyed by instance. These are read for every incoming object and
+/// activity, so they are cached instead of querying the database each time.
+static POLICIES: CacheLock<Arc<HashMap<InstanceId, FederationPolicy>>> = LazyLock::new(|| {
+  Cache::builder()
+    .max_capacity(1)
+    .time_to_live(CACHE_DURATION_FEDERATION)
+    .build()
+});
+
+impl FederationPolicy {
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &FederationPolicyForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    let policy = insert_into(federation_policy::table)
+      .values(form)
+      .on_conflict(federation_policy::instance_id)
+      .do_update()
+      .set((form, federation_policy::updated_at.eq(Utc::now())))
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    POLICIES.invalidate_all();
+    Ok(policy)
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, instance_id_: InstanceId) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    let deleted =
+      delete(federation_policy::table.filter(federation_policy::instance_id.eq(instance_id_)))
+        .execute(conn)
+        .await
+        .with_lemmy_type(LemmyErrorType::Deleted)?;
+    POLICIES.invalidate_all();
+    Ok(deleted)
+  }
+
+  /// Returns `None` if there is no policy for the instance, meaning that its content is accepted
+  /// as is.
+  pub async fn read_for_instance(
+    pool: &mut DbPool<'_>,
+    instance_id_: InstanceId,
+  ) -> LemmyResult<Option<Self>> {
+    let policies = POLICIES
+      .try_get_with((), async move {
+        let conn = &mut get_conn(pool).await?;
+        let policies = federation_policy::table
+          .load::<Self>(conn)
+          .await
+          .with_lemmy_type(LemmyErrorType::NotFound)?;
+        LemmyResult::Ok(Arc::new(
+          policies.into_iter().map(|p| (p.instance_id, p)).collect(),
+        ))
+      })
+      .await
+      .map_err(|_e| LemmyErrorType::NotFound)?;
+    Ok(policies.get(&instance_id_).cloned())
+  }
+}
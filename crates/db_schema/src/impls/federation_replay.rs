@@ -0,0 +1,182 @@
+use crate::{
+  newtypes::{ActivityId, CommunityId, FederationReplayId},
+  source::{
+    activity::SentActivity,
+    community::Community,
+    federation_replay::{FederationReplay, FederationReplayInsertForm, FederationReplayUpdateForm},
+  },
+};
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, QueryDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{InstanceId, schema::federation_replay};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for FederationReplay {
+  type InsertForm = FederationReplayInsertForm;
+  type UpdateForm = FederationReplayUpdateForm;
+  type IdType = FederationReplayId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(federation_replay::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id: FederationReplayId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(federation_replay::table.find(id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl FederationReplay {
+  /// Store a new replay, which the federation queue picks up within a minute. Activities are
+  /// resent starting from the given activity id or time, up to the newest activity.
+  pub async fn schedule(
+    pool: &mut DbPool<'_>,
+    instance_id: InstanceId,
+    community_id: Option<CommunityId>,
+    since: Option<DateTime<Utc>>,
+    since_activity_id: Option<ActivityId>,
+  ) -> LemmyResult<Self> {
+    if let Some(community_id) = community_id {
+      // Only activities of local communities are sent to their followers
+      let community = Community::read(pool, community_id).await?;
+      if !community.local {
+        Err(LemmyErrorType::InvalidFederationReplay)?
+      }
+    }
+
+    if since_activity_id.is_some_and(|id| id.0 <= 0) {
+      Err(LemmyErrorType::InvalidFederationReplay)?
+    }
+
+    let start_id = match (since_activity_id, since) {
+      (Some(id), _) => Some(id),
+      (None, Some(since)) => SentActivity::first_id_since(pool, since).await?,
+      (None, None) => Err(LemmyErrorType::InvalidFederationReplay)?,
+    };
+    let end_id = SentActivity::latest_id(pool).await?;
+    let (Some(start_id), Some(end_id)) = (start_id, end_id) else {
+      Err(LemmyErrorType::NothingToReplay)?
+    };
+    if start_id > end_id {
+      Err(LemmyErrorType::NothingToReplay)?
+    }
+
+    let form = FederationReplayInsertForm::new(instance_id, community_id, start_id, end_id);
+    Self::create(pool, &form).await
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    federation_replay::table
+      .order_by(federation_replay::id.desc())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn list_unfinished(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    federation_replay::table
+      .filter(federation_replay::finished_at.is_null())
+      .order_by(federation_replay::id)
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+  use crate::{
+    newtypes::ActivityId,
+    source::{
+      activity::{SentActivity, SentActivityForm},
+      federation_replay::FederationReplay,
+      instance::Instance,
+    },
+  };
+  use lemmy_db_schema_file::enums::ActorType;
+  use lemmy_diesel_utils::connection::build_db_pool_for_tests;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_schedule() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "replay.example.com").await?;
+    let mut activities = vec![];
+    for i in 0..3 {
+      let form = SentActivityForm {
+        ap_id: Url::parse(&format!("http://example.com/activity/replay-{i}"))?.into(),
+        data: json!({}),
+        sensitive: false,
+        actor_apub_id: Url::parse("http://example.com/u/exampleuser")?.into(),
+        actor_type: ActorType::Person,
+        send_all_instances: true,
+        send_community_followers_of: None,
+        send_inboxes: vec![],
+      };
+      activities.push(SentActivity::create(pool, form).await?);
+    }
+    let second = &activities[1];
+    let last = &activities[2];
+
+    // Replay by time starts with the first activity published at that time
+    let replay =
+      FederationReplay::schedule(pool, instance.id, None, Some(second.published_at), None).await?;
+    assert!(replay.start_id <= second.id);
+    assert_eq!(last.id, replay.end_id);
+    assert_eq!(None, replay.started_at);
+
+    let replay = FederationReplay::schedule(pool, instance.id, None, None, Some(last.id)).await?;
+    assert_eq!(last.id, replay.start_id);
+    assert_eq!(last.id, replay.end_id);
+
+    let unfinished = FederationReplay::list_unfinished(pool).await?;
+    assert_eq!(2, unfinished.len());
+
+    // Needs a start, and there must be something to resend
+    let missing_start = FederationReplay::schedule(pool, instance.id, None, None, None).await;
+    assert!(missing_start.is_err());
+    let nothing = FederationReplay::schedule(
+      pool,
+      instance.id,
+      None,
+      None,
+      Some(ActivityId(last.id.0 + 1)),
+    )
+    .await;
+    assert!(nothing.is_err());
+    let negative =
+      FederationReplay::schedule(pool, instance.id, None, None, Some(ActivityId(-5))).await;
+    assert!(negative.is_err());
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -9,9 +9,10 @@ use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
   QueryDsl,
-  dsl::exists,
+  dsl::{exists, sql},
   insert_into,
   select,
+  sql_types::BigInt,
 };
 use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
 use lemmy_db_schema_file::{
@@ -86,6 +87,24 @@ impl LocalImage {
       .await
       .with_lemmy_type(LemmyErrorType::Deleted)
   }
+
+  /// Total size of all uploads by the person in bytes, for checking the upload quota.
+  /// Automatically generated post thumbnails are not counted.
+  pub async fn total_size_for_person(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    local_image::table
+      .filter(local_image::person_id.eq(person_id))
+      .filter(local_image::thumbnail_for_post_id.is_null())
+      .select(sql::<BigInt>(
+        "coalesce(sum(local_image.file_size), 0)::bigint",
+      ))
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
 }
 
 impl RemoteImage {
@@ -114,6 +133,14 @@ impl RemoteImage {
     .then_some(())
     .ok_or(LemmyErrorType::NotFound.into())
   }
+
+  pub async fn delete(pool: &mut DbPool<'_>, link_: DbUrl) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(remote_image::table.filter(remote_image::link.eq(link_)))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::Deleted)
+  }
 }
 
 impl ImageDetails {
@@ -77,6 +77,17 @@ impl Instance {
       }
     }
   }
+  /// Read the instance with the given domain, without creating it.
+  pub async fn read_from_domain(pool: &mut DbPool<'_>, domain_: &str) -> LemmyResult<Self> {
+    use lemmy_db_schema_file::schema::instance::domain;
+    let conn = &mut get_conn(pool).await?;
+    instance::table
+      .filter(lower(domain).eq(&domain_.to_lowercase()))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub async fn read(pool: &mut DbPool<'_>, instance_id: InstanceId) -> LemmyResult<Self> {
     let conn = &mut get_conn(pool).await?;
     instance::table
@@ -190,6 +201,20 @@ impl Instance {
 }
 
 impl InstanceActions {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    instance_id: InstanceId,
+    person_id: PersonId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    instance_actions::table
+      .find((person_id, instance_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
   pub async fn block_communities(
     pool: &mut DbPool<'_>,
     form: &InstanceCommunitiesBlockForm,
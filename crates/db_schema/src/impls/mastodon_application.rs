@@ -0,0 +1,171 @@
+use crate::source::mastodon_application::{
+  MastodonApplication,
+  MastodonApplicationInsertForm,
+  MastodonAuthorizationCode,
+  MastodonAuthorizationCodeInsertForm,
+};
+use diesel::{
+  ExpressionMethods,
+  IntoSql,
+  QueryDsl,
+  delete,
+  dsl::{IntervalDsl, insert_into, now},
+  sql_types::Timestamptz,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::{mastodon_application, mastodon_authorization_code};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl MastodonApplication {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &MastodonApplicationInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(mastodon_application::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  pub async fn read_from_client_id(pool: &mut DbPool<'_>, client_id: &str) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    mastodon_application::table
+      .filter(mastodon_application::client_id.eq(client_id))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::OauthAuthorizationInvalid)
+  }
+}
+
+impl MastodonAuthorizationCode {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &MastodonAuthorizationCodeInsertForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    // Remove codes which were never exchanged
+    delete(mastodon_authorization_code::table)
+      .filter(
+        mastodon_authorization_code::published_at.lt(now.into_sql::<Timestamptz>() - 10.minutes()),
+      )
+      .execute(conn)
+      .await?;
+    insert_into(mastodon_authorization_code::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// Codes can only be used once, and are valid for ten minutes.
+  pub async fn read_and_delete(pool: &mut DbPool<'_>, code: &str) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    delete(mastodon_authorization_code::table)
+      .filter(mastodon_authorization_code::code.eq(code))
+      .filter(
+        mastodon_authorization_code::published_at.gt(now.into_sql::<Timestamptz>() - 10.minutes()),
+      )
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::OauthAuthorizationInvalid)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    mastodon_application::{
+      MastodonApplication,
+      MastodonApplicationInsertForm,
+      MastodonAuthorizationCode,
+      MastodonAuthorizationCodeInsertForm,
+    },
+    person::{Person, PersonInsertForm},
+  };
+  use diesel::{ExpressionMethods, QueryDsl, dsl::IntervalDsl};
+  use diesel_async::RunQueryDsl;
+  use lemmy_db_schema_file::schema::mastodon_authorization_code;
+  use lemmy_diesel_utils::{
+    connection::{build_db_pool_for_tests, get_conn},
+    traits::Crud,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_authorization_code() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "mastodon-app.tld").await?;
+    let person = Person::create(
+      pool,
+      &PersonInsertForm::test_form(instance.id, "mastodon_app"),
+    )
+    .await?;
+    let local_user =
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(person.id), vec![]).await?;
+
+    let app_form = MastodonApplicationInsertForm::new(
+      "client".to_string(),
+      "secret".to_string().into(),
+      "app".to_string(),
+      None,
+      vec!["https://app.tld/callback".to_string()],
+      "read".to_string(),
+    );
+    let app = MastodonApplication::create(pool, &app_form).await?;
+    assert_eq!(
+      app,
+      MastodonApplication::read_from_client_id(pool, "client").await?
+    );
+    assert!(
+      MastodonApplication::read_from_client_id(pool, "other")
+        .await
+        .is_err()
+    );
+
+    let code_form = MastodonAuthorizationCodeInsertForm::new(
+      "code".to_string().into(),
+      app.id,
+      local_user.id,
+      "https://app.tld/callback".to_string(),
+    );
+    let code = MastodonAuthorizationCode::create(pool, &code_form).await?;
+    assert_eq!(
+      code,
+      MastodonAuthorizationCode::read_and_delete(pool, "code").await?
+    );
+
+    // Cannot reuse the same code again
+    assert!(
+      MastodonAuthorizationCode::read_and_delete(pool, "code")
+        .await
+        .is_err()
+    );
+
+    // Expired codes are rejected
+    MastodonAuthorizationCode::create(pool, &code_form).await?;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(mastodon_authorization_code::table.find("code"))
+      .set(mastodon_authorization_code::published_at.eq(diesel::dsl::now - 11.minutes()))
+      .execute(conn)
+      .await?;
+    assert!(
+      MastodonAuthorizationCode::read_and_delete(pool, "code")
+        .await
+        .is_err()
+    );
+
+    Person::delete(pool, person.id).await?;
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -1,5 +1,5 @@
 use crate::{
-  newtypes::CommunityId,
+  newtypes::{CommunityId, RuleId},
   source::{
     comment::Comment,
     modlog::{Modlog, ModlogInsertForm},
@@ -8,7 +8,12 @@ use crate::{
   },
 };
 use chrono::{DateTime, Utc};
-use diesel::dsl::insert_into;
+use diesel::{
+  ExpressionMethods,
+  PgExpressionMethods,
+  QueryDsl,
+  dsl::{insert_into, max, not},
+};
 use diesel_async::RunQueryDsl;
 #[cfg(feature = "full")]
 use lemmy_db_schema_file::schema::modlog;
@@ -28,9 +33,51 @@ impl Modlog {
       .await
       .with_lemmy_type(LemmyErrorType::CouldntCreate)
   }
+
+  /// Count the warnings which a person received since the given time. If `community_id` is set,
+  /// only warnings in that community are counted, otherwise only site warnings. Warnings from
+  /// before the last ban are not counted, as they were already punished with that ban.
+  pub async fn count_warnings(
+    pool: &mut DbPool<'_>,
+    target_person_id: PersonId,
+    community_id: Option<CommunityId>,
+    since: DateTime<Utc>,
+  ) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    let (warn_kind, ban_kind) = if community_id.is_some() {
+      (ModlogKind::ModWarnUser, ModlogKind::ModBanFromCommunity)
+    } else {
+      (ModlogKind::AdminWarnUser, ModlogKind::AdminBan)
+    };
+    let last_ban: Option<DateTime<Utc>> = modlog::table
+      .filter(modlog::kind.eq(ban_kind))
+      .filter(modlog::target_person_id.eq(target_person_id))
+      .filter(modlog::target_community_id.is_not_distinct_from(community_id))
+      .filter(not(modlog::is_revert))
+      .select(max(modlog::published_at))
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    let since = last_ban.map_or(since, |b| b.max(since));
+
+    modlog::table
+      .filter(modlog::kind.eq(warn_kind))
+      .filter(modlog::target_person_id.eq(target_person_id))
+      .filter(modlog::target_community_id.is_not_distinct_from(community_id))
+      .filter(modlog::published_at.gt(since))
+      .filter(not(modlog::is_revert))
+      .count()
+      .get_result(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
 }
 
 impl<'a> ModlogInsertForm<'a> {
+  /// Cite the community or site rule which was violated.
+  pub fn with_rule(self, rule_id: Option<RuleId>) -> Self {
+    Self { rule_id, ..self }
+  }
   pub fn admin_ban(
     mod_person: &Person,
     target_person_id: PersonId,
@@ -121,6 +168,18 @@ impl<'a> ModlogInsertForm<'a> {
     }
   }
 
+  pub fn admin_merge_community(
+    mod_person_id: PersonId,
+    community_id: CommunityId,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_community_id: Some(community_id),
+      ..ModlogInsertForm::new(ModlogKind::AdminMergeCommunity, false, mod_person_id)
+    }
+  }
+
   pub fn mod_change_community_visibility(
     mod_person_id: PersonId,
     community_id: CommunityId,
@@ -150,6 +209,22 @@ impl<'a> ModlogInsertForm<'a> {
       ..ModlogInsertForm::new(ModlogKind::ModBanFromCommunity, !removed, mod_person_id)
     }
   }
+  pub fn mod_mute_from_community(
+    mod_person_id: PersonId,
+    community_id: CommunityId,
+    target_person_id: PersonId,
+    muted: bool,
+    expires_at: Option<DateTime<Utc>>,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      expires_at,
+      target_community_id: Some(community_id),
+      target_person_id: Some(target_person_id),
+      ..ModlogInsertForm::new(ModlogKind::ModMuteFromCommunity, !muted, mod_person_id)
+    }
+  }
   pub fn mod_add_to_community(
     mod_person_id: PersonId,
     community_id: CommunityId,
@@ -197,6 +272,22 @@ impl<'a> ModlogInsertForm<'a> {
       ..ModlogInsertForm::new(ModlogKind::AdminBlockInstance, !block, mod_person_id)
     }
   }
+  pub fn admin_set_instance_policy(
+    mod_person_id: PersonId,
+    instance_id: InstanceId,
+    has_restrictions: bool,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_instance_id: Some(instance_id),
+      ..ModlogInsertForm::new(
+        ModlogKind::AdminSetInstancePolicy,
+        !has_restrictions,
+        mod_person_id,
+      )
+    }
+  }
   pub fn admin_purge_comment(
     mod_person_id: PersonId,
     comment: &Comment,
@@ -234,6 +325,17 @@ impl<'a> ModlogInsertForm<'a> {
       ..ModlogInsertForm::new(ModlogKind::AdminPurgePerson, false, mod_person_id)
     }
   }
+  pub fn admin_purge_user_media(
+    mod_person_id: PersonId,
+    target_person_id: PersonId,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      target_person_id: Some(target_person_id),
+      reason: Some(reason),
+      ..ModlogInsertForm::new(ModlogKind::AdminPurgeUserMedia, false, mod_person_id)
+    }
+  }
   pub fn mod_feature_post_community(mod_person_id: PersonId, post: &Post, featured: bool) -> Self {
     Self {
       target_post_id: Some(post.id),
@@ -245,6 +347,63 @@ impl<'a> ModlogInsertForm<'a> {
       )
     }
   }
+  pub fn mod_deny_ban_appeal(
+    mod_person_id: PersonId,
+    community_id: CommunityId,
+    target_person_id: PersonId,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_community_id: Some(community_id),
+      target_person_id: Some(target_person_id),
+      ..ModlogInsertForm::new(ModlogKind::ModDenyBanAppeal, false, mod_person_id)
+    }
+  }
+  pub fn admin_deny_ban_appeal(
+    mod_person: &Person,
+    target_person_id: PersonId,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_person_id: Some(target_person_id),
+      target_instance_id: Some(mod_person.instance_id),
+      ..ModlogInsertForm::new(ModlogKind::AdminDenyBanAppeal, false, mod_person.id)
+    }
+  }
+  pub fn mod_warn_user(
+    mod_person_id: PersonId,
+    community_id: CommunityId,
+    target_person_id: PersonId,
+    reason: &'a str,
+  ) -> Self {
+    Self {
+      reason: Some(reason),
+      target_community_id: Some(community_id),
+      target_person_id: Some(target_person_id),
+      ..ModlogInsertForm::new(ModlogKind::ModWarnUser, false, mod_person_id)
+    }
+  }
+  pub fn admin_warn_user(mod_person: &Person, target_person_id: PersonId, reason: &'a str) -> Self {
+    Self {
+      reason: Some(reason),
+      target_person_id: Some(target_person_id),
+      target_instance_id: Some(mod_person.instance_id),
+      ..ModlogInsertForm::new(ModlogKind::AdminWarnUser, false, mod_person.id)
+    }
+  }
+  pub fn admin_restrict_user(
+    mod_person: &Person,
+    target_person_id: PersonId,
+    restricted: bool,
+  ) -> Self {
+    Self {
+      target_person_id: Some(target_person_id),
+      target_instance_id: Some(mod_person.instance_id),
+      ..ModlogInsertForm::new(ModlogKind::AdminRestrictUser, !restricted, mod_person.id)
+    }
+  }
   pub fn admin_feature_post_site(mod_person_id: PersonId, post: &Post, featured: bool) -> Self {
     Self {
       target_post_id: Some(post.id),
@@ -0,0 +1,131 @@
+use crate::{
+  newtypes::{ModmailMessageId, ModmailThreadId},
+  source::modmail::{
+    ModmailMessage,
+    ModmailMessageInsertForm,
+    ModmailThread,
+    ModmailThreadInsertForm,
+    ModmailThreadUpdateForm,
+  },
+};
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::{modmail_message, modmail_thread};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for ModmailThread {
+  type InsertForm = ModmailThreadInsertForm;
+  type UpdateForm = ModmailThreadUpdateForm;
+  type IdType = ModmailThreadId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(modmail_thread::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    thread_id: ModmailThreadId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(modmail_thread::table.find(thread_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl ModmailThread {
+  pub async fn read_from_ap_id(pool: &mut DbPool<'_>, ap_id: &DbUrl) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    modmail_thread::table
+      .filter(modmail_thread::ap_id.eq(ap_id))
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Update the thread after a new message was added. The sender has obviously read the thread,
+  /// and a message from the user reopens an archived thread.
+  pub async fn add_message(
+    pool: &mut DbPool<'_>,
+    thread_id: ModmailThreadId,
+    from_mod: bool,
+    published_at: DateTime<Utc>,
+  ) -> LemmyResult<Self> {
+    let form = if from_mod {
+      ModmailThreadUpdateForm {
+        mods_read_at: Some(Some(published_at)),
+        last_message_at: Some(published_at),
+        ..Default::default()
+      }
+    } else {
+      ModmailThreadUpdateForm {
+        archived: Some(false),
+        creator_read_at: Some(published_at),
+        last_message_at: Some(published_at),
+        ..Default::default()
+      }
+    };
+    Self::update(pool, thread_id, &form).await
+  }
+}
+
+impl Crud for ModmailMessage {
+  type InsertForm = ModmailMessageInsertForm;
+  type UpdateForm = ModmailMessageInsertForm;
+  type IdType = ModmailMessageId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(modmail_message::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    message_id: ModmailMessageId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(modmail_message::table.find(message_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl ModmailMessage {
+  /// Insert a federated message. Returns `None` if it was already received before.
+  pub async fn insert_apub(
+    pool: &mut DbPool<'_>,
+    form: &ModmailMessageInsertForm,
+  ) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(modmail_message::table)
+      .values(form)
+      .on_conflict(modmail_message::ap_id)
+      .do_nothing()
+      .get_result::<Self>(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+}
@@ -1,6 +1,6 @@
 use crate::{
   diesel::{BoolExpressionMethods, NullableExpressionMethods, OptionalExtension},
-  newtypes::{CommunityId, LocalUserId},
+  newtypes::LocalUserId,
   source::person::{
     Person,
     PersonActions,
@@ -233,9 +233,21 @@ impl Followable for PersonActions {
       .with_lemmy_type(LemmyErrorType::AlreadyExists)
   }
 
-  /// Currently no user following
-  async fn follow_accepted(_: &mut DbPool<'_>, _: CommunityId, _: PersonId) -> LemmyResult<Self> {
-    Err(LemmyErrorType::NotFound.into())
+  async fn follow_accepted(
+    pool: &mut DbPool<'_>,
+    target_id: Self::IdType,
+    person_id: PersonId,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    let find_action = person_actions::table
+      .find((person_id, target_id))
+      .filter(person_actions::follow_pending.is_not_null());
+    diesel::update(find_action)
+      .set(person_actions::follow_pending.eq(Some(false)))
+      .returning(Self::as_select())
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
 
   async fn unfollow(
@@ -0,0 +1,160 @@
+use crate::{
+  newtypes::PersonReportId,
+  source::person_report::{PersonReport, PersonReportForm},
+  traits::Reportable,
+};
+use chrono::Utc;
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  QueryDsl,
+  dsl::{insert_into, update},
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{PersonId, schema::person_report};
+use lemmy_diesel_utils::connection::{DbPool, get_conn};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Reportable for PersonReport {
+  type Form = PersonReportForm;
+  type IdType = PersonReportId;
+  type ObjectIdType = PersonId;
+  /// creates a person report and returns it. If the creator already reported the person, the
+  /// existing report is updated and reopened instead. This happens for reports forwarded by other
+  /// instances, which all use the same system account as creator.
+  ///
+  /// * `conn` - the postgres connection
+  /// * `person_report_form` - the filled PersonReportForm to insert
+  async fn report(pool: &mut DbPool<'_>, form: &Self::Form) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(person_report::table)
+      .values(form)
+      .on_conflict((person_report::person_id, person_report::creator_id))
+      .do_update()
+      .set((
+        form,
+        person_report::resolved.eq(false),
+        person_report::resolver_id.eq(None::<PersonId>),
+        person_report::updated_at.eq(Utc::now()),
+      ))
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  /// resolve a person report
+  ///
+  /// * `conn` - the postgres connection
+  /// * `report_id` - the id of the report to resolve
+  /// * `by_resolver_id` - the id of the user resolving the report
+  async fn update_resolved(
+    pool: &mut DbPool<'_>,
+    report_id_: Self::IdType,
+    by_resolver_id: PersonId,
+    is_resolved: bool,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(person_report::table.find(report_id_))
+      .set((
+        person_report::resolved.eq(is_resolved),
+        person_report::resolver_id.eq(by_resolver_id),
+        person_report::updated_at.eq(Utc::now()),
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  async fn resolve_apub(
+    pool: &mut DbPool<'_>,
+    object_id: Self::ObjectIdType,
+    report_creator_id: PersonId,
+    resolver_id: PersonId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      person_report::table.filter(
+        person_report::person_id
+          .eq(object_id)
+          .and(person_report::creator_id.eq(report_creator_id)),
+      ),
+    )
+    .set((
+      person_report::resolved.eq(true),
+      person_report::resolver_id.eq(resolver_id),
+      person_report::updated_at.eq(Utc::now()),
+    ))
+    .execute(conn)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+
+  async fn resolve_all_for_object(
+    pool: &mut DbPool<'_>,
+    person_id_: Self::ObjectIdType,
+    by_resolver_id: PersonId,
+  ) -> LemmyResult<usize> {
+    let conn = &mut get_conn(pool).await?;
+    update(person_report::table.filter(person_report::person_id.eq(person_id_)))
+      .set((
+        person_report::resolved.eq(true),
+        person_report::resolver_id.eq(by_resolver_id),
+        person_report::updated_at.eq(Utc::now()),
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use crate::source::{
+    instance::Instance,
+    person::{Person, PersonInsertForm},
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_repeated_person_report() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let creator = Person::create(pool, &PersonInsertForm::test_form(instance.id, "mara")).await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "olek")).await?;
+
+    let form = PersonReportForm {
+      creator_id: creator.id,
+      person_id: person.id,
+      original_person_name: person.name.clone(),
+      original_person_display_name: None,
+      original_person_bio: None,
+      original_person_avatar: None,
+      original_person_banner: None,
+      reason: "first reason".to_string(),
+    };
+    let report = PersonReport::report(pool, &form).await?;
+    PersonReport::update_resolved(pool, report.id, creator.id, true).await?;
+
+    // Reporting the same person again reopens the existing report
+    let form = PersonReportForm {
+      reason: "second reason".to_string(),
+      ..form
+    };
+    let repeated = PersonReport::report(pool, &form).await?;
+    assert_eq!(report.id, repeated.id);
+    assert_eq!("second reason", repeated.reason);
+    assert!(!repeated.resolved);
+    assert_eq!(None, repeated.resolver_id);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
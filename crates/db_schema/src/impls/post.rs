@@ -242,6 +242,25 @@ impl Post {
       .with_lemmy_type(LemmyErrorType::CouldntUpdate)
   }
 
+  /// Publish all posts which were held back while the creator was shadow restricted. Returns the
+  /// released posts in the order they were written.
+  pub async fn release_held_for_creator(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+
+    let mut posts: Vec<Self> = update(post::table)
+      .filter(post::creator_id.eq(creator_id))
+      .filter(post::held_for_review)
+      .set(post::held_for_review.eq(false))
+      .get_results(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    posts.sort_by_key(|p| p.published_at);
+    Ok(posts)
+  }
+
   pub fn is_post_creator(person_id: PersonId, post_creator_id: PersonId) -> bool {
     person_id == post_creator_id
   }
@@ -667,6 +686,7 @@ mod tests {
       scaled_rank: RANK_DEFAULT,
       unresolved_report_count: 0,
       federation_pending: false,
+      held_for_review: false,
     };
 
     // Post Like
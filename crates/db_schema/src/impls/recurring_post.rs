@@ -0,0 +1,147 @@
+use crate::{
+  newtypes::{CommunityId, RecurringPostId, TagId},
+  source::recurring_post::{
+    RecurringPost,
+    RecurringPostInsertForm,
+    RecurringPostTag,
+    RecurringPostUpdateForm,
+    recurring_post_keys as key,
+  },
+  utils::limit_fetch,
+};
+use diesel::{ExpressionMethods, QueryDsl, delete, insert_into};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use i_love_jesus::SortDirection;
+use lemmy_db_schema_file::schema::{recurring_post, recurring_post_tag};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  pagination::{
+    CursorData,
+    PagedResponse,
+    PaginationCursor,
+    PaginationCursorConversion,
+    paginate_response,
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for RecurringPost {
+  type InsertForm = RecurringPostInsertForm;
+  type UpdateForm = RecurringPostUpdateForm;
+  type IdType = RecurringPostId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(recurring_post::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    recurring_post_id: RecurringPostId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(recurring_post::table.find(recurring_post_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl PaginationCursorConversion for RecurringPost {
+  type PaginatedType = RecurringPost;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    RecurringPost::read(pool, RecurringPostId(cursor.id()?)).await
+  }
+}
+
+impl RecurringPost {
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    page_cursor: Option<PaginationCursor>,
+    limit: Option<i64>,
+  ) -> LemmyResult<PagedResponse<Self>> {
+    let limit = limit_fetch(limit, None)?;
+    let query = recurring_post::table
+      .filter(recurring_post::community_id.eq(community_id))
+      .limit(limit)
+      .into_boxed();
+    let paginated_query = Self::paginate(query, &page_cursor, SortDirection::Desc, pool, None)
+      .await?
+      .then_order_by(key::published_at)
+      .then_order_by(key::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    paginate_response(res, limit, page_cursor)
+  }
+}
+
+impl RecurringPostTag {
+  /// Replaces all tags of the recurring post.
+  pub async fn update(
+    pool: &mut DbPool<'_>,
+    recurring_post_id: RecurringPostId,
+    tag_ids: &[TagId],
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+
+    conn
+      .run_transaction(|conn| {
+        async move {
+          delete(
+            recurring_post_tag::table
+              .filter(recurring_post_tag::recurring_post_id.eq(recurring_post_id)),
+          )
+          .execute(conn)
+          .await
+          .with_lemmy_type(LemmyErrorType::Deleted)?;
+
+          let forms = tag_ids
+            .iter()
+            .map(|tag_id| RecurringPostTag {
+              recurring_post_id,
+              tag_id: *tag_id,
+            })
+            .collect::<Vec<_>>();
+          insert_into(recurring_post_tag::table)
+            .values(forms)
+            .get_results(conn)
+            .await
+            .with_lemmy_type(LemmyErrorType::CouldntCreate)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  pub async fn read_for_recurring_posts(
+    pool: &mut DbPool<'_>,
+    recurring_post_ids: &[RecurringPostId],
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    recurring_post_tag::table
+      .filter(recurring_post_tag::recurring_post_id.eq_any(recurring_post_ids))
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
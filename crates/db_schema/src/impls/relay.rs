@@ -0,0 +1,145 @@
+use crate::{
+  newtypes::RelayId,
+  source::relay::{Relay, RelayInsertForm, RelayUpdateForm},
+};
+use diesel::{
+  ExpressionMethods,
+  NullableExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+  insert_into,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::relay;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+  utils::{functions::lower, now},
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for Relay {
+  type InsertForm = RelayInsertForm;
+  type UpdateForm = RelayUpdateForm;
+  type IdType = RelayId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(relay::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id: RelayId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(relay::table.find(id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl Relay {
+  pub async fn list(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    relay::table
+      .order_by(relay::id)
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: &DbUrl,
+  ) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    relay::table
+      .filter(lower(relay::ap_id).eq(object_id.to_lowercase()))
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Update the stats after an announce was received from the relay.
+  pub async fn record_announce(
+    pool: &mut DbPool<'_>,
+    id: RelayId,
+    post_imported: bool,
+    community_discovered: bool,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(relay::table.find(id))
+      .set((
+        relay::last_received_at.eq(now().nullable()),
+        relay::announces_received.eq(relay::announces_received + 1),
+        relay::posts_imported.eq(relay::posts_imported + i64::from(post_imported)),
+        relay::communities_discovered
+          .eq(relay::communities_discovered + i64::from(community_discovered)),
+      ))
+      .execute(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::source::{
+    instance::Instance,
+    relay::{Relay, RelayInsertForm},
+  };
+  use lemmy_db_schema_file::enums::{CommunityFollowerState, RelayMode};
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, traits::Crud};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_record_announce() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "relay.example.com").await?;
+    let ap_id: Url = "https://relay.example.com/actor".parse()?;
+    let inbox: Url = "https://relay.example.com/inbox".parse()?;
+    let form = RelayInsertForm::new(
+      ap_id.clone().into(),
+      inbox.into(),
+      "pubkey".to_string(),
+      instance.id,
+      RelayMode::KnownCommunities,
+    );
+    let relay = Relay::create(pool, &form).await?;
+    assert_eq!(CommunityFollowerState::Pending, relay.follow_state);
+
+    Relay::record_announce(pool, relay.id, true, false).await?;
+    Relay::record_announce(pool, relay.id, false, false).await?;
+
+    let read = Relay::read_from_apub_id(pool, &ap_id.into())
+      .await?
+      .map(|r| {
+        (
+          r.announces_received,
+          r.posts_imported,
+          r.communities_discovered,
+        )
+      });
+    assert_eq!(Some((2, 1, 0)), read);
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
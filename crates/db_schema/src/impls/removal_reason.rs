@@ -0,0 +1,58 @@
+use crate::{
+  newtypes::{CommunityId, RemovalReasonId},
+  source::removal_reason::{RemovalReason, RemovalReasonInsertForm, RemovalReasonUpdateForm},
+};
+use diesel::{ExpressionMethods, QueryDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::schema::removal_reason;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for RemovalReason {
+  type InsertForm = RemovalReasonInsertForm;
+  type UpdateForm = RemovalReasonUpdateForm;
+  type IdType = RemovalReasonId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(removal_reason::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    removal_reason_id: RemovalReasonId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(removal_reason::table.find(removal_reason_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl RemovalReason {
+  /// Removal reasons of the given community, ordered by title.
+  pub async fn read_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    removal_reason::table
+      .filter(removal_reason::community_id.eq(community_id))
+      .filter(removal_reason::deleted.eq(false))
+      .order_by(removal_reason::title)
+      .then_order_by(removal_reason::id)
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
@@ -0,0 +1,66 @@
+use crate::{
+  newtypes::{ReportCombinedId, ReportNoteId},
+  source::{
+    combined::report::ReportCombined,
+    report_note::{ReportNote, ReportNoteInsertForm},
+  },
+};
+use chrono::Utc;
+use diesel::{ExpressionMethods, QueryDsl, insert_into};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{report_combined, report_note},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl Crud for ReportNote {
+  type InsertForm = ReportNoteInsertForm;
+  type UpdateForm = ReportNoteInsertForm;
+  type IdType = ReportNoteId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(report_note::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    note_id: ReportNoteId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(report_note::table.find(note_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl ReportCombined {
+  /// Assign the report to a mod, or remove the assignment if `assignee_id` is none.
+  pub async fn assign(
+    pool: &mut DbPool<'_>,
+    report_id: ReportCombinedId,
+    assignee_id: Option<PersonId>,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(report_combined::table.find(report_id))
+      .set((
+        report_combined::assignee_id.eq(assignee_id),
+        report_combined::assigned_at.eq(assignee_id.map(|_| Utc::now())),
+      ))
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
@@ -0,0 +1,219 @@
+use crate::{
+  newtypes::{CommunityId, RuleId},
+  source::rule::{Rule, RuleInsertForm, RuleUpdateForm},
+};
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+  insert_into,
+  upsert::excluded,
+};
+use diesel_async::{RunQueryDsl, scoped_futures::ScopedFutureExt};
+use lemmy_db_schema_file::schema::rule;
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  dburl::DbUrl,
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use std::collections::HashSet;
+
+impl Crud for Rule {
+  type InsertForm = RuleInsertForm;
+  type UpdateForm = RuleUpdateForm;
+  type IdType = RuleId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(rule::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreate)
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    rule_id: RuleId,
+    form: &Self::UpdateForm,
+  ) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(rule::table.find(rule_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdate)
+  }
+}
+
+impl Rule {
+  /// Rules of the given community in display order, or site rules if no community is given.
+  pub async fn read_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = rule::table
+      .filter(rule::deleted.eq(false))
+      .order_by(rule::position)
+      .then_order_by(rule::id)
+      .into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(rule::community_id.eq(community_id)),
+      None => query.filter(rule::community_id.is_null()),
+    };
+    query
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn read_from_ap_id(pool: &mut DbPool<'_>, ap_id: &DbUrl) -> LemmyResult<Option<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    rule::table
+      .filter(rule::ap_id.eq(ap_id))
+      .filter(rule::deleted.eq(false))
+      .first(conn)
+      .await
+      .optional()
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Replace the rules of a remote community with those from the federated `Group`. Rules which
+  /// are not included anymore are marked as deleted, so that existing reports keep their rule.
+  /// Rules which belong to a different community or to the site are never changed.
+  pub async fn update_many(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    mut forms: Vec<RuleInsertForm>,
+    existing_rules: Vec<Rule>,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    forms.retain(|f| f.community_id == Some(community_id));
+    let new_rule_ids = forms
+      .iter()
+      .filter_map(|rule| rule.ap_id.clone())
+      .collect::<HashSet<_>>();
+    let delete_forms = existing_rules
+      .into_iter()
+      .filter(|rule| !new_rule_ids.contains(&rule.ap_id))
+      .map(|r| RuleInsertForm {
+        ap_id: Some(r.ap_id),
+        deleted: Some(true),
+        ..RuleInsertForm::new(r.community_id, r.title)
+      });
+    forms.extend(delete_forms);
+    if forms.is_empty() {
+      return Ok(());
+    }
+
+    conn
+      .run_transaction(|conn| {
+        async move {
+          // The upsert below would overwrite rules with the same ap_id of other communities
+          let ap_ids: Vec<DbUrl> = forms.iter().filter_map(|f| f.ap_id.clone()).collect();
+          let foreign_ids: HashSet<DbUrl> = rule::table
+            .filter(rule::ap_id.eq_any(ap_ids))
+            .filter(
+              rule::community_id
+                .is_null()
+                .or(rule::community_id.ne(community_id)),
+            )
+            .select(rule::ap_id)
+            .load(conn)
+            .await?
+            .into_iter()
+            .collect();
+          forms.retain(|f| f.ap_id.as_ref().is_some_and(|a| !foreign_ids.contains(a)));
+          if forms.is_empty() {
+            return Ok(());
+          }
+
+          insert_into(rule::table)
+            .values(&forms)
+            .on_conflict(rule::ap_id)
+            .do_update()
+            .set((
+              rule::title.eq(excluded(rule::title)),
+              rule::description.eq(excluded(rule::description)),
+              rule::position.eq(excluded(rule::position)),
+              rule::deleted.eq(excluded(rule::deleted)),
+            ))
+            .execute(conn)
+            .await?;
+
+          Ok(())
+        }
+        .scope_boxed()
+      })
+      .await?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::source::{
+    community::{Community, CommunityInsertForm},
+    instance::Instance,
+  };
+  use lemmy_diesel_utils::connection::build_db_pool_for_tests;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_update_many_other_community() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "rules.example.com").await?;
+    let form = CommunityInsertForm::new(
+      instance.id,
+      "rules_a".to_string(),
+      "rules a".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community_a = Community::create(pool, &form).await?;
+    let form = CommunityInsertForm::new(
+      instance.id,
+      "rules_b".to_string(),
+      "rules b".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community_b = Community::create(pool, &form).await?;
+
+    let ap_id: DbUrl = Url::parse("https://rules.example.com/c/rules_a/rule/1")?.into();
+    let form = RuleInsertForm {
+      ap_id: Some(ap_id.clone()),
+      ..RuleInsertForm::new(Some(community_a.id), "Be nice".to_string())
+    };
+    Rule::create(pool, &form).await?;
+
+    // Community b sends a rule with the same id, which must not overwrite the rule of community a
+    let form = RuleInsertForm {
+      ap_id: Some(ap_id.clone()),
+      ..RuleInsertForm::new(Some(community_b.id), "Overwritten".to_string())
+    };
+    Rule::update_many(pool, community_b.id, vec![form], vec![]).await?;
+
+    let rule = Rule::read_from_ap_id(pool, &ap_id).await?;
+    assert_eq!(
+      Some(("Be nice".to_string(), Some(community_a.id))),
+      rule.map(|r| (r.title, r.community_id))
+    );
+    assert!(
+      Rule::read_for_community(pool, Some(community_b.id))
+        .await?
+        .is_empty()
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -172,6 +172,7 @@ pub enum ReportType {
   Comments,
   PrivateMessages,
   Communities,
+  Persons,
 }
 
 #[derive(
@@ -189,6 +190,18 @@ pub enum PostFeatureType {
   Community,
 }
 
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// How the author of removed content is told about the removal reason.
+pub enum RemovalNotification {
+  /// A private message from the mod to the author.
+  PrivateMessage,
+  /// A distinguished mod comment replying to the removed post or comment.
+  Comment,
+}
+
 #[derive(
   EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash,
 )]
@@ -264,6 +277,33 @@ pub type Person2AliasAllColumnsTuple = (
   AliasedField<aliases::Person2, person::comment_score>,
 );
 
+#[cfg(feature = "full")]
+/// A helper tuple for person 3 alias columns
+pub type Person3AliasAllColumnsTuple = (
+  AliasedField<aliases::Person3, person::id>,
+  AliasedField<aliases::Person3, person::name>,
+  AliasedField<aliases::Person3, person::display_name>,
+  AliasedField<aliases::Person3, person::avatar>,
+  AliasedField<aliases::Person3, person::published_at>,
+  AliasedField<aliases::Person3, person::updated_at>,
+  AliasedField<aliases::Person3, person::ap_id>,
+  AliasedField<aliases::Person3, person::bio>,
+  AliasedField<aliases::Person3, person::local>,
+  AliasedField<aliases::Person3, person::private_key>,
+  AliasedField<aliases::Person3, person::public_key>,
+  AliasedField<aliases::Person3, person::last_refreshed_at>,
+  AliasedField<aliases::Person3, person::banner>,
+  AliasedField<aliases::Person3, person::deleted>,
+  AliasedField<aliases::Person3, person::inbox_url>,
+  AliasedField<aliases::Person3, person::matrix_user_id>,
+  AliasedField<aliases::Person3, person::bot_account>,
+  AliasedField<aliases::Person3, person::instance_id>,
+  AliasedField<aliases::Person3, person::post_count>,
+  AliasedField<aliases::Person3, person::post_score>,
+  AliasedField<aliases::Person3, person::comment_count>,
+  AliasedField<aliases::Person3, person::comment_score>,
+);
+
 #[cfg(feature = "full")]
 /// A helper tuple for more my instance persons actions
 pub type MyInstancePersonsActionsAllColumnsTuple = (
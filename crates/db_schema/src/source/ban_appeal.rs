@@ -0,0 +1,55 @@
+use crate::newtypes::{BanAppealId, CommunityId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {i_love_jesus::CursorKeysModule, lemmy_db_schema_file::schema::ban_appeal};
+
+/// An appeal of a banned user against a community ban, or against a site ban if `community_id`
+/// is empty.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = ban_appeal_keys))]
+pub struct BanAppeal {
+  pub id: BanAppealId,
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+  /// When the appealed ban was issued. There can only be one appeal per ban.
+  pub banned_at: DateTime<Utc>,
+  /// The mod or admin who accepted or denied the appeal.
+  pub resolver_id: Option<PersonId>,
+  pub accepted: bool,
+  pub deny_reason: Option<String>,
+  pub published_at: DateTime<Utc>,
+  pub resolved_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+pub struct BanAppealInsertForm {
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+  pub banned_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+pub struct BanAppealUpdateForm {
+  pub resolver_id: Option<Option<PersonId>>,
+  pub accepted: Option<bool>,
+  pub deny_reason: Option<Option<String>>,
+  pub resolved_at: Option<Option<DateTime<Utc>>>,
+}
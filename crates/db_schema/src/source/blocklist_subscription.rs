@@ -0,0 +1,56 @@
+use crate::newtypes::BlocklistSubscriptionId;
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::enums::BlocklistFormat;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{blocklist_subscription, blocklist_subscription_entry};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = blocklist_subscription))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An external instance blocklist which is synced periodically.
+pub struct BlocklistSubscription {
+  pub id: BlocklistSubscriptionId,
+  pub url: String,
+  pub format: BlocklistFormat,
+  pub published_at: DateTime<Utc>,
+  pub synced_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = blocklist_subscription))]
+pub struct BlocklistSubscriptionInsertForm {
+  pub url: String,
+  pub format: BlocklistFormat,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = blocklist_subscription))]
+pub struct BlocklistSubscriptionUpdateForm {
+  pub synced_at: Option<Option<DateTime<Utc>>>,
+}
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, Insertable)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = blocklist_subscription_entry))]
+#[cfg_attr(feature = "full", diesel(primary_key(subscription_id, domain)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A domain listed in a subscribed blocklist.
+pub struct BlocklistSubscriptionEntry {
+  pub subscription_id: BlocklistSubscriptionId,
+  pub domain: String,
+  pub reason: Option<String>,
+}
@@ -1,6 +1,7 @@
 use crate::newtypes::{
   CommentReportId,
   CommunityReportId,
+  PersonReportId,
   PostReportId,
   PrivateMessageReportId,
   ReportCombinedId,
@@ -8,6 +9,7 @@ use crate::newtypes::{
 use chrono::{DateTime, Utc};
 #[cfg(feature = "full")]
 use i_love_jesus::CursorKeysModule;
+use lemmy_db_schema_file::PersonId;
 #[cfg(feature = "full")]
 use lemmy_db_schema_file::schema::report_combined;
 use serde::{Deserialize, Serialize};
@@ -30,4 +32,9 @@ pub struct ReportCombined {
   pub comment_report_id: Option<CommentReportId>,
   pub private_message_report_id: Option<PrivateMessageReportId>,
   pub community_report_id: Option<CommunityReportId>,
+  /// The mod or admin who is working on the report.
+  pub assignee_id: Option<PersonId>,
+  pub assigned_at: Option<DateTime<Utc>>,
+  pub person_report_id: Option<PersonReportId>,
+  pub resolved_at: Option<DateTime<Utc>>,
 }
@@ -67,6 +67,10 @@ pub struct Comment {
   pub federation_pending: bool,
   /// Whether the comment is locked.
   pub locked: bool,
+  /// Comments of shadow restricted users are only visible to the creator and to mods, until the
+  /// restriction is lifted. Not exposed, so that the creator doesn't notice.
+  #[serde(skip)]
+  pub held_for_review: bool,
 }
 
 #[derive(Debug, Clone, derive_new::new, Serialize, Deserialize)]
@@ -96,6 +100,8 @@ pub struct CommentInsertForm {
   pub federation_pending: Option<bool>,
   #[new(default)]
   pub locked: Option<bool>,
+  #[new(default)]
+  pub held_for_review: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -113,6 +119,7 @@ pub struct CommentUpdateForm {
   pub language_id: Option<LanguageId>,
   pub federation_pending: Option<bool>,
   pub locked: Option<bool>,
+  pub held_for_review: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -1,4 +1,4 @@
-use crate::newtypes::{CommentId, CommentReportId};
+use crate::newtypes::{CommentId, CommentReportId, RuleId};
 use chrono::{DateTime, Utc};
 use lemmy_db_schema_file::PersonId;
 #[cfg(feature = "full")]
@@ -29,6 +29,8 @@ pub struct CommentReport {
   pub published_at: DateTime<Utc>,
   pub updated_at: Option<DateTime<Utc>>,
   pub violates_instance_rules: bool,
+  /// The community or site rule which was violated.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Clone)]
@@ -40,4 +42,5 @@ pub struct CommentReportForm {
   pub original_comment_text: String,
   pub reason: String,
   pub violates_instance_rules: bool,
+  pub rule_id: Option<RuleId>,
 }
@@ -95,6 +95,15 @@ pub struct Community {
   pub report_count: i16,
   pub unresolved_report_count: i16,
   pub local_removed: bool,
+  /// Number of warnings within [Community.strike_window_days] after which a user is banned
+  /// automatically. Disabled if not set.
+  pub strike_ban_threshold: Option<i32>,
+  /// Time span in which warnings are counted towards [Community.strike_ban_threshold].
+  pub strike_window_days: i32,
+  /// Duration of automatic bans caused by too many warnings.
+  pub strike_ban_days: i32,
+  /// If the community was merged into another one, the community which it redirects to.
+  pub moved_to_id: Option<CommunityId>,
 }
 
 #[derive(Debug, Clone, derive_new::new)]
@@ -145,6 +154,8 @@ pub struct CommunityInsertForm {
   pub summary: Option<String>,
   #[new(default)]
   pub local_removed: Option<bool>,
+  #[new(default)]
+  pub moved_to_id: Option<CommunityId>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -173,6 +184,10 @@ pub struct CommunityUpdateForm {
   pub visibility: Option<CommunityVisibility>,
   pub summary: Option<Option<String>>,
   pub local_removed: Option<bool>,
+  pub strike_ban_threshold: Option<Option<i32>>,
+  pub strike_window_days: Option<i32>,
+  pub strike_ban_days: Option<i32>,
+  pub moved_to_id: Option<Option<CommunityId>>,
 }
 
 #[skip_serializing_none]
@@ -212,6 +227,10 @@ pub struct CommunityActions {
   #[serde(skip)]
   pub follow_approver_id: Option<PersonId>,
   pub notifications: Option<CommunityNotificationsMode>,
+  /// When this user was muted, so that they can't post or comment.
+  pub received_mute_at: Option<DateTime<Utc>>,
+  /// When their mute expires.
+  pub mute_expires_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -236,6 +255,18 @@ pub struct CommunityPersonBanForm {
   pub received_ban_at: DateTime<Utc>,
 }
 
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_actions))]
+pub struct CommunityPersonMuteForm {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  #[new(default)]
+  pub mute_expires_at: Option<Option<DateTime<Utc>>>,
+  #[new(value = "Utc::now()")]
+  pub received_mute_at: DateTime<Utc>,
+}
+
 #[derive(Clone, derive_new::new)]
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
 #[cfg_attr(feature = "full", diesel(table_name = community_actions))]
@@ -0,0 +1,91 @@
+use crate::newtypes::{CommentId, CommunityId, DraftId, LanguageId, LocalUserId, PostId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::{PersonId, enums::DraftKind};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {i_love_jesus::CursorKeysModule, lemmy_db_schema_file::schema::draft};
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = draft))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = draft_keys))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An unpublished post, comment or private message, only visible to its author.
+///
+/// The fields which are set depend on the kind: posts use `community_id`, comments `post_id` and
+/// `parent_id`, private messages `recipient_id`.
+pub struct Draft {
+  pub id: DraftId,
+  pub local_user_id: LocalUserId,
+  pub kind: DraftKind,
+  pub community_id: Option<CommunityId>,
+  pub post_id: Option<PostId>,
+  pub parent_id: Option<CommentId>,
+  pub recipient_id: Option<PersonId>,
+  pub name: Option<String>,
+  pub url: Option<String>,
+  pub body: Option<String>,
+  pub alt_text: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  pub custom_thumbnail: Option<String>,
+  pub scheduled_publish_time_at: Option<DateTime<Utc>>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = draft))]
+pub struct DraftInsertForm {
+  pub local_user_id: LocalUserId,
+  pub kind: DraftKind,
+  #[new(default)]
+  pub community_id: Option<CommunityId>,
+  #[new(default)]
+  pub post_id: Option<PostId>,
+  #[new(default)]
+  pub parent_id: Option<CommentId>,
+  #[new(default)]
+  pub recipient_id: Option<PersonId>,
+  #[new(default)]
+  pub name: Option<String>,
+  #[new(default)]
+  pub url: Option<String>,
+  #[new(default)]
+  pub body: Option<String>,
+  #[new(default)]
+  pub alt_text: Option<String>,
+  #[new(default)]
+  pub nsfw: Option<bool>,
+  #[new(default)]
+  pub language_id: Option<LanguageId>,
+  #[new(default)]
+  pub custom_thumbnail: Option<String>,
+  #[new(default)]
+  pub scheduled_publish_time_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = draft))]
+pub struct DraftUpdateForm {
+  pub community_id: Option<Option<CommunityId>>,
+  pub parent_id: Option<Option<CommentId>>,
+  pub name: Option<Option<String>>,
+  pub url: Option<Option<String>>,
+  pub body: Option<Option<String>>,
+  pub alt_text: Option<Option<String>>,
+  pub nsfw: Option<Option<bool>>,
+  pub language_id: Option<Option<LanguageId>>,
+  pub custom_thumbnail: Option<Option<String>>,
+  pub scheduled_publish_time_at: Option<Option<DateTime<Utc>>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
@@ -1,3 +1,4 @@
+use crate::newtypes::BlocklistSubscriptionId;
 use chrono::{DateTime, Utc};
 use lemmy_db_schema_file::InstanceId;
 #[cfg(feature = "full")]
@@ -25,6 +26,8 @@ pub struct FederationBlockList {
   pub published_at: DateTime<Utc>,
   pub updated_at: Option<DateTime<Utc>>,
   pub expires_at: Option<DateTime<Utc>>,
+  /// Set if the block was applied from a blocklist subscription.
+  pub subscription_id: Option<BlocklistSubscriptionId>,
 }
 
 #[derive(Clone, Default, derive_new::new)]
@@ -35,4 +38,6 @@ pub struct FederationBlockListForm {
   #[new(default)]
   pub updated_at: Option<DateTime<Utc>>,
   pub expires_at: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub subscription_id: Option<BlocklistSubscriptionId>,
 }
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::InstanceId;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::federation_policy;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Restrictions which are applied to content received from a given instance, for cases where
+/// blocking the instance entirely would be too much.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Associations, Identifiable)
+)]
+#[cfg_attr(
+  feature = "full",
+  diesel(belongs_to(crate::source::instance::Instance))
+)]
+#[cfg_attr(feature = "full", diesel(table_name = federation_policy))]
+#[cfg_attr(feature = "full", diesel(primary_key(instance_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct FederationPolicy {
+  #[serde(skip)]
+  pub instance_id: InstanceId,
+  /// Content is hidden from the All listing, but still visible to followers.
+  pub silence: bool,
+  /// Images and thumbnails are not fetched.
+  pub strip_media: bool,
+  /// All posts and communities are marked as NSFW.
+  pub force_nsfw: bool,
+  pub reject_reports: bool,
+  pub reject_votes: bool,
+  /// User avatars are not fetched.
+  pub no_avatar: bool,
+  pub reason: String,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = federation_policy))]
+pub struct FederationPolicyForm {
+  pub instance_id: InstanceId,
+  pub silence: bool,
+  pub strip_media: bool,
+  pub force_nsfw: bool,
+  pub reject_reports: bool,
+  pub reject_votes: bool,
+  pub no_avatar: bool,
+  pub reason: String,
+}
@@ -0,0 +1,58 @@
+use crate::newtypes::{ActivityId, CommunityId, FederationReplayId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::InstanceId;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::federation_replay;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = federation_replay))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Resend activities to a single instance, for example after it lost data.
+pub struct FederationReplay {
+  pub id: FederationReplayId,
+  pub instance_id: InstanceId,
+  /// Only resend activities of this local community. Otherwise the federation queue of the
+  /// instance is rewound.
+  pub community_id: Option<CommunityId>,
+  /// The first activity which is resent.
+  pub start_id: ActivityId,
+  /// The newest activity at the time when the replay was requested.
+  pub end_id: ActivityId,
+  /// The last activity which was resent so far.
+  pub last_sent_id: Option<ActivityId>,
+  /// Only counted for community replays, a rewound queue skips activities which are irrelevant
+  /// for the instance.
+  pub activities_sent: i64,
+  pub published_at: DateTime<Utc>,
+  pub started_at: Option<DateTime<Utc>>,
+  pub finished_at: Option<DateTime<Utc>>,
+  /// Why the replay was stopped before all activities were sent.
+  pub error: Option<String>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = federation_replay))]
+pub struct FederationReplayInsertForm {
+  pub instance_id: InstanceId,
+  pub community_id: Option<CommunityId>,
+  pub start_id: ActivityId,
+  pub end_id: ActivityId,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = federation_replay))]
+pub struct FederationReplayUpdateForm {
+  pub last_sent_id: Option<Option<ActivityId>>,
+  pub activities_sent: Option<i64>,
+  pub started_at: Option<Option<DateTime<Utc>>>,
+  pub finished_at: Option<Option<DateTime<Utc>>>,
+  pub error: Option<Option<String>>,
+}
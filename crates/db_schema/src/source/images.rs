@@ -30,6 +30,10 @@ pub struct LocalImage {
   pub person_id: Option<PersonId>,
   /// This means the image is an auto-generated thumbnail, for a post.
   pub thumbnail_for_post_id: Option<PostId>,
+  /// Set if a plugin flagged the image for review by admins.
+  pub flagged_reason: Option<String>,
+  /// In bytes
+  pub file_size: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -39,6 +43,8 @@ pub struct LocalImageForm {
   pub pictrs_alias: String,
   pub person_id: PersonId,
   pub thumbnail_for_post_id: Option<Option<PostId>>,
+  pub flagged_reason: Option<String>,
+  pub file_size: i64,
 }
 
 /// Stores all images which are hosted on remote domains. When attempting to proxy an image, it
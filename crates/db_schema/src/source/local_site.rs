@@ -99,6 +99,26 @@ pub struct LocalSite {
   #[serde(skip)]
   pub system_account: PersonId,
   pub default_items_per_page: i32,
+  /// Drafts which weren't edited for this many days are deleted automatically.
+  pub draft_max_age_days: i32,
+  /// How many megabytes each user can upload. Unlimited if not set.
+  pub upload_quota_mb: Option<i32>,
+  /// Upload quota for users who moderate at least one community.
+  pub moderator_upload_quota_mb: Option<i32>,
+  /// Number of site warnings within [LocalSite.strike_window_days] after which a user is banned
+  /// automatically. Disabled if not set.
+  pub strike_ban_threshold: Option<i32>,
+  /// Time span in which warnings are counted towards [LocalSite.strike_ban_threshold].
+  pub strike_window_days: i32,
+  /// Duration of automatic bans caused by too many warnings.
+  pub strike_ban_days: i32,
+  /// Modlog entries older than this are only visible to admins. Unlimited if not set.
+  pub modlog_retention_days: Option<i32>,
+  /// The moderator of modlog entries older than this is only visible to admins.
+  pub modlog_anonymize_days: Option<i32>,
+  /// Whether Activitypub fetches of communities, users, posts and comments require a valid HTTP
+  /// signature from an instance which isn't blocked. Implies signed outgoing fetches.
+  pub federation_authorized_fetch: bool,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -200,4 +220,13 @@ pub struct LocalSiteUpdateForm {
   pub disable_email_notifications: Option<bool>,
   pub suggested_communities: Option<MultiCommunityId>,
   pub default_items_per_page: Option<i32>,
+  pub draft_max_age_days: Option<i32>,
+  pub upload_quota_mb: Option<Option<i32>>,
+  pub moderator_upload_quota_mb: Option<Option<i32>>,
+  pub strike_ban_threshold: Option<Option<i32>>,
+  pub strike_window_days: Option<i32>,
+  pub strike_ban_days: Option<i32>,
+  pub modlog_retention_days: Option<Option<i32>>,
+  pub modlog_anonymize_days: Option<Option<i32>>,
+  pub federation_authorized_fetch: Option<bool>,
 }
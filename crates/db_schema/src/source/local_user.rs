@@ -78,6 +78,11 @@ pub struct LocalUser {
   pub show_upvote_percentage: bool,
   pub show_person_votes: bool,
   pub default_items_per_page: i32,
+  /// Overrides the upload quota of the site for this user, in megabytes.
+  pub upload_quota_mb: Option<i32>,
+  /// When the user was shadow restricted by an admin. Not exposed to the user themselves.
+  #[serde(skip)]
+  pub restricted_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -190,4 +195,6 @@ pub struct LocalUserUpdateForm {
   pub show_upvote_percentage: Option<bool>,
   pub show_person_votes: Option<bool>,
   pub default_items_per_page: Option<i32>,
+  pub upload_quota_mb: Option<Option<i32>>,
+  pub restricted_at: Option<Option<DateTime<Utc>>>,
 }
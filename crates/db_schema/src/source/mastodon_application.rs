@@ -0,0 +1,63 @@
+use crate::newtypes::{LocalUserId, MastodonApplicationId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{mastodon_application, mastodon_authorization_code};
+use lemmy_diesel_utils::sensitive::SensitiveString;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = mastodon_application))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+/// A client application registered through the Mastodon compatible api.
+pub struct MastodonApplication {
+  pub id: MastodonApplicationId,
+  pub client_id: String,
+  #[serde(skip)]
+  pub client_secret: SensitiveString,
+  pub name: String,
+  pub website: Option<String>,
+  /// Authorization codes are only sent to one of these.
+  pub redirect_uris: Vec<String>,
+  pub scopes: String,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = mastodon_application))]
+pub struct MastodonApplicationInsertForm {
+  pub client_id: String,
+  pub client_secret: SensitiveString,
+  pub name: String,
+  pub website: Option<String>,
+  pub redirect_uris: Vec<String>,
+  pub scopes: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = mastodon_authorization_code))]
+#[cfg_attr(feature = "full", diesel(primary_key(code)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+/// Issued after the user logs in on the authorize form, and exchanged by the application for a
+/// new login token.
+pub struct MastodonAuthorizationCode {
+  pub code: SensitiveString,
+  pub application_id: MastodonApplicationId,
+  pub local_user_id: LocalUserId,
+  pub redirect_uri: String,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = mastodon_authorization_code))]
+pub struct MastodonAuthorizationCodeInsertForm {
+  pub code: SensitiveString,
+  pub application_id: MastodonApplicationId,
+  pub local_user_id: LocalUserId,
+  pub redirect_uri: String,
+}
@@ -4,6 +4,9 @@ use url::Url;
 #[cfg(feature = "full")]
 pub mod activity;
 pub mod actor_language;
+pub mod ban_appeal;
+pub mod blocked_image;
+pub mod blocklist_subscription;
 pub mod captcha_answer;
 pub mod combined;
 pub mod comment;
@@ -14,10 +17,13 @@ pub mod community_community_follow;
 pub mod community_report;
 pub mod custom_emoji;
 pub mod custom_emoji_keyword;
+pub mod draft;
 pub mod email_verification;
 pub mod federation_allowlist;
 pub mod federation_blocklist;
+pub mod federation_policy;
 pub mod federation_queue_state;
+pub mod federation_replay;
 pub mod images;
 pub mod instance;
 pub mod keyword_block;
@@ -27,18 +33,27 @@ pub mod local_site_rate_limit;
 pub mod local_site_url_blocklist;
 pub mod local_user;
 pub mod login_token;
+pub mod mastodon_application;
+pub mod mod_note;
 pub mod modlog;
+pub mod modmail;
 pub mod multi_community;
 pub mod notification;
 pub mod oauth_account;
 pub mod oauth_provider;
 pub mod password_reset_request;
 pub mod person;
+pub mod person_report;
 pub mod post;
 pub mod post_report;
 pub mod private_message;
 pub mod private_message_report;
+pub mod recurring_post;
 pub mod registration_application;
+pub mod relay;
+pub mod removal_reason;
+pub mod report_note;
+pub mod rule;
 pub mod secret;
 pub mod site;
 pub mod tag;
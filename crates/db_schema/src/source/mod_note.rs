@@ -0,0 +1,54 @@
+use crate::newtypes::{CommentId, CommunityId, ModNoteId, PostId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::mod_note;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A note about a user which is shared by all mods of a community, or by all admins if
+/// `community_id` is empty. Unlike `PersonActions::note` it is never shown to the user.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_note))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ModNote {
+  pub id: ModNoteId,
+  /// The user which the note is about.
+  pub target_person_id: PersonId,
+  pub creator_id: PersonId,
+  /// The community whose mods can see the note, or none for notes visible to admins.
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+  /// The post which led to this note, if any.
+  pub post_id: Option<PostId>,
+  /// The comment which led to this note, if any.
+  pub comment_id: Option<CommentId>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_note))]
+pub struct ModNoteInsertForm {
+  pub target_person_id: PersonId,
+  pub creator_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+  #[new(default)]
+  pub post_id: Option<PostId>,
+  #[new(default)]
+  pub comment_id: Option<CommentId>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_note))]
+pub struct ModNoteUpdateForm {
+  pub content: Option<String>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
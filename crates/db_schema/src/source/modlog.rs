@@ -1,4 +1,4 @@
-use crate::newtypes::{CommentId, CommunityId, ModlogId, PostId};
+use crate::newtypes::{CommentId, CommunityId, ModlogId, PostId, RuleId};
 use chrono::{DateTime, Utc};
 #[cfg(feature = "full")]
 use i_love_jesus::CursorKeysModule;
@@ -36,6 +36,8 @@ pub struct Modlog {
   pub target_instance_id: Option<InstanceId>,
   pub expires_at: Option<DateTime<Utc>>,
   pub published_at: DateTime<Utc>,
+  /// The community or site rule which was cited for this action.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(derive_new::new)]
@@ -59,4 +61,6 @@ pub struct ModlogInsertForm<'a> {
   pub(crate) target_instance_id: Option<InstanceId>,
   #[new(default)]
   pub(crate) expires_at: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub(crate) rule_id: Option<RuleId>,
 }
@@ -0,0 +1,105 @@
+use crate::newtypes::{CommunityId, ModmailMessageId, ModmailThreadId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use {
+  i_love_jesus::CursorKeysModule,
+  lemmy_db_schema_file::schema::{modmail_message, modmail_thread},
+};
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_thread))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = modmail_thread_keys))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A conversation between a user and the moderators of a community.
+pub struct ModmailThread {
+  pub id: ModmailThreadId,
+  pub ap_id: DbUrl,
+  pub community_id: CommunityId,
+  /// The user who opened the thread.
+  pub creator_id: PersonId,
+  pub subject: String,
+  /// Archived threads are hidden from the mod inbox until the user writes again.
+  pub archived: bool,
+  pub local: bool,
+  /// When the thread creator last read the thread.
+  pub creator_read_at: DateTime<Utc>,
+  /// When any moderator of the community last read the thread.
+  pub mods_read_at: Option<DateTime<Utc>>,
+  pub last_message_at: DateTime<Utc>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_thread))]
+pub struct ModmailThreadInsertForm {
+  pub community_id: CommunityId,
+  pub creator_id: PersonId,
+  pub subject: String,
+  #[new(default)]
+  pub ap_id: Option<DbUrl>,
+  #[new(default)]
+  pub local: Option<bool>,
+  #[new(default)]
+  pub published_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_thread))]
+pub struct ModmailThreadUpdateForm {
+  pub ap_id: Option<DbUrl>,
+  pub archived: Option<bool>,
+  pub creator_read_at: Option<DateTime<Utc>>,
+  pub mods_read_at: Option<Option<DateTime<Utc>>>,
+  pub last_message_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_message))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A message in a mod mail thread.
+pub struct ModmailMessage {
+  pub id: ModmailMessageId,
+  pub ap_id: DbUrl,
+  pub thread_id: ModmailThreadId,
+  pub creator_id: PersonId,
+  pub content: String,
+  /// Messages from moderators are shown as coming from the mod team.
+  pub from_mod: bool,
+  pub local: bool,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = modmail_message))]
+pub struct ModmailMessageInsertForm {
+  pub thread_id: ModmailThreadId,
+  pub creator_id: PersonId,
+  pub content: String,
+  pub from_mod: bool,
+  #[new(default)]
+  pub ap_id: Option<DbUrl>,
+  #[new(default)]
+  pub local: Option<bool>,
+  #[new(default)]
+  pub published_at: Option<DateTime<Utc>>,
+}
@@ -132,7 +132,7 @@ pub struct PersonUpdateForm {
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
 pub struct PersonActions {
-  #[serde(skip)]
+  /// When the person was followed.
   pub followed_at: Option<DateTime<Utc>>,
   /// When the person was blocked.
   pub blocked_at: Option<DateTime<Utc>>,
@@ -140,7 +140,7 @@ pub struct PersonActions {
   pub person_id: PersonId,
   #[serde(skip)]
   pub target_id: PersonId,
-  #[serde(skip)]
+  /// Whether the follow is waiting to be accepted by the remote instance of the person.
   pub follow_pending: Option<bool>,
   /// When the person was noted.
   pub noted_at: Option<DateTime<Utc>>,
@@ -0,0 +1,46 @@
+use crate::newtypes::PersonReportId;
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::person_report;
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = person_report))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A report on a user profile.
+pub struct PersonReport {
+  pub id: PersonReportId,
+  pub creator_id: PersonId,
+  pub person_id: PersonId,
+  pub original_person_name: String,
+  pub original_person_display_name: Option<String>,
+  pub original_person_bio: Option<String>,
+  pub original_person_avatar: Option<String>,
+  pub original_person_banner: Option<String>,
+  pub reason: String,
+  pub resolved: bool,
+  pub resolver_id: Option<PersonId>,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = person_report))]
+pub struct PersonReportForm {
+  pub creator_id: PersonId,
+  pub person_id: PersonId,
+  pub original_person_name: String,
+  pub original_person_display_name: Option<String>,
+  pub original_person_bio: Option<String>,
+  pub original_person_avatar: Option<DbUrl>,
+  pub original_person_banner: Option<DbUrl>,
+  pub reason: String,
+}
@@ -88,6 +88,10 @@ pub struct Post {
   pub federation_pending: bool,
   pub embed_video_width: Option<i32>,
   pub embed_video_height: Option<i32>,
+  /// Posts of shadow restricted users are only visible to the creator and to mods, until the
+  /// restriction is lifted. Not exposed, so that the creator doesn't notice.
+  #[serde(skip)]
+  pub held_for_review: bool,
 }
 
 // TODO: FromBytes, ToBytes are only needed to develop wasm plugin, could be behind feature flag
@@ -144,6 +148,8 @@ pub struct PostInsertForm {
   pub scheduled_publish_time_at: Option<DateTime<Utc>>,
   #[new(default)]
   pub federation_pending: Option<bool>,
+  #[new(default)]
+  pub held_for_review: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -174,6 +180,7 @@ pub struct PostUpdateForm {
   pub alt_text: Option<Option<String>>,
   pub scheduled_publish_time_at: Option<Option<DateTime<Utc>>>,
   pub federation_pending: Option<bool>,
+  pub held_for_review: Option<bool>,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
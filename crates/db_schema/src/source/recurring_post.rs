@@ -0,0 +1,95 @@
+use crate::newtypes::{CommunityId, LanguageId, PostId, RecurringPostId, TagId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use i_love_jesus::CursorKeysModule;
+use lemmy_db_schema_file::PersonId;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::{recurring_post, recurring_post_tag};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Identifiable, CursorKeysModule)
+)]
+#[cfg_attr(feature = "full", diesel(table_name = recurring_post))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", cursor_keys_module(name = recurring_post_keys))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A template for a post which is created regularly in a community, like a weekly discussion
+/// thread.
+pub struct RecurringPost {
+  pub id: RecurringPostId,
+  pub community_id: CommunityId,
+  /// The account which creates the posts.
+  pub creator_id: PersonId,
+  /// A cron expression in UTC, eg `0 12 * * 1` for every monday at noon.
+  pub schedule: String,
+  /// The post title, which can contain date placeholders like `{date}`.
+  pub name: String,
+  pub body: Option<String>,
+  pub language_id: Option<LanguageId>,
+  pub nsfw: bool,
+  /// Feature the created posts in the community.
+  pub feature_post: bool,
+  /// Unfeature the previously created post once a new one is created.
+  pub unfeature_previous: bool,
+  pub previous_post_id: Option<PostId>,
+  /// Empty if the schedule can't be satisfied.
+  pub next_publish_at: Option<DateTime<Utc>>,
+  pub enabled: bool,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = recurring_post))]
+pub struct RecurringPostInsertForm {
+  pub community_id: CommunityId,
+  pub creator_id: PersonId,
+  pub schedule: String,
+  pub name: String,
+  pub next_publish_at: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub body: Option<String>,
+  #[new(default)]
+  pub language_id: Option<LanguageId>,
+  #[new(default)]
+  pub nsfw: Option<bool>,
+  #[new(default)]
+  pub feature_post: Option<bool>,
+  #[new(default)]
+  pub unfeature_previous: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = recurring_post))]
+pub struct RecurringPostUpdateForm {
+  pub creator_id: Option<PersonId>,
+  pub schedule: Option<String>,
+  pub name: Option<String>,
+  pub body: Option<Option<String>>,
+  pub language_id: Option<Option<LanguageId>>,
+  pub nsfw: Option<bool>,
+  pub feature_post: Option<bool>,
+  pub unfeature_previous: Option<bool>,
+  pub previous_post_id: Option<Option<PostId>>,
+  pub next_publish_at: Option<Option<DateTime<Utc>>>,
+  pub enabled: Option<bool>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = recurring_post_tag))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+/// A tag which is added to all posts created from a recurring post.
+pub struct RecurringPostTag {
+  pub recurring_post_id: RecurringPostId,
+  pub tag_id: TagId,
+}
@@ -0,0 +1,64 @@
+use crate::newtypes::RelayId;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::relay;
+use lemmy_db_schema_file::{
+  InstanceId,
+  enums::{CommunityFollowerState, RelayMode},
+};
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = relay))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An ActivityPub relay which the site actor follows.
+pub struct Relay {
+  pub id: RelayId,
+  pub ap_id: DbUrl,
+  #[serde(skip)]
+  pub inbox_url: DbUrl,
+  #[serde(skip)]
+  pub public_key: String,
+  pub instance_id: InstanceId,
+  pub mode: RelayMode,
+  /// Pending until the relay accepts the follow.
+  pub follow_state: CommunityFollowerState,
+  pub published_at: DateTime<Utc>,
+  #[serde(skip)]
+  pub last_refreshed_at: DateTime<Utc>,
+  /// When the last announce was received from the relay.
+  pub last_received_at: Option<DateTime<Utc>>,
+  pub announces_received: i64,
+  /// Posts which were not known to this instance before.
+  pub posts_imported: i64,
+  /// Communities which were fetched because of a relayed post. Only in discover mode.
+  pub communities_discovered: i64,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = relay))]
+pub struct RelayInsertForm {
+  pub ap_id: DbUrl,
+  pub inbox_url: DbUrl,
+  pub public_key: String,
+  pub instance_id: InstanceId,
+  pub mode: RelayMode,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = relay))]
+pub struct RelayUpdateForm {
+  pub inbox_url: Option<DbUrl>,
+  pub public_key: Option<String>,
+  pub mode: Option<RelayMode>,
+  pub follow_state: Option<CommunityFollowerState>,
+  pub last_refreshed_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,44 @@
+use crate::newtypes::{CommunityId, RemovalReasonId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::removal_reason;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A predefined reason which mods can select when removing a post or comment. The template
+/// may contain placeholders like `{rule}` or `{post_title}`, which are filled in on removal.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = removal_reason))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RemovalReason {
+  pub id: RemovalReasonId,
+  pub community_id: CommunityId,
+  pub title: String,
+  pub template: String,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub deleted: bool,
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = removal_reason))]
+pub struct RemovalReasonInsertForm {
+  pub community_id: CommunityId,
+  pub title: String,
+  pub template: String,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = removal_reason))]
+pub struct RemovalReasonUpdateForm {
+  pub title: Option<String>,
+  pub template: Option<String>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+  pub deleted: Option<bool>,
+}
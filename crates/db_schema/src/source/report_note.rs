@@ -0,0 +1,30 @@
+use crate::newtypes::{ReportCombinedId, ReportNoteId};
+use chrono::{DateTime, Utc};
+use lemmy_db_schema_file::PersonId;
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::report_note;
+use serde::{Deserialize, Serialize};
+
+/// An internal comment of the mod team about a report. Never shown to the report creator.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = report_note))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ReportNote {
+  pub id: ReportNoteId,
+  pub report_combined_id: ReportCombinedId,
+  pub creator_id: PersonId,
+  pub content: String,
+  pub published_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_note))]
+pub struct ReportNoteInsertForm {
+  pub report_combined_id: ReportCombinedId,
+  pub creator_id: PersonId,
+  pub content: String,
+}
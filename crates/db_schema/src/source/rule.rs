@@ -0,0 +1,58 @@
+use crate::newtypes::{CommunityId, RuleId};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use lemmy_db_schema_file::schema::rule;
+use lemmy_diesel_utils::dburl::DbUrl;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A numbered rule of a community, or of the site if `community_id` is empty. Reports and
+/// removals can reference the rule which was violated.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = rule))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct Rule {
+  pub id: RuleId,
+  pub ap_id: DbUrl,
+  /// The community that this rule belongs to, or none for site rules.
+  pub community_id: Option<CommunityId>,
+  pub title: String,
+  pub description: Option<String>,
+  /// Rules are shown in ascending order of this value.
+  pub position: i32,
+  pub published_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub deleted: bool,
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = rule))]
+pub struct RuleInsertForm {
+  pub community_id: Option<CommunityId>,
+  pub title: String,
+  #[new(default)]
+  pub ap_id: Option<DbUrl>,
+  #[new(default)]
+  pub description: Option<String>,
+  #[new(default)]
+  pub position: Option<i32>,
+  #[new(default)]
+  pub deleted: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = rule))]
+pub struct RuleUpdateForm {
+  pub ap_id: Option<DbUrl>,
+  pub title: Option<String>,
+  pub description: Option<Option<String>>,
+  pub position: Option<i32>,
+  pub updated_at: Option<Option<DateTime<Utc>>>,
+  pub deleted: Option<bool>,
+}
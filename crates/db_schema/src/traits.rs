@@ -15,7 +15,7 @@ pub trait Followable: Sized {
   ) -> impl Future<Output = LemmyResult<Self>> + Send;
   fn follow_accepted(
     pool: &mut DbPool<'_>,
-    community_id: CommunityId,
+    item_id: Self::IdType,
     person_id: PersonId,
   ) -> impl Future<Output = LemmyResult<Self>> + Send;
   fn unfollow(
@@ -3,6 +3,7 @@ use diesel::{
   ExpressionMethods,
   NullableExpressionMethods,
   QueryDsl,
+  dsl::not,
   helper_types::{Eq, NotEq},
 };
 use lemmy_db_schema_file::{
@@ -11,10 +12,12 @@ use lemmy_db_schema_file::{
   schema::{
     community,
     community_actions,
+    federation_policy,
     instance_actions,
     local_site,
     multi_community,
     multi_community_entry,
+    person,
     person_actions,
   },
 };
@@ -52,6 +55,30 @@ pub fn filter_not_unlisted_or_is_subscribed() -> _ {
   not_unlisted.or(is_subscribed)
 }
 
+#[diesel::dsl::auto_type]
+fn silenced_instances() -> _ {
+  federation_policy::table
+    .filter(federation_policy::silence.eq(true))
+    .select(federation_policy::instance_id)
+}
+
+/// Hide persons from silenced instances.
+#[diesel::dsl::auto_type]
+pub fn filter_person_not_silenced() -> _ {
+  not(person::instance_id.eq_any(silenced_instances()))
+}
+
+/// Hide content from communities and users on silenced instances, unless the user follows the
+/// community. Rows without a person (eg communities in search results) are only checked by
+/// community.
+#[diesel::dsl::auto_type]
+pub fn filter_not_silenced_or_is_subscribed() -> _ {
+  let is_subscribed: IsSubscribedType = filter_is_subscribed();
+  not(community::instance_id.eq_any(silenced_instances()))
+    .and(person::id.is_null().or(filter_person_not_silenced()))
+    .or(is_subscribed)
+}
+
 #[diesel::dsl::auto_type]
 pub fn filter_suggested_communities() -> _ {
   community::id.eq_any(
@@ -61,3 +88,12 @@ pub fn filter_suggested_communities() -> _ {
       .select(multi_community_entry::community_id.assume_not_null()),
   )
 }
+
+type IsFollowingCreatorType =
+  Eq<lemmy_db_schema_file::schema::person_actions::follow_pending, Option<bool>>;
+
+/// Only show content from persons which the user follows, and which accepted the follow. The
+/// pending state is cleared on unfollow, so it is enough to check that.
+pub fn filter_is_following_creator() -> IsFollowingCreatorType {
+  person_actions::follow_pending.eq(Some(false))
+}
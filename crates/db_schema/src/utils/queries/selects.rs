@@ -1,4 +1,8 @@
-use crate::{Person1AliasAllColumnsTuple, Person2AliasAllColumnsTuple};
+use crate::{
+  Person1AliasAllColumnsTuple,
+  Person2AliasAllColumnsTuple,
+  Person3AliasAllColumnsTuple,
+};
 use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
@@ -23,6 +27,7 @@ use lemmy_db_schema_file::{
     creator_local_user,
     person1,
     person2,
+    person3,
   },
   schema::{
     comment,
@@ -30,6 +35,7 @@ use lemmy_db_schema_file::{
     community_actions,
     instance_actions,
     local_user,
+    mod_note,
     person,
     post,
     post_tag,
@@ -84,6 +90,17 @@ pub fn creator_is_moderator() -> _ {
     .is_not_null()
 }
 
+/// Checks if there are mod notes about the creator in the item's community. For items without a
+/// community, this checks for site-wide notes instead.
+#[diesel::dsl::auto_type]
+pub fn creator_has_mod_notes() -> _ {
+  exists(
+    mod_note::table
+      .filter(mod_note::target_person_id.eq(person::id))
+      .filter(mod_note::community_id.is_not_distinct_from(community::id.nullable())),
+  )
+}
+
 #[diesel::dsl::auto_type]
 pub fn creator_banned_from_community() -> _ {
   creator_community_actions
@@ -372,3 +389,8 @@ pub fn person1_select() -> Person1AliasAllColumnsTuple {
 pub fn person2_select() -> Person2AliasAllColumnsTuple {
   person2.fields(person::all_columns)
 }
+
+/// The select for the person3 alias.
+pub fn person3_select() -> Person3AliasAllColumnsTuple {
+  person3.fields(person::all_columns)
+}
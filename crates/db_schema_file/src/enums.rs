@@ -71,6 +71,8 @@ pub enum ListingType {
   ModeratorView,
   /// Communities which are recommended by local instance admins
   Suggested,
+  /// Content only from persons you are following.
+  FollowedPersons,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
@@ -336,4 +338,69 @@ pub enum ModlogKind {
   ModRemovePost,
   ModTransferCommunity,
   ModLockComment,
+  ModDenyBanAppeal,
+  AdminDenyBanAppeal,
+  ModWarnUser,
+  AdminWarnUser,
+  ModMuteFromCommunity,
+  AdminMergeCommunity,
+  AdminRestrictUser,
+  AdminPurgeUserMedia,
+  AdminSetInstancePolicy,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::DraftKindEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// The type of content a draft will be published as.
+pub enum DraftKind {
+  #[default]
+  Post,
+  Comment,
+  PrivateMessage,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::BlocklistFormatEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// The format of a subscribed instance blocklist.
+pub enum BlocklistFormat {
+  /// A CSV file in the format exported by Mastodon, with the domain in the first column.
+  #[default]
+  Csv,
+  /// The public list of blocked instances of another Lemmy instance.
+  Lemmy,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "full", derive(DbEnum))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::RelayModeEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// Which content is imported from a relay.
+pub enum RelayMode {
+  /// Only import posts in communities which are already known to this instance.
+  #[default]
+  KnownCommunities,
+  /// Also fetch communities which aren't known yet.
+  Discover,
 }
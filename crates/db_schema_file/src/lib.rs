@@ -13,7 +13,7 @@ pub mod table_impls;
 
 #[cfg(feature = "full")]
 pub mod aliases {
-  use crate::schema::{community_actions, instance_actions, local_user, person};
+  use crate::schema::{community_actions, instance_actions, local_user, person, person_actions};
   diesel::alias!(
     community_actions as creator_community_actions: CreatorCommunityActions,
     instance_actions as creator_home_instance_actions: CreatorHomeInstanceActions,
@@ -23,6 +23,8 @@ pub mod aliases {
     local_user as creator_local_user: CreatorLocalUser,
     person as person1: Person1,
     person as person2: Person2,
+    person as person3: Person3,
+    person_actions as person_follows: PersonFollows,
   );
 }
 
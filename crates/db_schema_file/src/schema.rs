@@ -5,6 +5,10 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "actor_type_enum"))]
   pub struct ActorTypeEnum;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "blocklist_format_enum"))]
+  pub struct BlocklistFormatEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "comment_sort_type_enum"))]
   pub struct CommentSortTypeEnum;
@@ -21,6 +25,10 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "community_visibility"))]
   pub struct CommunityVisibility;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "draft_kind_enum"))]
+  pub struct DraftKindEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "federation_mode_enum"))]
   pub struct FederationModeEnum;
@@ -57,6 +65,10 @@ pub mod sql_types {
   #[diesel(postgres_type(name = "registration_mode_enum"))]
   pub struct RegistrationModeEnum;
 
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "relay_mode_enum"))]
+  pub struct RelayModeEnum;
+
   #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
   #[diesel(postgres_type(name = "tag_color_enum"))]
   pub struct TagColorEnum;
@@ -66,6 +78,53 @@ pub mod sql_types {
   pub struct VoteShowEnum;
 }
 
+diesel::table! {
+    ban_appeal (id) {
+        id -> Int4,
+        person_id -> Int4,
+        community_id -> Nullable<Int4>,
+        content -> Text,
+        banned_at -> Timestamptz,
+        resolver_id -> Nullable<Int4>,
+        accepted -> Bool,
+        deny_reason -> Nullable<Text>,
+        published_at -> Timestamptz,
+        resolved_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    blocked_image (id) {
+        id -> Int4,
+        hash -> Int8,
+        reason -> Nullable<Text>,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::BlocklistFormatEnum;
+
+    blocklist_subscription (id) {
+        id -> Int4,
+        #[max_length = 2000]
+        url -> Varchar,
+        format -> BlocklistFormatEnum,
+        published_at -> Timestamptz,
+        synced_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    blocklist_subscription_entry (subscription_id, domain) {
+        subscription_id -> Int4,
+        #[max_length = 255]
+        domain -> Varchar,
+        reason -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     captcha_answer (uuid) {
         uuid -> Uuid,
@@ -103,6 +162,7 @@ diesel::table! {
         unresolved_report_count -> Int2,
         federation_pending -> Bool,
         locked -> Bool,
+        held_for_review -> Bool,
     }
 }
 
@@ -128,6 +188,7 @@ diesel::table! {
         published_at -> Timestamptz,
         updated_at -> Nullable<Timestamptz>,
         violates_instance_rules -> Bool,
+        rule_id -> Nullable<Int4>,
     }
 }
 
@@ -182,6 +243,10 @@ diesel::table! {
         report_count -> Int2,
         unresolved_report_count -> Int2,
         local_removed -> Bool,
+        strike_ban_threshold -> Nullable<Int4>,
+        strike_window_days -> Int4,
+        strike_ban_days -> Int4,
+        moved_to_id -> Nullable<Int4>,
     }
 }
 
@@ -201,6 +266,8 @@ diesel::table! {
         follow_state -> Nullable<CommunityFollowerState>,
         follow_approver_id -> Nullable<Int4>,
         notifications -> Nullable<CommunityNotificationsModeEnum>,
+        received_mute_at -> Nullable<Timestamptz>,
+        mute_expires_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -259,6 +326,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::DraftKindEnum;
+
+    draft (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        kind -> DraftKindEnum,
+        community_id -> Nullable<Int4>,
+        post_id -> Nullable<Int4>,
+        parent_id -> Nullable<Int4>,
+        recipient_id -> Nullable<Int4>,
+        #[max_length = 200]
+        name -> Nullable<Varchar>,
+        #[max_length = 2000]
+        url -> Nullable<Varchar>,
+        body -> Nullable<Text>,
+        alt_text -> Nullable<Text>,
+        nsfw -> Nullable<Bool>,
+        language_id -> Nullable<Int4>,
+        #[max_length = 2000]
+        custom_thumbnail -> Nullable<Varchar>,
+        scheduled_publish_time_at -> Nullable<Timestamptz>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     email_verification (id) {
         id -> Int4,
@@ -283,6 +378,22 @@ diesel::table! {
         published_at -> Timestamptz,
         updated_at -> Nullable<Timestamptz>,
         expires_at -> Nullable<Timestamptz>,
+        subscription_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    federation_policy (instance_id) {
+        instance_id -> Int4,
+        silence -> Bool,
+        strip_media -> Bool,
+        force_nsfw -> Bool,
+        reject_reports -> Bool,
+        reject_votes -> Bool,
+        no_avatar -> Bool,
+        reason -> Text,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -296,6 +407,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    federation_replay (id) {
+        id -> Int4,
+        instance_id -> Int4,
+        community_id -> Nullable<Int4>,
+        start_id -> Int8,
+        end_id -> Int8,
+        last_sent_id -> Nullable<Int8>,
+        activities_sent -> Int8,
+        published_at -> Timestamptz,
+        started_at -> Nullable<Timestamptz>,
+        finished_at -> Nullable<Timestamptz>,
+        error -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     image_details (link) {
         link -> Text,
@@ -347,6 +474,8 @@ diesel::table! {
         published_at -> Timestamptz,
         person_id -> Nullable<Int4>,
         thumbnail_for_post_id -> Nullable<Int4>,
+        flagged_reason -> Nullable<Text>,
+        file_size -> Int8,
     }
 }
 
@@ -403,6 +532,15 @@ diesel::table! {
         suggested_communities -> Nullable<Int4>,
         system_account -> Int4,
         default_items_per_page -> Int4,
+        draft_max_age_days -> Int4,
+        upload_quota_mb -> Nullable<Int4>,
+        moderator_upload_quota_mb -> Nullable<Int4>,
+        strike_ban_threshold -> Nullable<Int4>,
+        strike_window_days -> Int4,
+        strike_ban_days -> Int4,
+        modlog_retention_days -> Nullable<Int4>,
+        modlog_anonymize_days -> Nullable<Int4>,
+        federation_authorized_fetch -> Bool,
     }
 }
 
@@ -483,6 +621,8 @@ diesel::table! {
         show_upvote_percentage -> Bool,
         show_person_votes -> Bool,
         default_items_per_page -> Int4,
+        upload_quota_mb -> Nullable<Int4>,
+        restricted_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -511,6 +651,43 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mastodon_application (id) {
+        id -> Int4,
+        client_id -> Text,
+        client_secret -> Text,
+        name -> Text,
+        website -> Nullable<Text>,
+        redirect_uris -> Array<Text>,
+        scopes -> Text,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    mastodon_authorization_code (code) {
+        code -> Text,
+        application_id -> Int4,
+        local_user_id -> Int4,
+        redirect_uri -> Text,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    mod_note (id) {
+        id -> Int4,
+        target_person_id -> Int4,
+        creator_id -> Int4,
+        community_id -> Nullable<Int4>,
+        content -> Text,
+        post_id -> Nullable<Int4>,
+        comment_id -> Nullable<Int4>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::ModlogKind;
@@ -528,6 +705,40 @@ diesel::table! {
         target_instance_id -> Nullable<Int4>,
         expires_at -> Nullable<Timestamptz>,
         published_at -> Timestamptz,
+        rule_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    modmail_message (id) {
+        id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        thread_id -> Int4,
+        creator_id -> Int4,
+        content -> Text,
+        from_mod -> Bool,
+        local -> Bool,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    modmail_thread (id) {
+        id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        community_id -> Int4,
+        creator_id -> Int4,
+        #[max_length = 255]
+        subject -> Varchar,
+        archived -> Bool,
+        local -> Bool,
+        creator_read_at -> Timestamptz,
+        mods_read_at -> Nullable<Timestamptz>,
+        last_message_at -> Timestamptz,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -699,6 +910,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    person_report (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        person_id -> Int4,
+        original_person_name -> Text,
+        original_person_display_name -> Nullable<Text>,
+        original_person_bio -> Nullable<Text>,
+        original_person_avatar -> Nullable<Text>,
+        original_person_banner -> Nullable<Text>,
+        reason -> Text,
+        resolved -> Bool,
+        resolver_id -> Nullable<Int4>,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     person_saved_combined (id) {
         saved_at -> Timestamptz,
@@ -753,6 +982,7 @@ diesel::table! {
         federation_pending -> Bool,
         embed_video_width -> Nullable<Int4>,
         embed_video_height -> Nullable<Int4>,
+        held_for_review -> Bool,
     }
 }
 
@@ -789,6 +1019,7 @@ diesel::table! {
         published_at -> Timestamptz,
         updated_at -> Nullable<Timestamptz>,
         violates_instance_rules -> Bool,
+        rule_id -> Nullable<Int4>,
     }
 }
 
@@ -837,6 +1068,35 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    recurring_post (id) {
+        id -> Int4,
+        community_id -> Int4,
+        creator_id -> Int4,
+        #[max_length = 100]
+        schedule -> Varchar,
+        #[max_length = 200]
+        name -> Varchar,
+        body -> Nullable<Text>,
+        language_id -> Nullable<Int4>,
+        nsfw -> Bool,
+        feature_post -> Bool,
+        unfeature_previous -> Bool,
+        previous_post_id -> Nullable<Int4>,
+        next_publish_at -> Nullable<Timestamptz>,
+        enabled -> Bool,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    recurring_post_tag (recurring_post_id, tag_id) {
+        recurring_post_id -> Int4,
+        tag_id -> Int4,
+    }
+}
+
 diesel::table! {
     registration_application (id) {
         id -> Int4,
@@ -856,6 +1116,43 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RelayModeEnum;
+    use super::sql_types::CommunityFollowerState;
+
+    relay (id) {
+        id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        #[max_length = 255]
+        inbox_url -> Varchar,
+        public_key -> Text,
+        instance_id -> Int4,
+        mode -> RelayModeEnum,
+        follow_state -> CommunityFollowerState,
+        published_at -> Timestamptz,
+        last_refreshed_at -> Timestamptz,
+        last_received_at -> Nullable<Timestamptz>,
+        announces_received -> Int8,
+        posts_imported -> Int8,
+        communities_discovered -> Int8,
+    }
+}
+
+diesel::table! {
+    removal_reason (id) {
+        id -> Int4,
+        community_id -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        template -> Text,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+        deleted -> Bool,
+    }
+}
+
 diesel::table! {
     report_combined (id) {
         id -> Int4,
@@ -864,6 +1161,35 @@ diesel::table! {
         comment_report_id -> Nullable<Int4>,
         private_message_report_id -> Nullable<Int4>,
         community_report_id -> Nullable<Int4>,
+        assignee_id -> Nullable<Int4>,
+        assigned_at -> Nullable<Timestamptz>,
+        person_report_id -> Nullable<Int4>,
+        resolved_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    report_note (id) {
+        id -> Int4,
+        report_combined_id -> Int4,
+        creator_id -> Int4,
+        content -> Text,
+        published_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    rule (id) {
+        id -> Int4,
+        ap_id -> Text,
+        community_id -> Nullable<Int4>,
+        #[max_length = 255]
+        title -> Varchar,
+        description -> Nullable<Text>,
+        position -> Int4,
+        published_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+        deleted -> Bool,
     }
 }
 
@@ -965,22 +1291,35 @@ diesel::table! {
     }
 }
 
+diesel::joinable!(ban_appeal -> community (community_id));
+diesel::joinable!(blocklist_subscription_entry -> blocklist_subscription (subscription_id));
 diesel::joinable!(comment -> language (language_id));
 diesel::joinable!(comment -> person (creator_id));
 diesel::joinable!(comment -> post (post_id));
 diesel::joinable!(comment_actions -> comment (comment_id));
 diesel::joinable!(comment_actions -> person (person_id));
 diesel::joinable!(comment_report -> comment (comment_id));
+diesel::joinable!(comment_report -> rule (rule_id));
 diesel::joinable!(community -> instance (instance_id));
 diesel::joinable!(community_actions -> community (community_id));
 diesel::joinable!(community_language -> community (community_id));
 diesel::joinable!(community_language -> language (language_id));
 diesel::joinable!(community_report -> community (community_id));
 diesel::joinable!(custom_emoji_keyword -> custom_emoji (custom_emoji_id));
+diesel::joinable!(draft -> comment (parent_id));
+diesel::joinable!(draft -> community (community_id));
+diesel::joinable!(draft -> language (language_id));
+diesel::joinable!(draft -> local_user (local_user_id));
+diesel::joinable!(draft -> person (recipient_id));
+diesel::joinable!(draft -> post (post_id));
 diesel::joinable!(email_verification -> local_user (local_user_id));
 diesel::joinable!(federation_allowlist -> instance (instance_id));
+diesel::joinable!(federation_blocklist -> blocklist_subscription (subscription_id));
 diesel::joinable!(federation_blocklist -> instance (instance_id));
+diesel::joinable!(federation_policy -> instance (instance_id));
 diesel::joinable!(federation_queue_state -> instance (instance_id));
+diesel::joinable!(federation_replay -> community (community_id));
+diesel::joinable!(federation_replay -> instance (instance_id));
 diesel::joinable!(instance_actions -> instance (instance_id));
 diesel::joinable!(instance_actions -> person (person_id));
 diesel::joinable!(local_image -> person (person_id));
@@ -994,6 +1333,16 @@ diesel::joinable!(local_user_keyword_block -> local_user (local_user_id));
 diesel::joinable!(local_user_language -> language (language_id));
 diesel::joinable!(local_user_language -> local_user (local_user_id));
 diesel::joinable!(login_token -> local_user (user_id));
+diesel::joinable!(mastodon_authorization_code -> local_user (local_user_id));
+diesel::joinable!(mastodon_authorization_code -> mastodon_application (application_id));
+diesel::joinable!(mod_note -> comment (comment_id));
+diesel::joinable!(mod_note -> community (community_id));
+diesel::joinable!(mod_note -> post (post_id));
+diesel::joinable!(modlog -> rule (rule_id));
+diesel::joinable!(modmail_message -> modmail_thread (thread_id));
+diesel::joinable!(modmail_message -> person (creator_id));
+diesel::joinable!(modmail_thread -> community (community_id));
+diesel::joinable!(modmail_thread -> person (creator_id));
 diesel::joinable!(multi_community -> instance (instance_id));
 diesel::joinable!(multi_community -> person (creator_id));
 diesel::joinable!(multi_community_entry -> community (community_id));
@@ -1023,15 +1372,29 @@ diesel::joinable!(post -> person (creator_id));
 diesel::joinable!(post_actions -> person (person_id));
 diesel::joinable!(post_actions -> post (post_id));
 diesel::joinable!(post_report -> post (post_id));
+diesel::joinable!(post_report -> rule (rule_id));
 diesel::joinable!(post_tag -> post (post_id));
 diesel::joinable!(post_tag -> tag (tag_id));
 diesel::joinable!(private_message_report -> private_message (private_message_id));
+diesel::joinable!(recurring_post -> community (community_id));
+diesel::joinable!(recurring_post -> language (language_id));
+diesel::joinable!(recurring_post -> person (creator_id));
+diesel::joinable!(recurring_post -> post (previous_post_id));
+diesel::joinable!(recurring_post_tag -> recurring_post (recurring_post_id));
+diesel::joinable!(recurring_post_tag -> tag (tag_id));
 diesel::joinable!(registration_application -> local_user (local_user_id));
 diesel::joinable!(registration_application -> person (admin_id));
+diesel::joinable!(relay -> instance (instance_id));
+diesel::joinable!(removal_reason -> community (community_id));
 diesel::joinable!(report_combined -> comment_report (comment_report_id));
 diesel::joinable!(report_combined -> community_report (community_report_id));
+diesel::joinable!(report_combined -> person (assignee_id));
+diesel::joinable!(report_combined -> person_report (person_report_id));
 diesel::joinable!(report_combined -> post_report (post_report_id));
 diesel::joinable!(report_combined -> private_message_report (private_message_report_id));
+diesel::joinable!(report_note -> person (creator_id));
+diesel::joinable!(report_note -> report_combined (report_combined_id));
+diesel::joinable!(rule -> community (community_id));
 diesel::joinable!(search_combined -> comment (comment_id));
 diesel::joinable!(search_combined -> community (community_id));
 diesel::joinable!(search_combined -> multi_community (multi_community_id));
@@ -1043,6 +1406,9 @@ diesel::joinable!(site_language -> site (site_id));
 diesel::joinable!(tag -> community (community_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+  ban_appeal,
+  blocklist_subscription,
+  blocklist_subscription_entry,
   comment,
   comment_actions,
   comment_report,
@@ -1050,10 +1416,13 @@ diesel::allow_tables_to_appear_in_same_query!(
   community_actions,
   community_language,
   community_report,
+  draft,
   email_verification,
   federation_allowlist,
   federation_blocklist,
+  federation_policy,
   federation_queue_state,
+  federation_replay,
   instance,
   instance_actions,
   language,
@@ -1064,7 +1433,12 @@ diesel::allow_tables_to_appear_in_same_query!(
   local_user_keyword_block,
   local_user_language,
   login_token,
+  mastodon_application,
+  mastodon_authorization_code,
+  mod_note,
   modlog,
+  modmail_message,
+  modmail_thread,
   multi_community,
   multi_community_entry,
   multi_community_follow,
@@ -1075,6 +1449,7 @@ diesel::allow_tables_to_appear_in_same_query!(
   person,
   person_content_combined,
   person_liked_combined,
+  person_report,
   person_saved_combined,
   post,
   post_actions,
@@ -1082,8 +1457,14 @@ diesel::allow_tables_to_appear_in_same_query!(
   post_tag,
   private_message,
   private_message_report,
+  recurring_post,
+  recurring_post_tag,
   registration_application,
+  relay,
+  removal_reason,
   report_combined,
+  report_note,
+  rule,
   search_combined,
   site,
   site_language,
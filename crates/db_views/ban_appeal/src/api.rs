@@ -0,0 +1,48 @@
+use crate::BanAppealView;
+use lemmy_db_schema::newtypes::{BanAppealId, CommunityId};
+use lemmy_diesel_utils::pagination::PaginationCursor;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Appeal against your ban from a community, or against your site ban if community_id is empty.
+///
+/// Only one appeal can be submitted per ban.
+pub struct CreateBanAppeal {
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Accept or deny a ban appeal. Accepting it lifts the ban.
+pub struct ResolveBanAppeal {
+  pub ban_appeal_id: BanAppealId,
+  pub accept: bool,
+  /// Shown in the modlog, and to the user if the appeal is denied.
+  pub reason: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List ban appeals for communities which you moderate, and for site bans if you are an admin.
+pub struct ListBanAppeals {
+  /// Only show appeals for bans from this community.
+  pub community_id: Option<CommunityId>,
+  pub unresolved_only: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct BanAppealResponse {
+  pub ban_appeal_view: BanAppealView,
+}
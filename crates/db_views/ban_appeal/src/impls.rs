@@ -0,0 +1,254 @@
+use crate::BanAppealView;
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+  dsl::count,
+};
+use diesel_async::RunQueryDsl;
+use i_love_jesus::SortDirection;
+use lemmy_db_schema::{
+  newtypes::{BanAppealId, CommunityId},
+  source::ban_appeal::{BanAppeal, ban_appeal_keys as key},
+  utils::limit_fetch,
+};
+use lemmy_db_schema_file::{
+  PersonId,
+  aliases,
+  schema::{ban_appeal, community, community_actions, person},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  pagination::{
+    CursorData,
+    PagedResponse,
+    PaginationCursor,
+    PaginationCursorConversion,
+    paginate_response,
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl PaginationCursorConversion for BanAppealView {
+  type PaginatedType = BanAppeal;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.ban_appeal.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    BanAppeal::read(pool, BanAppealId(cursor.id()?)).await
+  }
+}
+
+impl BanAppealView {
+  #[diesel::dsl::auto_type(no_type_alias)]
+  fn joins() -> _ {
+    let creator_join = person::table.on(ban_appeal::person_id.eq(person::id));
+    let resolver_join = aliases::person1
+      .on(ban_appeal::resolver_id.eq(aliases::person1.field(person::id).nullable()));
+
+    ban_appeal::table
+      .inner_join(creator_join)
+      .left_join(community::table)
+      .left_join(resolver_join)
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, id: BanAppealId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    Self::joins()
+      .filter(ban_appeal::id.eq(id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Number of unresolved appeals in communities which the user moderates, and of site ban
+  /// appeals for admins.
+  pub async fn get_unresolved_count(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    is_admin: bool,
+  ) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    let moderated_communities = community_actions::table
+      .filter(community_actions::person_id.eq(person_id))
+      .filter(community_actions::became_moderator_at.is_not_null())
+      .select(community_actions::community_id.nullable());
+
+    let mut query = ban_appeal::table
+      .filter(ban_appeal::resolved_at.is_null())
+      .select(count(ban_appeal::id))
+      .into_boxed();
+    query = if is_admin {
+      query.filter(
+        ban_appeal::community_id
+          .eq_any(moderated_communities)
+          .or(ban_appeal::community_id.is_null()),
+      )
+    } else {
+      query.filter(ban_appeal::community_id.eq_any(moderated_communities))
+    };
+    query
+      .first::<i64>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[derive(Default)]
+pub struct BanAppealQuery {
+  /// Only appeals for this community. Permissions need to be checked by the caller.
+  pub community_id: Option<CommunityId>,
+  pub unresolved_only: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl BanAppealQuery {
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    is_admin: bool,
+  ) -> LemmyResult<PagedResponse<BanAppealView>> {
+    let limit = limit_fetch(self.limit, None)?;
+    let mut query = BanAppealView::joins()
+      .select(BanAppealView::as_select())
+      .limit(limit)
+      .into_boxed();
+
+    if let Some(community_id) = self.community_id {
+      query = query.filter(ban_appeal::community_id.eq(community_id));
+    } else {
+      let moderated_communities = community_actions::table
+        .filter(community_actions::person_id.eq(person_id))
+        .filter(community_actions::became_moderator_at.is_not_null())
+        .select(community_actions::community_id.nullable());
+      query = if is_admin {
+        query.filter(
+          ban_appeal::community_id
+            .eq_any(moderated_communities)
+            .or(ban_appeal::community_id.is_null()),
+        )
+      } else {
+        query.filter(ban_appeal::community_id.eq_any(moderated_communities))
+      };
+    }
+
+    if self.unresolved_only.unwrap_or_default() {
+      query = query.filter(ban_appeal::resolved_at.is_null());
+    }
+
+    let paginated_query =
+      BanAppealView::paginate(query, &self.page_cursor, SortDirection::Desc, pool, None)
+        .await?
+        .then_order_by(key::published_at)
+        .then_order_by(key::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<BanAppealView>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    paginate_response(res, limit, self.page_cursor)
+  }
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+  use super::*;
+  use chrono::Utc;
+  use lemmy_db_schema::{
+    assert_length,
+    source::{
+      ban_appeal::BanAppealInsertForm,
+      community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+  };
+  use lemmy_diesel_utils::connection::build_db_pool_for_tests;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_ban_appeal_visibility() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let user_form = PersonInsertForm::test_form(instance.id, "appeal_user");
+    let user = Person::create(pool, &user_form).await?;
+    let mod_form = PersonInsertForm::test_form(instance.id, "appeal_mod");
+    let moderator = Person::create(pool, &mod_form).await?;
+
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "appeal_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let moderator_form = CommunityModeratorForm::new(community.id, moderator.id);
+    CommunityActions::join(pool, &moderator_form).await?;
+
+    let community_appeal_form = BanAppealInsertForm::new(
+      user.id,
+      Some(community.id),
+      "Please unban me".to_string(),
+      Utc::now(),
+    );
+    let community_appeal = BanAppeal::create(pool, &community_appeal_form).await?;
+    let site_appeal_form =
+      BanAppealInsertForm::new(user.id, None, "Please unban me".to_string(), Utc::now());
+    BanAppeal::create(pool, &site_appeal_form).await?;
+
+    // the mod only sees the community appeal, an admin also sees the site appeal
+    assert_eq!(
+      1,
+      BanAppealView::get_unresolved_count(pool, moderator.id, false).await?
+    );
+    assert_eq!(
+      2,
+      BanAppealView::get_unresolved_count(pool, moderator.id, true).await?
+    );
+    let list = BanAppealQuery::default()
+      .list(pool, moderator.id, false)
+      .await?;
+    assert_length!(1, list.items);
+    assert_eq!(community_appeal.id, list.items[0].ban_appeal.id);
+    assert_eq!(
+      Some(community.id),
+      list.items[0].community.as_ref().map(|c| c.id)
+    );
+
+    // the banned user is no mod, so doesn't see any appeals
+    assert_eq!(
+      0,
+      BanAppealView::get_unresolved_count(pool, user.id, false).await?
+    );
+
+    let existing = BanAppeal::read_for_ban(
+      pool,
+      user.id,
+      Some(community.id),
+      community_appeal.banned_at,
+    )
+    .await?;
+    assert_eq!(Some(community_appeal.id), existing.map(|a| a.id));
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -1,5 +1,8 @@
 use crate::CommentView;
-use lemmy_db_schema::newtypes::{CommentId, CommunityId, LanguageId, PostId};
+use lemmy_db_schema::{
+  RemovalNotification,
+  newtypes::{CommentId, CommunityId, DraftId, LanguageId, PostId, RemovalReasonId, RuleId},
+};
 use lemmy_db_schema_file::enums::{CommentSortType, ListingType};
 use lemmy_diesel_utils::pagination::PaginationCursor;
 use serde::{Deserialize, Serialize};
@@ -24,6 +27,8 @@ pub struct CreateComment {
   pub post_id: PostId,
   pub parent_id: Option<CommentId>,
   pub language_id: Option<LanguageId>,
+  /// The draft which this comment was written in. It gets deleted once the comment is created.
+  pub draft_id: Option<DraftId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -126,6 +131,13 @@ pub struct RemoveComment {
   pub comment_id: CommentId,
   pub removed: bool,
   pub reason: String,
+  /// The community or site rule which was violated.
+  pub rule_id: Option<RuleId>,
+  /// A predefined removal reason of the community. Its template is filled in and used as the
+  /// modlog reason, with `{reason}` replaced by the `reason` field.
+  pub removal_reason_id: Option<RemovalReasonId>,
+  /// Send the removal reason to the comment creator.
+  pub notify_author: Option<RemovalNotification>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
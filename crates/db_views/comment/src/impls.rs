@@ -21,7 +21,12 @@ use lemmy_db_schema::{
   },
   utils::{
     limit_fetch,
-    queries::filters::{filter_blocked, filter_suggested_communities},
+    queries::filters::{
+      filter_blocked,
+      filter_is_following_creator,
+      filter_not_silenced_or_is_subscribed,
+      filter_suggested_communities,
+    },
   },
 };
 use lemmy_db_schema_file::{
@@ -128,11 +133,19 @@ impl CommentView {
     // content, otherwise it is filtered out. Admins can view private community content
     // without restriction.
     if !my_local_user.is_admin() {
-      query = query.filter(
-        community::visibility
-          .ne(CommunityVisibility::Private)
-          .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
-      );
+      query = query
+        .filter(
+          community::visibility
+            .ne(CommunityVisibility::Private)
+            .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
+        )
+        // comments of shadow restricted users are only visible to themselves and to mods
+        .filter(
+          comment::held_for_review
+            .eq(false)
+            .or(comment::creator_id.nullable().eq(my_local_user.person_id()))
+            .or(community_actions::became_moderator_at.is_not_null()),
+        );
     }
 
     query
@@ -205,11 +218,12 @@ impl CommentQuery<'_> {
     query = match o.listing_type.unwrap_or_default() {
       ListingType::Subscribed => query.filter(is_subscribed),
       ListingType::Local => query.filter(community::local.eq(true)),
-      ListingType::All => query,
+      ListingType::All => query.filter(filter_not_silenced_or_is_subscribed()),
       ListingType::ModeratorView => {
         query.filter(community_actions::became_moderator_at.is_not_null())
       }
       ListingType::Suggested => query.filter(filter_suggested_communities()),
+      ListingType::FollowedPersons => query.filter(filter_is_following_creator()),
     };
 
     if !o.local_user.show_bot_accounts() {
@@ -247,11 +261,18 @@ impl CommentQuery<'_> {
     );
 
     if !o.local_user.is_admin() {
-      query = query.filter(
-        community::visibility
-          .ne(CommunityVisibility::Private)
-          .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
-      );
+      query = query
+        .filter(
+          community::visibility
+            .ne(CommunityVisibility::Private)
+            .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
+        )
+        .filter(
+          comment::held_for_review
+            .eq(false)
+            .or(comment::creator_id.nullable().eq(my_person_id))
+            .or(community_actions::became_moderator_at.is_not_null()),
+        );
     }
 
     // Filter by the time range
@@ -347,7 +368,7 @@ mod tests {
       instance::Instance,
       language::Language,
       local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
-      person::{Person, PersonActions, PersonBlockForm, PersonInsertForm},
+      person::{Person, PersonActions, PersonBlockForm, PersonFollowerForm, PersonInsertForm},
       post::{Post, PostInsertForm, PostUpdateForm},
       site::{Site, SiteInsertForm},
     },
@@ -823,6 +844,44 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn comment_listing_followed_persons() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let kate_form = PersonInsertForm::test_form(data.instance.id, "kate");
+    let kate = Person::create(pool, &kate_form).await?;
+    let kate_local_user =
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(kate.id), vec![]).await?;
+
+    // Kate follows sara, which is accepted, and timmy, which is still pending
+    let form = PersonFollowerForm::new(data.sara_person.id, kate.id, false);
+    PersonActions::follow(pool, &form).await?;
+    let form = PersonFollowerForm::new(data.timmy_local_user_view.person.id, kate.id, true);
+    PersonActions::follow(pool, &form).await?;
+
+    let followed_persons_query = || CommentQuery {
+      listing_type: Some(ListingType::FollowedPersons),
+      local_user: Some(&kate_local_user),
+      ..Default::default()
+    };
+    let comments = followed_persons_query().list(&data.site, pool).await?;
+    assert_eq!(
+      vec![data.comment_1.id],
+      comments.iter().map(|c| c.comment.id).collect::<Vec<_>>()
+    );
+
+    // Once the follow is accepted, comments of timmy are shown too
+    PersonActions::follow_accepted(pool, data.timmy_local_user_view.person.id, kate.id).await?;
+    let comments = followed_persons_query().list(&data.site, pool).await?;
+    assert_eq!(6, comments.len());
+
+    Person::delete(pool, kate.id).await?;
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn comment_listing_local_user_banned_from_community() -> LemmyResult<()> {
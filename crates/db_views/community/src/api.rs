@@ -3,8 +3,8 @@ use lemmy_db_schema::{
   CommunitySortType,
   MultiCommunityListingType,
   MultiCommunitySortType,
-  newtypes::{CommunityId, LanguageId, MultiCommunityId, TagId},
-  source::site::Site,
+  newtypes::{CommunityId, LanguageId, MultiCommunityId, RemovalReasonId, RuleId, TagId},
+  source::{removal_reason::RemovalReason, rule::Rule, site::Site},
 };
 use lemmy_db_schema_file::{
   PersonId,
@@ -61,6 +61,33 @@ pub struct BanFromCommunity {
   pub expires_at: Option<i64>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Import older posts and comments of a remote community from its outbox. Runs in the background.
+pub struct BackfillCommunity {
+  pub community_id: CommunityId,
+  /// Maximum number of outbox pages to fetch. Each page contains either up to 20 posts, or the
+  /// comments of a single post. Defaults to 100.
+  pub max_pages: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Mute a user in a community. Unlike a ban, the user can still read and vote in the community,
+/// but can't create or edit posts and comments.
+pub struct MuteFromCommunity {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  pub mute: bool,
+  pub reason: String,
+  /// A time that the mute will expire, in unix epoch seconds.
+  pub expires_at: Option<i64>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -143,6 +170,12 @@ pub struct EditCommunity {
   pub posting_restricted_to_mods: Option<bool>,
   pub discussion_languages: Option<Vec<LanguageId>>,
   pub visibility: Option<CommunityVisibility>,
+  /// Number of warnings after which a user is banned automatically. 0 disables it.
+  pub strike_ban_threshold: Option<i32>,
+  /// Time span in which warnings are counted towards the strike threshold.
+  pub strike_window_days: Option<i32>,
+  /// Duration of automatic bans caused by too many warnings.
+  pub strike_ban_days: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -236,6 +269,18 @@ pub struct RemoveCommunity {
   pub reason: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Merges a local community into another local community. Its posts, tags, subscribers and
+/// moderators are moved, and the old community redirects to the new one. Remote communities can't
+/// be merged.
+pub struct MergeCommunity {
+  pub community_id: CommunityId,
+  pub target_community_id: CommunityId,
+  pub reason: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -360,3 +405,101 @@ pub struct DeleteCommunityTag {
   pub tag_id: TagId,
   pub delete: bool,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create a community rule. Without community_id, creates a site rule (only for admins).
+pub struct CreateRule {
+  pub community_id: Option<CommunityId>,
+  pub title: String,
+  pub description: Option<String>,
+  pub position: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Make changes to a community or site rule.
+pub struct EditRule {
+  pub rule_id: RuleId,
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub position: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a community or site rule.
+pub struct DeleteRule {
+  pub rule_id: RuleId,
+  pub delete: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the rules of a community, or the site rules if no community is given.
+pub struct ListRules {
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The list of rules, in display order.
+pub struct ListRulesResponse {
+  pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create a predefined removal reason for a community.
+///
+/// The template can use the placeholders `{rule}`, `{post_title}`, `{community}`, `{author}` and
+/// `{reason}`.
+pub struct CreateRemovalReason {
+  pub community_id: CommunityId,
+  pub title: String,
+  pub template: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Make changes to a removal reason.
+pub struct EditRemovalReason {
+  pub removal_reason_id: RemovalReasonId,
+  pub title: Option<String>,
+  pub template: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a removal reason.
+pub struct DeleteRemovalReason {
+  pub removal_reason_id: RemovalReasonId,
+  pub delete: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the removal reasons of a community (only for mods).
+pub struct ListRemovalReasons {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListRemovalReasonsResponse {
+  pub removal_reasons: Vec<RemovalReason>,
+}
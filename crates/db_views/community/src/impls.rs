@@ -139,17 +139,21 @@ impl CommunityQuery<'_> {
       .limit(limit)
       .into_boxed();
 
-    // Hide deleted and removed for non-admins
+    // Hide deleted, removed and merged for non-admins
     let is_admin = o.local_user.map(|l| l.admin).unwrap_or_default();
     if !is_admin {
       query = query
         .filter(Community::hide_removed_and_deleted())
+        .filter(community::moved_to_id.is_null())
         .filter(filter_not_unlisted_or_is_subscribed());
     }
 
     if let Some(listing_type) = o.listing_type {
       query = match listing_type {
-        ListingType::All => query.filter(filter_not_unlisted_or_is_subscribed()),
+        // Communities have no creator to follow, so all of them are listed
+        ListingType::All | ListingType::FollowedPersons => {
+          query.filter(filter_not_unlisted_or_is_subscribed())
+        }
         ListingType::Subscribed => query.filter(filter_is_subscribed()),
         ListingType::Local => query
           .filter(community::local.eq(true))
@@ -1,3 +1,6 @@
+use crate::UploaderView;
+use lemmy_db_schema::{newtypes::BlockedImageId, source::blocked_image::BlockedImage};
+use lemmy_db_schema_file::PersonId;
 use lemmy_diesel_utils::pagination::PaginationCursor;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -35,6 +38,8 @@ pub struct ImageProxyParams {
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
 /// Get your user's image / media uploads.
 pub struct ListMedia {
+  /// Only for admins listing all media: show only images which were flagged by a plugin.
+  pub flagged_only: Option<bool>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
@@ -47,3 +52,77 @@ pub struct UploadImageResponse {
   pub image_url: Url,
   pub filename: String,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Block an image, so that it and similar images can't be uploaded or proxied anymore.
+///
+/// The url can point to a local or remote image.
+pub struct BlockImage {
+  pub image_url: Url,
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Remove an image from the blocklist.
+pub struct UnblockImage {
+  pub id: BlockedImageId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Fetches the list of blocked images.
+pub struct ListBlockedImages {
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct BlockedImageResponse {
+  pub blocked_image: BlockedImage,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Lists the users whose uploads take up the most space. Only for admins.
+pub struct ListTopUploaders {
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListTopUploadersResponse {
+  pub uploaders: Vec<UploaderView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Deletes all images uploaded by a user from the server. Only for admins.
+pub struct PurgeUserMedia {
+  pub person_id: PersonId,
+  pub reason: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Sets a custom upload quota for a local user. Only for admins.
+pub struct SetUploadQuota {
+  pub person_id: PersonId,
+  /// In megabytes. Leave empty to use the quota of the site again, or set to 0 to prevent any
+  /// uploads.
+  pub upload_quota_mb: Option<i32>,
+}
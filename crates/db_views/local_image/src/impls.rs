@@ -1,9 +1,18 @@
-use crate::LocalImageView;
-use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use crate::{LocalImageView, UploaderView};
+use diesel::{
+  ExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+  dsl::{count_star, sql},
+  sql_types::BigInt,
+};
 use diesel_async::RunQueryDsl;
 use i_love_jesus::SortDirection;
 use lemmy_db_schema::{
-  source::images::{LocalImage, local_image_keys as key},
+  source::{
+    images::{LocalImage, local_image_keys as key},
+    person::Person,
+  },
   utils::limit_fetch,
 };
 use lemmy_db_schema_file::{
@@ -72,16 +81,21 @@ impl LocalImageView {
 
   pub async fn get_all_paged(
     pool: &mut DbPool<'_>,
+    flagged_only: bool,
     cursor_data: Option<PaginationCursor>,
     limit: Option<i64>,
   ) -> LemmyResult<PagedResponse<Self>> {
     let limit = limit_fetch(limit, None)?;
 
-    let query = Self::joins()
+    let mut query = Self::joins()
       .select(Self::as_select())
       .limit(limit)
       .into_boxed();
 
+    if flagged_only {
+      query = query.filter(local_image::flagged_reason.is_not_null());
+    }
+
     let paginated_query =
       Self::paginate(query, &cursor_data, SortDirection::Asc, pool, None).await?;
     let conn = &mut get_conn(pool).await?;
@@ -93,6 +107,36 @@ impl LocalImageView {
   }
 }
 
+impl UploaderView {
+  /// Lists the users whose uploads take up the most space.
+  pub async fn list_top(pool: &mut DbPool<'_>, limit: Option<i64>) -> LemmyResult<Vec<Self>> {
+    let limit = limit_fetch(limit, None)?;
+    let total_size = || sql::<BigInt>("sum(local_image.file_size)::bigint");
+    let conn = &mut get_conn(pool).await?;
+    let res = local_image::table
+      .inner_join(person::table)
+      .filter(local_image::thumbnail_for_post_id.is_null())
+      .group_by(person::id)
+      .select((Person::as_select(), count_star(), total_size()))
+      .order_by(total_size().desc())
+      .limit(limit)
+      .load::<(Person, i64, i64)>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+
+    Ok(
+      res
+        .into_iter()
+        .map(|(person, image_count, total_size)| UploaderView {
+          person,
+          image_count,
+          total_size,
+        })
+        .collect(),
+    )
+  }
+}
+
 impl PaginationCursorConversion for LocalImageView {
   type PaginatedType = LocalImage;
   fn to_cursor(&self) -> CursorData {
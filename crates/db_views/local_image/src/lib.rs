@@ -23,3 +23,14 @@ pub struct LocalImageView {
   #[cfg_attr(feature = "full", diesel(embed))]
   pub post: Option<Post>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// How much a user has uploaded, for admins.
+pub struct UploaderView {
+  pub person: Person,
+  pub image_count: i64,
+  /// In bytes
+  pub total_size: i64,
+}
@@ -31,3 +31,35 @@ pub struct GetModlog {
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(export))]
+/// The file format of a modlog export.
+pub enum ModlogExportFormat {
+  #[default]
+  Csv,
+  /// One JSON encoded `ModlogView` per line.
+  JsonLines,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Export the modlog as a file. Without community_id this is only available for admins, otherwise
+/// also for mods of the community.
+pub struct ExportModlog {
+  pub community_id: Option<CommunityId>,
+  pub mod_person_id: Option<PersonId>,
+  pub type_: Option<ModlogKindFilter>,
+  pub other_person_id: Option<PersonId>,
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  /// Only include entries published at or after this time, in unix epoch seconds.
+  pub published_after: Option<i64>,
+  /// Only include entries published before this time, in unix epoch seconds.
+  pub published_before: Option<i64>,
+  pub format: Option<ModlogExportFormat>,
+}
@@ -1,4 +1,5 @@
 use crate::ModlogView;
+use chrono::{DateTime, Utc};
 use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
@@ -29,7 +30,7 @@ use lemmy_db_schema::{
 use lemmy_db_schema_file::{
   PersonId,
   aliases,
-  enums::ListingType,
+  enums::{ListingType, ModlogKind},
   schema::{comment, community, community_actions, instance, modlog, person, post},
 };
 use lemmy_diesel_utils::{
@@ -103,13 +104,18 @@ pub struct ModlogQuery<'a> {
   pub local_user: Option<&'a LocalUser>,
   pub mod_person_id: Option<PersonId>,
   pub target_person_id: Option<PersonId>,
+  pub published_after: Option<DateTime<Utc>>,
+  pub published_before: Option<DateTime<Utc>>,
+  /// Hide the moderator of entries published before this time.
+  pub hide_mod_names_before: Option<DateTime<Utc>>,
   pub page_cursor: Option<PaginationCursor>,
   pub limit: Option<i64>,
+  pub no_limit: Option<bool>,
 }
 
 impl ModlogQuery<'_> {
   pub async fn list(self, pool: &mut DbPool<'_>) -> LemmyResult<PagedResponse<ModlogView>> {
-    let limit = limit_fetch(self.limit, None)?;
+    let limit = limit_fetch(self.limit, self.no_limit)?;
 
     let target_person = aliases::person1.field(person::id);
     let my_person_id = self.local_user.person_id();
@@ -139,6 +145,14 @@ impl ModlogQuery<'_> {
       query = query.filter(comment::id.eq(comment_id))
     }
 
+    if let Some(published_after) = self.published_after {
+      query = query.filter(modlog::published_at.ge(published_after))
+    }
+
+    if let Some(published_before) = self.published_before {
+      query = query.filter(modlog::published_at.lt(published_before))
+    }
+
     if let Some(type_) = self.type_ {
       query = match type_ {
         ModlogKindFilter::All => query,
@@ -146,8 +160,14 @@ impl ModlogQuery<'_> {
       };
     }
 
+    // Shadow restrictions must not be visible to the restricted user
+    if !self.local_user.is_admin() {
+      query = query.filter(modlog::kind.ne(ModlogKind::AdminRestrictUser));
+    }
+
     query = match self.listing_type.unwrap_or(ListingType::All) {
-      ListingType::All => query,
+      // Mod actions are not filtered by followed persons
+      ListingType::All | ListingType::FollowedPersons => query,
       ListingType::Subscribed => query.filter(filter_is_subscribed()),
       ListingType::Local => query
         .filter(community::local.eq(true))
@@ -174,7 +194,12 @@ impl ModlogQuery<'_> {
     // Map the query results to the enum
     let out = res
       .into_iter()
-      .map(|u| u.hide_mod_name(hide_modlog_names))
+      .map(|u| {
+        let anonymized = self
+          .hide_mod_names_before
+          .is_some_and(|before| u.modlog.published_at < before);
+        u.hide_mod_name(hide_modlog_names || anonymized)
+      })
       .collect();
 
     paginate_response(out, limit, self.page_cursor)
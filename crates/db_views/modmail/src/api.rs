@@ -0,0 +1,87 @@
+use crate::{ModmailMessageView, ModmailThreadView};
+use lemmy_db_schema::newtypes::{CommunityId, ModmailThreadId};
+use lemmy_diesel_utils::pagination::PaginationCursor;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Open a new mod mail thread with the moderators of a community.
+pub struct CreateModmailThread {
+  pub community_id: CommunityId,
+  pub subject: String,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Reply to a mod mail thread, either as its creator or on behalf of the mod team.
+pub struct CreateModmailMessage {
+  pub thread_id: ModmailThreadId,
+  pub content: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List mod mail threads. By default these are the threads which you opened.
+pub struct ListModmailThreads {
+  /// Show the mod mail inbox of this community. Requires mod permissions.
+  pub community_id: Option<CommunityId>,
+  /// Show the mod mail inbox of all communities which you moderate.
+  pub moderator_view: Option<bool>,
+  pub archived: Option<bool>,
+  pub unread_only: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get a mod mail thread with all of its messages.
+pub struct GetModmailThread {
+  pub thread_id: ModmailThreadId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Archive or reopen a mod mail thread. Only for mods.
+pub struct ArchiveModmailThread {
+  pub thread_id: ModmailThreadId,
+  pub archived: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Mark a mod mail thread as read, for yourself or for the whole mod team.
+pub struct MarkModmailThreadAsRead {
+  pub thread_id: ModmailThreadId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ModmailThreadResponse {
+  pub thread_view: ModmailThreadView,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct GetModmailThreadResponse {
+  pub thread_view: ModmailThreadView,
+  pub messages: Vec<ModmailMessageView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ModmailMessageResponse {
+  pub message_view: ModmailMessageView,
+}
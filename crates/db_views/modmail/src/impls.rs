@@ -0,0 +1,383 @@
+use crate::{ModmailMessageView, ModmailThreadView};
+use diesel::{
+  BoolExpressionMethods,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+  dsl::count,
+};
+use diesel_async::RunQueryDsl;
+use i_love_jesus::SortDirection;
+use lemmy_db_schema::{
+  newtypes::{CommunityId, ModmailThreadId},
+  source::modmail::{ModmailThread, modmail_thread_keys as key},
+  utils::limit_fetch,
+};
+use lemmy_db_schema_file::{
+  PersonId,
+  schema::{community, community_actions, modmail_message, modmail_thread, person},
+};
+use lemmy_diesel_utils::{
+  connection::{DbPool, get_conn},
+  pagination::{
+    CursorData,
+    PagedResponse,
+    PaginationCursor,
+    PaginationCursorConversion,
+    paginate_response,
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+impl ModmailThreadView {
+  #[diesel::dsl::auto_type(no_type_alias)]
+  fn joins() -> _ {
+    modmail_thread::table
+      .inner_join(community::table)
+      .inner_join(person::table.on(modmail_thread::creator_id.eq(person::id)))
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, thread_id: ModmailThreadId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    Self::joins()
+      .filter(modmail_thread::id.eq(thread_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  /// Number of threads with unread messages, both those which the user opened and those in the
+  /// mod mail inbox of communities which the user moderates.
+  pub async fn get_unread_count(pool: &mut DbPool<'_>, person_id: PersonId) -> LemmyResult<i64> {
+    let conn = &mut get_conn(pool).await?;
+    let moderated_communities = community_actions::table
+      .filter(community_actions::person_id.eq(person_id))
+      .filter(community_actions::became_moderator_at.is_not_null())
+      .select(community_actions::community_id);
+
+    modmail_thread::table
+      .filter(
+        modmail_thread::creator_id
+          .eq(person_id)
+          .and(modmail_thread::last_message_at.gt(modmail_thread::creator_read_at))
+          .or(
+            modmail_thread::community_id
+              .eq_any(moderated_communities)
+              .and(modmail_thread::archived.eq(false))
+              .and(
+                modmail_thread::mods_read_at.is_null().or(
+                  modmail_thread::last_message_at
+                    .nullable()
+                    .gt(modmail_thread::mods_read_at),
+                ),
+              ),
+          ),
+      )
+      .select(count(modmail_thread::id))
+      .first::<i64>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+impl PaginationCursorConversion for ModmailThreadView {
+  type PaginatedType = ModmailThread;
+
+  fn to_cursor(&self) -> CursorData {
+    CursorData::new_id(self.thread.id.0)
+  }
+
+  async fn from_cursor(
+    cursor: CursorData,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self::PaginatedType> {
+    ModmailThread::read(pool, ModmailThreadId(cursor.id()?)).await
+  }
+}
+
+#[derive(Default)]
+pub struct ModmailThreadQuery {
+  /// Only threads of this community. Permissions need to be checked by the caller.
+  pub community_id: Option<CommunityId>,
+  /// Threads of all communities which the user moderates, instead of the user's own threads.
+  pub moderator_view: Option<bool>,
+  pub archived: Option<bool>,
+  pub unread_only: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl ModmailThreadQuery {
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> LemmyResult<PagedResponse<ModmailThreadView>> {
+    let limit = limit_fetch(self.limit, None)?;
+    let mut query = ModmailThreadView::joins()
+      .select(ModmailThreadView::as_select())
+      .limit(limit)
+      .into_boxed();
+
+    let as_mod = self.community_id.is_some() || self.moderator_view.unwrap_or_default();
+    if let Some(community_id) = self.community_id {
+      query = query.filter(modmail_thread::community_id.eq(community_id));
+    } else if as_mod {
+      let moderated_communities = community_actions::table
+        .filter(community_actions::person_id.eq(person_id))
+        .filter(community_actions::became_moderator_at.is_not_null())
+        .select(community_actions::community_id);
+      query = query.filter(modmail_thread::community_id.eq_any(moderated_communities));
+    } else {
+      query = query.filter(modmail_thread::creator_id.eq(person_id));
+    }
+
+    if let Some(archived) = self.archived {
+      query = query.filter(modmail_thread::archived.eq(archived));
+    }
+
+    if self.unread_only.unwrap_or_default() {
+      query = if as_mod {
+        query.filter(
+          modmail_thread::mods_read_at.is_null().or(
+            modmail_thread::last_message_at
+              .nullable()
+              .gt(modmail_thread::mods_read_at),
+          ),
+        )
+      } else {
+        query.filter(modmail_thread::last_message_at.gt(modmail_thread::creator_read_at))
+      };
+    }
+
+    let paginated_query =
+      ModmailThreadView::paginate(query, &self.page_cursor, SortDirection::Desc, pool, None)
+        .await?
+        .then_order_by(key::last_message_at)
+        .then_order_by(key::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query
+      .load::<ModmailThreadView>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)?;
+    paginate_response(res, limit, self.page_cursor)
+  }
+}
+
+impl ModmailMessageView {
+  /// All messages of a thread, oldest first.
+  pub async fn list_for_thread(
+    pool: &mut DbPool<'_>,
+    thread_id: ModmailThreadId,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    modmail_message::table
+      .inner_join(person::table)
+      .filter(modmail_message::thread_id.eq(thread_id))
+      .order_by(modmail_message::published_at)
+      .then_order_by(modmail_message::id)
+      .select(Self::as_select())
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+  use super::*;
+  use lemmy_db_schema::{
+    assert_length,
+    source::{
+      community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
+      instance::Instance,
+      modmail::{
+        ModmailMessage,
+        ModmailMessageInsertForm,
+        ModmailThreadInsertForm,
+        ModmailThreadUpdateForm,
+      },
+      person::{Person, PersonInsertForm},
+    },
+  };
+  use lemmy_diesel_utils::{connection::build_db_pool_for_tests, dburl::DbUrl};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_modmail_unread() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let user_form = PersonInsertForm::test_form(instance.id, "modmail_user");
+    let user = Person::create(pool, &user_form).await?;
+    let mod_form = PersonInsertForm::test_form(instance.id, "modmail_mod");
+    let moderator = Person::create(pool, &mod_form).await?;
+
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "modmail_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let moderator_form = CommunityModeratorForm::new(community.id, moderator.id);
+    CommunityActions::join(pool, &moderator_form).await?;
+
+    // user opens a thread, which is unread for the mod
+    let thread_form = ModmailThreadInsertForm::new(community.id, user.id, "Question".to_string());
+    let thread = ModmailThread::create(pool, &thread_form).await?;
+    let message_form =
+      ModmailMessageInsertForm::new(thread.id, user.id, "Hello mods".to_string(), false);
+    let message = ModmailMessage::create(pool, &message_form).await?;
+    ModmailThread::add_message(pool, thread.id, false, message.published_at).await?;
+
+    assert_eq!(0, ModmailThreadView::get_unread_count(pool, user.id).await?);
+    assert_eq!(
+      1,
+      ModmailThreadView::get_unread_count(pool, moderator.id).await?
+    );
+
+    let mod_inbox = ModmailThreadQuery {
+      moderator_view: Some(true),
+      ..Default::default()
+    }
+    .list(pool, moderator.id)
+    .await?;
+    assert_length!(1, mod_inbox.items);
+    assert_eq!(thread.id, mod_inbox.items[0].thread.id);
+
+    // the user doesn't see threads of the mod inbox
+    let user_inbox = ModmailThreadQuery {
+      moderator_view: Some(true),
+      ..Default::default()
+    }
+    .list(pool, user.id)
+    .await?;
+    assert_length!(0, user_inbox.items);
+
+    // mod replies, now the thread is unread for the user only
+    let reply_form =
+      ModmailMessageInsertForm::new(thread.id, moderator.id, "Hi there".to_string(), true);
+    let reply = ModmailMessage::create(pool, &reply_form).await?;
+    ModmailThread::add_message(pool, thread.id, true, reply.published_at).await?;
+
+    assert_eq!(1, ModmailThreadView::get_unread_count(pool, user.id).await?);
+    assert_eq!(
+      0,
+      ModmailThreadView::get_unread_count(pool, moderator.id).await?
+    );
+
+    let unread = ModmailThreadQuery {
+      unread_only: Some(true),
+      ..Default::default()
+    }
+    .list(pool, user.id)
+    .await?;
+    assert_length!(1, unread.items);
+
+    let messages = ModmailMessageView::list_for_thread(pool, thread.id).await?;
+    assert_length!(2, messages);
+    assert!(messages[1].message.from_mod);
+    assert_eq!(moderator.id, messages[1].creator.id);
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_modmail_archive_and_federation() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld").await?;
+    let user_form = PersonInsertForm::test_form(instance.id, "modmail_user");
+    let user = Person::create(pool, &user_form).await?;
+    let mod_form = PersonInsertForm::test_form(instance.id, "modmail_mod");
+    let moderator = Person::create(pool, &mod_form).await?;
+
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "modmail_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let moderator_form = CommunityModeratorForm::new(community.id, moderator.id);
+    CommunityActions::join(pool, &moderator_form).await?;
+
+    // a thread received from a remote instance can be found by its federation id
+    let thread_ap_id: DbUrl = Url::parse("https://remote.example.com/modmail/1")?.into();
+    let thread_form = ModmailThreadInsertForm {
+      ap_id: Some(thread_ap_id.clone()),
+      local: Some(false),
+      ..ModmailThreadInsertForm::new(community.id, user.id, "Question".to_string())
+    };
+    let thread = ModmailThread::create(pool, &thread_form).await?;
+    let read = ModmailThread::read_from_ap_id(pool, &thread_ap_id).await?;
+    assert_eq!(Some(thread.id), read.map(|t| t.id));
+
+    // the same message is only stored once
+    let message_form = ModmailMessageInsertForm {
+      ap_id: Some(Url::parse("https://remote.example.com/modmail_message/1")?.into()),
+      local: Some(false),
+      ..ModmailMessageInsertForm::new(thread.id, user.id, "Hello mods".to_string(), false)
+    };
+    let message = ModmailMessage::insert_apub(pool, &message_form)
+      .await?
+      .ok_or(LemmyErrorType::NotFound)?;
+    assert!(
+      ModmailMessage::insert_apub(pool, &message_form)
+        .await?
+        .is_none()
+    );
+    ModmailThread::add_message(pool, thread.id, false, message.published_at).await?;
+    assert_eq!(
+      1,
+      ModmailThreadView::get_unread_count(pool, moderator.id).await?
+    );
+
+    // archived threads don't count as unread for the mods
+    let archive_form = ModmailThreadUpdateForm {
+      archived: Some(true),
+      ..Default::default()
+    };
+    ModmailThread::update(pool, thread.id, &archive_form).await?;
+    assert_eq!(
+      0,
+      ModmailThreadView::get_unread_count(pool, moderator.id).await?
+    );
+    let archived = ModmailThreadQuery {
+      moderator_view: Some(true),
+      archived: Some(true),
+      ..Default::default()
+    }
+    .list(pool, moderator.id)
+    .await?;
+    assert_length!(1, archived.items);
+
+    // a new message from the user reopens the thread
+    let message_form =
+      ModmailMessageInsertForm::new(thread.id, user.id, "Any news?".to_string(), false);
+    let message = ModmailMessage::create(pool, &message_form).await?;
+    let thread = ModmailThread::add_message(pool, thread.id, false, message.published_at).await?;
+    assert!(!thread.archived);
+    assert_eq!(
+      1,
+      ModmailThreadView::get_unread_count(pool, moderator.id).await?
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,40 @@
+#[cfg(feature = "full")]
+use diesel::{Queryable, Selectable};
+use lemmy_db_schema::source::{
+  community::Community,
+  modmail::{ModmailMessage, ModmailThread},
+  person::Person,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod api;
+#[cfg(feature = "full")]
+pub mod impls;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A mod mail thread view.
+pub struct ModmailThreadView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub thread: ModmailThread,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub community: Community,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub creator: Person,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A mod mail message view.
+pub struct ModmailMessageView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub message: ModmailMessage,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub creator: Person,
+}
@@ -17,7 +17,7 @@ use lemmy_db_schema::{
   },
   utils::{limit_fetch, queries::filters::filter_blocked},
 };
-use lemmy_db_schema_file::schema::{notification, person};
+use lemmy_db_schema_file::schema::{comment, community_actions, notification, person, post};
 use lemmy_db_views_modlog::ModlogView;
 use lemmy_db_views_notification_sql::notification_joins;
 use lemmy_db_views_post::PostView;
@@ -60,6 +60,13 @@ impl NotificationView {
     if !show_bot_accounts {
       query = query.filter(person::bot_account.is_distinct_from(true));
     }
+    query = query.filter(
+      post::held_for_review
+        .is_distinct_from(true)
+        .and(comment::held_for_review.is_distinct_from(true))
+        .or(notification::modlog_id.is_not_null())
+        .or(community_actions::became_moderator_at.is_not_null()),
+    );
 
     query
       .first::<i64>(conn)
@@ -157,6 +164,16 @@ impl NotificationQuery {
     // Dont show replies from blocked users or instances
     query = query.filter(filter_blocked());
 
+    // Dont show content of shadow restricted users, unless you moderate the community. Mod actions
+    // are always shown, as they are sent to the creator of the content.
+    query = query.filter(
+      post::held_for_review
+        .is_distinct_from(true)
+        .and(comment::held_for_review.is_distinct_from(true))
+        .or(notification::modlog_id.is_not_null())
+        .or(community_actions::became_moderator_at.is_not_null()),
+    );
+
     if let Some(type_) = self.type_ {
       query = match type_ {
         NotificationTypeFilter::All => query,
@@ -3,7 +3,7 @@ use lemmy_db_schema::{
   assert_length,
   source::{
     comment::{Comment, CommentInsertForm},
-    community::{Community, CommunityInsertForm},
+    community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
     instance::Instance,
     modlog::{Modlog, ModlogInsertForm},
     notification::{Notification, NotificationInsertForm},
@@ -197,3 +197,52 @@ async fn test_modlog() -> LemmyResult<()> {
 
   cleanup(data, pool).await
 }
+
+#[tokio::test]
+#[serial]
+async fn test_held_for_review() -> LemmyResult<()> {
+  let pool = &build_db_pool_for_tests();
+  let pool = &mut pool.into();
+  let data = init_data(pool).await?;
+
+  let community_form = CommunityInsertForm::new(
+    data.alice.instance_id,
+    "comm".to_string(),
+    "title".to_string(),
+    "pubkey".to_string(),
+  );
+  let community = Community::create(pool, &community_form).await?;
+
+  // Bob is shadow restricted, so his post and comment are held
+  let post_form = PostInsertForm {
+    held_for_review: Some(true),
+    ..PostInsertForm::new("title".to_string(), data.bob.id, community.id)
+  };
+  let post = Post::create(pool, &post_form).await?;
+  let comment_form = CommentInsertForm {
+    held_for_review: Some(true),
+    ..CommentInsertForm::new(data.bob.id, post.id, "comment".to_string())
+  };
+  let comment = Comment::create(pool, &comment_form, None).await?;
+  let post_notif_form =
+    NotificationInsertForm::new_post(post.id, data.alice.id, NotificationType::Subscribed);
+  let comment_notif_form =
+    NotificationInsertForm::new_comment(comment.id, data.alice.id, NotificationType::Mention);
+  Notification::create(pool, &[post_notif_form, comment_notif_form]).await?;
+
+  // Hidden from other users
+  let count = NotificationView::get_unread_count(pool, &data.alice, false).await?;
+  assert_eq!(0, count);
+  let notifs = NotificationQuery::default().list(pool, &data.alice).await?;
+  assert_length!(0, notifs);
+
+  // Visible to mods of the community
+  let mod_form = CommunityModeratorForm::new(community.id, data.alice.id);
+  CommunityActions::join(pool, &mod_form).await?;
+  let count = NotificationView::get_unread_count(pool, &data.alice, false).await?;
+  assert_eq!(2, count);
+  let notifs = NotificationQuery::default().list(pool, &data.alice).await?;
+  assert_length!(2, notifs);
+
+  cleanup(data, pool).await
+}
@@ -1,8 +1,12 @@
-use crate::PersonView;
-use lemmy_db_schema::source::site::Site;
+use crate::{ModNoteView, PersonView};
+use lemmy_db_schema::{
+  newtypes::{CommentId, CommunityId, ModNoteId, PostId},
+  source::site::Site,
+};
 use lemmy_db_schema_file::PersonId;
 use lemmy_db_views_community::MultiCommunityView;
 use lemmy_db_views_community_moderator::CommunityModeratorView;
+use lemmy_diesel_utils::pagination::PaginationCursor;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -41,6 +45,29 @@ pub struct BanPerson {
   pub expires_at: Option<i64>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Give a formal warning to a person, in a community or for the whole site if no community is
+/// given. Too many warnings lead to an automatic ban, depending on the strike settings.
+pub struct WarnPerson {
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Shadow restrict a local user suspected of spamming. While restricted, their new posts and
+/// comments are only visible to themselves and to moderators, and are not federated. Lifting the
+/// restriction publishes the held content.
+pub struct RestrictPerson {
+  pub person_id: PersonId,
+  pub restrict: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -50,6 +77,28 @@ pub struct BlockPerson {
   pub block: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Follow a person, so that their posts and comments are shown with the `FollowedPersons` listing
+/// type.
+pub struct FollowPerson {
+  pub person_id: PersonId,
+  pub follow: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the followers of a person, or the persons they are following. Only available for your own
+/// account, or for admins.
+pub struct ListPersonFollows {
+  pub person_id: PersonId,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -103,3 +152,62 @@ pub struct NotePerson {
   pub person_id: PersonId,
   pub note: String,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Write a note about a person, which is shared with the other mods of the community.
+///
+/// Without community_id, the note is shared with all admins instead.
+pub struct CreateModNote {
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+  /// The post which led to this note.
+  pub post_id: Option<PostId>,
+  /// The comment which led to this note.
+  pub comment_id: Option<CommentId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit a mod note.
+pub struct EditModNote {
+  pub note_id: ModNoteId,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a mod note.
+pub struct DeleteModNote {
+  pub note_id: ModNoteId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the mod notes about a person in a community, or the site-wide notes if community_id is
+/// empty.
+pub struct ListModNotes {
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ModNoteResponse {
+  pub mod_note_view: ModNoteView,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListModNotesResponse {
+  pub mod_notes: Vec<ModNoteView>,
+}
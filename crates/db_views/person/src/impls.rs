@@ -1,20 +1,32 @@
-use crate::PersonView;
-use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use crate::{ModNoteView, PersonView};
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, SelectableHelper};
 use diesel_async::RunQueryDsl;
-use lemmy_db_schema::source::person::Person;
+use i_love_jesus::SortDirection;
+use lemmy_db_schema::{
+  newtypes::{CommunityId, ModNoteId},
+  source::person::{Person, person_keys},
+  utils::limit_fetch,
+};
 use lemmy_db_schema_file::{
   InstanceId,
   PersonId,
+  aliases::person_follows,
   joins::{
     creator_home_instance_actions_join,
     creator_local_instance_actions_join,
     my_person_actions_join,
   },
-  schema::{local_user, person},
+  schema::{local_user, mod_note, person, person_actions},
 };
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
-  pagination::{CursorData, PaginationCursorConversion},
+  pagination::{
+    CursorData,
+    PagedResponse,
+    PaginationCursor,
+    PaginationCursorConversion,
+    paginate_response,
+  },
   traits::Crud,
 };
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
@@ -90,6 +102,138 @@ impl PersonView {
       .await
       .with_lemmy_type(LemmyErrorType::NotFound)
   }
+
+  /// Persons who are following the given person, only accepted follows are included.
+  pub async fn list_followers(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    my_person_id: Option<PersonId>,
+    local_instance_id: InstanceId,
+    page_cursor: Option<PaginationCursor>,
+    limit: Option<i64>,
+  ) -> LemmyResult<PagedResponse<Self>> {
+    Self::list_follows(
+      pool,
+      person_id,
+      true,
+      my_person_id,
+      local_instance_id,
+      page_cursor,
+      limit,
+    )
+    .await
+  }
+
+  /// Persons who are followed by the given person, only accepted follows are included.
+  pub async fn list_following(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    my_person_id: Option<PersonId>,
+    local_instance_id: InstanceId,
+    page_cursor: Option<PaginationCursor>,
+    limit: Option<i64>,
+  ) -> LemmyResult<PagedResponse<Self>> {
+    Self::list_follows(
+      pool,
+      person_id,
+      false,
+      my_person_id,
+      local_instance_id,
+      page_cursor,
+      limit,
+    )
+    .await
+  }
+
+  async fn list_follows(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    followers: bool,
+    my_person_id: Option<PersonId>,
+    local_instance_id: InstanceId,
+    page_cursor: Option<PaginationCursor>,
+    limit: Option<i64>,
+  ) -> LemmyResult<PagedResponse<Self>> {
+    let limit = limit_fetch(limit, None)?;
+    let follower_id = person_follows.field(person_actions::person_id);
+    let target_id = person_follows.field(person_actions::target_id);
+    let accepted_follows = person_follows.filter(
+      person_follows
+        .field(person_actions::follow_pending)
+        .eq(Some(false)),
+    );
+
+    let mut query = Self::joins(my_person_id, local_instance_id)
+      .filter(person::deleted.eq(false))
+      .limit(limit)
+      .select(Self::as_select())
+      .into_boxed();
+
+    query = if followers {
+      query.filter(
+        person::id.eq_any(
+          accepted_follows
+            .filter(target_id.eq(person_id))
+            .select(follower_id),
+        ),
+      )
+    } else {
+      query.filter(
+        person::id.eq_any(
+          accepted_follows
+            .filter(follower_id.eq(person_id))
+            .select(target_id),
+        ),
+      )
+    };
+
+    let paginated_query =
+      PersonView::paginate(query, &page_cursor, SortDirection::Desc, pool, None)
+        .await?
+        .then_order_by(person_keys::id);
+
+    let conn = &mut get_conn(pool).await?;
+    let res = paginated_query.load::<Self>(conn).await?;
+    paginate_response(res, limit, page_cursor)
+  }
+}
+
+impl ModNoteView {
+  /// Notes about the given person which are visible to the mods of a community, or site-wide notes
+  /// for admins if no community is given. Newest first.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    target_person_id: PersonId,
+    community_id: Option<CommunityId>,
+  ) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = mod_note::table
+      .inner_join(person::table.on(mod_note::creator_id.eq(person::id)))
+      .filter(mod_note::target_person_id.eq(target_person_id))
+      .order_by(mod_note::published_at.desc())
+      .then_order_by(mod_note::id.desc())
+      .select(Self::as_select())
+      .into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(mod_note::community_id.eq(community_id)),
+      None => query.filter(mod_note::community_id.is_null()),
+    };
+    query
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, note_id: ModNoteId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    mod_note::table
+      .inner_join(person::table.on(mod_note::creator_id.eq(person::id)))
+      .filter(mod_note::id.eq(note_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
 }
 
 #[cfg(test)]
@@ -102,8 +246,17 @@ mod tests {
     source::{
       instance::Instance,
       local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
-      person::{Person, PersonActions, PersonInsertForm, PersonNoteForm, PersonUpdateForm},
+      mod_note::{ModNote, ModNoteInsertForm},
+      person::{
+        Person,
+        PersonActions,
+        PersonFollowerForm,
+        PersonInsertForm,
+        PersonNoteForm,
+        PersonUpdateForm,
+      },
     },
+    traits::Followable,
   };
   use lemmy_diesel_utils::{
     connection::{DbPool, build_db_pool_for_tests},
@@ -240,4 +393,72 @@ mod tests {
 
     cleanup(data, pool).await
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn follows() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+    let instance_id = data.alice.instance_id;
+
+    // bob is remote, so the follow is pending until it gets accepted
+    let form = PersonFollowerForm::new(data.bob.id, data.alice.id, true);
+    PersonActions::follow(pool, &form).await?;
+
+    let following =
+      PersonView::list_following(pool, data.alice.id, None, instance_id, None, None).await?;
+    assert_length!(0, following.items);
+
+    PersonActions::follow_accepted(pool, data.bob.id, data.alice.id).await?;
+
+    let following =
+      PersonView::list_following(pool, data.alice.id, None, instance_id, None, None).await?;
+    assert_length!(1, following.items);
+    assert_eq!(data.bob.id, following.items[0].person.id);
+
+    let followers =
+      PersonView::list_followers(pool, data.bob.id, None, instance_id, None, None).await?;
+    assert_length!(1, followers.items);
+    assert_eq!(data.alice.id, followers.items[0].person.id);
+
+    // alice has no followers
+    let followers =
+      PersonView::list_followers(pool, data.alice.id, None, instance_id, None, None).await?;
+    assert_length!(0, followers.items);
+
+    PersonActions::unfollow(pool, data.alice.id, data.bob.id).await?;
+    let followers =
+      PersonView::list_followers(pool, data.bob.id, None, instance_id, None, None).await?;
+    assert_length!(0, followers.items);
+
+    cleanup(data, pool).await
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn mod_notes() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let form = ModNoteInsertForm::new(
+      data.bob.id,
+      data.alice.id,
+      None,
+      "Posted spam before".to_string(),
+    );
+    let note = ModNote::create(pool, &form).await?;
+
+    let notes = ModNoteView::list(pool, data.bob.id, None).await?;
+    assert_length!(1, notes);
+    assert_eq!(note, notes[0].note);
+    assert_eq!(data.alice.id, notes[0].creator.id);
+
+    // there are no notes about alice
+    let notes = ModNoteView::list(pool, data.alice.id, None).await?;
+    assert_length!(0, notes);
+
+    cleanup(data, pool).await
+  }
 }
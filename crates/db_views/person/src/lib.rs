@@ -1,5 +1,8 @@
 use chrono::{DateTime, Utc};
-use lemmy_db_schema::source::person::{Person, PersonActions};
+use lemmy_db_schema::source::{
+  mod_note::ModNote,
+  person::{Person, PersonActions},
+};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "full")]
 use {
@@ -49,3 +52,16 @@ pub struct PersonView {
   )]
   pub ban_expires_at: Option<DateTime<Utc>>,
 }
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A mod note view, with the mod or admin who wrote it.
+pub struct ModNoteView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub note: ModNote,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub creator: Person,
+}
@@ -4,6 +4,7 @@ use diesel::{
   ExpressionMethods,
   JoinOnDsl,
   NullableExpressionMethods,
+  PgExpressionMethods,
   QueryDsl,
   SelectableHelper,
 };
@@ -15,7 +16,7 @@ use lemmy_db_schema::{
   impls::local_user::LocalUserOptionHelper,
   source::combined::person_content::{PersonContentCombined, person_content_combined_keys as key},
   traits::InternalToCombinedView,
-  utils::limit_fetch,
+  utils::{limit_fetch, queries::filters::filter_not_silenced_or_is_subscribed},
 };
 use lemmy_db_schema_file::{
   InstanceId,
@@ -195,11 +196,24 @@ impl PersonContentCombinedQuery {
     // content, otherwise it is filtered out. Admins can view private community content
     // without restriction.
     if !my_local_user.is_admin() {
-      query = query.filter(
-        community::visibility
-          .ne(CommunityVisibility::Private)
-          .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
-      );
+      query = query
+        .filter(
+          community::visibility
+            .ne(CommunityVisibility::Private)
+            .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
+        )
+        // Content of shadow restricted users is only visible to themselves and to mods
+        .filter(
+          post::held_for_review
+            .eq(false)
+            .and(comment::held_for_review.is_distinct_from(true))
+            .or(item_creator.nullable().eq(my_person_id))
+            .or(community_actions::became_moderator_at.is_not_null()),
+        )
+        // Content from silenced instances is hidden, same as in the All listing
+        .filter(
+          filter_not_silenced_or_is_subscribed().or(item_creator.nullable().eq(my_person_id)),
+        );
     }
 
     // Sorting by published
@@ -244,7 +258,13 @@ mod tests {
   use lemmy_db_schema::{
     source::{
       comment::{Comment, CommentInsertForm},
-      community::{Community, CommunityActions, CommunityFollowerForm, CommunityInsertForm},
+      community::{
+        Community,
+        CommunityActions,
+        CommunityFollowerForm,
+        CommunityInsertForm,
+        CommunityModeratorForm,
+      },
       instance::Instance,
       local_user::{LocalUser, LocalUserInsertForm},
       person::{Person, PersonInsertForm},
@@ -479,4 +499,56 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn held_for_review() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    // Sara is shadow restricted, so her new post and comment are held
+    let sara_local_user_form = LocalUserInsertForm::test_form(data.sara.id);
+    LocalUser::create(pool, &sara_local_user_form, vec![]).await?;
+    let sara_view = LocalUserView::read_person(pool, data.sara.id).await?;
+    let community_id = data.sara_post.community_id;
+    let post_form = PostInsertForm {
+      held_for_review: Some(true),
+      ..PostInsertForm::new("sara held post".into(), data.sara.id, community_id)
+    };
+    Post::create(pool, &post_form).await?;
+    let comment_form = CommentInsertForm {
+      held_for_review: Some(true),
+      ..CommentInsertForm::new(data.sara.id, data.timmy_post.id, "sara held comment".into())
+    };
+    Comment::create(pool, &comment_form, None).await?;
+
+    // Hidden from other users and logged out users
+    let sara_content = PersonContentCombinedQuery::new(data.sara.id)
+      .list(pool, None, data.instance.id)
+      .await?;
+    assert_eq!(3, sara_content.len());
+    let sara_content = PersonContentCombinedQuery::new(data.sara.id)
+      .list(pool, Some(&data.timmy_view), data.instance.id)
+      .await?;
+    assert_eq!(3, sara_content.len());
+
+    // Visible to the creator
+    let sara_content = PersonContentCombinedQuery::new(data.sara.id)
+      .list(pool, Some(&sara_view), data.instance.id)
+      .await?;
+    assert_eq!(5, sara_content.len());
+
+    // Visible to mods of the community
+    let timmy_mod_form = CommunityModeratorForm::new(community_id, data.timmy.id);
+    CommunityActions::join(pool, &timmy_mod_form).await?;
+    let sara_content = PersonContentCombinedQuery::new(data.sara.id)
+      .list(pool, Some(&data.timmy_view), data.instance.id)
+      .await?;
+    assert_eq!(5, sara_content.len());
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
 }
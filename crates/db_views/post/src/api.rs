@@ -1,9 +1,24 @@
 use crate::PostView;
 use lemmy_db_schema::{
   PostFeatureType,
-  newtypes::{CommunityId, LanguageId, MultiCommunityId, PostId, TagId},
+  RemovalNotification,
+  newtypes::{
+    CommunityId,
+    DraftId,
+    LanguageId,
+    MultiCommunityId,
+    PostId,
+    RecurringPostId,
+    RemovalReasonId,
+    RuleId,
+    TagId,
+  },
+  source::recurring_post::RecurringPost,
+};
+use lemmy_db_schema_file::{
+  PersonId,
+  enums::{ListingType, PostNotificationsMode, PostSortType},
 };
-use lemmy_db_schema_file::enums::{ListingType, PostNotificationsMode, PostSortType};
 use lemmy_diesel_utils::{dburl::DbUrl, pagination::PaginationCursor};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -30,6 +45,8 @@ pub struct CreatePost {
   pub tags: Option<Vec<TagId>>,
   /// Time when this post should be scheduled. Null means publish immediately.
   pub scheduled_publish_time_at: Option<i64>,
+  /// The draft which this post was written in. It gets deleted once the post is created.
+  pub draft_id: Option<DraftId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -247,6 +264,13 @@ pub struct RemovePost {
   pub post_id: PostId,
   pub removed: bool,
   pub reason: String,
+  /// The community or site rule which was violated.
+  pub rule_id: Option<RuleId>,
+  /// A predefined removal reason of the community. Its template is filled in and used as the
+  /// modlog reason, with `{reason}` replaced by the `reason` field.
+  pub removal_reason_id: Option<RemovalReasonId>,
+  /// Send the removal reason to the post creator.
+  pub notify_author: Option<RemovalNotification>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -267,3 +291,83 @@ pub struct MarkManyPostsAsRead {
   pub post_ids: Vec<PostId>,
   pub read: bool,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Create a template for a post which gets published regularly in a community (only doable by
+/// mods).
+pub struct CreateRecurringPost {
+  pub community_id: CommunityId,
+  /// The account which creates the posts, needs to be a moderator of the community. Defaults to
+  /// yourself.
+  pub creator_id: Option<PersonId>,
+  /// A cron expression in UTC, eg `0 12 * * 1` for every monday at noon.
+  pub schedule: String,
+  /// The post title. Can contain the placeholders `{date}`, `{year}`, `{month}`, `{month_name}`,
+  /// `{day}`, `{weekday}` and `{week}`.
+  pub name: String,
+  pub body: Option<String>,
+  pub language_id: Option<LanguageId>,
+  pub nsfw: Option<bool>,
+  pub tags: Option<Vec<TagId>>,
+  /// Feature each new post in the community.
+  pub feature_post: Option<bool>,
+  /// Unfeature the previous post when a new one is published.
+  pub unfeature_previous: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit a recurring post template.
+pub struct EditRecurringPost {
+  pub id: RecurringPostId,
+  pub creator_id: Option<PersonId>,
+  pub schedule: Option<String>,
+  pub name: Option<String>,
+  pub body: Option<String>,
+  pub language_id: Option<LanguageId>,
+  pub nsfw: Option<bool>,
+  pub tags: Option<Vec<TagId>>,
+  pub feature_post: Option<bool>,
+  pub unfeature_previous: Option<bool>,
+  /// Pause or resume publishing.
+  pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a recurring post template. Posts which were already published are kept.
+pub struct DeleteRecurringPost {
+  pub id: RecurringPostId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the recurring post templates of a community (only doable by mods).
+pub struct ListRecurringPosts {
+  pub community_id: CommunityId,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RecurringPostView {
+  pub recurring_post: RecurringPost,
+  pub tags: Vec<TagId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RecurringPostResponse {
+  pub recurring_post_view: RecurringPostView,
+}
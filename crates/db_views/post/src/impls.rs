@@ -29,7 +29,9 @@ use lemmy_db_schema::{
     limit_fetch,
     queries::filters::{
       filter_blocked,
+      filter_is_following_creator,
       filter_is_subscribed,
+      filter_not_silenced_or_is_subscribed,
       filter_not_unlisted_or_is_subscribed,
       filter_suggested_communities,
     },
@@ -188,6 +190,11 @@ impl PostView {
           community::visibility
             .ne(CommunityVisibility::Private)
             .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
+        )
+        .filter(
+          post::held_for_review
+            .eq(false)
+            .or(post::creator_id.nullable().eq(my_person_id)),
         );
     }
 
@@ -417,11 +424,16 @@ impl PostQuery<'_> {
           .filter(community::local.eq(true))
           .filter(filter_not_unlisted_or_is_subscribed());
       }
-      ListingType::All => query = query.filter(filter_not_unlisted_or_is_subscribed()),
+      ListingType::All => {
+        query = query
+          .filter(filter_not_unlisted_or_is_subscribed())
+          .filter(filter_not_silenced_or_is_subscribed());
+      }
       ListingType::ModeratorView => {
         query = query.filter(community_actions::became_moderator_at.is_not_null());
       }
       ListingType::Suggested => query = query.filter(filter_suggested_communities()),
+      ListingType::FollowedPersons => query = query.filter(filter_is_following_creator()),
     }
 
     if !o.show_nsfw.unwrap_or(o.local_user.show_nsfw(site)) {
@@ -475,7 +487,14 @@ impl PostQuery<'_> {
         // only show removed posts to admin
         .filter(community::removed.eq(false))
         .filter(community::local_removed.eq(false))
-        .filter(post::removed.eq(false));
+        .filter(post::removed.eq(false))
+        // posts of shadow restricted users are only visible to themselves and to mods
+        .filter(
+          post::held_for_review
+            .eq(false)
+            .or(post::creator_id.nullable().eq(my_person_id))
+            .or(community_actions::became_moderator_at.is_not_null()),
+        );
     }
 
     // Dont filter blocks or missing languages for moderator view type
@@ -32,7 +32,14 @@ use lemmy_db_schema::{
     local_site::{LocalSite, LocalSiteUpdateForm},
     local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
     multi_community::{MultiCommunity, MultiCommunityInsertForm},
-    person::{Person, PersonActions, PersonBlockForm, PersonInsertForm, PersonNoteForm},
+    person::{
+      Person,
+      PersonActions,
+      PersonBlockForm,
+      PersonFollowerForm,
+      PersonInsertForm,
+      PersonNoteForm,
+    },
     post::{Post, PostActions, PostHideForm, PostInsertForm, PostLikeForm, PostUpdateForm},
     site::Site,
     tag::{PostTag, Tag, TagInsertForm},
@@ -2322,3 +2329,39 @@ async fn post_listing_multi_community(data: &mut Data) -> LemmyResult<()> {
 
   Ok(())
 }
+
+#[test_context(Data)]
+#[tokio::test]
+#[serial]
+async fn post_listing_followed_persons(data: &mut Data) -> LemmyResult<()> {
+  let pool = &data.pool();
+  let pool = &mut pool.into();
+
+  let followed_persons_query = || PostQuery {
+    listing_type: Some(ListingType::FollowedPersons),
+    local_user: Some(&data.john.local_user),
+    ..data.default_post_query()
+  };
+
+  // John follows tegan, which is accepted, and the bot, which is still pending
+  let form = PersonFollowerForm::new(data.tegan.person.id, data.john.person.id, false);
+  PersonActions::follow(pool, &form).await?;
+  let form = PersonFollowerForm::new(data.bot.person.id, data.john.person.id, true);
+  PersonActions::follow(pool, &form).await?;
+
+  let listing = followed_persons_query().list(&data.site, pool).await?;
+  assert_eq!(vec![POST_WITH_TAGS, POST], names(&listing));
+
+  // Once the follow is accepted, posts of the bot are shown too
+  PersonActions::follow_accepted(pool, data.bot.person.id, data.john.person.id).await?;
+  let listing = followed_persons_query().list(&data.site, pool).await?;
+  assert_eq!(vec![POST_WITH_TAGS, POST_BY_BOT, POST], names(&listing));
+
+  // The listing is empty after unfollowing
+  PersonActions::unfollow(pool, data.john.person.id, data.tegan.person.id).await?;
+  PersonActions::unfollow(pool, data.john.person.id, data.bot.person.id).await?;
+  let listing = followed_persons_query().list(&data.site, pool).await?;
+  assert!(listing.is_empty());
+
+  Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::PrivateMessageView;
-use lemmy_db_schema::newtypes::PrivateMessageId;
+use lemmy_db_schema::newtypes::{DraftId, PrivateMessageId};
 use lemmy_db_schema_file::PersonId;
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +10,8 @@ use serde::{Deserialize, Serialize};
 pub struct CreatePrivateMessage {
   pub content: String,
   pub recipient_id: PersonId,
+  /// The draft which this message was written in. It gets deleted once the message is sent.
+  pub draft_id: Option<DraftId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
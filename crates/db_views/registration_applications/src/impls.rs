@@ -267,6 +267,7 @@ mod tests {
         show_score: sara_local_user.show_score,
         show_upvote_percentage: sara_local_user.show_upvote_percentage,
         show_person_votes: sara_local_user.show_person_votes,
+        upload_quota_mb: sara_local_user.upload_quota_mb,
       },
       creator: Person {
         id: sara_person.id,
@@ -1,4 +1,13 @@
-use crate::{CommentReportView, CommunityReportView, PostReportView, PrivateMessageReportView};
+use crate::{
+  CommentReportView,
+  CommunityReportMetricsView,
+  CommunityReportView,
+  PersonReportView,
+  PostReportView,
+  PrivateMessageReportView,
+  ReportCombinedView,
+  ReportNoteView,
+};
 use lemmy_db_schema::{
   ReportType,
   newtypes::{
@@ -6,12 +15,17 @@ use lemmy_db_schema::{
     CommentReportId,
     CommunityId,
     CommunityReportId,
+    PersonReportId,
     PostId,
     PostReportId,
     PrivateMessageId,
     PrivateMessageReportId,
+    ReportCombinedId,
+    ReportNoteId,
+    RuleId,
   },
 };
+use lemmy_db_schema_file::PersonId;
 use lemmy_diesel_utils::pagination::PaginationCursor;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -36,6 +50,12 @@ pub struct ListReports {
   pub show_community_rule_violations: Option<bool>,
   /// If true, view all your created reports. Works for non-admins/mods also.
   pub my_reports_only: Option<bool>,
+  /// Only show post and comment reports which reference this rule.
+  pub rule_id: Option<RuleId>,
+  /// Only show reports assigned to this mod.
+  pub assignee_id: Option<PersonId>,
+  /// Only show reports which nobody is working on yet.
+  pub unassigned_only: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -62,6 +82,10 @@ pub struct CreateCommentReport {
   pub comment_id: CommentId,
   pub reason: String,
   pub violates_instance_rules: Option<bool>,
+  /// The community or site rule which was violated. Site rules are always shown to admins.
+  pub rule_id: Option<RuleId>,
+  /// Also send the report to the instance where the reported user is registered.
+  pub forward_to_origin: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -73,6 +97,25 @@ pub struct CreateCommunityReport {
   pub reason: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Report a user profile. These reports are handled by the admins.
+pub struct CreatePersonReport {
+  pub person_id: PersonId,
+  pub reason: String,
+  /// Also send the report to the instance where the reported user is registered.
+  pub forward_to_origin: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A person report response.
+pub struct PersonReportResponse {
+  pub person_report_view: PersonReportView,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -81,6 +124,10 @@ pub struct CreatePostReport {
   pub post_id: PostId,
   pub reason: String,
   pub violates_instance_rules: Option<bool>,
+  /// The community or site rule which was violated. Site rules are always shown to admins.
+  pub rule_id: Option<RuleId>,
+  /// Also send the report to the instance where the reported user is registered.
+  pub forward_to_origin: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -101,6 +148,15 @@ pub struct ResolveCommunityReport {
   pub resolved: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Resolve a person report (admins only).
+pub struct ResolvePersonReport {
+  pub report_id: PersonReportId,
+  pub resolved: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
@@ -143,3 +199,82 @@ pub struct PrivateMessageReportResponse {
 pub struct PostReportResponse {
   pub post_report_view: PostReportView,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Assign a report to a mod, who is then working on it. Use your own id to claim the report, or
+/// leave `assignee_id` empty to unassign it.
+pub struct AssignReport {
+  pub report_id: ReportCombinedId,
+  pub assignee_id: Option<PersonId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A report response, for any type of report.
+pub struct ReportCombinedResponse {
+  pub report_combined_view: ReportCombinedView,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Add an internal mod comment to a report.
+pub struct CreateReportNote {
+  pub report_id: ReportCombinedId,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a report note. Only possible for its author.
+pub struct DeleteReportNote {
+  pub note_id: ReportNoteId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// List the mod comments on a report.
+pub struct ListReportNotes {
+  pub report_id: ReportCombinedId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A report note response.
+pub struct ReportNoteResponse {
+  pub report_note_view: ReportNoteView,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The report notes response.
+pub struct ListReportNotesResponse {
+  pub notes: Vec<ReportNoteView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get report handling statistics per community (admin only).
+pub struct GetReportMetrics {
+  /// Only include reports from the last days. Defaults to 30, at most 365.
+  pub days: Option<i32>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// The report metrics response.
+pub struct GetReportMetricsResponse {
+  pub communities: Vec<CommunityReportMetricsView>,
+}
@@ -1,19 +1,26 @@
 use crate::{
   CommentReportView,
+  CommunityReportMetricsView,
   CommunityReportView,
   LocalUserView,
+  PersonReportView,
   PostReportView,
   PrivateMessageReportView,
   ReportCombinedView,
   ReportCombinedViewInternal,
+  ReportNoteView,
 };
 use chrono::{DateTime, Days, Utc};
 use diesel::{
   BoolExpressionMethods,
   ExpressionMethods,
+  JoinOnDsl,
   PgExpressionMethods,
   QueryDsl,
+  QueryableByName,
   SelectableHelper,
+  sql_query,
+  sql_types::{BigInt, Double, Integer, Nullable, Timestamptz},
 };
 use diesel_async::RunQueryDsl;
 use i_love_jesus::asc_if;
@@ -23,18 +30,24 @@ use lemmy_db_schema::{
     CommentReportId,
     CommunityId,
     CommunityReportId,
+    PersonReportId,
     PostId,
     PostReportId,
     PrivateMessageReportId,
+    ReportCombinedId,
+    ReportNoteId,
+    RuleId,
   },
   source::{
     combined::report::{ReportCombined, report_combined_keys as key},
+    community::Community,
     person::Person,
   },
   traits::InternalToCombinedView,
   utils::limit_fetch,
 };
 use lemmy_db_schema_file::{
+  PersonId,
   aliases,
   schema::{
     comment_report,
@@ -42,10 +55,12 @@ use lemmy_db_schema_file::{
     community_actions,
     community_report,
     person,
+    person_report,
     post,
     post_report,
     private_message_report,
     report_combined,
+    report_note,
   },
 };
 use lemmy_db_views_report_combined_sql::report_combined_joins;
@@ -60,8 +75,24 @@ use lemmy_diesel_utils::{
   },
 };
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use std::collections::HashMap;
 
 impl ReportCombinedViewInternal {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    report_id: ReportCombinedId,
+    my_person: &Person,
+  ) -> LemmyResult<ReportCombinedView> {
+    let conn = &mut get_conn(pool).await?;
+    let res = report_combined_joins(my_person.id, my_person.instance_id)
+      .filter(report_combined::id.eq(report_id))
+      .select(ReportCombinedViewInternal::as_select())
+      .first(conn)
+      .await?;
+
+    InternalToCombinedView::map_to_enum(res).ok_or(LemmyErrorType::NotFound.into())
+  }
+
   pub async fn read_comment_report(
     pool: &mut DbPool<'_>,
     report_id: CommentReportId,
@@ -119,6 +150,25 @@ impl ReportCombinedViewInternal {
     Ok(c)
   }
 
+  pub async fn read_person_report(
+    pool: &mut DbPool<'_>,
+    report_id: PersonReportId,
+    my_person: &Person,
+  ) -> LemmyResult<PersonReportView> {
+    let conn = &mut get_conn(pool).await?;
+    let res = report_combined_joins(my_person.id, my_person.instance_id)
+      .filter(report_combined::person_report_id.eq(report_id))
+      .select(ReportCombinedViewInternal::as_select())
+      .first(conn)
+      .await?;
+
+    let res = InternalToCombinedView::map_to_enum(res);
+    let Some(ReportCombinedView::Person(p)) = res else {
+      return Err(LemmyErrorType::NotFound.into());
+    };
+    Ok(p)
+  }
+
   pub async fn read_private_message_report(
     pool: &mut DbPool<'_>,
     report_id: PrivateMessageReportId,
@@ -171,6 +221,7 @@ impl PaginationCursorConversion for ReportCombinedView {
       ReportCombinedView::Post(v) => ('P', v.post_report.id.0),
       ReportCombinedView::PrivateMessage(v) => ('M', v.private_message_report.id.0),
       ReportCombinedView::Community(v) => ('Y', v.community_report.id.0),
+      ReportCombinedView::Person(v) => ('U', v.person_report.id.0),
     };
     CursorData::new_with_prefix(prefix, id)
   }
@@ -191,6 +242,7 @@ impl PaginationCursorConversion for ReportCombinedView {
       'P' => query.filter(report_combined::post_report_id.eq(id)),
       'M' => query.filter(report_combined::private_message_report_id.eq(id)),
       'Y' => query.filter(report_combined::community_report_id.eq(id)),
+      'U' => query.filter(report_combined::person_report_id.eq(id)),
       _ => return Err(LemmyErrorType::CouldntParsePaginationToken.into()),
     };
     let token = query.first(conn).await?;
@@ -209,6 +261,9 @@ pub struct ReportCombinedQuery {
   pub show_community_rule_violations: Option<bool>,
   pub page_cursor: Option<PaginationCursor>,
   pub my_reports_only: Option<bool>,
+  pub rule_id: Option<RuleId>,
+  pub assignee_id: Option<PersonId>,
+  pub unassigned_only: Option<bool>,
   pub limit: Option<i64>,
 }
 
@@ -248,6 +303,22 @@ impl ReportCombinedQuery {
       query = query.filter(post::id.eq(post_id));
     }
 
+    if let Some(rule_id) = self.rule_id {
+      query = query.filter(
+        post_report::rule_id
+          .eq(rule_id)
+          .or(comment_report::rule_id.eq(rule_id)),
+      );
+    }
+
+    if let Some(assignee_id) = self.assignee_id {
+      query = query.filter(report_combined::assignee_id.eq(assignee_id));
+    }
+
+    if self.unassigned_only.unwrap_or_default() {
+      query = query.filter(report_combined::assignee_id.is_null());
+    }
+
     if self.my_reports_only.unwrap_or_default() {
       query = query.filter(report_creator.eq(user.person.id));
     }
@@ -261,6 +332,7 @@ impl ReportCombinedQuery {
           query.filter(report_combined::private_message_report_id.is_not_null())
         }
         ReportType::Communities => query.filter(report_combined::community_report_id.is_not_null()),
+        ReportType::Persons => query.filter(report_combined::person_report_id.is_not_null()),
       }
     }
 
@@ -296,14 +368,131 @@ impl ReportCombinedQuery {
   }
 }
 
+impl ReportNoteView {
+  /// All notes on a report, oldest first.
+  pub async fn list(pool: &mut DbPool<'_>, report_id: ReportCombinedId) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    report_note::table
+      .inner_join(person::table.on(report_note::creator_id.eq(person::id)))
+      .filter(report_note::report_combined_id.eq(report_id))
+      .order_by(report_note::published_at.asc())
+      .then_order_by(report_note::id.asc())
+      .select(Self::as_select())
+      .load::<Self>(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, note_id: ReportNoteId) -> LemmyResult<Self> {
+    let conn = &mut get_conn(pool).await?;
+    report_note::table
+      .inner_join(person::table.on(report_note::creator_id.eq(person::id)))
+      .filter(report_note::id.eq(note_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
+#[derive(QueryableByName)]
+struct CommunityReportMetricsRow {
+  #[diesel(sql_type = Integer)]
+  community_id: CommunityId,
+  #[diesel(sql_type = BigInt)]
+  report_count: i64,
+  #[diesel(sql_type = BigInt)]
+  unresolved_count: i64,
+  #[diesel(sql_type = Nullable<Double>)]
+  average_assign_seconds: Option<f64>,
+  #[diesel(sql_type = Nullable<Double>)]
+  average_resolve_seconds: Option<f64>,
+  #[diesel(sql_type = Nullable<Double>)]
+  median_resolve_seconds: Option<f64>,
+}
+
+impl CommunityReportMetricsView {
+  /// Statistics about post and comment reports created since the given time, for the communities
+  /// with the most reports.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    since: DateTime<Utc>,
+    limit: Option<i64>,
+  ) -> LemmyResult<Vec<Self>> {
+    let limit = limit_fetch(limit, None)?;
+    let conn = &mut get_conn(pool).await?;
+    // Raw `sql_query` is used because Diesel has no support for `FILTER` and `percentile_cont`.
+    let rows = sql_query(
+      "SELECT
+        community_id,
+        count(*) AS report_count,
+        count(*) FILTER (WHERE NOT resolved) AS unresolved_count,
+        CAST(avg(extract(epoch FROM assigned_at - published_at)) AS float8)
+          AS average_assign_seconds,
+        CAST(avg(extract(epoch FROM resolved_at - published_at)) FILTER (WHERE resolved) AS float8)
+          AS average_resolve_seconds,
+        percentile_cont(0.5) WITHIN GROUP (ORDER BY extract(epoch FROM resolved_at - published_at))
+          FILTER (WHERE resolved) AS median_resolve_seconds
+      FROM (
+        SELECT
+          post.community_id,
+          report_combined.published_at,
+          report_combined.assigned_at,
+          report_combined.resolved_at,
+          coalesce(post_report.resolved, comment_report.resolved) AS resolved
+        FROM report_combined
+        LEFT JOIN post_report ON post_report.id = report_combined.post_report_id
+        LEFT JOIN comment_report ON comment_report.id = report_combined.comment_report_id
+        LEFT JOIN comment ON comment.id = comment_report.comment_id
+        INNER JOIN post ON post.id = coalesce(post_report.post_id, comment.post_id)
+        WHERE report_combined.published_at > $1
+      ) AS r
+      GROUP BY community_id
+      ORDER BY report_count DESC, community_id
+      LIMIT $2",
+    )
+    .bind::<Timestamptz, _>(since)
+    .bind::<BigInt, _>(limit)
+    .load::<CommunityReportMetricsRow>(conn)
+    .await?;
+
+    let community_ids: Vec<_> = rows.iter().map(|r| r.community_id).collect();
+    let communities: HashMap<_, _> = community::table
+      .filter(community::id.eq_any(community_ids))
+      .select(Community::as_select())
+      .load::<Community>(conn)
+      .await?
+      .into_iter()
+      .map(|c| (c.id, c))
+      .collect();
+
+    Ok(
+      rows
+        .into_iter()
+        .filter_map(|r| {
+          Some(Self {
+            community: communities.get(&r.community_id)?.clone(),
+            report_count: r.report_count,
+            unresolved_count: r.unresolved_count,
+            average_assign_seconds: r.average_assign_seconds,
+            average_resolve_seconds: r.average_resolve_seconds,
+            median_resolve_seconds: r.median_resolve_seconds,
+          })
+        })
+        .collect(),
+    )
+  }
+}
+
 /// Mods can only see reports for posts/comments inside of communities where they are moderator,
 /// and which have `violates_instance_rules == false`.
 #[diesel::dsl::auto_type]
 fn filter_mod_reports() -> _ {
   community_actions::became_moderator_at
     .is_not_null()
-    // Reporting a community or private message must go to admins
+    // Reporting a community, person or private message must go to admins
     .and(report_combined::community_report_id.is_null())
+    .and(report_combined::person_report_id.is_null())
     .and(report_combined::private_message_report_id.is_null())
     .and(filter_violates_instance_rules().is_distinct_from(true))
 }
@@ -325,6 +514,7 @@ fn filter_violates_instance_rules() -> _ {
   post_report::violates_instance_rules
     .or(comment_report::violates_instance_rules)
     .or(report_combined::community_report_id.is_not_null())
+    .or(report_combined::person_report_id.is_not_null())
     .or(report_combined::private_message_report_id.is_not_null())
 }
 
@@ -334,6 +524,7 @@ fn report_is_not_resolved() -> _ {
     .or(comment_report::resolved)
     .or(private_message_report::resolved)
     .or(community_report::resolved)
+    .or(person_report::resolved)
     .is_distinct_from(true)
 }
 
@@ -357,6 +548,9 @@ impl InternalToCombinedView for ReportCombinedViewInternal {
         post_creator,
         creator: v.report_creator,
         resolver: v.resolver,
+        report_combined_id: v.report_combined.id,
+        assignee: v.assignee,
+        assigned_at: v.report_combined.assigned_at,
         community_actions: v.community_actions,
         post_actions: v.post_actions,
         person_actions: v.person_actions,
@@ -366,6 +560,7 @@ impl InternalToCombinedView for ReportCombinedViewInternal {
         creator_ban_expires_at: v.creator_ban_expires_at,
         creator_banned_from_community: v.creator_banned_from_community,
         creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+        creator_has_mod_notes: v.creator_has_mod_notes,
       }))
     } else if let (
       Some(comment_report),
@@ -388,6 +583,9 @@ impl InternalToCombinedView for ReportCombinedViewInternal {
         creator: v.report_creator,
         comment_creator,
         resolver: v.resolver,
+        report_combined_id: v.report_combined.id,
+        assignee: v.assignee,
+        assigned_at: v.report_combined.assigned_at,
         community_actions: v.community_actions,
         comment_actions: v.comment_actions,
         person_actions: v.person_actions,
@@ -397,6 +595,21 @@ impl InternalToCombinedView for ReportCombinedViewInternal {
         creator_ban_expires_at: v.creator_ban_expires_at,
         creator_banned_from_community: v.creator_banned_from_community,
         creator_community_ban_expires_at: v.creator_community_ban_expires_at,
+        creator_has_mod_notes: v.creator_has_mod_notes,
+      }))
+    } else if let (Some(person_report), Some(person)) = (v.person_report, v.creator.clone()) {
+      Some(ReportCombinedView::Person(PersonReportView {
+        person_report,
+        person,
+        creator: v.report_creator,
+        resolver: v.resolver,
+        report_combined_id: v.report_combined.id,
+        assignee: v.assignee,
+        assigned_at: v.report_combined.assigned_at,
+        creator_is_admin: v.creator_is_admin,
+        creator_banned: v.creator_banned,
+        creator_ban_expires_at: v.creator_ban_expires_at,
+        creator_has_mod_notes: v.creator_has_mod_notes,
       }))
     } else if let (
       Some(private_message_report),
@@ -411,9 +624,13 @@ impl InternalToCombinedView for ReportCombinedViewInternal {
           creator: v.report_creator,
           private_message_creator,
           resolver: v.resolver,
+          report_combined_id: v.report_combined.id,
+          assignee: v.assignee,
+          assigned_at: v.report_combined.assigned_at,
           creator_is_admin: v.creator_is_admin,
           creator_banned: v.creator_banned,
           creator_ban_expires_at: v.creator_ban_expires_at,
+          creator_has_mod_notes: v.creator_has_mod_notes,
         },
       ))
     } else if let (Some(community), Some(community_report)) = (v.community, v.community_report) {
@@ -422,6 +639,9 @@ impl InternalToCombinedView for ReportCombinedViewInternal {
         community,
         creator: v.report_creator,
         resolver: v.resolver,
+        report_combined_id: v.report_combined.id,
+        assignee: v.assignee,
+        assigned_at: v.report_combined.assigned_at,
         creator_is_admin: v.creator_is_admin,
         creator_is_moderator: v.creator_is_moderator,
         creator_banned: v.creator_banned,
@@ -440,9 +660,11 @@ impl InternalToCombinedView for ReportCombinedViewInternal {
 mod tests {
 
   use crate::{
+    CommunityReportMetricsView,
     LocalUserView,
     ReportCombinedView,
     ReportCombinedViewInternal,
+    ReportNoteView,
     impls::ReportCombinedQuery,
   };
   use chrono::{Days, Utc};
@@ -452,6 +674,7 @@ mod tests {
     ReportType,
     assert_length,
     source::{
+      combined::report::ReportCombined,
       comment::{Comment, CommentInsertForm},
       comment_report::{CommentReport, CommentReportForm},
       community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
@@ -459,10 +682,13 @@ mod tests {
       instance::{Instance, InstanceActions, InstanceBanForm},
       local_user::{LocalUser, LocalUserInsertForm},
       person::{Person, PersonInsertForm},
+      person_report::{PersonReport, PersonReportForm},
       post::{Post, PostInsertForm},
       post_report::{PostReport, PostReportForm},
       private_message::{PrivateMessage, PrivateMessageInsertForm},
       private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
+      report_note::{ReportNote, ReportNoteInsertForm},
+      rule::{Rule, RuleInsertForm},
     },
     traits::{Bannable, Reportable},
   };
@@ -605,6 +831,7 @@ mod tests {
       original_post_body: None,
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     let inserted_post_report = PostReport::report(pool, &sara_report_post_form).await?;
 
@@ -615,6 +842,7 @@ mod tests {
       original_comment_text: "A test comment rv".into(),
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     CommentReport::report(pool, &sara_report_comment_form).await?;
 
@@ -827,6 +1055,7 @@ mod tests {
       original_post_body: None,
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
 
     PostReport::report(pool, &sara_report_form).await?;
@@ -840,6 +1069,7 @@ mod tests {
       original_post_body: None,
       reason: "from jessica".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
 
     let inserted_jessica_report = PostReport::report(pool, &jessica_report_form).await?;
@@ -958,6 +1188,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
 
     CommentReport::report(pool, &sara_report_form).await?;
@@ -969,6 +1200,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from jessica".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
 
     let inserted_jessica_report = CommentReport::report(pool, &jessica_report_form).await?;
@@ -1138,6 +1370,74 @@ mod tests {
 
     Ok(())
   }
+  #[tokio::test]
+  #[serial]
+  async fn person_reports() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    // jessica reports sara's profile
+    let person_report_form = PersonReportForm {
+      creator_id: data.jessica.id,
+      person_id: data.sara.id,
+      original_person_name: data.sara.name.clone(),
+      original_person_display_name: None,
+      original_person_bio: None,
+      original_person_avatar: None,
+      original_person_banner: None,
+      reason: "offensive bio".into(),
+    };
+    let person_report = PersonReport::report(pool, &person_report_form).await?;
+
+    // Only admins can see it, mods can't
+    let reports = ReportCombinedQuery::default()
+      .list(pool, &data.timmy_view)
+      .await?;
+    assert_length!(0, reports);
+
+    let reports = ReportCombinedQuery {
+      type_: Some(ReportType::Persons),
+      ..Default::default()
+    }
+    .list(pool, &data.admin_view)
+    .await?;
+    assert_length!(1, reports);
+    if let ReportCombinedView::Person(v) = &reports[0] {
+      assert!(!v.person_report.resolved);
+      assert_eq!(data.jessica.name, v.creator.name);
+      assert_eq!(data.sara.name, v.person.name);
+      assert_eq!(person_report.reason, v.person_report.reason);
+      let read_report = ReportCombinedViewInternal::read_person_report(
+        pool,
+        person_report.id,
+        &data.admin_view.person,
+      )
+      .await?;
+      assert_eq!(&read_report, v);
+    } else {
+      panic!("wrong type");
+    }
+    assert_eq!(
+      1,
+      ReportCombinedViewInternal::get_report_count(pool, &data.admin_view).await?
+    );
+
+    // banning sara resolves all reports about the profile
+    PersonReport::resolve_all_for_object(pool, data.sara.id, data.admin_view.person.id).await?;
+
+    let reports = ReportCombinedQuery {
+      unresolved_only: Some(true),
+      ..Default::default()
+    }
+    .list(pool, &data.admin_view)
+    .await?;
+    assert_length!(0, reports);
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
 
   #[tokio::test]
   #[serial]
@@ -1155,6 +1455,7 @@ mod tests {
       original_post_body: None,
       reason: "from sara".into(),
       violates_instance_rules: true,
+      rule_id: None,
     };
     PostReport::report(pool, &report_form).await?;
 
@@ -1184,6 +1485,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     let comment_report = CommentReport::report(pool, &report_form).await?;
 
@@ -1229,6 +1531,64 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn filter_by_rule() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let rule_form = RuleInsertForm::new(Some(data.community.id), "No spam".into());
+    let rule = Rule::create(pool, &rule_form).await?;
+
+    // sara reports the comment for breaking the rule
+    let sara_report_form = CommentReportForm {
+      creator_id: data.sara.id,
+      comment_id: data.comment.id,
+      original_comment_text: "this was it at time of creation".into(),
+      reason: "spam".into(),
+      violates_instance_rules: false,
+      rule_id: Some(rule.id),
+    };
+    CommentReport::report(pool, &sara_report_form).await?;
+
+    // jessica reports the post without a rule
+    let jessica_report_form = PostReportForm {
+      creator_id: data.jessica.id,
+      post_id: data.post.id,
+      original_post_name: "Orig post".into(),
+      original_post_url: None,
+      original_post_body: None,
+      reason: "from jessica".into(),
+      violates_instance_rules: false,
+      rule_id: None,
+    };
+    PostReport::report(pool, &jessica_report_form).await?;
+
+    let reports = ReportCombinedQuery::default()
+      .list(pool, &data.timmy_view)
+      .await?;
+    assert_length!(2, reports);
+
+    // Only the comment report references the rule
+    let reports = ReportCombinedQuery {
+      rule_id: Some(rule.id),
+      ..Default::default()
+    }
+    .list(pool, &data.timmy_view)
+    .await?;
+    assert_length!(1, reports);
+    if let ReportCombinedView::Comment(v) = &reports[0] {
+      assert_eq!(Some(rule.id), v.comment_report.rule_id);
+    } else {
+      panic!("wrong type");
+    }
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn my_reports_only() -> LemmyResult<()> {
@@ -1243,6 +1603,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     CommentReport::report(pool, &sara_report_form).await?;
 
@@ -1253,6 +1614,7 @@ mod tests {
       original_comment_text: "this was it at time of creation".into(),
       reason: "from timmy".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     CommentReport::report(pool, &timmy_report_form).await?;
 
@@ -1299,6 +1661,7 @@ mod tests {
       original_post_body: None,
       reason: "from sara".into(),
       violates_instance_rules: false,
+      rule_id: None,
     };
     let inserted_sara_report = PostReport::report(pool, &sara_report_form).await?;
 
@@ -1317,4 +1680,76 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn report_assignment_and_notes() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let sara_report_form = PostReportForm {
+      creator_id: data.sara.id,
+      post_id: data.post.id,
+      original_post_name: "Orig post".into(),
+      original_post_url: None,
+      original_post_body: None,
+      reason: "from sara".into(),
+      violates_instance_rules: false,
+      rule_id: None,
+    };
+    let inserted_report = PostReport::report(pool, &sara_report_form).await?;
+    let report_view =
+      ReportCombinedViewInternal::read_post_report(pool, inserted_report.id, &data.timmy).await?;
+    assert!(report_view.assignee.is_none());
+    let report_id = report_view.report_combined_id;
+
+    // Timmy claims the report
+    let assigned = ReportCombined::assign(pool, report_id, Some(data.timmy.id)).await?;
+    assert!(assigned.assigned_at.is_some());
+    let report_view =
+      ReportCombinedViewInternal::read_post_report(pool, inserted_report.id, &data.timmy).await?;
+    assert_eq!(Some(data.timmy.id), report_view.assignee.map(|a| a.id));
+
+    let assigned_to_timmy = ReportCombinedQuery {
+      assignee_id: Some(data.timmy.id),
+      ..Default::default()
+    }
+    .list(pool, &data.timmy_view)
+    .await?;
+    assert_length!(1, assigned_to_timmy);
+    let unassigned = ReportCombinedQuery {
+      unassigned_only: Some(true),
+      ..Default::default()
+    }
+    .list(pool, &data.timmy_view)
+    .await?;
+    assert_length!(0, unassigned);
+
+    // Notes are listed oldest first
+    let form = ReportNoteInsertForm::new(report_id, data.timmy.id, "looking into it".into());
+    ReportNote::create(pool, &form).await?;
+    let form = ReportNoteInsertForm::new(report_id, data.admin_view.person.id, "thanks".into());
+    ReportNote::create(pool, &form).await?;
+    let notes = ReportNoteView::list(pool, report_id).await?;
+    assert_length!(2, notes);
+    assert_eq!(data.timmy.id, notes[0].creator.id);
+    assert_eq!("thanks", notes[1].note.content);
+
+    // Unassigning clears the timestamp as well
+    let unassigned = ReportCombined::assign(pool, report_id, None).await?;
+    assert!(unassigned.assigned_at.is_none());
+
+    PostReport::update_resolved(pool, inserted_report.id, data.timmy.id, true).await?;
+    let metrics = CommunityReportMetricsView::list(pool, Utc::now() - Days::new(1), None).await?;
+    assert_length!(1, metrics);
+    assert_eq!(data.community.id, metrics[0].community.id);
+    assert_eq!(1, metrics[0].report_count);
+    assert_eq!(0, metrics[0].unresolved_count);
+    assert!(metrics[0].average_resolve_seconds.is_some());
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
 }
@@ -1,15 +1,20 @@
 use chrono::{DateTime, Utc};
-use lemmy_db_schema::source::{
-  combined::report::ReportCombined,
-  comment::{Comment, CommentActions},
-  comment_report::CommentReport,
-  community::{Community, CommunityActions},
-  community_report::CommunityReport,
-  person::{Person, PersonActions},
-  post::{Post, PostActions},
-  post_report::PostReport,
-  private_message::PrivateMessage,
-  private_message_report::PrivateMessageReport,
+use lemmy_db_schema::{
+  newtypes::ReportCombinedId,
+  source::{
+    combined::report::ReportCombined,
+    comment::{Comment, CommentActions},
+    comment_report::CommentReport,
+    community::{Community, CommunityActions},
+    community_report::CommunityReport,
+    person::{Person, PersonActions},
+    person_report::PersonReport,
+    post::{Post, PostActions},
+    post_report::PostReport,
+    private_message::PrivateMessage,
+    private_message_report::PrivateMessageReport,
+    report_note::ReportNote,
+  },
 };
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -20,14 +25,20 @@ use {
     CreatorLocalHomeCommunityBanExpiresType,
     creator_ban_expires_from_community,
     creator_banned_from_community,
+    creator_has_mod_notes,
     creator_is_moderator,
     creator_local_home_community_ban_expires,
     creator_local_home_community_banned,
     local_user_is_admin,
     person1_select,
     person2_select,
+    person3_select,
+  },
+  lemmy_db_schema::{
+    Person1AliasAllColumnsTuple,
+    Person2AliasAllColumnsTuple,
+    Person3AliasAllColumnsTuple,
   },
-  lemmy_db_schema::{Person1AliasAllColumnsTuple, Person2AliasAllColumnsTuple},
   lemmy_db_views_local_user::LocalUserView,
 };
 
@@ -50,6 +61,8 @@ pub struct ReportCombinedViewInternal {
   pub private_message_report: Option<PrivateMessageReport>,
   #[diesel(embed)]
   pub community_report: Option<CommunityReport>,
+  #[diesel(embed)]
+  pub person_report: Option<PersonReport>,
   #[diesel(
     select_expression_type = Person1AliasAllColumnsTuple,
     select_expression = person1_select()
@@ -68,6 +81,11 @@ pub struct ReportCombinedViewInternal {
     select_expression = person2_select().nullable()
   )]
   pub resolver: Option<Person>,
+  #[diesel(
+    select_expression_type = Nullable<Person3AliasAllColumnsTuple>,
+    select_expression = person3_select().nullable()
+  )]
+  pub assignee: Option<Person>,
   #[diesel(select_expression = local_user_is_admin())]
   pub creator_is_admin: bool,
   #[diesel(select_expression = creator_is_moderator())]
@@ -83,6 +101,8 @@ pub struct ReportCombinedViewInternal {
   pub creator_banned_from_community: bool,
   #[diesel(select_expression = creator_ban_expires_from_community())]
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  #[diesel(select_expression = creator_has_mod_notes())]
+  pub creator_has_mod_notes: bool,
   #[diesel(embed)]
   pub community: Option<Community>,
   #[diesel(embed)]
@@ -104,6 +124,7 @@ pub enum ReportCombinedView {
   Comment(CommentReportView),
   PrivateMessage(PrivateMessageReportView),
   Community(CommunityReportView),
+  Person(PersonReportView),
 }
 
 #[skip_serializing_none]
@@ -117,9 +138,15 @@ pub struct PrivateMessageReportView {
   pub creator: Person,
   pub private_message_creator: Person,
   pub resolver: Option<Person>,
+  pub report_combined_id: ReportCombinedId,
+  /// The mod who is working on this report.
+  pub assignee: Option<Person>,
+  pub assigned_at: Option<DateTime<Utc>>,
   pub creator_is_admin: bool,
   pub creator_banned: bool,
   pub creator_ban_expires_at: Option<DateTime<Utc>>,
+  /// Whether the admins have written notes about the message creator.
+  pub creator_has_mod_notes: bool,
 }
 
 #[skip_serializing_none]
@@ -136,6 +163,10 @@ pub struct CommentReportView {
   pub comment_creator: Person,
   pub comment_actions: Option<CommentActions>,
   pub resolver: Option<Person>,
+  pub report_combined_id: ReportCombinedId,
+  /// The mod who is working on this report.
+  pub assignee: Option<Person>,
+  pub assigned_at: Option<DateTime<Utc>>,
   pub person_actions: Option<PersonActions>,
   pub community_actions: Option<CommunityActions>,
   pub creator_is_admin: bool,
@@ -144,6 +175,8 @@ pub struct CommentReportView {
   pub creator_ban_expires_at: Option<DateTime<Utc>>,
   pub creator_banned_from_community: bool,
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  /// Whether the mods have written notes about the item creator.
+  pub creator_has_mod_notes: bool,
 }
 
 #[skip_serializing_none]
@@ -156,6 +189,10 @@ pub struct CommunityReportView {
   pub community: Community,
   pub creator: Person,
   pub resolver: Option<Person>,
+  pub report_combined_id: ReportCombinedId,
+  /// The mod who is working on this report.
+  pub assignee: Option<Person>,
+  pub assigned_at: Option<DateTime<Utc>>,
   pub creator_is_admin: bool,
   pub creator_is_moderator: bool,
   pub creator_banned: bool,
@@ -164,6 +201,28 @@ pub struct CommunityReportView {
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A report on a user profile.
+pub struct PersonReportView {
+  pub person_report: PersonReport,
+  /// The reported user.
+  pub person: Person,
+  pub creator: Person,
+  pub resolver: Option<Person>,
+  pub report_combined_id: ReportCombinedId,
+  /// The mod who is working on this report.
+  pub assignee: Option<Person>,
+  pub assigned_at: Option<DateTime<Utc>>,
+  pub creator_is_admin: bool,
+  pub creator_banned: bool,
+  pub creator_ban_expires_at: Option<DateTime<Utc>>,
+  /// Whether the admins have written notes about the reported user.
+  pub creator_has_mod_notes: bool,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -179,10 +238,46 @@ pub struct PostReportView {
   pub post_actions: Option<PostActions>,
   pub person_actions: Option<PersonActions>,
   pub resolver: Option<Person>,
+  pub report_combined_id: ReportCombinedId,
+  /// The mod who is working on this report.
+  pub assignee: Option<Person>,
+  pub assigned_at: Option<DateTime<Utc>>,
   pub creator_is_admin: bool,
   pub creator_is_moderator: bool,
   pub creator_banned: bool,
   pub creator_ban_expires_at: Option<DateTime<Utc>>,
   pub creator_banned_from_community: bool,
   pub creator_community_ban_expires_at: Option<DateTime<Utc>>,
+  /// Whether the mods have written notes about the item creator.
+  pub creator_has_mod_notes: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// An internal mod comment on a report, with its author.
+pub struct ReportNoteView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub note: ReportNote,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub creator: Person,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// How quickly the mods of a community handle post and comment reports.
+pub struct CommunityReportMetricsView {
+  pub community: Community,
+  pub report_count: i64,
+  pub unresolved_count: i64,
+  /// Average time until a report was assigned to a mod, in seconds.
+  pub average_assign_seconds: Option<f64>,
+  /// Average time until a report was resolved, in seconds.
+  pub average_resolve_seconds: Option<f64>,
+  /// Median time until a report was resolved, in seconds.
+  pub median_resolve_seconds: Option<f64>,
 }
@@ -25,6 +25,7 @@ use lemmy_db_schema_file::{
     local_user,
     person,
     person_actions,
+    person_report,
     post,
     post_actions,
     post_report,
@@ -42,6 +43,7 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
   let report_creator = aliases::person1.field(person::id);
 
   let resolver = aliases::person2.field(person::id).nullable();
+  let assignee = aliases::person3.field(person::id).nullable();
 
   let comment_join = comment::table.on(comment_report::comment_id.eq(comment::id));
   let private_message_join =
@@ -64,14 +66,16 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
       .eq(report_creator)
       .or(comment_report::creator_id.eq(report_creator))
       .or(private_message_report::creator_id.eq(report_creator))
-      .or(community_report::creator_id.eq(report_creator)),
+      .or(community_report::creator_id.eq(report_creator))
+      .or(person_report::creator_id.eq(report_creator)),
   );
 
   let item_creator_join = person::table.on(
     post::creator_id
       .eq(item_creator)
       .or(comment::creator_id.eq(item_creator))
-      .or(private_message::creator_id.eq(item_creator)),
+      .or(private_message::creator_id.eq(item_creator))
+      .or(person_report::person_id.eq(item_creator)),
   );
 
   let resolver_join = aliases::person2.on(
@@ -79,9 +83,12 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
       .eq(resolver)
       .or(post_report::resolver_id.eq(resolver))
       .or(comment_report::resolver_id.eq(resolver))
-      .or(community_report::resolver_id.eq(resolver)),
+      .or(community_report::resolver_id.eq(resolver))
+      .or(person_report::resolver_id.eq(resolver)),
   );
 
+  let assignee_join = aliases::person3.on(report_combined::assignee_id.eq(assignee));
+
   let community_join = community::table.on(
     community_report::community_id
       .eq(community::id)
@@ -130,12 +137,14 @@ pub fn report_combined_joins(my_person_id: PersonId, local_instance_id: Instance
     .left_join(comment_report::table)
     .left_join(private_message_report::table)
     .left_join(community_report::table)
+    .left_join(person_report::table)
     .inner_join(report_creator_join)
     .left_join(comment_join)
     .left_join(private_message_join)
     .left_join(post_join)
     .left_join(item_creator_join)
     .left_join(resolver_join)
+    .left_join(assignee_join)
     .left_join(community_join)
     .left_join(creator_community_actions_join)
     .left_join(creator_home_instance_actions_join())
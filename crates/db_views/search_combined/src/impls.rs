@@ -12,6 +12,7 @@ use diesel::{
   ExpressionMethods,
   JoinOnDsl,
   NullableExpressionMethods,
+  PgExpressionMethods,
   PgTextExpressionMethods,
   QueryDsl,
   SelectableHelper,
@@ -32,8 +33,11 @@ use lemmy_db_schema::{
   utils::{
     limit_fetch,
     queries::filters::{
+      filter_is_following_creator,
       filter_is_subscribed,
+      filter_not_silenced_or_is_subscribed,
       filter_not_unlisted_or_is_subscribed,
+      filter_person_not_silenced,
       filter_suggested_communities,
     },
   },
@@ -352,13 +356,15 @@ impl SearchCombinedQuery {
       ),
       ListingType::All => query.filter(
         filter_not_unlisted_or_is_subscribed()
-          .or(is_person)
+          .and(filter_not_silenced_or_is_subscribed())
+          .or(is_person.and(filter_person_not_silenced()))
           .or(is_multi_community),
       ),
       ListingType::ModeratorView => {
         query.filter(community_actions::became_moderator_at.is_not_null())
       }
       ListingType::Suggested => query.filter(filter_suggested_communities()),
+      ListingType::FollowedPersons => query.filter(filter_is_following_creator()),
     };
 
     // Filter by the time range
@@ -404,6 +410,17 @@ impl SearchCombinedQuery {
       );
     };
 
+    // Posts and comments of shadow restricted users are only visible to themselves and to mods
+    if !my_local_user.is_admin() {
+      query = query.filter(
+        post::held_for_review
+          .is_distinct_from(true)
+          .and(comment::held_for_review.is_distinct_from(true))
+          .or(item_creator.nullable().eq(my_person_id))
+          .or(community_actions::became_moderator_at.is_not_null()),
+      );
+    }
+
     // Only sort by asc if old
     let sort = self.sort.unwrap_or_default();
     let sort_direction = asc_if(sort == Old);
@@ -520,7 +537,14 @@ mod tests {
     assert_length,
     source::{
       comment::{Comment, CommentActions, CommentInsertForm, CommentLikeForm, CommentUpdateForm},
-      community::{Community, CommunityActions, CommunityFollowerForm, CommunityInsertForm},
+      community::{
+        Community,
+        CommunityActions,
+        CommunityFollowerForm,
+        CommunityInsertForm,
+        CommunityModeratorForm,
+      },
+      federation_policy::{FederationPolicy, FederationPolicyForm},
       instance::Instance,
       local_user::{LocalUser, LocalUserInsertForm},
       multi_community::{MultiCommunity, MultiCommunityInsertForm},
@@ -1228,6 +1252,55 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn held_for_review() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    // Sara is shadow restricted, so her new post and comment are held
+    let sara_local_user_form = LocalUserInsertForm::test_form(data.sara.id);
+    LocalUser::create(pool, &sara_local_user_form, vec![]).await?;
+    let sara_view = LocalUserView::read_person(pool, data.sara.id).await?;
+    let post_form = PostInsertForm {
+      held_for_review: Some(true),
+      ..PostInsertForm::new("held_search".into(), data.sara.id, data.community.id)
+    };
+    Post::create(pool, &post_form).await?;
+    let comment_form = CommentInsertForm {
+      held_for_review: Some(true),
+      ..CommentInsertForm::new(data.sara.id, data.timmy_post.id, "held_search".into())
+    };
+    Comment::create(pool, &comment_form, None).await?;
+
+    let query = || SearchCombinedQuery {
+      search_term: Some("held_search".into()),
+      ..Default::default()
+    };
+
+    // Hidden from other users and logged out users
+    let anon_search = query().list(pool, &None, &data.site).await?;
+    assert_length!(0, anon_search);
+    let timmy_view = Some(data.timmy_view.clone());
+    let timmy_search = query().list(pool, &timmy_view, &data.site).await?;
+    assert_length!(0, timmy_search);
+
+    // Visible to the creator
+    let sara_search = query().list(pool, &Some(sara_view), &data.site).await?;
+    assert_length!(2, sara_search);
+
+    // Visible to mods of the community
+    let timmy_mod_form = CommunityModeratorForm::new(data.community.id, data.timmy.id);
+    CommunityActions::join(pool, &timmy_mod_form).await?;
+    let timmy_search = query().list(pool, &timmy_view, &data.site).await?;
+    assert_length!(2, timmy_search);
+
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn nsfw_post() -> LemmyResult<()> {
@@ -1287,6 +1360,64 @@ mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn silenced_instance() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let community_query = || SearchCombinedQuery {
+      community_id: Some(data.community.id),
+      ..Default::default()
+    };
+    let person_query = || SearchCombinedQuery {
+      type_: Some(SearchType::Users),
+      ..Default::default()
+    };
+    let community_search = community_query()
+      .list(pool, &Some(data.timmy_view.clone()), &data.site)
+      .await?;
+    assert!(!community_search.is_empty());
+    assert_length!(2, person_query().list(pool, &None, &data.site).await?);
+
+    let policy_form = FederationPolicyForm {
+      instance_id: data.instance.id,
+      silence: true,
+      strip_media: false,
+      force_nsfw: false,
+      reject_reports: false,
+      reject_votes: false,
+      no_avatar: false,
+      reason: "noisy".to_string(),
+    };
+    FederationPolicy::upsert(pool, &policy_form).await?;
+
+    // Communities, posts, comments and persons from the silenced instance are hidden
+    let silenced_search = community_query()
+      .list(pool, &Some(data.timmy_view.clone()), &data.site)
+      .await?;
+    assert_length!(0, silenced_search);
+    assert_length!(0, person_query().list(pool, &None, &data.site).await?);
+
+    // Unless the community is followed
+    let follow_form = CommunityFollowerForm::new(
+      data.community.id,
+      data.timmy.id,
+      CommunityFollowerState::Accepted,
+    );
+    CommunityActions::follow(pool, &follow_form).await?;
+    let followed_search = community_query()
+      .list(pool, &Some(data.timmy_view.clone()), &data.site)
+      .await?;
+    assert_eq!(community_search.len(), followed_search.len());
+
+    FederationPolicy::delete(pool, data.instance.id).await?;
+    cleanup(data, pool).await?;
+
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn private_community() -> LemmyResult<()> {
@@ -1,9 +1,24 @@
-use crate::{ReadableFederationState, SiteView};
+use crate::{FederationPolicyView, ReadableFederationState, SiteView};
 use lemmy_db_schema::{
-  newtypes::{LanguageId, MultiCommunityId, OAuthProviderId, TaglineId},
+  newtypes::{
+    ActivityId,
+    BlocklistSubscriptionId,
+    CommentId,
+    CommunityId,
+    DraftId,
+    LanguageId,
+    MultiCommunityId,
+    OAuthProviderId,
+    PostId,
+    RelayId,
+    TaglineId,
+  },
   source::{
+    blocklist_subscription::{BlocklistSubscription, BlocklistSubscriptionEntry},
     comment::Comment,
     community::Community,
+    draft::Draft,
+    federation_replay::FederationReplay,
     instance::Instance,
     language::Language,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
@@ -13,18 +28,23 @@ use lemmy_db_schema::{
     person::Person,
     post::Post,
     private_message::PrivateMessage,
+    relay::Relay,
     tagline::Tagline,
   },
 };
 use lemmy_db_schema_file::{
   InstanceId,
+  PersonId,
   enums::{
+    BlocklistFormat,
     CommentSortType,
+    DraftKind,
     FederationMode,
     ListingType,
     PostListingMode,
     PostSortType,
     RegistrationMode,
+    RelayMode,
     VoteShow,
   },
 };
@@ -62,6 +82,161 @@ pub struct AdminBlockInstanceParams {
   pub expires_at: Option<i64>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Set the federation policy for an instance. If all restrictions are false, the policy is
+/// removed.
+pub struct AdminSetInstancePolicy {
+  pub instance: String,
+  /// Hide content from the All listing, it is still visible to followers.
+  pub silence: bool,
+  /// Don't fetch images and thumbnails.
+  pub strip_media: bool,
+  /// Mark all posts and communities as NSFW.
+  pub force_nsfw: bool,
+  pub reject_reports: bool,
+  pub reject_votes: bool,
+  /// Don't fetch user avatars.
+  pub no_avatar: bool,
+  /// The reason is listed publicly on the site endpoint.
+  pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Subscribe to an external instance blocklist. It is synced daily, and changes need to be
+/// applied with `ApplyBlocklistSubscription`.
+pub struct CreateBlocklistSubscription {
+  /// For the Lemmy format, the url of the instance.
+  pub url: String,
+  pub format: BlocklistFormat,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Unsubscribe from a blocklist. Instances which were blocked from it stay blocked.
+pub struct DeleteBlocklistSubscription {
+  pub id: BlocklistSubscriptionId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get the pending changes of a blocklist subscription.
+pub struct GetBlocklistSubscription {
+  pub id: BlocklistSubscriptionId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Block and unblock instances according to the last sync of the blocklist.
+pub struct ApplyBlocklistSubscription {
+  pub id: BlocklistSubscriptionId,
+  /// Only apply the changes for these domains. By default all pending changes are applied.
+  pub domains: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A blocklist subscription, with the changes that still need to be reviewed.
+pub struct BlocklistSubscriptionResponse {
+  pub subscription: BlocklistSubscription,
+  /// Domains in the blocklist which aren't blocked yet.
+  pub pending_blocks: Vec<BlocklistSubscriptionEntry>,
+  /// Instances which were blocked from this blocklist, but were removed from it since. Domains
+  /// which another subscribed blocklist still lists are not included.
+  pub pending_unblocks: Vec<Instance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListBlocklistSubscriptionsResponse {
+  pub subscriptions: Vec<BlocklistSubscription>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Subscribe to an ActivityPub relay, to receive posts from outside of followed communities.
+pub struct CreateRelay {
+  /// The actor id of the relay, eg `https://relay.example.org/actor`.
+  pub ap_id: String,
+  /// Defaults to `KnownCommunities`.
+  pub mode: Option<RelayMode>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Change which posts are imported from a relay.
+pub struct EditRelay {
+  pub id: RelayId,
+  pub mode: RelayMode,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Unfollow a relay and remove it. Imported posts are kept.
+pub struct DeleteRelay {
+  pub id: RelayId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct RelayResponse {
+  pub relay: Relay,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct ListRelaysResponse {
+  pub relays: Vec<Relay>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Resend federated activities to a single instance, for example after it lost data. Sent
+/// activities are only stored for a week, so older ones can't be resent.
+pub struct CreateFederationReplay {
+  /// The domain of the instance.
+  pub instance: String,
+  /// Only resend activities of this local community. By default the whole federation queue of the
+  /// instance is rewound.
+  pub community_id: Option<CommunityId>,
+  /// Resend activities published since this time, in unix epoch seconds.
+  pub since: Option<i64>,
+  /// Resend activities starting with this id. Either this or `since` is required.
+  pub since_activity_id: Option<ActivityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct FederationReplayResponse {
+  pub replay: FederationReplay,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// All federation replays with their progress, newest first.
+pub struct ListFederationReplaysResponse {
+  pub replays: Vec<FederationReplay>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -152,6 +327,15 @@ pub struct CreateSite {
   pub disallow_nsfw_content: Option<bool>,
   pub disable_email_notifications: Option<bool>,
   pub suggested_communities: Option<MultiCommunityId>,
+  pub draft_max_age_days: Option<i32>,
+  pub upload_quota_mb: Option<i32>,
+  pub moderator_upload_quota_mb: Option<i32>,
+  pub strike_ban_threshold: Option<i32>,
+  pub strike_window_days: Option<i32>,
+  pub strike_ban_days: Option<i32>,
+  pub modlog_retention_days: Option<i32>,
+  pub modlog_anonymize_days: Option<i32>,
+  pub federation_authorized_fetch: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -274,6 +458,26 @@ pub struct EditSite {
   pub disable_email_notifications: Option<bool>,
   /// A multicommunity with suggested communities which is shown on the homepage
   pub suggested_communities: Option<MultiCommunityId>,
+  /// Drafts which weren't edited for this many days are deleted automatically. Must be at least
+  /// one day.
+  pub draft_max_age_days: Option<i32>,
+  /// How many megabytes each user can upload. 0 means unlimited.
+  pub upload_quota_mb: Option<i32>,
+  /// Upload quota for users who moderate at least one community. 0 means unlimited.
+  pub moderator_upload_quota_mb: Option<i32>,
+  /// Number of site warnings after which a user is banned automatically. 0 disables it.
+  pub strike_ban_threshold: Option<i32>,
+  /// Time span in which warnings are counted towards the strike threshold.
+  pub strike_window_days: Option<i32>,
+  /// Duration of automatic bans caused by too many warnings.
+  pub strike_ban_days: Option<i32>,
+  /// Modlog entries older than this many days are only visible to admins. 0 disables it.
+  pub modlog_retention_days: Option<i32>,
+  /// Hide the moderator of modlog entries older than this many days, except for admins. 0
+  /// disables it.
+  pub modlog_anonymize_days: Option<i32>,
+  /// Require signed Activitypub fetches from instances which aren't blocked.
+  pub federation_authorized_fetch: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -324,6 +528,8 @@ pub struct GetSiteResponse {
   ///
   /// Useful for estimating when your application will be approved.
   pub last_application_duration_seconds: Option<i64>,
+  /// Restrictions which are applied to content from specific instances, with the reasons.
+  pub federation_policies: Vec<FederationPolicyView>,
 }
 
 #[skip_serializing_none]
@@ -634,6 +840,85 @@ pub struct EditTagline {
   pub content: String,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Save a draft for a post, comment or private message.
+///
+/// Posts need a `community_id`, comments a `post_id` and private messages a `recipient_id`.
+pub struct CreateDraft {
+  pub kind: DraftKind,
+  pub community_id: Option<CommunityId>,
+  pub post_id: Option<PostId>,
+  pub parent_id: Option<CommentId>,
+  pub recipient_id: Option<PersonId>,
+  /// The post title.
+  pub name: Option<String>,
+  pub url: Option<String>,
+  /// The post body, comment content or private message content, in markdown.
+  pub body: Option<String>,
+  pub alt_text: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  pub custom_thumbnail: Option<String>,
+  pub scheduled_publish_time_at: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Edit a draft. The kind and target of a draft can't be changed.
+pub struct EditDraft {
+  pub id: DraftId,
+  /// Only for post drafts.
+  pub community_id: Option<CommunityId>,
+  /// Only for comment drafts.
+  pub parent_id: Option<CommentId>,
+  pub name: Option<String>,
+  pub url: Option<String>,
+  pub body: Option<String>,
+  pub alt_text: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  pub custom_thumbnail: Option<String>,
+  pub scheduled_publish_time_at: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Delete a draft
+pub struct DeleteDraft {
+  pub id: DraftId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Get a single draft
+pub struct GetDraft {
+  pub id: DraftId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// Fetches your drafts, newest first.
+pub struct ListDrafts {
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+pub struct DraftResponse {
+  pub draft: Draft,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[cfg_attr(feature = "full", derive(FromBytes))]
 #[cfg_attr(feature = "full", encoding(Json))]
@@ -746,8 +1031,8 @@ impl Default for SuccessResponse {
 }
 
 /// Contains the amount of unread items of various types. For normal users this means the number of
-/// unread notifications, mods and admins get additional unread counts for reports, registration
-/// applications and pending follows to private communities.
+/// unread notifications and mod mail threads, mods and admins get additional unread counts for
+/// reports, registration applications and pending follows to private communities.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -757,4 +1042,9 @@ pub struct UnreadCountsResponse {
   pub report_count: Option<i64>,
   pub pending_follow_count: Option<i64>,
   pub registration_application_count: Option<i64>,
+  /// Mod mail threads with unread messages, including those in the inbox of moderated
+  /// communities.
+  pub modmail_count: i64,
+  /// Unresolved ban appeals in moderated communities, and for site bans if you are an admin.
+  pub ban_appeal_count: Option<i64>,
 }
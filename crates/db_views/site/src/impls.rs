@@ -1,5 +1,6 @@
 use crate::{
   FederatedInstanceView,
+  FederationPolicyView,
   ReadableFederationState,
   SiteView,
   api::{GetFederatedInstances, GetFederatedInstancesKind, UserSettingsBackup},
@@ -31,6 +32,7 @@ use lemmy_db_schema_file::{
   schema::{
     federation_allowlist,
     federation_blocklist,
+    federation_policy,
     federation_queue_state,
     instance,
     local_site,
@@ -192,6 +194,19 @@ impl FederatedInstanceView {
   }
 }
 
+impl FederationPolicyView {
+  pub async fn list(pool: &mut DbPool<'_>) -> LemmyResult<Vec<Self>> {
+    let conn = &mut get_conn(pool).await?;
+    federation_policy::table
+      .inner_join(instance::table)
+      .select(Self::as_select())
+      .order_by(instance::domain)
+      .load(conn)
+      .await
+      .with_lemmy_type(LemmyErrorType::NotFound)
+  }
+}
+
 impl PaginationCursorConversion for FederatedInstanceView {
   type PaginatedType = Instance;
   fn to_cursor(&self) -> CursorData {
@@ -4,6 +4,7 @@ use diesel::{Queryable, Selectable};
 use lemmy_db_schema::source::{
   federation_allowlist::FederationAllowList,
   federation_blocklist::FederationBlockList,
+  federation_policy::FederationPolicy,
   federation_queue_state::FederationQueueState,
   instance::Instance,
   local_site::LocalSite,
@@ -53,6 +54,19 @@ pub struct FederatedInstanceView {
   pub allowed: Option<FederationAllowList>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(optional_fields, export))]
+/// A federation policy, together with the instance it applies to.
+pub struct FederationPolicyView {
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub instance: Instance,
+  #[cfg_attr(feature = "full", diesel(embed))]
+  pub policy: FederationPolicy,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
@@ -92,7 +92,7 @@ FROM (
     FROM
         select_old_and_new_rows AS old_and_new_rows
     WHERE
-        r.is_counted (comment)
+        r.is_counted_content (comment)
     GROUP BY
         (comment).creator_id) AS diff
 WHERE
@@ -129,7 +129,9 @@ FROM (
             parent_id
         FROM
             select_old_and_new_rows AS old_and_new_rows,
-            LATERAL r.parent_comment_ids ((comment).path) AS parent_id) AS expanded_old_and_new_rows
+            LATERAL r.parent_comment_ids ((comment).path) AS parent_id
+        WHERE
+            NOT (comment).held_for_review) AS expanded_old_and_new_rows
     GROUP BY
         parent_id) AS diff
 WHERE
@@ -156,7 +158,7 @@ FROM
     select_old_and_new_rows AS old_and_new_rows
     LEFT JOIN post ON post.id = (comment).post_id
 WHERE
-    r.is_counted (comment)
+    r.is_counted_content (comment)
 GROUP BY
     post.id) AS diff
 WHERE
@@ -176,7 +178,7 @@ FROM (
     FROM
         select_old_and_new_rows AS old_and_new_rows
     WHERE
-        r.is_counted (comment)
+        r.is_counted_content (comment)
         AND (comment).local) AS diff
 WHERE
     diff.comments != 0;
@@ -194,7 +196,7 @@ BEGIN
             (post).creator_id, coalesce(sum(count_diff), 0) AS post_count
         FROM select_old_and_new_rows AS old_and_new_rows
         WHERE
-            r.is_counted (post)
+            r.is_counted_content (post)
         GROUP BY (post).creator_id) AS diff
 WHERE
     a.id = diff.creator_id
@@ -212,7 +214,7 @@ FROM (
     FROM
         select_old_and_new_rows AS old_and_new_rows
     WHERE
-        r.is_counted (post)
+        r.is_counted_content (post)
     GROUP BY
         (post).community_id) AS diff
 WHERE
@@ -230,7 +232,7 @@ FROM (
     FROM
         select_old_and_new_rows AS old_and_new_rows
     WHERE
-        r.is_counted (post)
+        r.is_counted_content (post)
         AND (post).local) AS diff
 WHERE
     diff.posts != 0;
@@ -394,9 +396,41 @@ CREATE TRIGGER change_values
     BEFORE INSERT ON private_message
     FOR EACH ROW
     EXECUTE FUNCTION r.private_message_change_values ();
+CREATE FUNCTION r.modmail_thread_change_values ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    -- Set local ap_id
+    IF NEW.local THEN
+        NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/modmail/' || NEW.id::text));
+    END IF;
+    RETURN NEW;
+END
+$$;
+CREATE TRIGGER change_values
+    BEFORE INSERT ON modmail_thread
+    FOR EACH ROW
+    EXECUTE FUNCTION r.modmail_thread_change_values ();
+CREATE FUNCTION r.modmail_message_change_values ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    -- Set local ap_id
+    IF NEW.local THEN
+        NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/modmail_message/' || NEW.id::text));
+    END IF;
+    RETURN NEW;
+END
+$$;
+CREATE TRIGGER change_values
+    BEFORE INSERT ON modmail_message
+    FOR EACH ROW
+    EXECUTE FUNCTION r.modmail_message_change_values ();
 -- Combined tables triggers
 -- These insert (published_at, item_id) into X_combined tables
--- Reports (comment_report, post_report, private_message_report)
+-- Reports (comment_report, post_report, private_message_report, community_report, person_report)
 CREATE PROCEDURE r.create_report_combined_trigger (table_name text)
 LANGUAGE plpgsql
 AS $a$
@@ -414,6 +448,29 @@ BEGIN
         AFTER INSERT ON thing
         FOR EACH ROW
         EXECUTE FUNCTION r.report_combined_thing_insert ( );
+    -- Remember when the report was resolved, for report metrics
+    CREATE FUNCTION r.report_combined_thing_resolve ( )
+        RETURNS TRIGGER
+        LANGUAGE plpgsql
+        AS $$
+        BEGIN
+            UPDATE
+                report_combined
+            SET
+                resolved_at = CASE WHEN NEW.resolved THEN
+                    now()
+                ELSE
+                    NULL
+                END
+            WHERE
+                thing_id = NEW.id;
+            RETURN NULL;
+        END $$;
+    CREATE TRIGGER report_combined_resolve
+        AFTER UPDATE OF resolved ON thing
+        FOR EACH ROW
+        WHEN (OLD.resolved IS DISTINCT FROM NEW.resolved)
+        EXECUTE FUNCTION r.report_combined_thing_resolve ( );
         $b$,
         'thing',
         table_name);
@@ -423,6 +480,7 @@ CALL r.create_report_combined_trigger ('post_report');
 CALL r.create_report_combined_trigger ('comment_report');
 CALL r.create_report_combined_trigger ('private_message_report');
 CALL r.create_report_combined_trigger ('community_report');
+CALL r.create_report_combined_trigger ('person_report');
 -- person_content (comment, post)
 CREATE PROCEDURE r.create_person_content_combined_trigger (table_name text)
 LANGUAGE plpgsql
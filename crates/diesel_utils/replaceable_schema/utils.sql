@@ -57,6 +57,18 @@ BEGIN
 END;
 $$;
 
+-- Posts and comments of shadow restricted users are only counted once they are approved.
+CREATE FUNCTION r.is_counted_content (item record)
+    RETURNS bool
+    LANGUAGE plpgsql
+    IMMUTABLE PARALLEL SAFE
+    AS $$
+BEGIN
+    RETURN r.is_counted (item)
+        AND NOT item.held_for_review;
+END;
+$$;
+
 CREATE FUNCTION r.local_url (url_path text)
     RETURNS text
     LANGUAGE sql
@@ -51,3 +51,24 @@ pub async fn send_new_report_email_to_admins(
   }
   Ok(())
 }
+
+/// Send a new ban appeal notification to the mods or admins who can resolve it
+pub fn send_new_ban_appeal_email(
+  recipients: Vec<LocalUserView>,
+  appellant_username: &str,
+  settings: &'static Settings,
+) {
+  let appeals_link = &format!("{}/ban_appeals", settings.get_protocol_and_hostname());
+
+  for recipient in recipients {
+    if let Some(email) = recipient.local_user.email {
+      let subject = format!(
+        "{}: new ban appeal from {appellant_username}",
+        settings.hostname
+      );
+      let body =
+        format!("<h1>New ban appeal</h1><br><a href=\"{appeals_link}\">Review ban appeals</a>");
+      send_email(subject, email, recipient.person.name, body, settings);
+    }
+  }
+}
@@ -474,6 +474,16 @@ fn create_modlog_items(modlog: Vec<ModlogView>, settings: &Settings) -> LemmyRes
           ),
           settings,
         ),
+        ModlogKind::AdminSetInstancePolicy => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "Admin {} federation policy for {}",
+            if r.modlog.is_revert { "removed" } else { "set" },
+            &target_instance_domain
+          ),
+          settings,
+        ),
         ModlogKind::AdminPurgeComment => {
           build_modlog_item(r, &modlog_url, "Admin purged comment", settings)
         }
@@ -486,6 +496,12 @@ fn create_modlog_items(modlog: Vec<ModlogView>, settings: &Settings) -> LemmyRes
         ModlogKind::AdminPurgePost => {
           build_modlog_item(r, &modlog_url, "Admin purged post", settings)
         }
+        ModlogKind::AdminPurgeUserMedia => build_modlog_item(
+          r,
+          &modlog_url,
+          format!("Admin purged media of {}", &target_person_name),
+          settings,
+        ),
         ModlogKind::AdminAdd => build_modlog_item(
           r,
           &modlog_url,
@@ -596,6 +612,12 @@ fn create_modlog_items(modlog: Vec<ModlogView>, settings: &Settings) -> LemmyRes
           ),
           settings,
         ),
+        ModlogKind::AdminMergeCommunity => build_modlog_item(
+          r,
+          &modlog_url,
+          format!("Merged community /c/{}", &target_community_name),
+          settings,
+        ),
         ModlogKind::ModRemovePost => build_modlog_item(
           r,
           &modlog_url,
@@ -629,6 +651,57 @@ fn create_modlog_items(modlog: Vec<ModlogView>, settings: &Settings) -> LemmyRes
           ),
           settings,
         ),
+        ModlogKind::ModDenyBanAppeal => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "Denied ban appeal of {} in /c/{}",
+            &target_person_name, &target_community_name
+          ),
+          settings,
+        ),
+        ModlogKind::AdminDenyBanAppeal => build_modlog_item(
+          r,
+          &modlog_url,
+          format!("Denied ban appeal of {}", &target_person_name),
+          settings,
+        ),
+        ModlogKind::ModWarnUser => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "Warned {} in /c/{}",
+            &target_person_name, &target_community_name
+          ),
+          settings,
+        ),
+        ModlogKind::AdminWarnUser => build_modlog_item(
+          r,
+          &modlog_url,
+          format!("Warned {}", &target_person_name),
+          settings,
+        ),
+        ModlogKind::ModMuteFromCommunity => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "{} {} in /c/{}",
+            muted_unmuted_str(r.modlog.is_revert),
+            &target_person_name,
+            &target_community_name
+          ),
+          settings,
+        ),
+        ModlogKind::AdminRestrictUser => build_modlog_item(
+          r,
+          &modlog_url,
+          format!(
+            "{} {}",
+            restricted_unrestricted_str(r.modlog.is_revert),
+            &target_person_name
+          ),
+          settings,
+        ),
       }
     })
     .collect::<LemmyResult<Vec<Item>>>()?;
@@ -644,6 +717,18 @@ fn banned_unbanned_str(is_revert: bool) -> &'static str {
   if is_revert { "Unbanned" } else { "Banned" }
 }
 
+fn muted_unmuted_str(is_revert: bool) -> &'static str {
+  if is_revert { "Unmuted" } else { "Muted" }
+}
+
+fn restricted_unrestricted_str(is_revert: bool) -> &'static str {
+  if is_revert {
+    "Unrestricted"
+  } else {
+    "Restricted"
+  }
+}
+
 fn removed_restored_str(is_revert: bool) -> &'static str {
   if is_revert { "Restored" } else { "Removed" }
 }
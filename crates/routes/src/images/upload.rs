@@ -3,8 +3,9 @@ use UploadType::*;
 use actix_web::{self, HttpRequest, web::*};
 use lemmy_api_utils::{
   context::LemmyContext,
-  request::PictrsResponse,
-  utils::{is_admin, is_mod_or_admin},
+  image_moderation::check_uploaded_image,
+  request::{PictrsFile, PictrsResponse, delete_image_alias, fetch_pictrs_file_size},
+  utils::{check_upload_quota, is_admin, is_mod_or_admin},
 };
 use lemmy_db_schema::source::{
   community::{Community, CommunityUpdateForm},
@@ -170,6 +171,9 @@ async fn do_upload_image(
   local_user_view: &LocalUserView,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<UploadImageResponse> {
+  // Don't bother uploading if the quota is already used up
+  check_upload_quota(local_user_view, 0, context).await?;
+
   let pictrs = context.settings().pictrs()?;
   let max_upload_size = pictrs.max_upload_size.map(|m| m.to_string());
   let image_url = format!("{}image", pictrs.url);
@@ -219,7 +223,19 @@ async fn do_upload_image(
     ))?;
 
   let mut images = res.json::<PictrsResponse>().await?;
-  for image in &images.files {
+
+  // Check all images before storing anything, and remove them from pict-rs if one is rejected
+  let checked = match check_uploaded_images(&images.files, local_user_view, context).await {
+    Ok(checked) => checked,
+    Err(e) => {
+      for image in &images.files {
+        delete_image_alias(&image.file, context).await.ok();
+      }
+      return Err(e);
+    }
+  };
+
+  for (image, (flagged_reason, file_size)) in images.files.iter().zip(checked) {
     // Pictrs allows uploading multiple images in a single request. Lemmy doesnt need this,
     // but still a user may upload multiple and so we need to store all links in db for
     // to allow deletion via web ui.
@@ -227,6 +243,8 @@ async fn do_upload_image(
       pictrs_alias: image.file.clone(),
       person_id: local_user_view.person.id,
       thumbnail_for_post_id: None,
+      flagged_reason,
+      file_size,
     };
 
     let protocol_and_hostname = context.settings().get_protocol_and_hostname();
@@ -247,3 +265,23 @@ async fn do_upload_image(
     filename: image.file,
   })
 }
+
+/// Checks new uploads against the blocklist, plugins and the upload quota. Returns the flag reason
+/// and file size for each image.
+async fn check_uploaded_images(
+  files: &[PictrsFile],
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<(Option<String>, i64)>> {
+  let mut checked = vec![];
+  for image in files {
+    let flagged_reason =
+      check_uploaded_image(&image.file, local_user_view.person.id, context).await?;
+    let file_size = fetch_pictrs_file_size(&image.file, context).await?;
+    checked.push((flagged_reason, file_size));
+  }
+
+  let total_size = checked.iter().map(|(_, size)| size).sum();
+  check_upload_quota(local_user_view, total_size, context).await?;
+  Ok(checked)
+}
@@ -14,20 +14,30 @@ use diesel::{
   sql_query,
   sql_types::{BigInt, Integer, Timestamptz},
 };
-use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use diesel_async::{AsyncPgConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
 use diesel_uplete::uplete;
 use lemmy_api_utils::{
+  blocklist::sync_blocklist_subscription,
   context::LemmyContext,
+  notify::NotifyData,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::send_webmention,
+  utils::{check_community_mod_action, check_local_user_valid, send_webmention},
 };
 use lemmy_db_schema::{
   source::{
+    blocklist_subscription::BlocklistSubscription,
     community::Community,
+    draft::Draft,
     instance::{Instance, InstanceForm},
+    local_site::LocalSite,
     local_user::LocalUser,
-    post::{Post, PostUpdateForm},
+    modlog::{Modlog, ModlogInsertForm},
+    person::Person,
+    post::{Post, PostActions, PostInsertForm, PostLikeForm, PostUpdateForm},
+    recurring_post::{RecurringPost, RecurringPostTag, RecurringPostUpdateForm},
+    tag::{PostTag, Tag},
   },
+  traits::Likeable,
   utils::DELETED_REPLACEMENT_TEXT,
 };
 use lemmy_db_schema_file::schema::{
@@ -43,9 +53,11 @@ use lemmy_db_schema_file::schema::{
   person,
   post,
   received_activity,
+  recurring_post,
   sent_activity,
   site,
 };
+use lemmy_db_views_local_user::LocalUserView;
 use lemmy_db_views_site::SiteView;
 use lemmy_diesel_utils::{
   connection::{DbPool, get_conn},
@@ -55,9 +67,10 @@ use lemmy_diesel_utils::{
 use lemmy_utils::{
   DB_BATCH_SIZE,
   error::{LemmyErrorType, LemmyResult},
+  utils::schedule::{CronSchedule, fill_date_placeholders},
 };
 use reqwest_middleware::ClientWithMiddleware;
-use std::time::Duration;
+use std::{collections::HashSet, time::Duration};
 use tracing::{info, warn};
 
 /// Schedules various cleanup tasks for lemmy in a background thread
@@ -66,7 +79,8 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   let mut scheduler = AsyncScheduler::with_tz(Utc);
 
   let context_1 = context.clone();
-  // Every 10 minutes update hot ranks, delete expired captchas and publish scheduled posts
+  // Every 10 minutes update hot ranks, delete expired captchas and publish scheduled and recurring
+  // posts
   scheduler.every(CTimeUnits::minutes(10)).run(move || {
     let context = context_1.clone();
 
@@ -83,6 +97,10 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
         .await
         .inspect_err(|e| warn!("Failed to publish scheduled posts: {e}"))
         .ok();
+      publish_recurring_posts(&context)
+        .await
+        .inspect_err(|e| warn!("Failed to publish recurring posts: {e}"))
+        .ok();
     }
   });
 
@@ -118,6 +136,8 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   // - Delete old denied users
   // - Update instance software
   // - Delete old outgoing activities
+  // - Delete old drafts
+  // - Sync subscribed instance blocklists
   scheduler.every(CTimeUnits::days(1)).run(move || {
     let context = context_1.reset_request_count();
 
@@ -146,6 +166,14 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
         .await
         .inspect_err(|e| warn!("Failed to clear old activities: {e}"))
         .ok();
+      delete_old_drafts(&mut context.pool())
+        .await
+        .inspect_err(|e| warn!("Failed to delete old drafts: {e}"))
+        .ok();
+      sync_blocklist_subscriptions(&context)
+        .await
+        .inspect_err(|e| warn!("Failed to sync blocklist subscriptions: {e}"))
+        .ok();
     }
   });
 
@@ -321,6 +349,15 @@ async fn delete_old_denied_users(pool: &mut DbPool<'_>) -> LemmyResult<()> {
   Ok(())
 }
 
+/// Delete drafts which weren't edited for longer than the age configured in local_site
+async fn delete_old_drafts(pool: &mut DbPool<'_>) -> LemmyResult<()> {
+  info!("Deleting old drafts...");
+  let local_site = SiteView::read_local(pool).await?.local_site;
+  let deleted = Draft::delete_older_than(pool, local_site.draft_max_age_days).await?;
+  info!("Done, deleted {deleted} drafts.");
+  Ok(())
+}
+
 /// overwrite posts and comments 30d after deletion
 async fn overwrite_deleted_posts_and_comments(pool: &mut DbPool<'_>) -> LemmyResult<()> {
   info!("Overwriting deleted posts...");
@@ -536,7 +573,7 @@ async fn update_local_user_count(pool: &mut DbPool<'_>) -> LemmyResult<()> {
   Ok(())
 }
 
-/// Set banned to false after ban expires
+/// Set banned to false after ban expires, and the same for community mutes
 async fn update_banned_when_expired(pool: &mut DbPool<'_>) -> LemmyResult<()> {
   info!("Updating banned column if it expires ...");
   let conn = &mut get_conn(pool).await?;
@@ -548,6 +585,13 @@ async fn update_banned_when_expired(pool: &mut DbPool<'_>) -> LemmyResult<()> {
     .execute(conn)
     .await?;
 
+  uplete(community_actions::table.filter(community_actions::mute_expires_at.lt(now().nullable())))
+    .set_null(community_actions::received_mute_at)
+    .set_null(community_actions::mute_expires_at)
+    .as_query()
+    .execute(conn)
+    .await?;
+
   uplete(instance_actions::table.filter(instance_actions::ban_expires_at.lt(now().nullable())))
     .set_null(instance_actions::received_ban_at)
     .set_null(instance_actions::ban_expires_at)
@@ -570,6 +614,19 @@ async fn delete_instance_block_when_expired(pool: &mut DbPool<'_>) -> LemmyResul
   Ok(())
 }
 
+/// Fetch the current content of all subscribed blocklists. The changes are not applied
+/// automatically, admins need to review them first.
+async fn sync_blocklist_subscriptions(context: &LemmyContext) -> LemmyResult<()> {
+  let subscriptions = BlocklistSubscription::list(&mut context.pool()).await?;
+  for subscription in subscriptions {
+    sync_blocklist_subscription(&subscription, context)
+      .await
+      .inspect_err(|e| warn!("Failed to sync blocklist {}: {e}", subscription.url))
+      .ok();
+  }
+  Ok(())
+}
+
 /// Find all unpublished posts with scheduled date in the future, and publish them.
 async fn publish_scheduled_posts(context: &Data<LemmyContext>) -> LemmyResult<()> {
   let pool = &mut context.pool();
@@ -610,14 +667,190 @@ async fn publish_scheduled_posts(context: &Data<LemmyContext>) -> LemmyResult<()
     };
     Post::update(&mut context.pool(), post.id, &form).await?;
 
-    // send out post via federation and webmention
+    // send out post via federation and webmention, unless the creator is shadow restricted
     let send_activity = SendActivityData::CreatePost(post.clone());
     ActivityChannel::submit_activity(send_activity, context)?;
-    send_webmention(post, &community);
+    if !post.held_for_review {
+      send_webmention(post, &community);
+    }
   }
   Ok(())
 }
 
+/// Create posts from all recurring post templates which are due, and schedule the next ones.
+async fn publish_recurring_posts(context: &Data<LemmyContext>) -> LemmyResult<()> {
+  let pool = &mut context.pool();
+  let site_view = SiteView::read_local(pool).await?;
+  let local_instance_id = site_view.instance.id;
+
+  let not_community_banned_action = community_actions::table
+    .find((person::id, community::id))
+    .filter(community_actions::received_ban_at.is_not_null());
+
+  let not_local_banned_action = instance_actions::table
+    .find((person::id, local_instance_id))
+    .filter(instance_actions::received_ban_at.is_not_null());
+
+  // The connection is released before publishing, which needs connections of its own
+  let due_recurring_posts: Vec<_> = {
+    let conn = &mut get_conn(pool).await?;
+    recurring_post::table
+      .inner_join(community::table)
+      .inner_join(person::table)
+      .filter(recurring_post::enabled)
+      .filter(recurring_post::next_publish_at.lt(now().nullable()))
+      // make sure the person and community are still around
+      .filter(not(person::deleted))
+      .filter(not(community::removed.or(community::deleted)))
+      // ensure that user isnt banned from community
+      .filter(not(exists(not_community_banned_action)))
+      // ensure that user isnt banned from local
+      .filter(not(exists(not_local_banned_action)))
+      .select((
+        RecurringPost::as_select(),
+        Community::as_select(),
+        Person::as_select(),
+      ))
+      .get_results::<(RecurringPost, Community, Person)>(conn)
+      .await?
+  };
+
+  for (recurring_post, community, creator) in due_recurring_posts {
+    let id = recurring_post.id;
+    publish_recurring_post(
+      recurring_post,
+      community,
+      creator,
+      &site_view.local_site,
+      context,
+    )
+    .await
+    .inspect_err(|e| warn!("Failed to publish recurring post {}: {e}", id.0))
+    .ok();
+  }
+  Ok(())
+}
+
+async fn publish_recurring_post(
+  recurring_post: RecurringPost,
+  community: Community,
+  creator: Person,
+  local_site: &LocalSite,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  // The creator may have lost mod rights since the recurring post was set up. Then it is
+  // disabled, instead of failing again on every run.
+  let creator_view = LocalUserView::read_person(&mut context.pool(), creator.id).await?;
+  let can_post = check_local_user_valid(&creator_view).is_ok()
+    && check_community_mod_action(&creator_view, &community, false, &mut context.pool())
+      .await
+      .is_ok();
+  if !can_post {
+    let form = RecurringPostUpdateForm {
+      enabled: Some(false),
+      ..Default::default()
+    };
+    RecurringPost::update(&mut context.pool(), recurring_post.id, &form).await?;
+    Err(LemmyErrorType::NotAModOrAdmin)?
+  }
+  let feature_post = recurring_post.feature_post;
+
+  // Fill in the date when the post was due, in case the task ran late
+  let date = recurring_post.next_publish_at.unwrap_or_else(Utc::now);
+  let post_form = PostInsertForm {
+    body: recurring_post.body.clone(),
+    language_id: recurring_post.language_id,
+    nsfw: Some(recurring_post.nsfw || community.nsfw),
+    featured_community: Some(feature_post),
+    ..PostInsertForm::new(
+      fill_date_placeholders(&recurring_post.name, date),
+      creator.id,
+      community.id,
+    )
+  };
+  let next_publish_at = CronSchedule::parse(&recurring_post.schedule)?.next_after(Utc::now());
+
+  // Create the post and schedule the next one together, so that it is never published twice
+  let pool = &mut context.pool();
+  let conn = &mut get_conn(pool).await?;
+  let recurring_post_id = recurring_post.id;
+  let post = conn
+    .run_transaction(|conn| {
+      async move {
+        let post = Post::create(&mut conn.into(), &post_form).await?;
+        let form = RecurringPostUpdateForm {
+          previous_post_id: Some(Some(post.id)),
+          next_publish_at: Some(next_publish_at),
+          ..Default::default()
+        };
+        RecurringPost::update(&mut conn.into(), recurring_post_id, &form).await?;
+        Ok(post)
+      }
+      .scope_boxed()
+    })
+    .await?;
+
+  // Only use tags which still exist in the community
+  let community_tags: HashSet<_> = Tag::read_for_community(&mut context.pool(), community.id)
+    .await?
+    .into_iter()
+    .map(|t| t.id)
+    .collect();
+  let tag_ids: Vec<_> =
+    RecurringPostTag::read_for_recurring_posts(&mut context.pool(), &[recurring_post.id])
+      .await?
+      .into_iter()
+      .map(|t| t.tag_id)
+      .filter(|t| community_tags.contains(t))
+      .collect();
+  if !tag_ids.is_empty() {
+    PostTag::update(&mut context.pool(), &post, &tag_ids).await?;
+  }
+
+  let like_form = PostLikeForm::new(post.id, creator.id, Some(true));
+  PostActions::like(&mut context.pool(), &like_form).await?;
+
+  ActivityChannel::submit_activity(SendActivityData::CreatePost(post.clone()), context)?;
+  send_webmention(post.clone(), &community);
+
+  if feature_post {
+    let modlog_form = ModlogInsertForm::mod_feature_post_community(creator.id, &post, true);
+    Modlog::create(&mut context.pool(), &[modlog_form]).await?;
+    ActivityChannel::submit_activity(
+      SendActivityData::FeaturePost(post.clone(), creator.clone(), true),
+      context,
+    )?;
+  }
+
+  if let Some(previous_post_id) = recurring_post.previous_post_id
+    && recurring_post.unfeature_previous
+  {
+    let previous_post = Post::read(&mut context.pool(), previous_post_id).await?;
+    if previous_post.featured_community {
+      let form = PostUpdateForm {
+        featured_community: Some(false),
+        ..Default::default()
+      };
+      let previous_post = Post::update(&mut context.pool(), previous_post_id, &form).await?;
+      let modlog_form =
+        ModlogInsertForm::mod_feature_post_community(creator.id, &previous_post, false);
+      Modlog::create(&mut context.pool(), &[modlog_form]).await?;
+      ActivityChannel::submit_activity(
+        SendActivityData::FeaturePost(previous_post, creator.clone(), false),
+        context,
+      )?;
+    }
+  }
+
+  NotifyData {
+    do_send_email: !local_site.disable_email_notifications,
+    ..NotifyData::new(post, creator, community)
+  }
+  .send(context);
+
+  Ok(())
+}
+
 /// Updates the instance software and version.
 ///
 /// Does so using the /.well-known/nodeinfo protocol described here:
@@ -714,9 +947,11 @@ mod tests {
   use lemmy_api_utils::request::client_builder;
   use lemmy_db_schema::{
     source::{
-      community::{Community, CommunityInsertForm},
+      community::{Community, CommunityActions, CommunityInsertForm, CommunityModeratorForm},
+      local_user::{LocalUser, LocalUserInsertForm},
       person::{Person, PersonInsertForm},
       post::{Post, PostActions, PostInsertForm, PostLikeForm},
+      recurring_post::{RecurringPost, RecurringPostInsertForm},
     },
     test_data::TestData,
     traits::Likeable,
@@ -788,9 +1023,11 @@ mod tests {
     clear_old_activities(pool).await?;
     overwrite_deleted_posts_and_comments(pool).await?;
     delete_old_denied_users(pool).await?;
+    delete_old_drafts(pool).await?;
     update_instance_software(pool, context.client()).await?;
     delete_expired_captcha_answers(pool).await?;
     publish_scheduled_posts(&context).await?;
+    publish_recurring_posts(&context).await?;
 
     let community_after = Community::read(pool, community.id).await?;
     assert_eq!(
@@ -809,4 +1046,92 @@ mod tests {
     data.delete(pool).await?;
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_publish_recurring_post() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let data = TestData::create(pool).await?;
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(
+        data.instance.id,
+        "recurring".to_owned(),
+        "title".to_owned(),
+        "pubkey".to_owned(),
+      ),
+    )
+    .await?;
+    let moderator = Person::create(
+      pool,
+      &PersonInsertForm::test_form(data.instance.id, "recurring_mod"),
+    )
+    .await?;
+    LocalUser::create(pool, &LocalUserInsertForm::test_form(moderator.id), vec![]).await?;
+    CommunityActions::join(
+      pool,
+      &CommunityModeratorForm::new(community.id, moderator.id),
+    )
+    .await?;
+    let former_moderator = Person::create(
+      pool,
+      &PersonInsertForm::test_form(data.instance.id, "recurring_former_mod"),
+    )
+    .await?;
+    LocalUser::create(
+      pool,
+      &LocalUserInsertForm::test_form(former_moderator.id),
+      vec![],
+    )
+    .await?;
+
+    let due_at = Utc::now() - chrono::TimeDelta::minutes(1);
+    let recurring_post = RecurringPost::create(
+      pool,
+      &RecurringPostInsertForm::new(
+        community.id,
+        moderator.id,
+        "@weekly".to_owned(),
+        "Weekly thread {date}".to_owned(),
+        Some(due_at),
+      ),
+    )
+    .await?;
+    let orphaned_recurring_post = RecurringPost::create(
+      pool,
+      &RecurringPostInsertForm::new(
+        community.id,
+        former_moderator.id,
+        "@weekly".to_owned(),
+        "Orphaned thread".to_owned(),
+        Some(due_at),
+      ),
+    )
+    .await?;
+
+    publish_recurring_posts(&context).await?;
+
+    // The due template was published and the next run scheduled
+    let recurring_post = RecurringPost::read(pool, recurring_post.id).await?;
+    let post_id = recurring_post
+      .previous_post_id
+      .ok_or(LemmyErrorType::NotFound)?;
+    assert!(recurring_post.next_publish_at > Some(Utc::now()));
+    let post = Post::read(pool, post_id).await?;
+    assert_eq!(
+      format!("Weekly thread {}", due_at.format("%Y-%m-%d")),
+      post.name
+    );
+    assert_eq!(moderator.id, post.creator_id);
+
+    // The creator is not a mod, so the template is disabled instead of published
+    let orphaned_recurring_post = RecurringPost::read(pool, orphaned_recurring_post.id).await?;
+    assert!(!orphaned_recurring_post.enabled);
+    assert_eq!(None, orphaned_recurring_post.previous_post_id);
+
+    data.delete(pool).await?;
+    Ok(())
+  }
 }
@@ -7,25 +7,39 @@ use actix_web::{
   middleware::{self, Condition, ErrorHandlerResponse, ErrorHandlers},
   web::{Data, get, scope},
 };
+use chrono::{DateTime, TimeDelta, Utc};
 use clap::{Parser, Subcommand};
 use lemmy_api::sitemap::get_sitemap;
 use lemmy_api_utils::{
   context::LemmyContext,
+  plugins::plugin_markdown_inline_rules,
   request::client_builder,
   send_activity::ActivityChannel,
-  utils::local_site_rate_limit_to_rate_limit_config,
+  utils::{local_site_rate_limit_to_rate_limit_config, update_markdown_custom_emojis},
 };
 use lemmy_apub::{
   FEDERATION_HTTP_FETCH_LIMIT,
   VerifyUrlData,
-  collections::fetch_community_collections,
+  collections::{backfill_community, fetch_community_collections},
 };
 use lemmy_apub_activities::handle_outgoing_activities;
-use lemmy_apub_objects::objects::{community::FETCH_COMMUNITY_COLLECTIONS, instance::ApubSite};
+use lemmy_apub_objects::objects::{
+  community::{BACKFILL_COMMUNITY, FETCH_COMMUNITY_COLLECTIONS},
+  instance::ApubSite,
+};
 use lemmy_apub_send::{Opts, SendManager};
-use lemmy_db_schema::source::secret::Secret;
+use lemmy_db_schema::{
+  newtypes::ActivityId,
+  source::{
+    community::Community,
+    federation_replay::FederationReplay,
+    instance::Instance,
+    secret::Secret,
+  },
+  traits::ApubActor,
+};
 use lemmy_db_views_site::SiteView;
-use lemmy_diesel_utils::connection::build_db_pool;
+use lemmy_diesel_utils::{connection::build_db_pool, traits::Crud};
 use lemmy_routes::{
   feeds,
   middleware::{
@@ -52,7 +66,7 @@ use reqwest_middleware::ClientBuilder;
 use reqwest_tracing::TracingMiddleware;
 use serde_json::json;
 use std::{ops::Deref, time::Duration};
-use tokio::signal::unix::SignalKind;
+use tokio::{signal::unix::SignalKind, time::sleep};
 use tracing_actix_web::{DefaultRootSpanBuilder, TracingLogger};
 
 #[cfg_attr(target_arch = "x86_64", global_allocator)]
@@ -126,6 +140,25 @@ enum CmdSubcommand {
     #[arg(long, default_value_t = 1)]
     number: u64,
   },
+  /// Resend federated activities to a single instance, for example after it lost data.
+  ///
+  /// The replay is stored in the database and processed by the running federation queue. This
+  /// command prints the progress until it is finished, and can be stopped at any time.
+  FederationReplay {
+    /// Domain of the instance which should receive the activities again.
+    #[arg(long)]
+    instance: String,
+    /// Only resend activities of this local community. By default the whole federation queue of
+    /// the instance is rewound.
+    #[arg(long)]
+    community: Option<String>,
+    /// Resend activities published since this time, eg `2026-03-01T00:00:00Z`.
+    #[arg(long, required_unless_present = "since_activity_id")]
+    since: Option<DateTime<Utc>>,
+    /// Resend activities starting with this id.
+    #[arg(long, conflicts_with = "since")]
+    since_activity_id: Option<i64>,
+  },
 }
 
 #[derive(Subcommand, Debug, PartialEq, Eq)]
@@ -166,6 +199,22 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
     return Ok(());
   }
 
+  if let Some(CmdSubcommand::FederationReplay {
+    instance,
+    community,
+    since,
+    since_activity_id,
+  }) = args.subcommand
+  {
+    return federation_replay(
+      instance,
+      community,
+      since,
+      since_activity_id.map(ActivityId),
+    )
+    .await;
+  }
+
   // Print version number to log
   println!("Starting Lemmy v{}", *VERSION);
 
@@ -189,6 +238,12 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
     println!("Federation enabled, host is {}", &SETTINGS.hostname);
   }
 
+  // Load custom emojis and plugin rules for markdown rendering
+  update_markdown_custom_emojis(&mut (&pool).into()).await?;
+  if let Err(e) = plugin_markdown_inline_rules().await {
+    tracing::warn!("Failed to load markdown rules from plugins: {e}");
+  }
+
   // Set up the rate limiter
   let rate_limit_config =
     local_site_rate_limit_to_rate_limit_config(&site_view.local_site_rate_limit);
@@ -226,7 +281,10 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
     .debug(cfg!(debug_assertions))
     .http_signature_compat(true)
     .url_verifier(Box::new(VerifyUrlData(context.inner_pool().clone())));
-  if site_view.local_site.federation_signed_fetch {
+  // Authorized fetch only works if our own fetches are signed as well
+  if site_view.local_site.federation_signed_fetch
+    || site_view.local_site.federation_authorized_fetch
+  {
     let site: ApubSite = site_view.site.clone().into();
     federation_config_builder.signed_fetch_actor(&site);
   }
@@ -235,6 +293,9 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
   FETCH_COMMUNITY_COLLECTIONS
     .set(fetch_community_collections)
     .map_err(|_e| LemmyErrorType::Unknown("couldnt set function pointer".into()))?;
+  BACKFILL_COMMUNITY
+    .set(backfill_community)
+    .map_err(|_e| LemmyErrorType::Unknown("couldnt set function pointer".into()))?;
 
   let request_data = federation_config.to_request_data();
   let outgoing_activities_task =
@@ -305,6 +366,74 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
   Ok(())
 }
 
+/// How long the federation replay command waits for progress before giving up.
+const REPLAY_STALL_TIMEOUT: TimeDelta = TimeDelta::minutes(30);
+
+/// Schedule a federation replay, and print its progress until it is finished.
+async fn federation_replay(
+  instance: String,
+  community: Option<String>,
+  since: Option<DateTime<Utc>>,
+  since_activity_id: Option<ActivityId>,
+) -> LemmyResult<()> {
+  let pool = build_db_pool()?;
+  let pool = &mut (&pool).into();
+
+  let instance = Instance::read_from_domain(pool, &instance).await?;
+  let community_id = match community {
+    Some(name) => Some(
+      Community::read_from_name(pool, &name, None, false)
+        .await?
+        .ok_or(LemmyErrorType::NotFound)?
+        .id,
+    ),
+    None => None,
+  };
+  let mut replay =
+    FederationReplay::schedule(pool, instance.id, community_id, since, since_activity_id).await?;
+  println!(
+    "Resending activities {} to {} to {}",
+    replay.start_id.0, replay.end_id.0, instance.domain
+  );
+
+  let total = replay.end_id.0 - replay.start_id.0 + 1;
+  let mut last_progress = (replay.last_sent_id, Utc::now());
+  while replay.finished_at.is_none() {
+    sleep(Duration::from_secs(10)).await;
+    replay = FederationReplay::read(pool, replay.id).await?;
+    if replay.last_sent_id != last_progress.0 {
+      last_progress = (replay.last_sent_id, Utc::now());
+    }
+    // The federation queue may not be running, or the instance may be unreachable
+    if replay.finished_at.is_none() && Utc::now() - last_progress.1 > REPLAY_STALL_TIMEOUT {
+      println!(
+        "No progress for {} minutes, stopping. The replay continues in the background.",
+        REPLAY_STALL_TIMEOUT.num_minutes()
+      );
+      return Ok(());
+    }
+    let done = replay
+      .last_sent_id
+      .map_or(0, |last| last.0 - replay.start_id.0 + 1)
+      .max(0);
+    if replay.started_at.is_none() {
+      println!("Waiting for the federation queue to start the replay");
+    } else if replay.community_id.is_some() {
+      println!(
+        "{done}/{total} activities checked, {} sent",
+        replay.activities_sent
+      );
+    } else {
+      println!("{done}/{total} activities checked");
+    }
+  }
+  match replay.error {
+    Some(error) => println!("Replay stopped: {error}"),
+    None => println!("Replay finished"),
+  }
+  Ok(())
+}
+
 /// Creates temporary HTTP server which returns status 503 for all requests.
 fn create_startup_server() -> LemmyResult<ServerHandle> {
   let startup_server = HttpServer::new(move || {
@@ -365,6 +494,7 @@ fn create_http_server(
     app
       .configure(|cfg| lemmy_api_routes::config(cfg, &rate_limit))
       .configure(|cfg| lemmy_api_routes_v3::config(cfg, &rate_limit))
+      .configure(|cfg| lemmy_api_routes_mastodon::config(cfg, &rate_limit))
       .configure(|cfg| {
         if site_view.local_site.federation_enabled {
           lemmy_apub::http::routes::config(cfg);
@@ -20,6 +20,7 @@ pub enum LemmyErrorType {
   NotAnAdmin,
   CantBlockYourself,
   CantNoteYourself,
+  CantFollowYourself,
   CantBlockAdmin,
   PasswordsDoNotMatch,
   EmailNotVerified,
@@ -112,6 +113,7 @@ pub enum LemmyErrorType {
   OauthAuthorizationInvalid,
   OauthLoginFailed,
   OauthRegistrationClosed,
+  OauthInsufficientScope,
   NotFound,
   PostScheduleTimeMustBeInFuture,
   TooManyScheduledPosts,
@@ -125,6 +127,33 @@ pub enum LemmyErrorType {
   MultiCommunityEntryLimitReached,
   TooManyRequests,
   ResolveObjectFailed(String),
+  TooManyDrafts,
+  InvalidCronSchedule,
+  BlockedImage,
+  ImageRejected(String),
+  UploadQuotaExceeded,
+  RuleNotInCommunity,
+  NoModNoteEditAllowed,
+  ModNoteItemNotFromPerson,
+  NotBanned,
+  BanAppealAlreadySubmitted,
+  BanAppealAlreadyResolved,
+  BanAppealOutdated,
+  BanAppealRemoteCommunity,
+  NoReportNoteEditAllowed,
+  RemovalReasonNotInCommunity,
+  PersonIsMutedInCommunity,
+  CantBackfillLocalCommunity,
+  CommunityMoved,
+  InvalidCommunityMerge,
+  InvalidFederationReplay,
+  NothingToReplay,
+  InvalidStrikeSettings,
+  InvalidModlogRetention,
+  InvalidUploadQuota,
+  InvalidDraftTarget,
+  InvalidDraftMaxAge,
+  PersonFollowsArePrivate,
   #[serde(untagged)]
   #[cfg_attr(feature = "ts-rs", ts(skip))]
   UntranslatedError(Option<UntranslatedError>),
@@ -161,6 +190,7 @@ pub enum UntranslatedError {
   /// A remote community sent an activity to us, but actually no local user follows the community
   /// so the activity was rejected.
   CommunityHasNoFollowers(String),
+  RejectedByFederationPolicy,
 }
 
 cfg_if! {
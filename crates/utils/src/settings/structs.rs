@@ -119,6 +119,11 @@ pub struct PictrsConfig {
   /// banners can still be uploaded.
   #[default(false)]
   pub image_upload_disabled: bool,
+
+  /// Images are rejected if their perceptual hash differs from a blocked image in at most this
+  /// many bits (out of 64). Set to 0 to only block exact matches.
+  #[default(6)]
+  pub blocked_image_max_distance: u32,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default, Document, PartialEq)]
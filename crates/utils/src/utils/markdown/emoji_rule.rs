@@ -0,0 +1,80 @@
+use markdown_it::{
+  MarkdownIt,
+  Node,
+  NodeValue,
+  Renderer,
+  parser::inline::{InlineRule, InlineState},
+};
+use std::{
+  collections::HashMap,
+  sync::{LazyLock, RwLock},
+};
+
+/// Custom emojis of the local site, keyed by shortcode. Needs to be updated whenever emojis are
+/// created, edited or deleted.
+static CUSTOM_EMOJIS: LazyLock<RwLock<HashMap<String, CustomEmojiImage>>> =
+  LazyLock::new(Default::default);
+
+/// A custom emoji which gets rendered as `<img>` when its `:shortcode:` is used in markdown.
+#[derive(Debug, Clone)]
+pub struct CustomEmojiImage {
+  pub shortcode: String,
+  pub image_url: String,
+  pub alt_text: String,
+}
+
+impl NodeValue for CustomEmojiImage {
+  fn render(&self, node: &Node, fmt: &mut dyn Renderer) {
+    let mut attrs = node.attrs.clone();
+    attrs.push(("src", self.image_url.clone()));
+    attrs.push(("alt", self.alt_text.clone()));
+    attrs.push(("title", format!(":{}:", self.shortcode)));
+    attrs.push(("class", "icon".to_string()));
+    attrs.push(("class", "emoji".to_string()));
+
+    fmt.self_close("img", &attrs);
+  }
+}
+
+/// Replace the list of custom emojis which are rendered by the markdown parser.
+pub fn set_custom_emojis(emojis: impl IntoIterator<Item = CustomEmojiImage>) {
+  let emojis = emojis
+    .into_iter()
+    .map(|e| (e.shortcode.to_lowercase(), e))
+    .collect();
+  if let Ok(mut lock) = CUSTOM_EMOJIS.write() {
+    *lock = emojis;
+  }
+}
+
+struct CustomEmojiScanner;
+
+impl InlineRule for CustomEmojiScanner {
+  const MARKER: char = ':';
+
+  fn run(state: &mut InlineState) -> Option<(Node, usize)> {
+    let input = &state.src[state.pos..state.pos_max];
+    if !input.starts_with(Self::MARKER) {
+      return None;
+    }
+
+    // Ignore colons inside words or numbers, eg `10:30:00`
+    let prev = state.src[..state.pos].chars().next_back();
+    if prev.is_some_and(char::is_alphanumeric) {
+      return None;
+    }
+
+    let len = input[1..].find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))?;
+    if len == 0 || !input[1 + len..].starts_with(Self::MARKER) {
+      return None;
+    }
+
+    let shortcode = input[1..=len].to_lowercase();
+    let emoji = CUSTOM_EMOJIS.read().ok()?.get(&shortcode)?.clone();
+    Some((Node::new(emoji), len + 2))
+  }
+}
+
+pub fn add(md: &mut MarkdownIt) {
+  md.inline.add_rule::<CustomEmojiScanner>();
+}
@@ -0,0 +1,144 @@
+use markdown_it::{
+  MarkdownIt,
+  Node,
+  NodeValue,
+  Renderer,
+  parser::{
+    core::CoreRule,
+    inline::{Text, builtin::InlineParserRule},
+  },
+  plugins::cmark::block::{heading::ATXHeading, lheading::SetextHeading, paragraph::Paragraph},
+};
+use std::collections::HashMap;
+
+/// Paragraph which gets replaced by a table of contents.
+const TOC_MARKER: &str = "[[toc]]";
+
+/// Prefix for heading ids, so that they can't clash with ids used by the frontend (eg a heading
+/// named `app` would otherwise take over `#app`).
+const ID_PREFIX: &str = "user-content-";
+
+#[derive(Debug, Clone)]
+struct TocEntry {
+  level: u8,
+  id: String,
+  text: String,
+}
+
+/// Nested list of links to all headings in the document.
+#[derive(Debug)]
+pub struct TableOfContents {
+  entries: Vec<TocEntry>,
+}
+
+impl NodeValue for TableOfContents {
+  fn render(&self, node: &Node, fmt: &mut dyn Renderer) {
+    let mut attrs = node.attrs.clone();
+    attrs.push(("class", "table-of-contents".to_string()));
+
+    fmt.cr();
+    // Levels of the currently open lists. Each entry leaves its `<li>` open, so that nested
+    // lists can be placed inside of it.
+    let mut levels: Vec<u8> = vec![];
+    for entry in &self.entries {
+      match levels.last() {
+        None => {
+          fmt.open("ul", &attrs);
+          levels.push(entry.level);
+        }
+        Some(last) if entry.level > *last => {
+          fmt.open("ul", &[]);
+          levels.push(entry.level);
+        }
+        Some(_) => {
+          fmt.close("li");
+          while levels.len() > 1 && levels.last().is_some_and(|l| entry.level < *l) {
+            fmt.close("ul");
+            fmt.close("li");
+            levels.pop();
+          }
+        }
+      }
+      fmt.open("li", &[]);
+      fmt.open("a", &[("href", format!("#{}", entry.id))]);
+      fmt.text(&entry.text);
+      fmt.close("a");
+    }
+    while levels.pop().is_some() {
+      fmt.close("li");
+      fmt.close("ul");
+    }
+    fmt.cr();
+  }
+}
+
+/// Adds an `id` attribute to each heading so that it can be linked, and replaces `[[toc]]`
+/// paragraphs with a table of contents.
+pub(super) struct HeadingAnchorRule;
+
+impl CoreRule for HeadingAnchorRule {
+  fn run(root: &mut Node, _: &MarkdownIt) {
+    let mut entries = vec![];
+    let mut used_slugs: HashMap<String, usize> = HashMap::new();
+    root.walk_mut(|node, _| {
+      let level = if let Some(h) = node.cast::<ATXHeading>() {
+        h.level
+      } else if let Some(h) = node.cast::<SetextHeading>() {
+        h.level
+      } else {
+        return;
+      };
+      let text = node.collect_text();
+      let mut slug = slugify(&text);
+      let count = used_slugs.entry(slug.clone()).or_default();
+      if *count > 0 {
+        slug = format!("{slug}-{count}");
+      }
+      *count += 1;
+      let id = format!("{ID_PREFIX}{slug}");
+      node.attrs.push(("id", id.clone()));
+      entries.push(TocEntry { level, id, text });
+    });
+
+    if entries.is_empty() {
+      return;
+    }
+    root.walk_mut(|node, _| {
+      if is_toc_marker(node) {
+        *node = Node::new(TableOfContents {
+          entries: entries.clone(),
+        });
+      }
+    });
+  }
+}
+
+fn is_toc_marker(node: &Node) -> bool {
+  node.is::<Paragraph>()
+    && node.children.iter().all(|c| c.is::<Text>())
+    && node.collect_text().trim().eq_ignore_ascii_case(TOC_MARKER)
+}
+
+/// Convert heading text into a lowercase identifier which only contains alphanumeric characters,
+/// underscores and dashes.
+fn slugify(text: &str) -> String {
+  let mut slug = String::new();
+  for c in text.trim().chars().flat_map(char::to_lowercase) {
+    if c.is_alphanumeric() || c == '_' {
+      slug.push(c);
+    } else if (c.is_whitespace() || c == '-') && !slug.is_empty() && !slug.ends_with('-') {
+      slug.push('-');
+    }
+  }
+  let slug = slug.trim_end_matches('-');
+  if slug.is_empty() {
+    "section".to_string()
+  } else {
+    slug.to_string()
+  }
+}
+
+pub fn add(md: &mut MarkdownIt) {
+  md.add_rule::<HeadingAnchorRule>()
+    .after::<InlineParserRule>();
+}
@@ -49,6 +49,26 @@ pub fn markdown_rewrite_image_links(mut src: String) -> (String, Vec<Url>) {
   (src, links)
 }
 
+/// Turns all images in markdown into plain links, so that they are not loaded when rendering.
+pub fn markdown_strip_images(mut src: String) -> String {
+  let mut image_offsets = vec![];
+  PARSER.parse(&src).walk(|node, _depth| {
+    if node.cast::<Image>().is_some()
+      && let Some(srcmap) = node.srcmap
+    {
+      image_offsets.push(srcmap.get_byte_offsets().0);
+    }
+  });
+  // Remove the leading `!` of each image, going backwards so that offsets stay valid
+  image_offsets.sort_unstable();
+  for start in image_offsets.into_iter().rev() {
+    if src.get(start..=start) == Some("!") {
+      src.remove(start);
+    }
+  }
+  src
+}
+
 pub fn markdown_handle_title(src: &str, start: usize, end: usize) -> (&str, Option<&str>) {
   let content = src.get(start..end).unwrap_or_default();
   // necessary for custom emojis which look like `![name](url "title")`
@@ -62,19 +82,19 @@ pub fn markdown_find_links(src: &str) -> Vec<(usize, usize)> {
   find_urls::<Link>(src)
 }
 
+// Use separate markdown parser here, with most features disabled for faster parsing,
+// and a dummy link formatter which doesnt normalize links.
+static PARSER: LazyLock<MarkdownIt> = LazyLock::new(|| {
+  let mut p = MarkdownIt::new();
+  p.link_formatter = Box::new(NoopLinkFormatter {});
+  image::add(&mut p);
+  fence::add(&mut p);
+  link_rule::add(&mut p);
+  p
+});
+
 // Walk the syntax tree to find positions of image or link urls
 fn find_urls<T: NodeValue + UrlAndTitle>(src: &str) -> Vec<(usize, usize)> {
-  // Use separate markdown parser here, with most features disabled for faster parsing,
-  // and a dummy link formatter which doesnt normalize links.
-  static PARSER: LazyLock<MarkdownIt> = LazyLock::new(|| {
-    let mut p = MarkdownIt::new();
-    p.link_formatter = Box::new(NoopLinkFormatter {});
-    image::add(&mut p);
-    fence::add(&mut p);
-    link_rule::add(&mut p);
-    p
-  });
-
   let ast = PARSER.parse(src);
   let mut links_offsets = vec![];
   ast.walk(|node, _depth| {
@@ -210,4 +230,35 @@ mod tests {
       );
     });
   }
+
+  #[test]
+  fn test_markdown_strip_images() {
+    let tests: Vec<_> = vec![
+      (
+        "image turned into link",
+        "![link](http://example.com/image.jpg)",
+        "[link](http://example.com/image.jpg)",
+      ),
+      (
+        "multiple images",
+        "a ![x](http://example.com/1.jpg) b ![ითხოვს](http://example.com/2.jpg)",
+        "a [x](http://example.com/1.jpg) b [ითხოვს](http://example.com/2.jpg)",
+      ),
+      (
+        "links untouched",
+        "[link](http://example.com)",
+        "[link](http://example.com)",
+      ),
+      (
+        "fenced code untouched",
+        "```\n![x](http://example.com/1.jpg)\n```",
+        "```\n![x](http://example.com/1.jpg)\n```",
+      ),
+    ];
+
+    tests.iter().for_each(|&(msg, input, expected)| {
+      let result = markdown_strip_images(input.to_string());
+      assert_eq!(result, expected, "Testing {}", msg);
+    });
+  }
 }
@@ -3,9 +3,16 @@ use markdown_it::MarkdownIt;
 use regex::RegexSet;
 use std::sync::LazyLock;
 
+mod emoji_rule;
+mod heading_anchors;
 mod identifier_rule;
 pub mod image_links;
 mod link_rule;
+mod plugin_rule;
+mod spoiler_rule;
+
+pub use emoji_rule::{CustomEmojiImage, set_custom_emojis};
+pub use plugin_rule::{MarkdownInlineRule, set_plugin_inline_rules};
 
 static MARKDOWN_PARSER: LazyLock<MarkdownIt> = LazyLock::new(|| {
   let mut parser = MarkdownIt::new();
@@ -18,10 +25,16 @@ static MARKDOWN_PARSER: LazyLock<MarkdownIt> = LazyLock::new(|| {
   markdown_it_footnote::add(&mut parser);
   link_rule::add(&mut parser);
   identifier_rule::add(&mut parser);
+  spoiler_rule::add(&mut parser);
+  emoji_rule::add(&mut parser);
+  heading_anchors::add(&mut parser);
+  plugin_rule::add(&mut parser);
 
   parser
 });
 
+/// Render markdown to html. Raw html in the input is escaped, so the output can be used directly
+/// for federation `content`.
 pub fn markdown_to_html(text: &str) -> String {
   MARKDOWN_PARSER.parse(text).xrender()
 }
@@ -40,6 +53,7 @@ mod tests {
   use crate::utils::validation::check_urls_are_valid;
   use pretty_assertions::assert_eq;
   use regex::escape;
+  use serial_test::serial;
 
   #[test]
   fn test_basic_markdown() {
@@ -57,7 +71,7 @@ mod tests {
       (
         "headings",
         "# h1\n## h2\n### h3\n#### h4\n##### h5\n###### h6",
-        "<h1>h1</h1>\n<h2>h2</h2>\n<h3>h3</h3>\n<h4>h4</h4>\n<h5>h5</h5>\n<h6>h6</h6>\n",
+        "<h1 id=\"user-content-h1\">h1</h1>\n<h2 id=\"user-content-h2\">h2</h2>\n<h3 id=\"user-content-h3\">h3</h3>\n<h4 id=\"user-content-h4\">h4</h4>\n<h5 id=\"user-content-h5\">h5</h5>\n<h6 id=\"user-content-h6\">h6</h6>\n",
       ),
       ("line breaks", "First\rSecond", "<p>First\nSecond</p>\n"),
       (
@@ -68,7 +82,7 @@ mod tests {
       (
         "blockquotes",
         "> #### Hello\n > \n > - Hola\n > - 안영 \n>> Goodbye\n",
-        "<blockquote>\n<h4>Hello</h4>\n<ul>\n<li>Hola</li>\n<li>안영</li>\n</ul>\n<blockquote>\n<p>Goodbye</p>\n</blockquote>\n</blockquote>\n",
+        "<blockquote>\n<h4 id=\"user-content-hello\">Hello</h4>\n<ul>\n<li>Hola</li>\n<li>안영</li>\n</ul>\n<blockquote>\n<p>Goodbye</p>\n</blockquote>\n</blockquote>\n",
       ),
       (
         "lists (ordered, unordered)",
@@ -136,6 +150,17 @@ mod tests {
         "[@example@example.com](https://example.com/u/example)",
         "<p><a href=\"https://example.com/u/example\" rel=\"nofollow\" class=\"u-url mention\">@example@example.com</a></p>\n",
       ),
+      (
+        "inline spoiler",
+        "This is ||very secret||",
+        "<p>This is <span class=\"spoiler\">very secret</span></p>\n",
+      ),
+      (
+        "table of contents",
+        "[[toc]]\n\n# Intro\n## Details\n# Intro",
+        "<ul class=\"table-of-contents\"><li><a href=\"#user-content-intro\">Intro</a><ul><li><a href=\"#user-content-details\">Details</a></li></ul></li><li><a href=\"#user-content-intro-1\">Intro</a></li></ul>\n\
+         <h1 id=\"user-content-intro\">Intro</h1>\n<h2 id=\"user-content-details\">Details</h2>\n<h1 id=\"user-content-intro-1\">Intro</h1>\n",
+      ),
       (
         "dont add backslash escapes in urls",
         "[markdown link](https://en.wikipedia.org/wiki/Dragnet_(franchise))",
@@ -154,6 +179,48 @@ mod tests {
     });
   }
 
+  #[test]
+  #[serial]
+  fn test_custom_emoji() {
+    set_custom_emojis([CustomEmojiImage {
+      shortcode: "lemmy".to_string(),
+      image_url: "https://example.com/lemmy.png".to_string(),
+      alt_text: "Lemmy logo".to_string(),
+    }]);
+
+    let result = markdown_to_html("Hello :lemmy: :unknown: 10:30 `:lemmy:`");
+    assert_eq!(
+      "<p>Hello <img src=\"https://example.com/lemmy.png\" alt=\"Lemmy logo\" title=\":lemmy:\" class=\"icon emoji\" /> :unknown: 10:30 <code>:lemmy:</code></p>\n",
+      result
+    );
+  }
+
+  #[test]
+  #[serial]
+  fn test_plugin_inline_rules() -> LemmyResult<()> {
+    let rule = MarkdownInlineRule {
+      pattern: r"\bHTML\b".to_string(),
+      tag: "abbr".to_string(),
+      class: None,
+      content: None,
+      title: Some("HyperText Markup Language".to_string()),
+    };
+    set_plugin_inline_rules(vec![rule.clone()])?;
+
+    let result = markdown_to_html("Plain HTML, but not `HTML` <b>");
+    assert_eq!(
+      "<p>Plain <abbr title=\"HyperText Markup Language\">HTML</abbr>, but not <code>HTML</code> &lt;b&gt;</p>\n",
+      result
+    );
+
+    let script = MarkdownInlineRule {
+      tag: "script".to_string(),
+      ..rule
+    };
+    assert!(set_plugin_inline_rules(vec![script]).is_err());
+    Ok(())
+  }
+
   // This replicates the logic when saving url blocklist patterns and querying them.
   // Refer to lemmy_api_crud::site::update::update_site and
   // lemmy_api_common::utils::get_url_blocklist().
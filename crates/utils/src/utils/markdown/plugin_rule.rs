@@ -0,0 +1,165 @@
+use super::heading_anchors::HeadingAnchorRule;
+use crate::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use markdown_it::{
+  MarkdownIt,
+  Node,
+  NodeValue,
+  Renderer,
+  parser::{core::CoreRule, inline::Text},
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, RwLock};
+
+/// Tags which plugin rules are allowed to emit. Attributes are limited to `class` and `title`, so
+/// that plugins cant inject scripts or tracking images into federated content.
+const ALLOWED_TAGS: [&str; 16] = [
+  "abbr", "b", "cite", "code", "del", "em", "i", "ins", "kbd", "mark", "s", "small", "span",
+  "strong", "sub", "sup",
+];
+
+static PLUGIN_RULES: LazyLock<RwLock<Vec<CompiledRule>>> = LazyLock::new(Default::default);
+
+/// Extra inline markdown rule which can be registered by plugins. Text matching `pattern` is
+/// wrapped in the given tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownInlineRule {
+  /// Regex which is matched against plain text, not against code or urls.
+  pub pattern: String,
+  /// Tag to wrap the matched text with, eg `mark` or `abbr`.
+  pub tag: String,
+  pub class: Option<String>,
+  /// Text content of the tag. Can reference capture groups like `$1`, defaults to the full match.
+  pub content: Option<String>,
+  /// Title attribute, which can also reference capture groups.
+  pub title: Option<String>,
+}
+
+#[derive(Debug)]
+struct CompiledRule {
+  regex: Regex,
+  rule: MarkdownInlineRule,
+}
+
+/// Replace the inline rules which were registered by plugins.
+pub fn set_plugin_inline_rules(rules: Vec<MarkdownInlineRule>) -> LemmyResult<()> {
+  let compiled = rules
+    .into_iter()
+    .map(|rule| {
+      if !ALLOWED_TAGS.contains(&rule.tag.as_str()) {
+        Err(LemmyErrorType::PluginError(format!(
+          "markdown tag {} is not allowed",
+          rule.tag
+        )))?
+      }
+      let regex = Regex::new(&rule.pattern).with_lemmy_type(LemmyErrorType::InvalidRegex)?;
+      Ok(CompiledRule { regex, rule })
+    })
+    .collect::<LemmyResult<Vec<_>>>()?;
+  if let Ok(mut lock) = PLUGIN_RULES.write() {
+    *lock = compiled;
+  }
+  Ok(())
+}
+
+#[derive(Debug)]
+pub struct PluginInline {
+  tag: String,
+  class: Option<String>,
+  content: String,
+  title: Option<String>,
+}
+
+impl NodeValue for PluginInline {
+  fn render(&self, node: &Node, fmt: &mut dyn Renderer) {
+    let mut attrs = node.attrs.clone();
+    if let Some(class) = &self.class {
+      attrs.push(("class", class.clone()));
+    }
+    if let Some(title) = &self.title {
+      attrs.push(("title", title.clone()));
+    }
+
+    fmt.open(&self.tag, &attrs);
+    fmt.text(&self.content);
+    fmt.close(&self.tag);
+  }
+}
+
+struct PluginInlineRule;
+
+impl CoreRule for PluginInlineRule {
+  fn run(root: &mut Node, _: &MarkdownIt) {
+    let Ok(rules) = PLUGIN_RULES.read() else {
+      return;
+    };
+    if rules.is_empty() {
+      return;
+    }
+
+    root.walk_mut(|node, _| {
+      if !node.children.iter().any(|c| c.is::<Text>()) {
+        return;
+      }
+      let children = std::mem::take(&mut node.children);
+      for child in children {
+        match child.cast::<Text>() {
+          Some(text) => node.children.extend(split_text(&text.content, &rules)),
+          None => node.children.push(child),
+        }
+      }
+    });
+  }
+}
+
+/// Split a text node at each match of a plugin rule. Matches are converted to [PluginInline] nodes
+/// which dont have children, so they wont be split again.
+fn split_text(text: &str, rules: &[CompiledRule]) -> Vec<Node> {
+  let mut nodes = vec![];
+  let mut rest = text;
+  while !rest.is_empty() {
+    // Use the rule which matches first
+    let first = rules
+      .iter()
+      .filter_map(|r| r.regex.captures(rest).map(|c| (r, c)))
+      .filter(|(_, c)| !c.get_match().is_empty())
+      .min_by_key(|(_, c)| c.get_match().start());
+    let Some((compiled, captures)) = first else {
+      break;
+    };
+    let m = captures.get_match();
+    if m.start() > 0 {
+      nodes.push(Node::new(Text {
+        content: rest[..m.start()].to_string(),
+      }));
+    }
+    let expand = |template: &str| {
+      let mut out = String::new();
+      captures.expand(template, &mut out);
+      out
+    };
+    let rule = &compiled.rule;
+    nodes.push(Node::new(PluginInline {
+      tag: rule.tag.clone(),
+      class: rule.class.clone(),
+      content: rule
+        .content
+        .as_deref()
+        .map(expand)
+        .unwrap_or_else(|| m.as_str().to_string()),
+      title: rule.title.as_deref().map(expand),
+    }));
+    rest = &rest[m.end()..];
+  }
+  if !rest.is_empty() {
+    nodes.push(Node::new(Text {
+      content: rest.to_string(),
+    }));
+  }
+  nodes
+}
+
+pub fn add(md: &mut MarkdownIt) {
+  md.add_rule::<PluginInlineRule>()
+    .after::<HeadingAnchorRule>();
+}
@@ -0,0 +1,21 @@
+use markdown_it::{MarkdownIt, Node, NodeValue, Renderer, generics::inline::emph_pair};
+
+/// Inline spoiler written as `||hidden text||`. Block spoilers are handled by
+/// `markdown_it_block_spoiler`.
+#[derive(Debug)]
+pub struct InlineSpoiler;
+
+impl NodeValue for InlineSpoiler {
+  fn render(&self, node: &Node, fmt: &mut dyn Renderer) {
+    let mut attrs = node.attrs.clone();
+    attrs.push(("class", "spoiler".to_string()));
+
+    fmt.open("span", &attrs);
+    fmt.contents(&node.children);
+    fmt.close("span");
+  }
+}
+
+pub fn add(md: &mut MarkdownIt) {
+  emph_pair::add_with::<'|', 2, true>(md, || Node::new(InlineSpoiler));
+}
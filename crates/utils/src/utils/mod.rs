@@ -1,4 +1,5 @@
 pub mod markdown;
 pub mod mention;
+pub mod schedule;
 pub mod slurs;
 pub mod validation;
@@ -0,0 +1,243 @@
+use crate::error::{LemmyErrorType, LemmyResult};
+use chrono::{DateTime, Datelike, Days, Duration, NaiveTime, TimeZone, Timelike, Utc};
+
+/// How far ahead to look for the next matching time. Needs to be more than four years so that
+/// schedules for the 29th of February can be satisfied.
+const MAX_LOOKAHEAD_DAYS: i64 = 5 * 366;
+
+/// A cron-like schedule, used for recurring posts. All times are in UTC.
+///
+/// Uses the standard five fields: minute, hour, day of month, month and day of week. Each field
+/// can be `*`, a single value, a range (`1-5`), a list (`1,15`) or a step (`*/2`, `10-30/5`). Day
+/// of week uses 0 or 7 for Sunday. The shortcuts `@daily`, `@weekly`, `@monthly` and `@yearly` are
+/// also accepted.
+///
+/// Like in cron, if both day of month and day of week are restricted, a day matches if either of
+/// them matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+  minutes: u64,
+  hours: u64,
+  days_of_month: u64,
+  months: u64,
+  days_of_week: u64,
+  day_of_month_restricted: bool,
+  day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+  pub fn parse(schedule: &str) -> LemmyResult<Self> {
+    let schedule = match schedule.trim() {
+      "@yearly" | "@annually" => "0 0 1 1 *",
+      "@monthly" => "0 0 1 * *",
+      "@weekly" => "0 0 * * 0",
+      "@daily" | "@midnight" => "0 0 * * *",
+      s => s,
+    };
+    let fields: Vec<&str> = schedule.split_whitespace().collect();
+    let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+      Err(LemmyErrorType::InvalidCronSchedule)?
+    };
+
+    // Sunday can be written as 0 or 7
+    let mut days_of_week = parse_field(day_of_week, 0, 7)?;
+    if days_of_week & (1 << 7) != 0 {
+      days_of_week = (days_of_week | 1) & !(1 << 7);
+    }
+
+    Ok(Self {
+      minutes: parse_field(minute, 0, 59)?,
+      hours: parse_field(hour, 0, 23)?,
+      days_of_month: parse_field(day_of_month, 1, 31)?,
+      months: parse_field(month, 1, 12)?,
+      days_of_week,
+      day_of_month_restricted: day_of_month != "*",
+      day_of_week_restricted: day_of_week != "*",
+    })
+  }
+
+  /// Returns the first matching time which is strictly after the given time, or `None` if the
+  /// schedule can never be satisfied (eg the 31st of February).
+  pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let limit = after + Duration::days(MAX_LOOKAHEAD_DAYS);
+    let mut time = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+    while time < limit {
+      if !matches(self.months, time.month()) {
+        let (year, month) = if time.month() == 12 {
+          (time.year() + 1, 1)
+        } else {
+          (time.year(), time.month() + 1)
+        };
+        time = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).single()?;
+      } else if !self.day_matches(time) {
+        let next_day = time.date_naive().checked_add_days(Days::new(1))?;
+        time = next_day.and_time(NaiveTime::MIN).and_utc();
+      } else if !matches(self.hours, time.hour()) {
+        time = time.with_minute(0)? + Duration::hours(1);
+      } else if !matches(self.minutes, time.minute()) {
+        time += Duration::minutes(1);
+      } else {
+        return Some(time);
+      }
+    }
+    None
+  }
+
+  fn day_matches(&self, time: DateTime<Utc>) -> bool {
+    let day_of_month = matches(self.days_of_month, time.day());
+    let day_of_week = matches(self.days_of_week, time.weekday().num_days_from_sunday());
+    if self.day_of_month_restricted && self.day_of_week_restricted {
+      day_of_month || day_of_week
+    } else {
+      day_of_month && day_of_week
+    }
+  }
+}
+
+fn matches(field: u64, value: u32) -> bool {
+  field & (1 << value) != 0
+}
+
+/// Parses a single cron field into a bitset of the allowed values.
+fn parse_field(field: &str, min: u32, max: u32) -> LemmyResult<u64> {
+  let number = |s: &str| {
+    s.parse::<u32>()
+      .ok()
+      .filter(|n| (min..=max).contains(n))
+      .ok_or(LemmyErrorType::InvalidCronSchedule)
+  };
+
+  let mut bits = 0;
+  for part in field.split(',') {
+    let (range, step) = match part.split_once('/') {
+      Some((range, step)) => {
+        let step = step
+          .parse::<usize>()
+          .ok()
+          .filter(|s| *s > 0)
+          .ok_or(LemmyErrorType::InvalidCronSchedule)?;
+        (range, Some(step))
+      }
+      None => (part, None),
+    };
+    let (start, end) = if range == "*" {
+      (min, max)
+    } else if let Some((start, end)) = range.split_once('-') {
+      (number(start)?, number(end)?)
+    } else {
+      let start = number(range)?;
+      // `5/10` means every 10 starting at 5
+      (start, if step.is_some() { max } else { start })
+    };
+    if start > end {
+      Err(LemmyErrorType::InvalidCronSchedule)?
+    }
+    for value in (start..=end).step_by(step.unwrap_or(1)) {
+      bits |= 1 << value;
+    }
+  }
+  Ok(bits)
+}
+
+/// Replaces date placeholders in the title of a recurring post.
+///
+/// Supported are `{date}` (2026-01-31), `{year}`, `{month}` (01), `{month_name}` (January), `{day}`
+/// (31), `{weekday}` (Saturday) and `{week}` (ISO week number).
+pub fn fill_date_placeholders(template: &str, date: DateTime<Utc>) -> String {
+  [
+    ("{date}", "%Y-%m-%d"),
+    ("{year}", "%Y"),
+    ("{month}", "%m"),
+    ("{month_name}", "%B"),
+    ("{day}", "%d"),
+    ("{weekday}", "%A"),
+    ("{week}", "%V"),
+  ]
+  .iter()
+  .fold(template.to_string(), |title, (placeholder, format)| {
+    title.replace(placeholder, &date.format(format).to_string())
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{CronSchedule, fill_date_placeholders};
+  use crate::error::LemmyResult;
+  use chrono::{DateTime, TimeZone, Utc};
+  use pretty_assertions::assert_eq;
+
+  fn time(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc
+      .with_ymd_and_hms(year, month, day, hour, minute, 0)
+      .single()
+      .unwrap_or_default()
+  }
+
+  #[test]
+  fn test_parse_invalid() {
+    assert!(CronSchedule::parse("").is_err());
+    assert!(CronSchedule::parse("* * * *").is_err());
+    assert!(CronSchedule::parse("60 * * * *").is_err());
+    assert!(CronSchedule::parse("* 24 * * *").is_err());
+    assert!(CronSchedule::parse("* * 0 * *").is_err());
+    assert!(CronSchedule::parse("* * * 13 *").is_err());
+    assert!(CronSchedule::parse("* * * * 8").is_err());
+    assert!(CronSchedule::parse("*/0 * * * *").is_err());
+    assert!(CronSchedule::parse("5-1 * * * *").is_err());
+    assert!(CronSchedule::parse("a * * * *").is_err());
+  }
+
+  #[test]
+  fn test_next_after() -> LemmyResult<()> {
+    // Every monday at 12:00, starting on a saturday
+    let weekly = CronSchedule::parse("0 12 * * 1")?;
+    let start = time(2026, 1, 31, 15, 27);
+    assert_eq!(Some(time(2026, 2, 2, 12, 0)), weekly.next_after(start));
+    // Strictly after the given time
+    let monday = time(2026, 2, 2, 12, 0);
+    assert_eq!(Some(time(2026, 2, 9, 12, 0)), weekly.next_after(monday));
+
+    // Sunday can be 0 or 7
+    assert_eq!(
+      CronSchedule::parse("@weekly")?,
+      CronSchedule::parse("0 0 * * 7")?
+    );
+
+    let monthly = CronSchedule::parse("@monthly")?;
+    assert_eq!(Some(time(2026, 2, 1, 0, 0)), monthly.next_after(start));
+
+    let steps = CronSchedule::parse("*/15 9-17 * * 1-5")?;
+    assert_eq!(Some(time(2026, 2, 2, 9, 0)), steps.next_after(start));
+    assert_eq!(
+      Some(time(2026, 2, 2, 9, 15)),
+      steps.next_after(time(2026, 2, 2, 9, 0))
+    );
+
+    // Either day of month or day of week needs to match
+    let either = CronSchedule::parse("0 0 13 * 5")?;
+    assert_eq!(Some(time(2026, 2, 6, 0, 0)), either.next_after(start));
+
+    let leap_day = CronSchedule::parse("0 0 29 2 *")?;
+    assert_eq!(Some(time(2028, 2, 29, 0, 0)), leap_day.next_after(start));
+
+    let never = CronSchedule::parse("0 0 31 2 *")?;
+    assert_eq!(None, never.next_after(start));
+    Ok(())
+  }
+
+  #[test]
+  fn test_fill_date_placeholders() {
+    let date = time(2026, 1, 31, 15, 27);
+    assert_eq!(
+      "Weekly discussion 2026-01-31",
+      fill_date_placeholders("Weekly discussion {date}", date)
+    );
+    assert_eq!(
+      "Help thread, Saturday 31 January 2026 (week 05)",
+      fill_date_placeholders(
+        "Help thread, {weekday} {day} {month_name} {year} (week {week})",
+        date
+      )
+    );
+  }
+}
@@ -31,6 +31,10 @@ const MIN_LENGTH_BLOCKING_KEYWORD: usize = 3;
 const MAX_LENGTH_BLOCKING_KEYWORD: usize = 50;
 const ACTOR_NAME_MAX_LENGTH: usize = 20;
 const DISPLAY_NAME_MAX_LENGTH: usize = 50;
+const RULE_TITLE_MAX_LENGTH: usize = 200;
+const MODMAIL_SUBJECT_MAX_LENGTH: usize = 200;
+const STRIKE_MAX_THRESHOLD: i32 = 100;
+const STRIKE_MAX_DAYS: i32 = 3650;
 
 fn has_newline(name: &str) -> bool {
   name.contains('\n')
@@ -354,6 +358,61 @@ pub fn truncate_description(text: &str) -> String {
   truncate_for_db(text, SITE_SUMMARY_MAX_LENGTH)
 }
 
+/// Rule titles are limited to the same length as post titles.
+pub fn truncate_rule_title(text: &str) -> String {
+  truncate_for_db(text, RULE_TITLE_MAX_LENGTH)
+}
+
+/// Subjects of remote mod mail threads are cut to the length which local ones are validated
+/// against.
+pub fn truncate_modmail_subject(text: &str) -> String {
+  truncate_for_db(text, MODMAIL_SUBJECT_MAX_LENGTH)
+}
+
+/// Checks the warning escalation settings of a community or site. Zero means unchanged for the
+/// day counts, and disables escalation for the threshold.
+pub fn check_strike_settings(
+  threshold: Option<i32>,
+  window_days: Option<i32>,
+  ban_days: Option<i32>,
+) -> LemmyResult<()> {
+  let in_range = |val: Option<i32>, max: i32| val.is_none_or(|v| (0..=max).contains(&v));
+  if !in_range(threshold, STRIKE_MAX_THRESHOLD)
+    || !in_range(window_days, STRIKE_MAX_DAYS)
+    || !in_range(ban_days, STRIKE_MAX_DAYS)
+  {
+    Err(LemmyErrorType::InvalidStrikeSettings)?
+  }
+  Ok(())
+}
+
+/// Checks the modlog retention settings of the site. Zero disables them.
+pub fn check_modlog_retention(
+  retention_days: Option<i32>,
+  anonymize_days: Option<i32>,
+) -> LemmyResult<()> {
+  if retention_days.is_some_and(|d| d < 0) || anonymize_days.is_some_and(|d| d < 0) {
+    Err(LemmyErrorType::InvalidModlogRetention)?
+  }
+  Ok(())
+}
+
+/// Drafts need to be kept for at least a day.
+pub fn check_draft_max_age_days(max_age_days: Option<i32>) -> LemmyResult<()> {
+  if max_age_days.is_some_and(|d| d < 1) {
+    Err(LemmyErrorType::InvalidDraftMaxAge)?
+  }
+  Ok(())
+}
+
+/// Checks an upload quota in megabytes. Zero removes the quota.
+pub fn check_upload_quota_mb(quota_mb: Option<i32>) -> LemmyResult<()> {
+  if quota_mb.is_some_and(|q| q < 0) {
+    Err(LemmyErrorType::InvalidUploadQuota)?
+  }
+  Ok(())
+}
+
 pub fn check_api_elements_count(len: usize) -> LemmyResult<()> {
   if len >= MAX_API_PARAM_ELEMENTS {
     Err(LemmyErrorType::TooManyItems)?
@@ -371,6 +430,10 @@ mod tests {
       SITE_SUMMARY_MAX_LENGTH,
       URL_MAX_LENGTH,
       build_and_check_regex,
+      check_draft_max_age_days,
+      check_modlog_retention,
+      check_strike_settings,
+      check_upload_quota_mb,
       check_urls_are_valid,
       clean_url,
       clean_urls_in_text,
@@ -384,6 +447,8 @@ mod tests {
       site_name_length_check,
       summary_length_check,
       truncate_for_db,
+      truncate_modmail_subject,
+      truncate_rule_title,
     },
   };
   use pretty_assertions::assert_eq;
@@ -695,7 +760,47 @@ Line3",
     assert_eq!("Wales: 🏴󠁧󠁢󠁷󠁬󠁳󠁿", truncate_for_db("Wales: 🏴󠁧󠁢󠁷󠁬󠁳󠁿", 14));
     assert_eq!("it’s", truncate_for_db("it’s like this", 4));
     assert_eq!("🤦🏼‍♂️150", truncate_for_db("🤦🏼‍♂️150🤦🏼‍♂️", 11));
+    assert_eq!(200, truncate_rule_title(&"a".repeat(300)).chars().count());
+    assert_eq!(
+      200,
+      truncate_modmail_subject(&"a".repeat(300)).chars().count()
+    );
 
     Ok(())
   }
+
+  #[test]
+  fn test_check_strike_settings() {
+    assert!(check_strike_settings(None, None, None).is_ok());
+    assert!(check_strike_settings(Some(0), Some(0), Some(0)).is_ok());
+    assert!(check_strike_settings(Some(3), Some(30), Some(7)).is_ok());
+    assert!(check_strike_settings(Some(-1), None, None).is_err());
+    assert!(check_strike_settings(None, Some(-5), None).is_err());
+    assert!(check_strike_settings(None, None, Some(i32::MAX)).is_err());
+    assert!(check_strike_settings(Some(1000), None, None).is_err());
+  }
+
+  #[test]
+  fn test_check_modlog_retention() {
+    assert!(check_modlog_retention(None, None).is_ok());
+    assert!(check_modlog_retention(Some(0), Some(30)).is_ok());
+    assert!(check_modlog_retention(Some(-1), None).is_err());
+    assert!(check_modlog_retention(None, Some(-30)).is_err());
+  }
+
+  #[test]
+  fn test_check_draft_max_age_days() {
+    assert!(check_draft_max_age_days(None).is_ok());
+    assert!(check_draft_max_age_days(Some(1)).is_ok());
+    assert!(check_draft_max_age_days(Some(0)).is_err());
+    assert!(check_draft_max_age_days(Some(-7)).is_err());
+  }
+
+  #[test]
+  fn test_check_upload_quota_mb() {
+    assert!(check_upload_quota_mb(None).is_ok());
+    assert!(check_upload_quota_mb(Some(0)).is_ok());
+    assert!(check_upload_quota_mb(Some(100)).is_ok());
+    assert!(check_upload_quota_mb(Some(-1)).is_err());
+  }
 }
@@ -0,0 +1,7 @@
+ALTER TABLE local_site
+    DROP COLUMN draft_max_age_days;
+
+DROP TABLE draft;
+
+DROP TYPE draft_kind_enum;
+
@@ -0,0 +1,47 @@
+CREATE TYPE draft_kind_enum AS ENUM (
+    'Post',
+    'Comment',
+    'PrivateMessage'
+);
+
+-- Unpublished posts, comments and private messages, stored per local user
+CREATE TABLE draft (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    kind draft_kind_enum NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    parent_id int REFERENCES COMMENT ON UPDATE CASCADE ON DELETE CASCADE,
+    recipient_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    name varchar(200),
+    url varchar(2000),
+    body text,
+    alt_text text,
+    nsfw boolean,
+    language_id int REFERENCES LANGUAGE ON UPDATE CASCADE ON DELETE SET NULL,
+    custom_thumbnail varchar(2000),
+    scheduled_publish_time_at timestamptz,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    -- Drafts only reference the targets which make sense for their kind
+    CONSTRAINT draft_kind_target_check CHECK ((kind = 'Post'
+        AND post_id IS NULL
+        AND parent_id IS NULL
+        AND recipient_id IS NULL)
+        OR (kind = 'Comment'
+            AND community_id IS NULL
+            AND post_id IS NOT NULL
+            AND recipient_id IS NULL)
+        OR (kind = 'PrivateMessage'
+            AND community_id IS NULL
+            AND post_id IS NULL
+            AND parent_id IS NULL
+            AND recipient_id IS NOT NULL))
+);
+
+CREATE INDEX idx_draft_local_user_published ON draft (local_user_id, published_at DESC);
+
+-- Drafts which haven't been touched for this many days are deleted by a scheduled task
+ALTER TABLE local_site
+    ADD COLUMN draft_max_age_days int NOT NULL DEFAULT 90;
+
@@ -0,0 +1,4 @@
+DROP TABLE recurring_post_tag;
+
+DROP TABLE recurring_post;
+
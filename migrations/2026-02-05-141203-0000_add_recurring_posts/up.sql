@@ -0,0 +1,34 @@
+-- Templates for posts which are created by a scheduled task, eg weekly discussion threads
+CREATE TABLE recurring_post (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    -- The account which the posts are created from
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    -- Cron expression, in UTC
+    schedule varchar(100) NOT NULL,
+    -- Post title, which can contain date placeholders like {date}
+    name varchar(200) NOT NULL,
+    body text,
+    language_id int REFERENCES LANGUAGE ON UPDATE CASCADE ON DELETE SET NULL,
+    nsfw boolean NOT NULL DEFAULT FALSE,
+    feature_post boolean NOT NULL DEFAULT FALSE,
+    unfeature_previous boolean NOT NULL DEFAULT FALSE,
+    previous_post_id int REFERENCES post ON UPDATE CASCADE ON DELETE SET NULL,
+    next_publish_at timestamptz,
+    enabled boolean NOT NULL DEFAULT TRUE,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_recurring_post_community ON recurring_post (community_id);
+
+CREATE INDEX idx_recurring_post_next_publish ON recurring_post (next_publish_at)
+WHERE
+    enabled;
+
+CREATE TABLE recurring_post_tag (
+    recurring_post_id int REFERENCES recurring_post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    tag_id int REFERENCES tag ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    PRIMARY KEY (recurring_post_id, tag_id)
+);
+
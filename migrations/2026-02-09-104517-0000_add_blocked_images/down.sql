@@ -0,0 +1,5 @@
+DROP TABLE blocked_image;
+
+ALTER TABLE local_image
+    DROP COLUMN flagged_reason;
+
@@ -0,0 +1,12 @@
+-- Perceptual hashes of images which can't be uploaded or proxied
+CREATE TABLE blocked_image (
+    id serial PRIMARY KEY,
+    hash bigint NOT NULL UNIQUE,
+    reason text,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+-- Set by plugins which let an upload through, but want admins to review it
+ALTER TABLE local_image
+    ADD COLUMN flagged_reason text;
+
@@ -0,0 +1,14 @@
+ALTER TABLE local_image
+    DROP COLUMN file_size;
+
+ALTER TABLE local_site
+    DROP COLUMN upload_quota_mb,
+    DROP COLUMN moderator_upload_quota_mb;
+
+ALTER TABLE local_user
+    DROP COLUMN upload_quota_mb;
+
+SELECT
+    modlog_kind_remove ('AdminPurgeUserMedia');
+
+DROP FUNCTION modlog_kind_add, modlog_kind_remove, modlog_kind_recreate;
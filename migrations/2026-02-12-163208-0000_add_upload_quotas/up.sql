@@ -0,0 +1,103 @@
+-- Size of the stored file in bytes, zero for images uploaded before this was tracked
+ALTER TABLE local_image
+    ADD COLUMN file_size bigint NOT NULL DEFAULT 0;
+
+-- Upload quotas in megabytes, null means unlimited. Admins have no quota.
+ALTER TABLE local_site
+    ADD COLUMN upload_quota_mb int,
+    ADD COLUMN moderator_upload_quota_mb int;
+
+-- Overrides the quota from local_site for a single user
+ALTER TABLE local_user
+    ADD COLUMN upload_quota_mb int;
+
+-- Adding a modlog kind requires recreating the enum, because the check constraint can't reference
+-- values added with `ADD VALUE` in the same transaction. These functions do that, and extend or
+-- shrink the check constraint with the targets which entries of the kind must have. Kinds are
+-- removed in the opposite order in which they were added.
+CREATE FUNCTION modlog_kind_recreate (kinds text, new_check text)
+    RETURNS void
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    ALTER TABLE modlog
+        DROP CONSTRAINT modlog_check;
+    EXECUTE format('CREATE TYPE modlog_kind_tmp AS enum (%s)', kinds);
+    ALTER TABLE modlog
+        ALTER COLUMN kind TYPE modlog_kind_tmp
+        USING (kind::text::modlog_kind_tmp);
+    DROP TYPE modlog_kind;
+    ALTER TYPE modlog_kind_tmp RENAME TO modlog_kind;
+    EXECUTE 'ALTER TABLE modlog ADD CONSTRAINT modlog_check ' || new_check;
+END
+$$;
+
+CREATE FUNCTION modlog_kind_add (new_kind text, target_check text)
+    RETURNS void
+    LANGUAGE plpgsql
+    AS $$
+DECLARE
+    kinds text;
+    old_check text;
+BEGIN
+    -- The type is looked up by name, as it changes with every call
+    SELECT
+        string_agg(quote_literal(enumlabel), ', ' ORDER BY enumsortorder) INTO kinds
+    FROM
+        pg_enum
+    WHERE
+        enumtypid = to_regtype('modlog_kind');
+    SELECT
+        pg_get_constraintdef(oid) INTO old_check
+    FROM
+        pg_constraint
+    WHERE
+        conrelid = 'modlog'::regclass
+        AND conname = 'modlog_check';
+    -- The constraint is formatted as `CHECK ((... OR ...))`, so the new kind is inserted before the
+    -- closing parentheses.
+    PERFORM
+        modlog_kind_recreate (kinds || ', ' || quote_literal(new_kind), left(old_check, -2) || format(' OR ((kind = %L) AND %s)))', new_kind, target_check));
+END
+$$;
+
+CREATE FUNCTION modlog_kind_remove (old_kind text)
+    RETURNS void
+    LANGUAGE plpgsql
+    AS $$
+DECLARE
+    kinds text;
+    old_check text;
+    kind_position int;
+BEGIN
+    DELETE FROM modlog
+    WHERE kind::text = old_kind;
+    SELECT
+        string_agg(quote_literal(enumlabel), ', ' ORDER BY enumsortorder) INTO kinds
+    FROM
+        pg_enum
+    WHERE
+        enumtypid = to_regtype('modlog_kind')
+        AND enumlabel <> old_kind;
+    SELECT
+        pg_get_constraintdef(oid) INTO old_check
+    FROM
+        pg_constraint
+    WHERE
+        conrelid = 'modlog'::regclass
+        AND conname = 'modlog_check';
+    kind_position := position(format(' OR ((kind = %L::', old_kind) IN old_check);
+    IF kind_position = 0 THEN
+        RAISE EXCEPTION 'modlog kind % is not in modlog_check', old_kind;
+    END IF;
+    IF position(' OR ((kind = ' IN substr(old_check, kind_position + 1)) > 0 THEN
+        RAISE EXCEPTION 'modlog kinds added after % need to be removed first', old_kind;
+    END IF;
+    PERFORM
+        modlog_kind_recreate (kinds, left(old_check, kind_position - 1) || '))');
+END
+$$;
+
+-- Purging the media of a user is logged as well
+SELECT
+    modlog_kind_add ('AdminPurgeUserMedia', 'num_nonnulls (target_person_id) = 1 AND num_nonnulls (target_community_id, target_post_id, target_instance_id, target_comment_id) = 0');
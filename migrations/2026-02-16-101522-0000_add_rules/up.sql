@@ -0,0 +1,24 @@
+-- Numbered rules which are defined by a community, or by the site if community_id is null
+CREATE TABLE rule (
+    id serial PRIMARY KEY,
+    ap_id text NOT NULL UNIQUE DEFAULT generate_unique_changeme (),
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    title varchar(255) NOT NULL,
+    description text,
+    position int NOT NULL DEFAULT 0,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    deleted boolean NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX idx_rule_community ON rule (community_id);
+
+ALTER TABLE post_report
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
+
+ALTER TABLE comment_report
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
+
+ALTER TABLE modlog
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
+
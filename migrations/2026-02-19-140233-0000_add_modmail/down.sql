@@ -0,0 +1,4 @@
+DROP TABLE modmail_message;
+
+DROP TABLE modmail_thread;
+
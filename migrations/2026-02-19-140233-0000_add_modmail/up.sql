@@ -0,0 +1,33 @@
+-- Conversations between a user and the moderator team of a community
+CREATE TABLE modmail_thread (
+    id serial PRIMARY KEY,
+    ap_id varchar(255) NOT NULL UNIQUE,
+    community_id int NOT NULL REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    subject varchar(255) NOT NULL,
+    archived boolean NOT NULL DEFAULT FALSE,
+    local boolean NOT NULL DEFAULT TRUE,
+    creator_read_at timestamptz NOT NULL DEFAULT now(),
+    mods_read_at timestamptz,
+    last_message_at timestamptz NOT NULL DEFAULT now(),
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_modmail_thread_community ON modmail_thread (community_id, last_message_at DESC);
+
+CREATE INDEX idx_modmail_thread_creator ON modmail_thread (creator_id, last_message_at DESC);
+
+CREATE TABLE modmail_message (
+    id serial PRIMARY KEY,
+    ap_id varchar(255) NOT NULL UNIQUE,
+    thread_id int NOT NULL REFERENCES modmail_thread ON UPDATE CASCADE ON DELETE CASCADE,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL,
+    from_mod boolean NOT NULL DEFAULT FALSE,
+    local boolean NOT NULL DEFAULT TRUE,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_modmail_message_thread ON modmail_message (thread_id, published_at);
+
@@ -0,0 +1,15 @@
+-- Notes about a user which are shared between all mods of a community, or between all admins if
+-- community_id is null
+CREATE TABLE mod_note (
+    id serial PRIMARY KEY,
+    target_person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE SET NULL,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE SET NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_mod_note_target_community ON mod_note (target_person_id, community_id);
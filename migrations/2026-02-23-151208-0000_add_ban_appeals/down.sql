@@ -0,0 +1,7 @@
+DROP TABLE ban_appeal;
+
+SELECT
+    modlog_kind_remove ('AdminDenyBanAppeal');
+
+SELECT
+    modlog_kind_remove ('ModDenyBanAppeal');
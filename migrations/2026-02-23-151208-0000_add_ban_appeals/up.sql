@@ -0,0 +1,27 @@
+-- Appeals of banned users against a community ban, or against a site ban if community_id is null.
+-- Each ban is identified by its banned_at timestamp, so that only one appeal can be submitted per
+-- ban.
+CREATE TABLE ban_appeal (
+    id serial PRIMARY KEY,
+    person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL,
+    banned_at timestamptz NOT NULL,
+    resolver_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    accepted boolean NOT NULL DEFAULT FALSE,
+    deny_reason text,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    resolved_at timestamptz
+);
+
+CREATE UNIQUE INDEX idx_ban_appeal_unique ON ban_appeal (person_id, coalesce(community_id, 0), banned_at);
+
+CREATE INDEX idx_ban_appeal_community_unresolved ON ban_appeal (community_id)
+WHERE
+    resolved_at IS NULL;
+
+SELECT
+    modlog_kind_add ('ModDenyBanAppeal', 'num_nonnulls (target_community_id, target_person_id) = 2 AND num_nonnulls (target_post_id, target_instance_id, target_comment_id) = 0');
+
+SELECT
+    modlog_kind_add ('AdminDenyBanAppeal', 'num_nonnulls (target_person_id, target_instance_id) = 2 AND num_nonnulls (target_community_id, target_post_id, target_comment_id) = 0');
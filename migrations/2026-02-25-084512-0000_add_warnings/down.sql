@@ -0,0 +1,15 @@
+ALTER TABLE community
+    DROP COLUMN strike_ban_threshold,
+    DROP COLUMN strike_window_days,
+    DROP COLUMN strike_ban_days;
+
+ALTER TABLE local_site
+    DROP COLUMN strike_ban_threshold,
+    DROP COLUMN strike_window_days,
+    DROP COLUMN strike_ban_days;
+
+SELECT
+    modlog_kind_remove ('AdminWarnUser');
+
+SELECT
+    modlog_kind_remove ('ModWarnUser');
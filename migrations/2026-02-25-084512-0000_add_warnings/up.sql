@@ -0,0 +1,18 @@
+-- Settings for automatic escalation of warnings. Once a user has received strike_ban_threshold
+-- warnings within strike_window_days, they are banned for strike_ban_days. Escalation is disabled
+-- if the threshold is null.
+ALTER TABLE community
+    ADD COLUMN strike_ban_threshold int,
+    ADD COLUMN strike_window_days int NOT NULL DEFAULT 30,
+    ADD COLUMN strike_ban_days int NOT NULL DEFAULT 7;
+
+ALTER TABLE local_site
+    ADD COLUMN strike_ban_threshold int,
+    ADD COLUMN strike_window_days int NOT NULL DEFAULT 30,
+    ADD COLUMN strike_ban_days int NOT NULL DEFAULT 7;
+
+SELECT
+    modlog_kind_add ('ModWarnUser', 'num_nonnulls (target_community_id, target_person_id) = 2 AND num_nonnulls (target_post_id, target_instance_id, target_comment_id) = 0');
+
+SELECT
+    modlog_kind_add ('AdminWarnUser', 'num_nonnulls (target_person_id, target_instance_id) = 2 AND num_nonnulls (target_community_id, target_post_id, target_comment_id) = 0');
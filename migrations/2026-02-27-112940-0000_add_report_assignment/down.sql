@@ -0,0 +1,5 @@
+DROP TABLE report_note;
+
+ALTER TABLE report_combined
+    DROP COLUMN assignee_id,
+    DROP COLUMN assigned_at;
@@ -0,0 +1,20 @@
+-- Reports can be assigned to a single mod or admin, who is then working on it. Stored in
+-- report_combined so that it works the same for all report types.
+ALTER TABLE report_combined
+    ADD COLUMN assignee_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    ADD COLUMN assigned_at timestamptz;
+
+CREATE INDEX idx_report_combined_assignee ON report_combined (assignee_id)
+WHERE
+    assignee_id IS NOT NULL;
+
+-- Internal comments of the mod team about a report, not visible to the report creator.
+CREATE TABLE report_note (
+    id serial PRIMARY KEY,
+    report_combined_id int NOT NULL REFERENCES report_combined ON UPDATE CASCADE ON DELETE CASCADE,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_report_note_report ON report_note (report_combined_id);
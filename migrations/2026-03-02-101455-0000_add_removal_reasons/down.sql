@@ -0,0 +1 @@
+DROP TABLE removal_reason;
@@ -0,0 +1,14 @@
+-- Predefined removal reasons of a community, which mods can select when removing a post or
+-- comment. The template can contain placeholders which are filled in on removal.
+CREATE TABLE removal_reason (
+    id serial PRIMARY KEY,
+    community_id int NOT NULL REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    title varchar(255) NOT NULL,
+    template text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    deleted boolean NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX idx_removal_reason_community ON removal_reason (community_id);
+
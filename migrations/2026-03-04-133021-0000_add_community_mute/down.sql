@@ -0,0 +1,6 @@
+ALTER TABLE community_actions
+    DROP COLUMN received_mute_at,
+    DROP COLUMN mute_expires_at;
+
+SELECT
+    modlog_kind_remove ('ModMuteFromCommunity');
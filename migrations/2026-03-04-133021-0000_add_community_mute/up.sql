@@ -0,0 +1,13 @@
+-- A muted user can still read and vote in the community, but can't create or edit posts and
+-- comments.
+ALTER TABLE community_actions
+    ADD COLUMN received_mute_at timestamptz,
+    ADD COLUMN mute_expires_at timestamptz,
+    ADD CONSTRAINT community_actions_check_received_mute CHECK (NOT (received_mute_at IS NULL AND mute_expires_at IS NOT NULL));
+
+CREATE INDEX idx_community_actions_received_mute_not_null ON community_actions (person_id, community_id)
+WHERE
+    received_mute_at IS NOT NULL;
+
+SELECT
+    modlog_kind_add ('ModMuteFromCommunity', 'num_nonnulls (target_community_id, target_person_id) = 2 AND num_nonnulls (target_post_id, target_instance_id, target_comment_id) = 0');
@@ -0,0 +1,4 @@
+ALTER TABLE local_site
+    DROP COLUMN modlog_retention_days,
+    DROP COLUMN modlog_anonymize_days;
+
@@ -0,0 +1,7 @@
+-- Limits on how much of the modlog is visible to users who aren't admins. Entries older than
+-- modlog_retention_days are hidden, and the moderator is hidden from entries older than
+-- modlog_anonymize_days. Admins can always see the full modlog.
+ALTER TABLE local_site
+    ADD COLUMN modlog_retention_days int,
+    ADD COLUMN modlog_anonymize_days int;
+
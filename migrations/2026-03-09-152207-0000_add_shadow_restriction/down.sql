@@ -0,0 +1,12 @@
+SELECT
+    modlog_kind_remove ('AdminRestrictUser');
+
+ALTER TABLE local_user
+    DROP COLUMN restricted_at;
+
+ALTER TABLE post
+    DROP COLUMN held_for_review;
+
+ALTER TABLE comment
+    DROP COLUMN held_for_review;
+
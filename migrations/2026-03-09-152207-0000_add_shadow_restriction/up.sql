@@ -0,0 +1,22 @@
+-- Shadow restrictions for suspected spammers. New content of a restricted user is only visible
+-- to the creator and to moderators, and doesn't federate until the restriction is lifted.
+ALTER TABLE local_user
+    ADD COLUMN restricted_at timestamptz;
+
+ALTER TABLE post
+    ADD COLUMN held_for_review boolean NOT NULL DEFAULT FALSE;
+
+ALTER TABLE comment
+    ADD COLUMN held_for_review boolean NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_post_held_for_review ON post (creator_id)
+WHERE
+    held_for_review;
+
+CREATE INDEX idx_comment_held_for_review ON comment (creator_id)
+WHERE
+    held_for_review;
+
+-- Restrictions are logged, but the entries are only visible to admins
+SELECT
+    modlog_kind_add ('AdminRestrictUser', 'num_nonnulls (target_person_id, target_instance_id) = 2 AND num_nonnulls (target_community_id, target_post_id, target_comment_id) = 0');
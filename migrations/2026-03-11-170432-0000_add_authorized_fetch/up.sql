@@ -0,0 +1,5 @@
+-- When enabled, apub fetches of communities, users, posts and comments require a valid HTTP
+-- signature from an instance which isn't blocked.
+ALTER TABLE local_site
+    ADD COLUMN federation_authorized_fetch boolean NOT NULL DEFAULT FALSE;
+
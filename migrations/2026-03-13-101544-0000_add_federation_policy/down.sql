@@ -0,0 +1,4 @@
+SELECT
+    modlog_kind_remove ('AdminSetInstancePolicy');
+
+DROP TABLE federation_policy;
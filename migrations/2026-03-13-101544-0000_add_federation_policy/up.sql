@@ -0,0 +1,22 @@
+-- Per-instance federation policies, which are more fine-grained than the block and allow lists.
+-- They are applied when receiving content from users or communities on the instance.
+CREATE TABLE federation_policy (
+    instance_id int PRIMARY KEY REFERENCES instance ON UPDATE CASCADE ON DELETE CASCADE,
+    silence boolean NOT NULL DEFAULT FALSE,
+    strip_media boolean NOT NULL DEFAULT FALSE,
+    force_nsfw boolean NOT NULL DEFAULT FALSE,
+    reject_reports boolean NOT NULL DEFAULT FALSE,
+    reject_votes boolean NOT NULL DEFAULT FALSE,
+    no_avatar boolean NOT NULL DEFAULT FALSE,
+    reason text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz
+);
+
+CREATE INDEX idx_federation_policy_silence ON federation_policy (instance_id)
+WHERE
+    silence;
+
+
+SELECT
+    modlog_kind_add ('AdminSetInstancePolicy', 'num_nonnulls (target_instance_id) = 1 AND num_nonnulls (target_post_id, target_person_id, target_community_id, target_comment_id) = 0');
@@ -0,0 +1,9 @@
+ALTER TABLE federation_blocklist
+    DROP COLUMN subscription_id;
+
+DROP TABLE blocklist_subscription_entry;
+
+DROP TABLE blocklist_subscription;
+
+DROP TYPE blocklist_format_enum;
+
@@ -0,0 +1,28 @@
+CREATE TYPE blocklist_format_enum AS ENUM (
+    'Csv',
+    'Lemmy'
+);
+
+-- External instance blocklists which admins subscribed to. They are synced periodically, but
+-- changes are only applied after review.
+CREATE TABLE blocklist_subscription (
+    id serial PRIMARY KEY,
+    url varchar(2000) NOT NULL UNIQUE,
+    format blocklist_format_enum NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    synced_at timestamptz
+);
+
+-- The domains listed in a subscribed blocklist as of the last sync
+CREATE TABLE blocklist_subscription_entry (
+    subscription_id int REFERENCES blocklist_subscription ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    domain varchar(255) NOT NULL,
+    reason text,
+    PRIMARY KEY (subscription_id, domain)
+);
+
+-- Instance blocks which were applied from a subscription, so that they can be lifted again when
+-- the domain is removed from the blocklist.
+ALTER TABLE federation_blocklist
+    ADD COLUMN subscription_id int REFERENCES blocklist_subscription ON UPDATE CASCADE ON DELETE SET NULL;
+
@@ -0,0 +1,28 @@
+CREATE TABLE person_report (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    original_person_name text NOT NULL,
+    original_person_display_name text,
+    original_person_bio text,
+    original_person_avatar text,
+    original_person_banner text,
+    reason text NOT NULL,
+    resolved bool NOT NULL DEFAULT FALSE,
+    resolver_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    updated_at timestamptz,
+    UNIQUE (person_id, creator_id)
+);
+
+CREATE INDEX idx_person_report_published ON person_report (published_at DESC);
+
+CREATE INDEX idx_person_report_creator ON person_report (creator_id);
+
+CREATE INDEX idx_person_report_resolver ON person_report (resolver_id);
+
+ALTER TABLE report_combined
+    ADD COLUMN person_report_id int UNIQUE REFERENCES person_report ON UPDATE CASCADE ON DELETE CASCADE,
+    DROP CONSTRAINT report_combined_check,
+    ADD CHECK (num_nonnulls (post_report_id, comment_report_id, private_message_report_id, community_report_id, person_report_id) = 1);
+
@@ -0,0 +1,3 @@
+ALTER TYPE listing_type_enum
+    ADD VALUE 'FollowedPersons';
+
@@ -0,0 +1,5 @@
+ALTER TABLE community
+    DROP COLUMN moved_to_id;
+
+SELECT
+    modlog_kind_remove ('AdminMergeCommunity');
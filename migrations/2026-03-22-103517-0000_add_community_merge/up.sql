@@ -0,0 +1,7 @@
+-- A community which was merged into another one redirects to it, and is announced with `movedTo`
+-- over federation.
+ALTER TABLE community
+    ADD COLUMN moved_to_id int REFERENCES community ON UPDATE CASCADE ON DELETE SET NULL;
+
+SELECT
+    modlog_kind_add ('AdminMergeCommunity', 'num_nonnulls (target_community_id) = 1 AND num_nonnulls (target_post_id, target_instance_id, target_person_id, target_comment_id) = 0');
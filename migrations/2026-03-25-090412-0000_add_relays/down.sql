@@ -0,0 +1,4 @@
+DROP TABLE relay;
+
+DROP TYPE relay_mode_enum;
+
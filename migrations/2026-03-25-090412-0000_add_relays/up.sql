@@ -0,0 +1,23 @@
+CREATE TYPE relay_mode_enum AS ENUM (
+    'KnownCommunities',
+    'Discover'
+);
+
+-- ActivityPub relays which the site actor follows, to receive content from communities without
+-- local followers.
+CREATE TABLE relay (
+    id serial PRIMARY KEY,
+    ap_id varchar(255) NOT NULL UNIQUE,
+    inbox_url varchar(255) NOT NULL,
+    public_key text NOT NULL,
+    instance_id int REFERENCES instance ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    mode relay_mode_enum NOT NULL,
+    follow_state community_follower_state NOT NULL DEFAULT 'Pending',
+    published_at timestamptz NOT NULL DEFAULT now(),
+    last_refreshed_at timestamptz NOT NULL DEFAULT now(),
+    last_received_at timestamptz,
+    announces_received bigint NOT NULL DEFAULT 0,
+    posts_imported bigint NOT NULL DEFAULT 0,
+    communities_discovered bigint NOT NULL DEFAULT 0
+);
+
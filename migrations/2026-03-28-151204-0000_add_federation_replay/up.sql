@@ -0,0 +1,21 @@
+-- Requests from admins to resend activities to a single instance. They are processed by the
+-- federation queue.
+CREATE TABLE federation_replay (
+    id serial PRIMARY KEY,
+    instance_id int REFERENCES instance ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    -- If set, only activities of this community are resent. Otherwise the queue of the instance
+    -- is rewound.
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    start_id bigint NOT NULL,
+    end_id bigint NOT NULL,
+    last_sent_id bigint,
+    activities_sent bigint NOT NULL DEFAULT 0,
+    published_at timestamptz NOT NULL DEFAULT now(),
+    started_at timestamptz,
+    finished_at timestamptz
+);
+
+CREATE INDEX idx_federation_replay_unfinished ON federation_replay (id)
+WHERE
+    finished_at IS NULL;
+
@@ -0,0 +1,3 @@
+DROP TABLE mastodon_authorization_code;
+
+DROP TABLE mastodon_application;
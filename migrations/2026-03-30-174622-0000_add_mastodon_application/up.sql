@@ -0,0 +1,21 @@
+-- Applications registered through the Mastodon compatible api. Authorization codes are only
+-- issued for their registered redirect uris.
+CREATE TABLE mastodon_application (
+    id serial PRIMARY KEY,
+    client_id text NOT NULL UNIQUE,
+    client_secret text NOT NULL,
+    name text NOT NULL,
+    website text,
+    redirect_uris text[] NOT NULL,
+    scopes text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
+
+-- Short lived codes which are exchanged once for a new login token.
+CREATE TABLE mastodon_authorization_code (
+    code text PRIMARY KEY,
+    application_id int REFERENCES mastodon_application ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    redirect_uri text NOT NULL,
+    published_at timestamptz NOT NULL DEFAULT now()
+);
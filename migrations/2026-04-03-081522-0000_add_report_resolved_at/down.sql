@@ -0,0 +1,3 @@
+ALTER TABLE report_combined
+    DROP COLUMN resolved_at;
+
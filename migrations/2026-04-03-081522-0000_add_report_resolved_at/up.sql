@@ -0,0 +1,24 @@
+-- The time when a report was resolved, for report metrics. It is kept up to date by a trigger on
+-- the report tables. For existing reports the last update is the best approximation.
+ALTER TABLE report_combined
+    ADD COLUMN resolved_at timestamptz;
+
+UPDATE
+    report_combined
+SET
+    resolved_at = coalesce(post_report.updated_at, comment_report.updated_at, private_message_report.updated_at, community_report.updated_at, person_report.updated_at)
+FROM
+    report_combined AS rc
+    LEFT JOIN post_report ON post_report.id = rc.post_report_id
+        AND post_report.resolved
+    LEFT JOIN comment_report ON comment_report.id = rc.comment_report_id
+        AND comment_report.resolved
+    LEFT JOIN private_message_report ON private_message_report.id = rc.private_message_report_id
+        AND private_message_report.resolved
+    LEFT JOIN community_report ON community_report.id = rc.community_report_id
+        AND community_report.resolved
+    LEFT JOIN person_report ON person_report.id = rc.person_report_id
+        AND person_report.resolved
+WHERE
+    rc.id = report_combined.id;
+
@@ -0,0 +1,3 @@
+ALTER TABLE federation_replay
+    DROP COLUMN error;
+
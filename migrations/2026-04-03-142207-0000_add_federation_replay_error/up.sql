@@ -0,0 +1,5 @@
+-- Why a replay was stopped before all activities were sent, for example because the instance is
+-- blocked or dead.
+ALTER TABLE federation_replay
+    ADD COLUMN error text;
+